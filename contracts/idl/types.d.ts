@@ -40,15 +40,23 @@ export type Unit09Program = {
 export interface Unit09Instruction {
   name:
     | "initialize"
+    | "initializeMissing"
     | "setConfig"
+    | "rotateAdmin"
     | "registerRepo"
     | "updateRepo"
     | "registerModule"
     | "updateModule"
+    | "claimModuleName"
+    | "resolveClaim"
     | "linkModuleToRepo"
+    | "copyModuleLinksFrom"
+    | "expireLink"
     | "createFork"
     | "updateForkState"
     | "recordObservation"
+    | "rotateObserverKey"
+    | "claimObserverReward"
     | "recordMetrics"
     | "setMetadata";
   docs?: string[];
@@ -174,6 +182,7 @@ export interface Unit09TypeDescription {
     | "CreateForkArgs"
     | "UpdateForkStateArgs"
     | "RecordObservationArgs"
+    | "RotateObserverKeyArgs"
     | "RecordMetricsArgs"
     | "SetMetadataArgs";
   type: Unit09StructType;
@@ -185,15 +194,19 @@ export interface Unit09TypeDescription {
 export interface Unit09EventDescription {
   name:
     | "ConfigUpdated"
+    | "ConfigDiff"
     | "RepoRegistered"
     | "RepoUpdated"
     | "RepoActivationChanged"
     | "ModuleRegistered"
     | "ModuleVersionRegistered"
+    | "ModuleUpdated"
     | "ModuleLinkedToRepo"
+    | "ModuleLinkExpired"
     | "ForkCreated"
     | "ForkUpdated"
     | "ObservationRecorded"
+    | "ObserverKeyRotated"
     | "MetricsReconciled"
     | "GlobalMetadataUpdated";
   fields: Unit09EventField[];
@@ -252,6 +265,7 @@ export interface InitializeArgs {
   maxModulesPerRepo: number; // u32
   policyRef: Uint8Array; // [u8; 32]
   lifecycleNoteRef: Uint8Array; // [u8; 32]
+  initialLifecycleState: number; // u8
 }
 
 /**
@@ -261,7 +275,35 @@ export interface SetConfigArgs {
   feeBps: number | null; // option<u16>
   maxModulesPerRepo: number | null; // option<u32>
   isActive: boolean | null; // option<bool>
+  deactivationGraceSeconds: number | null; // option<u32>
+  restrictRepoCreation: boolean | null; // option<bool>
   policyRef: Uint8Array | null; // option<[u8; 32]>
+  treasury: string | null; // option<publicKey>
+  maxLocPerObservation: bigint | null; // option<u64>
+  maxFilesPerObservation: number | null; // option<u32>
+  forkFeeLamports: bigint | null; // option<u64>
+  defaultCategory: string | null; // option<string>
+  defaultVisibility: number | null; // option<u8>
+  useCpiEvents: boolean | null; // option<bool>
+  blockParentForkDeactivation: boolean | null; // option<bool>
+  enforceUniqueUrl: boolean | null; // option<bool>
+  metadataFrozen: boolean | null; // option<bool>
+  renameCooldownSecs: number | null; // option<u32>
+  requireVerifiedModulesForFork: boolean | null;
+  maxTotalRepos: bigint | null; // option<u64>
+  maxTotalModules: bigint | null; // option<u64>
+  maxTotalForks: bigint | null; // option<u64>
+  observationRewardLamports: bigint | null; // option<u64>
+  restrictForkCreation: boolean | null; // option<bool>
+  enforceContentAddressedModules: boolean | null; // option<bool>
+}
+
+/**
+ * Args for `pause`.
+ */
+export interface PauseArgs {
+  reason: string;
+  autoUnpauseAt: bigint; // i64, 0 = until `unpause`
 }
 
 /**
@@ -273,6 +315,8 @@ export interface RegisterRepoArgs {
   url: string;
   tags: string;
   allowObservation: boolean;
+  manifestUri: string | null; // option<string>
+  manifestChecksum: Uint8Array | null; // option<[u8; 32]>
 }
 
 /**
@@ -284,6 +328,8 @@ export interface UpdateRepoArgs {
   tags: string | null;
   isActive: boolean | null;
   allowObservation: boolean | null;
+  requireSignedObservations: boolean | null;
+  expectedUpdatedAt: bigint | null; // option<i64>
 }
 
 /**
@@ -305,6 +351,9 @@ export interface RegisterModuleArgs {
   changelogUri: string;
   isStable: boolean;
   createInitialVersionSnapshot: boolean;
+  parentModule: string; // publicKey, default = root module
+  activeFrom: bigint; // i64, 0 = usable immediately
+  metadataChecksum: Uint8Array | null; // option<[u8; 32]>
 }
 
 /**
@@ -316,11 +365,15 @@ export interface UpdateModuleArgs {
   category: string | null;
   tags: string | null;
   isActive: boolean | null;
+  isDeprecated: boolean | null; // false clears replacedBy
+  replacedBy: string | null; // publicKey, only while deprecating
   createVersionSnapshot: boolean;
   newVersion: SemanticVersionTuple | null;
   versionLabel: string | null;
   changelogUri: string | null;
   isStable: boolean | null;
+  expectedUpdatedAt: bigint | null; // option<i64>
+  metadataChecksum: Uint8Array | null; // option<[u8; 32]>, new uri without one clears it
 }
 
 /**
@@ -329,6 +382,37 @@ export interface UpdateModuleArgs {
 export interface LinkModuleToRepoArgs {
   isPrimary: boolean;
   notes: string;
+  expiresAt: bigint | null; // option<i64>, 0 = permanent
+}
+
+/**
+ * Args for `linkModulesToRepo`; `(module, link)` account pairs go in
+ * `remainingAccounts`, one per entry.
+ */
+export interface LinkModulesToRepoArgs {
+  links: LinkModuleToRepoArgs[];
+}
+
+/**
+ * Args for `copyModuleLinksFrom`; `(module, link)` account pairs go in
+ * `remainingAccounts`, one per upstream module.
+ */
+export interface CopyModuleLinksFromArgs {
+  notes: string;
+}
+
+/**
+ * Args for `claimModuleName`.
+ */
+export interface ClaimModuleNameArgs {
+  proofUri: string; // https:// URL of the domain proof
+}
+
+/**
+ * Args for `resolveClaim`.
+ */
+export interface ResolveClaimArgs {
+  approve: boolean;
 }
 
 /**
@@ -342,6 +426,8 @@ export interface CreateForkArgs {
   tags: string;
   isRoot: boolean;
   depth: number | null; // option<u16>
+  allowRetiredParent: boolean;
+  expiresAt: bigint | null; // option<i64>, unix seconds
 }
 
 /**
@@ -363,6 +449,25 @@ export interface RecordObservationArgs {
   modulesTouched: number; // u32
   revision: string;
   note: string;
+  moduleContributions: [string, bigint][] | null; // option<Vec<(publicKey, u64)>>
+  backfill: boolean;
+  contentHash: Uint8Array | null; // option<[u8; 32]>
+  commits: bigint | null; // option<u64>
+}
+
+/**
+ * Args for `rotateAdmin`.
+ */
+export interface RotateAdminArgs {
+  newAdmin: string; // publicKey
+  adminThreshold: number | null; // option<u8>
+}
+
+/**
+ * Args for `rotateObserverKey`.
+ */
+export interface RotateObserverKeyArgs {
+  newObserver: string; // publicKey
 }
 
 /**
@@ -382,7 +487,9 @@ export interface RecordMetricsArgs {
  */
 export interface SetMetadataArgs {
   description: string | null;
-  tags: string | null;
+  tags: string | null; // comma-separated, replaces all tags
+  removeTags: string[];
+  addTags: string[];
   websiteUrl: string | null;
   docsUrl: string | null;
   dashboardUrl: string | null;
@@ -402,11 +509,39 @@ export interface ConfigAccount {
   feeBps: number; // u16
   maxModulesPerRepo: number; // u32
   isActive: boolean;
+  restrictForkCreation: boolean; // owners need the "forker" role
+  enforceContentAddressedModules: boolean; // module keys derived from metadata
   policyRef: Uint8Array; // [u8; 32]
+  forkFeeLamports: bigint; // u64
+  defaultVisibility: number; // u8
+  useCpiEvents: boolean;
+  blockParentForkDeactivation: boolean;
+  enforceUniqueUrl: boolean;
+  paused: boolean;
+  autoUnpauseAt: bigint; // i64
+  metadataFrozen: boolean;
+  globalKill: boolean;
+  killedAt: bigint; // i64, 0 = not killed
+  renameCooldownSecs: number; // u32, 0 = no cooldown
+  requireVerifiedModulesForFork: boolean;
+  maxTotalRepos: bigint; // u64, 0 = no cap
+  maxTotalModules: bigint; // u64, 0 = no cap
+  maxTotalForks: bigint; // u64, 0 = no cap
+  observationRewardLamports: bigint; // u64, 0 = no rewards
+  adminThreshold: number; // u8, 0 or 1 = admin alone
+  pauseReason: string;
+  defaultCategory: string;
   bump: number; // u8
   createdAt: bigint; // i64
   updatedAt: bigint; // i64
-  reserved: Uint8Array; // [u8; 64]
+}
+
+/**
+ * One bucket of a per-category histogram.
+ */
+export interface CategoryTally {
+  category: string;
+  value: bigint;
 }
 
 /**
@@ -420,9 +555,12 @@ export interface MetricsAccount {
   totalObservations: bigint;
   totalLinesOfCode: bigint;
   totalFilesProcessed: bigint;
+  modulesByVersionCount: bigint[]; // [u64; 4]: 1, 2-5, 6-20, 21+ versions
+  forksByDepth: bigint[]; // [u64; 4]: depth 0, 1, 2, 3+ at creation
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array;
+  categoryLoc: CategoryTally[];
 }
 
 /**
@@ -453,12 +591,81 @@ export interface RepoAccount {
   totalObservations: bigint;
   totalLinesOfCode: bigint;
   totalFilesProcessed: bigint;
+  totalCommitsObserved: bigint;
   totalModules: number; // u32
   lastObservedAt: bigint;
+  currentStreakDays: number; // u32
+  longestStreakDays: number; // u32
+  activityTier: number; // u8: 0 = dormant, 1 = active, 2 = hot
   lastObserver: string; // publicKey
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 64]
+  pinnedModules: string[]; // Vec<publicKey>
+  moduleCategories: CategoryTally[];
+  manifestChecksum: Uint8Array; // [u8; 32]
+  lastContentHash: Uint8Array; // [u8; 32]
+  manifestUri: string;
+  lastSignedObservationSlot: bigint; // u64, 0 until a signed observation
+}
+
+/**
+ * Claim of a repository URL by a single Repo.
+ */
+export interface RepoUrlIndexAccount {
+  urlHash: Uint8Array; // [u8; 32]
+  repo: string; // publicKey
+  createdAt: bigint; // i64
+  schemaVersion: number; // u8
+  bump: number; // u8
+  reserved: Uint8Array; // [u8; 32]
+}
+
+/**
+ * Per-(repo, observer) observation tallies.
+ */
+export interface ObserverStatsAccount {
+  repo: string; // publicKey
+  observer: string; // publicKey
+  observationCount: bigint; // u64
+  totalLinesOfCode: bigint; // u64
+  firstObservedAt: bigint; // i64
+  lastObservedAt: bigint; // i64
+  accruedReward: bigint; // u64, lamports
+  schemaVersion: number; // u8
+  bump: number; // u8
+  lastRewardedAt: bigint; // i64, 0 before the first reward
+  reserved: Uint8Array; // [u8; 16]
+}
+
+/**
+ * Per-owner fork counter; numbers the owner's forks from 0.
+ */
+export interface OwnerForkCounterAccount {
+  owner: string; // publicKey
+  forkCount: number; // u32
+  createdAt: bigint; // i64
+  schemaVersion: number; // u8
+  bump: number; // u8
+  reserved: Uint8Array; // [u8; 32]
+}
+
+export interface LanguageTally {
+  language: string;
+  linesOfCode: bigint; // u64
+}
+
+/**
+ * Per-repo cumulative lines of code by language.
+ */
+export interface RepoLanguageStatsAccount {
+  repo: string; // publicKey
+  observationCount: bigint; // u64
+  updatedAt: bigint; // i64
+  schemaVersion: number; // u8
+  bump: number; // u8
+  reserved: Uint8Array; // [u8; 32]
+  languages: LanguageTally[];
 }
 
 /**
@@ -474,12 +681,27 @@ export interface ModuleAccount {
   category: string;
   tags: string;
   isActive: boolean;
+  isDeprecated: boolean;
+  replacedBy: string; // publicKey, default = no replacement
   majorVersion: number; // u16
   minorVersion: number; // u16
   patchVersion: number; // u16
+  observedLinesOfCode: bigint; // u64
+  stableVersionCount: number; // u16
+  versionCount: number; // u16
+  lastVersionAt: bigint; // i64
+  maturityScore: number; // u8, 0..=100
+  isVerified: boolean; // set by the admin only
+  verifiedAt: bigint; // i64, 0 = not verified
+  parentModule: string; // publicKey, default = root module
+  isDerivative: boolean;
+  derivativeCount: number; // u32
+  activeFrom: bigint; // i64, launch time; 0 = no embargo
+  lastRenamedAt: bigint; // i64, 0 = never renamed
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 64]
+  metadataChecksum: Uint8Array; // [u8; 32], zeroed when unknown
 }
 
 /**
@@ -499,6 +721,7 @@ export interface ModuleVersionAccount {
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 32]
+  dependencyLock: [string, number, number, number][]; // Vec<(publicKey, u16, u16, u16)>
 }
 
 /**
@@ -515,9 +738,13 @@ export interface ForkAccount {
   isRoot: boolean;
   isActive: boolean;
   depth: number; // u16
+  childCount: number; // u32
+  expiresAt: bigint; // i64, 0 = never
+  root: string; // publicKey, first fork of the lineage
+  ownerForkIndex: number; // u32, position among the owner's forks
   createdAt: bigint;
   updatedAt: bigint;
-  reserved: Uint8Array; // [u8; 64]
+  reserved: Uint8Array; // [u8; 12]
 }
 
 /**
@@ -533,7 +760,25 @@ export interface ModuleRepoLinkAccount {
   schemaVersion: number; // u8
   createdAt: bigint;
   updatedAt: bigint;
-  reserved: Uint8Array; // [u8; 63]
+  expiresAt: bigint; // i64, 0 = permanent; expired links are inactive
+  reserved: Uint8Array; // [u8; 55]
+}
+
+/**
+ * Ownership claim on a module, resolved by the config admin.
+ */
+export interface ModuleClaimAccount {
+  module: string; // publicKey
+  claimant: string; // publicKey
+  previousAuthority: string; // publicKey
+  proofUri: string;
+  status: number; // u8: 0 = pending, 1 = approved, 2 = rejected
+  filedAt: bigint;
+  resolvedAt: bigint;
+  resolvedBy: string; // publicKey
+  schemaVersion: number; // u8
+  bump: number;
+  reserved: Uint8Array; // [u8; 32]
 }
 
 /**
@@ -542,7 +787,7 @@ export interface ModuleRepoLinkAccount {
 export interface GlobalMetadataAccount {
   bump: number;
   description: string;
-  tags: string;
+  tagsVec: string[]; // at most 8 tags of up to 32 bytes
   websiteUrl: string;
   docsUrl: string;
   dashboardUrl: string;
@@ -575,6 +820,15 @@ export interface ConfigUpdatedEvent {
   maxModulesPerRepo: number;
 }
 
+export interface ConfigDiffEvent {
+  admin: string;
+  previousFeeBps: number;
+  feeBps: number;
+  previousMaxModulesPerRepo: number;
+  maxModulesPerRepo: number;
+  updatedAt: bigint;
+}
+
 export interface RepoRegisteredEvent {
   repo: string;
   owner: string;
@@ -595,11 +849,15 @@ export interface RepoActivationChangedEvent {
 export interface ModuleRegisteredEvent {
   module: string;
   repo: string;
-  owner: string;
+  authority: string;
+  name: string;
   category: string;
   majorVersion: number;
   minorVersion: number;
   patchVersion: number;
+  visibility: number; // u8, Visibility discriminant
+  parentModule: string;
+  isDerivative: boolean;
 }
 
 export interface ModuleVersionRegisteredEvent {
@@ -608,6 +866,35 @@ export interface ModuleVersionRegisteredEvent {
   minorVersion: number;
   patchVersion: number;
   isStable: boolean;
+  attestedBy: string; // default key when not attested
+}
+
+export interface ModuleUpdatedEvent {
+  module: string;
+  repo: string;
+  previousMajorVersion: number;
+  previousMinorVersion: number;
+  previousPatchVersion: number;
+  newMajorVersion: number;
+  newMinorVersion: number;
+  newPatchVersion: number;
+  previousIsActive: boolean;
+  newIsActive: boolean;
+  visibility: number; // u8, Visibility discriminant
+  updatedAt: bigint;
+}
+
+export interface ChangelogEntry {
+  majorVersion: number;
+  minorVersion: number;
+  patchVersion: number;
+  changelogUri: string;
+}
+
+export interface ChangelogAssembledEvent {
+  module: string;
+  entries: ChangelogEntry[];
+  assembledAt: bigint;
 }
 
 export interface ModuleLinkedToRepoEvent {
@@ -616,15 +903,25 @@ export interface ModuleLinkedToRepoEvent {
   linkedBy: string;
   isPrimary: boolean;
   updatedAt: bigint;
+  expiresAt: bigint; // i64, 0 = permanent
+}
+
+export interface ModuleLinkExpiredEvent {
+  module: string;
+  repo: string;
+  closedBy: string;
+  expiresAt: bigint;
+  closedAt: bigint;
 }
 
 export interface ForkCreatedEvent {
   fork: string;
-  owner: string;
   parent: string;
-  isRoot: boolean;
-  depth: number;
-  createdAt: bigint;
+  owner: string;
+  label: string;
+  feeLamports: bigint;
+  root: string;
+  ownerForkIndex: number;
 }
 
 export interface ForkUpdatedEvent {
@@ -644,6 +941,18 @@ export interface ObservationRecordedEvent {
   revision: string;
   note: string;
   observedAt: bigint;
+  healthScore: number; // u8, 0..=100, repo health after this observation
+  commits: bigint; // 0 when not reported
+  totalCommitsObserved: bigint;
+}
+
+export interface ObserverKeyRotatedEvent {
+  repo: string;
+  oldObserver: string;
+  newObserver: string;
+  observationCount: bigint;
+  totalLinesOfCode: bigint;
+  rotatedBy: string;
 }
 
 export interface MetricsReconciledEvent {
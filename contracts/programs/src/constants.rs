@@ -25,7 +25,18 @@ use anchor_lang::prelude::*;
 /// Bumping this value should be done whenever a breaking change is introduced
 /// to the on-chain data layout. Off-chain indexers and dashboards can use
 /// this to detect incompatible states.
-pub const CURRENT_SCHEMA_VERSION: u8 = 1;
+///
+/// v2 appended fields to `Config` and `Repo` and rewrote `Module` (whose
+/// v1 layout could not be kept as a prefix; see `ModuleV1`). Accounts
+/// created at v1 are migrated one by one by `set_schema_version`, starting
+/// with `Config`, which every other instruction needs to load.
+pub const CURRENT_SCHEMA_VERSION: u8 = 2;
+
+/// Oldest schema version this program still understands.
+///
+/// Together with `CURRENT_SCHEMA_VERSION` this bounds the values accepted by
+/// the `set_schema_version` override.
+pub const MIN_SCHEMA_VERSION: u8 = 1;
 
 /// Maximum allowed fee in basis points (50%).
 ///
@@ -53,6 +64,15 @@ pub const GLOBAL_METADATA_SEED: &str = "global_metadata";
 /// Seed used for repository PDAs.
 pub const REPO_SEED: &str = "repo";
 
+/// Seed used for repository URL index PDAs, together with the URL hash.
+pub const REPO_URL_INDEX_SEED: &str = "repo_url_index";
+
+/// Seed used for per-(repo, observer) `ObserverStats` PDAs.
+pub const OBSERVER_STATS_SEED: &str = "observer_stats";
+
+/// Seed used for per-repo `RepoLanguageStats` PDAs.
+pub const REPO_LANGUAGE_STATS_SEED: &str = "repo_language_stats";
+
 /// Seed used for module PDAs.
 pub const MODULE_SEED: &str = "module";
 
@@ -62,10 +82,30 @@ pub const MODULE_VERSION_SEED: &str = "module_version";
 /// Seed used for fork PDAs (Unit09 variants).
 pub const FORK_SEED: &str = "fork";
 
+/// Seed used for per-owner `OwnerForkCounter` PDAs.
+pub const OWNER_FORK_COUNTER_SEED: &str = "owner_fork_counter";
+
+/// Seed used for module-to-repo link PDAs.
+pub const MODULE_REPO_LINK_SEED: &str = "module_repo_link";
+
+/// Seed used for module ownership claim PDAs.
+pub const MODULE_CLAIM_SEED: &str = "module_claim";
+
 /// Seed used for authority PDAs, if you decide to store role-based authorities
 /// on-chain instead of relying purely on external policy.
 pub const AUTHORITY_SEED: &str = "authority";
 
+/// Seed for the lamport-only fee vault PDA.
+///
+/// Fees are sent here whenever `Config::treasury` is unset.
+pub const FEE_VAULT_SEED: &str = "fee_vault";
+
+/// Seed for the lamport-only rewards vault PDA.
+///
+/// Anyone may fund it with a plain transfer; `claim_observer_reward` pays
+/// accrued observation rewards out of it.
+pub const REWARDS_VAULT_SEED: &str = "rewards_vault";
+
 /// Seed for lifecycle tracking PDA, if used by the deployment.
 pub const LIFECYCLE_SEED: &str = "lifecycle";
 
@@ -91,6 +131,23 @@ pub const MAX_URL_LEN: usize = 256;
 /// Example: Arweave, IPFS, or any off-chain JSON manifest location.
 pub const MAX_METADATA_URI_LEN: usize = 256;
 
+/// Maximum length for a repository notification endpoint (`Repo::notify_uri`).
+pub const MAX_NOTIFY_URI_LEN: usize = 200;
+
+/// Maximum length for a release attestation reference
+/// (`ModuleVersion::attestation_uri`).
+pub const MAX_ATTESTATION_URI_LEN: usize = 200;
+
+/// Maximum length for a module claim's domain proof URL
+/// (`ModuleClaim::proof_uri`).
+pub const MAX_CLAIM_PROOF_URI_LEN: usize = 200;
+
+/// Maximum length for free-form notes on a module-to-repo link.
+pub const MAX_LINK_NOTES_LEN: usize = 128;
+
+/// Maximum length for the reason recorded by `pause`.
+pub const MAX_PAUSE_REASON_LEN: usize = 128;
+
 /// Maximum length for a human-readable description.
 ///
 /// Used by `GlobalMetadata::description` and any future description fields.
@@ -107,6 +164,30 @@ pub const MAX_REPO_TAGS_LEN: usize = 128;
 /// Optional: maximum length for a module category field.
 pub const MAX_MODULE_CATEGORY_LEN: usize = 64;
 
+/// Maximum length for an SPDX license identifier or expression on a module.
+///
+/// Example: `"Apache-2.0"`, `"MIT OR Apache-2.0"`.
+pub const MAX_LICENSE_SPDX_LEN: usize = 64;
+
+/// Maximum length for a single entry in `Module::tags_vec`.
+pub const MAX_MODULE_TAG_LEN: usize = 32;
+
+/// Maximum length for the registry-side ID of a module external reference.
+///
+/// Example: `"unit09-router"`, `"ghcr.io/unit09-labs/router:1.2.0"`.
+pub const MAX_EXTERNAL_REF_ID_LEN: usize = 96;
+
+/// Maximum length for a single authority role label (e.g. `"maintainer"`).
+pub const MAX_ROLE_LABEL_LEN: usize = 32;
+
+/// Maximum length for the free-form JSON payload on `GlobalMetadata`.
+pub const MAX_EXTRA_JSON_LEN: usize = 512;
+
+/// Number of bytes of a long text field copied into event previews.
+///
+/// Keeps event payloads small; indexers read the full value from the account.
+pub const METADATA_PREVIEW_LEN: usize = 64;
+
 // ---------------------------------------------------------------------------
 // Numeric Limits and Safety Bounds
 // ---------------------------------------------------------------------------
@@ -134,6 +215,126 @@ pub const MAX_LOC_PER_OBSERVATION: u64 = 10_000_000;
 /// Maximum file count that a single observation is expected to report.
 pub const MAX_FILES_PER_OBSERVATION: u32 = 100_000;
 
+/// Share of a hard observation cap (in percent) above which an observation
+/// is flagged as an anomaly while still being accepted.
+pub const OBSERVATION_SOFT_LIMIT_PCT: u64 = 80;
+
+/// Average lines of code per file above which an observation is flagged as
+/// having a suspicious ratio.
+pub const MAX_PLAUSIBLE_LOC_PER_FILE: u64 = 20_000;
+
+/// Observations of the same repository closer together than this are
+/// flagged as too frequent.
+pub const MIN_OBSERVATION_INTERVAL_SECONDS: i64 = 60;
+
+/// An observer earns at most one observation reward per repository within
+/// this many seconds; further observations still count but earn nothing.
+pub const OBSERVATION_REWARD_INTERVAL_SECONDS: i64 = 3_600;
+
+/// Domain tag that starts every worker-signed observation payload, so the
+/// signature cannot be replayed as a signature over anything else.
+pub const SIGNED_OBSERVATION_DOMAIN: &[u8] = b"unit09:observation:v2";
+
+/// How many slots after signing a worker-signed observation is accepted.
+pub const SIGNED_OBSERVATION_MAX_AGE_SLOTS: u64 = 150;
+
+/// Observation streak lengths, in days, announced with a `Unit09Log`.
+pub const OBSERVATION_STREAK_MILESTONES: [u32; 4] = [7, 30, 100, 365];
+
+/// `Repo::activity_tier` of a repository not observed recently.
+pub const ACTIVITY_TIER_DORMANT: u8 = 0;
+
+/// `Repo::activity_tier` of a repository observed recently.
+pub const ACTIVITY_TIER_ACTIVE: u8 = 1;
+
+/// `Repo::activity_tier` of a repository observed recently and often.
+pub const ACTIVITY_TIER_HOT: u8 = 2;
+
+/// A repository whose latest observation is older than this is dormant.
+pub const ACTIVITY_DORMANT_AFTER_SECONDS: i64 = 30 * SECONDS_PER_DAY;
+
+/// A repository observed within this window may be hot.
+pub const ACTIVITY_HOT_WITHIN_SECONDS: i64 = SECONDS_PER_DAY;
+
+/// Observations per day, averaged over the repository's lifetime, needed
+/// to be hot.
+pub const ACTIVITY_HOT_MIN_OBSERVATIONS_PER_DAY: u64 = 4;
+
+/// Maximum number of entries in an observation's per-language breakdown.
+///
+/// Validation walks every entry, so this bounds the compute a single
+/// `record_observation` can spend on the breakdown.
+pub const MAX_LANGUAGE_ENTRIES: usize = 16;
+
+/// Maximum length in bytes of a language name in a breakdown entry.
+pub const MAX_LANGUAGE_NAME_LEN: usize = 32;
+
+/// Maximum number of languages a `RepoLanguageStats` account tracks by name.
+pub const MAX_REPO_LANGUAGES: usize = 16;
+
+/// Maximum number of modules an observation can attribute lines of code to.
+///
+/// Each contribution loads and rewrites one `Module` account.
+pub const MAX_MODULE_CONTRIBUTIONS: usize = 8;
+
+/// Maximum `remaining_accounts` any instruction iterates over.
+///
+/// Each entry is deserialized and often rewritten, so a longer list risks
+/// running out of compute budget part-way through the loop. Instructions
+/// check this before touching any of them.
+pub const MAX_REMAINING_ACCOUNTS: usize = 16;
+
+/// Highest `Config::admin_threshold` that can be set.
+///
+/// Co-signers arrive as `(signer, authority)` pairs in `remaining_accounts`,
+/// so at most `MAX_REMAINING_ACCOUNTS / 2` of them can join `Config::admin`.
+pub const MAX_ADMIN_THRESHOLD: u8 = (MAX_REMAINING_ACCOUNTS / 2 + 1) as u8;
+
+/// Maximum number of role entries a single `Authority` account can hold.
+pub const MAX_AUTHORITY_ROLES: usize = 8;
+
+/// Maximum number of individual tags a single `Module` can carry.
+pub const MAX_MODULE_TAGS: usize = 8;
+
+/// Maximum number of external registry references a single `Module` can carry.
+pub const MAX_MODULE_EXTERNAL_REFS: usize = 4;
+
+/// Maximum number of dependencies pinned in a `ModuleVersion` dependency lock.
+pub const MAX_DEPENDENCY_LOCK_ENTRIES: usize = 8;
+
+/// Maximum number of modules a single `Repo` can pin as entrypoints.
+pub const MAX_REPO_PINNED_MODULES: usize = 8;
+
+/// Maximum named categories in a `CategoryTally` histogram.
+///
+/// Categories beyond this are folded into the `"other"` bucket.
+pub const MAX_CATEGORY_BUCKETS: usize = 8;
+
+/// Lower bounds of the `Metrics::modules_by_version_count` buckets.
+///
+/// With these bounds the buckets count modules with 1, 2–5, 6–20 and 21 or
+/// more versions. Modules without any version are not counted.
+pub const VERSION_COUNT_BUCKET_BOUNDS: [u16; 4] = [1, 2, 6, 21];
+
+/// Number of `Metrics::forks_by_depth` buckets.
+///
+/// Bucket `i` counts forks created at depth `i`; the last bucket also counts
+/// every deeper fork.
+pub const FORK_DEPTH_BUCKETS: usize = 4;
+
+/// Maximum links created or refreshed by one `link_modules_to_repo` call.
+pub const MAX_LINKS_PER_BATCH: usize = 8;
+
+/// Maximum module versions accepted by `collect_changelog`.
+///
+/// Bounds the size of the emitted `ChangelogAssembled` event.
+pub const MAX_CHANGELOG_VERSIONS: usize = 16;
+
+/// Maximum module keys per side accepted by `compute_fork_diff`.
+///
+/// Keeps the quadratic set comparison well inside the compute budget.
+pub const MAX_FORK_DIFF_MODULES: usize = 64;
+
 // ---------------------------------------------------------------------------
 // Time and Slot Related Defaults
 // ---------------------------------------------------------------------------
@@ -147,6 +348,13 @@ pub const SECONDS_PER_DAY: i64 = 86_400;
 /// Convenience constant: number of slots per day (approximate).
 pub const SLOTS_PER_DAY_APPROX: u64 = (SECONDS_PER_DAY as u64 * 1_000) / APPROX_SLOT_DURATION_MS;
 
+/// Default time between `Config::is_active` being cleared and
+/// `Config::assert_active` starting to fail.
+pub const DEFAULT_DEACTIVATION_GRACE_SECONDS: u32 = 600;
+
+/// Longest grace period an admin may request when deactivating.
+pub const MAX_DEACTIVATION_GRACE_SECONDS: u32 = 7 * SECONDS_PER_DAY as u32;
+
 // ---------------------------------------------------------------------------
 // Unit09-Specific Flavor Constants (purely semantic, not enforced on-chain)
 // ---------------------------------------------------------------------------
@@ -161,7 +369,8 @@ pub const PROTOCOL_TAGLINE: &str =
 
 /// Default authority role label for the primary admin in off-chain tooling.
 ///
-/// This does not affect on-chain behavior, but helps keep UI labels consistent.
+/// Held globally, it also lets an authority co-sign high-risk admin
+/// instructions when `Config::admin_threshold` is above 1.
 pub const ROLE_LABEL_ADMIN: &str = "admin";
 
 /// Default authority role label for maintainers.
@@ -170,6 +379,14 @@ pub const ROLE_LABEL_MAINTAINER: &str = "maintainer";
 /// Default authority role label for observers / workers that record observations.
 pub const ROLE_LABEL_OBSERVER: &str = "observer";
 
+/// Role label required to register repos when `Config::restrict_repo_creation`
+/// is enabled.
+pub const ROLE_LABEL_CREATOR: &str = "creator";
+
+/// Role label required to create forks when `Config::restrict_fork_creation`
+/// is enabled.
+pub const ROLE_LABEL_FORKER: &str = "forker";
+
 // ---------------------------------------------------------------------------
 // Helper Functions (optional inline helpers around constants)
 // ---------------------------------------------------------------------------
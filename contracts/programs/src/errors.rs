@@ -71,6 +71,34 @@ pub enum Unit09Error {
     #[msg("Fee basis points out of allowed range.")]
     InvalidFeeBps,
 
+    /// The account passed to receive fees is not the configured treasury or
+    /// fee vault, or is not a system account.
+    #[msg("Invalid fee destination.")]
+    InvalidFeeDestination,
+
+    /// The deployment is paused and its scheduled unpause time, if any,
+    /// has not been reached.
+    #[msg("Deployment is paused.")]
+    DeploymentPaused,
+
+    /// Metadata edits are blocked by `Config::metadata_frozen`.
+    #[msg("Metadata is frozen.")]
+    MetadataFrozen,
+
+    /// The admin engaged the kill switch (`Config::global_kill`); only
+    /// admin instructions are accepted.
+    #[msg("Deployment is killed; only admin instructions are accepted.")]
+    KillSwitchEngaged,
+
+    /// The kill switch cannot be disengaged because it is not engaged.
+    #[msg("Kill switch is not engaged.")]
+    KillSwitchNotEngaged,
+
+    /// Fewer distinct admins than `Config::admin_threshold` signed a
+    /// high-risk admin instruction.
+    #[msg("Admin signature quorum not met.")]
+    AdminQuorumNotMet,
+
     /// The configuration account has an unsupported schema version.
     ///
     /// This is useful when performing migrations or when an instruction
@@ -97,6 +125,22 @@ pub enum Unit09Error {
     #[msg("Authority role is not allowed to perform this action.")]
     AuthorityRoleNotAllowed,
 
+    /// The authority already holds the maximum number of role entries.
+    #[msg("Authority role limit reached.")]
+    AuthorityRoleLimitReached,
+
+    /// The role to revoke is not held by this authority.
+    #[msg("Authority does not hold the requested role.")]
+    AuthorityRoleNotFound,
+
+    /// Repo creation is restricted and the signer is not an approved creator.
+    #[msg("Repo creation is restricted to approved creators.")]
+    RepoCreationRestricted,
+
+    /// Fork creation is restricted and the owner is not an approved forker.
+    #[msg("Fork creation is restricted to approved forkers.")]
+    ForkCreationRestricted,
+
     // -----------------------------------------------------------------------
     // String / Bounds / Data Validation
     // -----------------------------------------------------------------------
@@ -120,6 +164,10 @@ pub enum Unit09Error {
     #[msg("Invalid or malformed URL.")]
     InvalidUrl,
 
+    /// A visibility value does not map to a known `Visibility` variant.
+    #[msg("Unknown visibility value.")]
+    InvalidVisibility,
+
     // -----------------------------------------------------------------------
     // Repositories
     // -----------------------------------------------------------------------
@@ -144,6 +192,39 @@ pub enum Unit09Error {
     #[msg("Repository PDA does not match the provided repository key.")]
     RepoKeyMismatch,
 
+    /// The repository cannot be closed while modules are still registered.
+    #[msg("Repository still has registered modules.")]
+    RepoHasModules,
+
+    /// No authority transfer is pending for this repository.
+    #[msg("No pending authority transfer.")]
+    NoPendingTransfer,
+
+    /// The proposed or accepting key is not a valid pending authority.
+    #[msg("Invalid pending authority.")]
+    InvalidPendingAuthority,
+
+    /// The repository already pins `MAX_REPO_PINNED_MODULES` modules.
+    #[msg("Repository reached maximum pinned modules.")]
+    RepoPinLimitReached,
+
+    /// The module is not pinned on this repository.
+    #[msg("Module is not pinned on this repository.")]
+    ModuleNotPinned,
+
+    /// Another repository already claimed this URL while
+    /// `Config::enforce_unique_url` is set.
+    #[msg("Repository URL is already registered.")]
+    RepoUrlTaken,
+
+    /// `update_repo` was given URL index accounts without changing the URL.
+    #[msg("URL index accounts are only accepted when the URL changes.")]
+    UnexpectedUrlIndex,
+
+    /// Manifest bytes do not match `Repo::manifest_checksum`.
+    #[msg("Manifest checksum mismatch.")]
+    ManifestChecksumMismatch,
+
     // -----------------------------------------------------------------------
     // Modules
     // -----------------------------------------------------------------------
@@ -152,6 +233,10 @@ pub enum Unit09Error {
     #[msg("Module is inactive.")]
     ModuleInactive,
 
+    /// The module is embargoed until its `active_from` launch time.
+    #[msg("Module is embargoed until its launch time.")]
+    ModuleEmbargoed,
+
     /// The requested module version does not exist.
     #[msg("Requested module version not found.")]
     ModuleVersionNotFound,
@@ -168,6 +253,91 @@ pub enum Unit09Error {
     #[msg("Module is immutable or locked.")]
     ModuleImmutable,
 
+    /// A build artifact does not match the checksum or size recorded on the
+    /// module version.
+    #[msg("Artifact does not match the recorded checksum or size.")]
+    ArtifactMismatch,
+
+    /// No build artifact checksum was recorded for this module version.
+    #[msg("No artifact recorded for this module version.")]
+    ArtifactNotRecorded,
+
+    /// The module version is already marked stable.
+    #[msg("Module version is already stable.")]
+    ModuleVersionAlreadyStable,
+
+    /// The module version is deprecated and cannot be promoted.
+    #[msg("Module version is deprecated.")]
+    ModuleVersionDeprecated,
+
+    /// The module version already records an attester.
+    #[msg("Module version is already attested.")]
+    ModuleVersionAlreadyAttested,
+
+    /// The parent module of a derivative is missing, does not match
+    /// `parent_module`, or is the module itself.
+    #[msg("Invalid parent module.")]
+    InvalidModuleParent,
+
+    /// A deprecated module's replacement is missing, is the module itself,
+    /// or is deprecated too.
+    #[msg("Invalid replacement module.")]
+    InvalidModuleReplacement,
+
+    /// Content-addressed modules are enforced and the module key is not
+    /// `Module::content_addressed_key` of its metadata checksum and repo.
+    #[msg("Module key is not content-addressed.")]
+    ModuleKeyNotContentAddressed,
+
+    /// A version's dependency lock pins more than
+    /// `MAX_DEPENDENCY_LOCK_ENTRIES` modules.
+    #[msg("Too many pinned dependencies.")]
+    DependencyLockTooLarge,
+
+    /// A dependency lock pins the module itself, pins a module twice, or
+    /// pins an all-zero version.
+    #[msg("Invalid dependency lock.")]
+    InvalidDependencyLock,
+
+    /// A packed version constraint has an unknown kind.
+    #[msg("Invalid version constraint.")]
+    InvalidVersionConstraint,
+
+    /// The module-to-repo link has no pending confirmation.
+    #[msg("Module link is not awaiting confirmation.")]
+    LinkNotPending,
+
+    /// The module-to-repo link has no expiry, or it has not passed yet.
+    #[msg("Module link has not expired.")]
+    LinkNotExpired,
+
+    /// More links were passed to `link_modules_to_repo` than
+    /// `MAX_LINKS_PER_BATCH`.
+    #[msg("Too many links in one batch.")]
+    LinkBatchTooLarge,
+
+    /// More module versions were supplied than `collect_changelog` accepts.
+    #[msg("Too many versions supplied for changelog.")]
+    ChangelogTooLarge,
+
+    /// The module was renamed less than `Config::rename_cooldown_secs` ago.
+    #[msg("Module was renamed too recently.")]
+    ModuleRenameCooldown,
+
+    /// The claimant's claim on the module is already awaiting the admin's
+    /// decision.
+    #[msg("Module already has a pending claim.")]
+    ModuleClaimPending,
+
+    /// The module claim has already been resolved.
+    #[msg("Module claim is not pending.")]
+    ModuleClaimNotPending,
+
+    /// The module changed hands after the claim was filed, so approving it
+    /// would take the module from someone the claim does not name.
+    #[msg("Module claim is stale.")]
+    ModuleClaimStale,
+
     // -----------------------------------------------------------------------
     // Forks
     // -----------------------------------------------------------------------
@@ -189,6 +359,36 @@ pub enum Unit09Error {
     #[msg("Only the fork owner can perform this action.")]
     InvalidForkOwner,
 
+    /// A status value does not map to a known `ForkStatus` variant.
+    #[msg("Unknown fork status value.")]
+    InvalidForkStatus,
+
+    /// The requested fork status change is not an allowed transition.
+    #[msg("Fork status transition is not allowed.")]
+    InvalidForkStatusTransition,
+
+    /// Too many module keys were supplied for a fork diff.
+    #[msg("Too many modules supplied for fork diff.")]
+    ForkDiffTooLarge,
+
+    /// The parent fork is retired and the override flag was not set.
+    #[msg("Parent fork is retired.")]
+    ParentForkRetired,
+
+    /// The fork still has child forks and the deployment refuses to
+    /// deactivate parents (`Config::block_parent_fork_deactivation`).
+    #[msg("Fork has child forks and cannot be deactivated.")]
+    ForkHasChildren,
+
+    /// The fork has no expiry, or its expiry time has not been reached.
+    #[msg("Fork has not expired.")]
+    ForkNotExpired,
+
+    /// A module passed to release the fork is not verified and the
+    /// deployment requires it (`Config::require_verified_modules_for_fork`).
+    #[msg("Fork references an unverified module.")]
+    ForkModuleUnverified,
+
     // -----------------------------------------------------------------------
     // Metrics and Observations
     // -----------------------------------------------------------------------
@@ -206,6 +406,49 @@ pub enum Unit09Error {
     #[msg("Metrics update is inconsistent with current state.")]
     MetricsInconsistent,
 
+    /// Observer stats can only move to a different key on the same repo.
+    #[msg("Observer key rotation must target a new key on the same repository.")]
+    InvalidObserverRotation,
+
+    /// The `ObserverStats` account passed for a reward belongs to another
+    /// repository or observer.
+    #[msg("Observer stats do not belong to the credited observer.")]
+    InvalidObserverStats,
+
+    /// The repo requires signed observations, but no worker signature or
+    /// worker `Authority` accompanied this one.
+    #[msg("Observation must be signed by a registered worker key.")]
+    MissingObservationSignature,
+
+    /// The signed payload does not match this observation's repo and
+    /// counts, or was signed too many slots ago.
+    #[msg("Signed observation payload does not match the observation.")]
+    ObservationSignatureMismatch,
+
+    /// The signed observation was signed at or before the slot of the
+    /// repo's latest accepted one, so it has been used already.
+    #[msg("Signed observation has already been used.")]
+    ObservationSignatureReplayed,
+
+    /// The deployment already holds as many repositories, modules or forks
+    /// as its `Config::max_total_*` cap allows.
+    #[msg("Deployment-wide limit for this account type has been reached.")]
+    GlobalLimitReached,
+
+    /// The observer has no unclaimed observation reward.
+    #[msg("No observation reward to claim.")]
+    NoRewardAccrued,
+
+    /// The rewards vault holds fewer spendable lamports than the reward
+    /// being claimed.
+    #[msg("Rewards vault cannot cover the claim.")]
+    RewardsVaultInsufficient,
+
+    /// An `ObserverStats` account being closed still holds an unclaimed
+    /// reward; the observer must claim it first.
+    #[msg("Observer stats still hold an unclaimed reward.")]
+    ObserverRewardUnclaimed,
+
     // -----------------------------------------------------------------------
     // Metadata
     // -----------------------------------------------------------------------
@@ -222,6 +465,30 @@ pub enum Unit09Error {
     #[msg("Metadata format is invalid or unsupported.")]
     MetadataInvalid,
 
+    /// Adding a tag would exceed the per-entity tag count limit.
+    #[msg("Too many tags.")]
+    TooManyTags,
+
+    /// A single tag exceeds its maximum length.
+    #[msg("Tag exceeds maximum allowed length.")]
+    TagTooLong,
+
+    /// The tag to remove is not present.
+    #[msg("Tag not found.")]
+    TagNotFound,
+
+    /// The external registry discriminant is not recognized.
+    #[msg("Invalid external registry.")]
+    InvalidExternalRegistry,
+
+    /// The module already carries the maximum number of external references.
+    #[msg("Too many external references.")]
+    TooManyExternalRefs,
+
+    /// The external reference to remove is not present.
+    #[msg("External reference not found.")]
+    ExternalRefNotFound,
+
     // -----------------------------------------------------------------------
     // Lifecycle / Upgrade / Migration
     // -----------------------------------------------------------------------
@@ -259,6 +526,10 @@ pub enum Unit09Error {
     #[msg("Account discriminator does not match expected type.")]
     InvalidAccountDiscriminator,
 
+    /// Account address does not match the expected PDA.
+    #[msg("Account does not match the expected PDA.")]
+    InvalidPda,
+
     /// A system account is required but a non-system account was provided.
     #[msg("Expected a system account, received a non-system account.")]
     ExpectedSystemAccount,
@@ -271,6 +542,29 @@ pub enum Unit09Error {
     #[msg("Expected a writable account, but the account is read-only.")]
     ExpectedWritableAccount,
 
+    /// The confirmation nonce supplied for a destructive admin operation
+    /// does not match the target account.
+    #[msg("Confirmation nonce does not match the target account.")]
+    InvalidConfirmationNonce,
+
+    /// The account is a deployment singleton and cannot be force-closed.
+    #[msg("Account is protected and cannot be closed.")]
+    ProtectedAccount,
+
+    /// More `remaining_accounts` were passed than `MAX_REMAINING_ACCOUNTS`.
+    #[msg("Too many remaining accounts; split the request into smaller batches.")]
+    TooManyRemainingAccounts,
+
+    /// The instruction before this one is not a single-signature Ed25519
+    /// program instruction whose offsets all point into its own data.
+    #[msg("Expected a preceding Ed25519 signature instruction.")]
+    InvalidEd25519Instruction,
+
+    /// The account changed after the caller read it (`expected_updated_at`
+    /// no longer matches `updated_at`).
+    #[msg("Account was modified concurrently; re-read and retry.")]
+    ConcurrentModification,
+
     // -----------------------------------------------------------------------
     // Rate Limits / Cooldowns (optional, for future extensions)
     // -----------------------------------------------------------------------
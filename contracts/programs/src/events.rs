@@ -21,12 +21,17 @@
 //!   instruction handlers already provided. Their field layout MUST remain
 //!   compatible with those handlers to compile correctly.
 //!
+//! - When `Config::use_cpi_events` is set, the core events are delivered
+//!   through a self-CPI instead of the program logs (see
+//!   `utils::EventSink`). The bytes are identical on both paths.
+//!
 //! - Additional events are defined for richer telemetry and future use,
 //!   but they are not yet wired into all handlers. They are provided here
 //!   so you can easily emit them later as the protocol grows.
 //!
 //! ===========================================================================
 
+use anchor_lang::error::Error;
 use anchor_lang::prelude::*;
 
 // ---------------------------------------------------------------------------
@@ -48,20 +53,82 @@ pub struct ConfigUpdated {
     pub max_modules_per_repo: u32,
 }
 
-/// Emitted when a new configuration admin is explicitly rotated.
+/// Emitted by `set_config` next to `ConfigUpdated`, pairing each value
+/// with the one it replaced so indexers can diff updates without keeping
+/// prior state.
 ///
-/// This is not wired into the base handlers yet, but can be used if you
-/// introduce an explicit admin rotation instruction.
+/// A separate event keeps the pinned `ConfigUpdated` layout unchanged.
+#[event]
+pub struct ConfigDiff {
+    /// Admin that applied the update.
+    pub admin: Pubkey,
+    /// Fee in basis points before the update.
+    pub previous_fee_bps: u16,
+    /// Fee in basis points after the update.
+    pub fee_bps: u16,
+    /// Maximum modules per repository before the update.
+    pub previous_max_modules_per_repo: u32,
+    /// Maximum modules per repository after the update.
+    pub max_modules_per_repo: u32,
+    /// Unix timestamp of the update.
+    pub updated_at: i64,
+}
+
+/// Emitted by `rotate_admin`.
 #[event]
 pub struct AdminRotated {
     /// Previous admin authority.
     pub old_admin: Pubkey,
     /// New admin authority.
     pub new_admin: Pubkey,
+    /// `Config::admin_threshold` after the rotation.
+    pub admin_threshold: u8,
     /// Unix timestamp of the rotation.
     pub rotated_at: i64,
 }
 
+/// Emitted by `pause`.
+#[event]
+pub struct ConfigPaused {
+    /// Admin that paused the deployment.
+    pub admin: Pubkey,
+    /// Reason recorded on `Config::pause_reason`.
+    pub reason: String,
+    /// Scheduled end of the pause, or 0 when it lasts until `unpause`.
+    pub auto_unpause_at: i64,
+    /// Unix timestamp of the pause.
+    pub paused_at: i64,
+}
+
+/// Emitted by `unpause`.
+#[event]
+pub struct ConfigUnpaused {
+    /// Admin that lifted the pause.
+    pub admin: Pubkey,
+    /// Unix timestamp of the unpause.
+    pub unpaused_at: i64,
+}
+
+/// Emitted by `engage_kill_switch`.
+#[event]
+pub struct KillSwitchEngaged {
+    /// Admin that engaged the kill switch.
+    pub admin: Pubkey,
+    /// Unix timestamp of the engagement.
+    pub engaged_at: i64,
+}
+
+/// Emitted by `disengage_kill_switch`.
+#[event]
+pub struct KillSwitchDisengaged {
+    /// Admin that disengaged the kill switch.
+    pub admin: Pubkey,
+    /// When the kill switch had been engaged.
+    pub engaged_at: i64,
+    /// Unix timestamp of the disengagement.
+    pub disengaged_at: i64,
+}
+
 // ---------------------------------------------------------------------------
 // Repository Events
 // ---------------------------------------------------------------------------
@@ -78,6 +145,8 @@ pub struct RepoRegistered {
     pub owner: Pubkey,
     /// URL where the repository can be accessed (GitHub, GitLab, etc.).
     pub url: String,
+    /// Discovery visibility (`Visibility` discriminant) indexers should honor.
+    pub visibility: u8,
 }
 
 /// Emitted when repository metadata is updated.
@@ -91,6 +160,10 @@ pub struct RepoUpdated {
     pub repo: Pubkey,
     /// New URL after the update.
     pub url: String,
+    /// Notification endpoint after the update (empty when unset).
+    pub notify_uri: String,
+    /// Discovery visibility after the update.
+    pub visibility: u8,
 }
 
 /// Emitted when a repository is activated or deactivated.
@@ -107,6 +180,67 @@ pub struct RepoActivationChanged {
     pub updated_at: i64,
 }
 
+/// Emitted when a repository authority proposes a new authority.
+#[event]
+pub struct RepoTransferInitiated {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Current authority that initiated the transfer.
+    pub authority: Pubkey,
+    /// Proposed authority that must accept the transfer.
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the pending authority accepts a repository transfer.
+#[event]
+pub struct RepoTransferAccepted {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Authority before the transfer.
+    pub previous_authority: Pubkey,
+    /// Authority after the transfer.
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when a pending repository transfer is cancelled.
+#[event]
+pub struct RepoTransferCancelled {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Authority that had been proposed.
+    pub pending_authority: Pubkey,
+    /// Signer that cancelled (repository authority or admin).
+    pub cancelled_by: Pubkey,
+}
+
+/// Emitted when a repository pins or unpins one of its modules.
+#[event]
+pub struct RepoModulePinChanged {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// Whether the module is now pinned.
+    pub pinned: bool,
+    /// Number of modules pinned after the change.
+    pub pinned_count: u8,
+}
+
+/// Emitted when a repository is closed and its rent reclaimed.
+#[event]
+pub struct RepoClosed {
+    /// PDA of the closed repository account.
+    pub repo: Pubkey,
+    /// Authority that closed the repository.
+    pub authority: Pubkey,
+    /// Account that received the reclaimed rent.
+    pub refund_to: Pubkey,
+    /// Number of `ObserverStats` accounts closed along with it.
+    pub observer_stats_closed: u16,
+    /// Unix timestamp of the close.
+    pub closed_at: i64,
+}
+
 // ---------------------------------------------------------------------------
 // Module Events
 // ---------------------------------------------------------------------------
@@ -125,8 +259,20 @@ pub struct ModuleRegistered {
     pub authority: Pubkey,
     /// Human-readable name of the module.
     pub name: String,
-    /// Version number assigned at registration time.
-    pub version: u32,
+    /// Category the module was registered under (after defaults applied).
+    pub category: String,
+    /// Semantic version assigned at registration time: major component.
+    pub major_version: u16,
+    /// Semantic version assigned at registration time: minor component.
+    pub minor_version: u16,
+    /// Semantic version assigned at registration time: patch component.
+    pub patch_version: u16,
+    /// Discovery visibility (`Visibility` discriminant) indexers should honor.
+    pub visibility: u8,
+    /// Module this one derives from, or `Pubkey::default()` for a root module.
+    pub parent_module: Pubkey,
+    /// Whether the module was registered as a derivative.
+    pub is_derivative: bool,
 }
 
 /// Emitted when a module is updated.
@@ -139,8 +285,72 @@ pub struct ModuleRegistered {
 pub struct ModuleUpdated {
     /// PDA of the module account.
     pub module: Pubkey,
-    /// New version number after the update.
-    pub version: u32,
+    /// PDA of the repository the update was made through.
+    pub repo: Pubkey,
+    /// Semantic version before the update: major component.
+    pub previous_major_version: u16,
+    /// Semantic version before the update: minor component.
+    pub previous_minor_version: u16,
+    /// Semantic version before the update: patch component.
+    pub previous_patch_version: u16,
+    /// Semantic version after the update: major component.
+    pub new_major_version: u16,
+    /// Semantic version after the update: minor component.
+    pub new_minor_version: u16,
+    /// Semantic version after the update: patch component.
+    pub new_patch_version: u16,
+    /// Whether the module was active before the update.
+    pub previous_is_active: bool,
+    /// Whether the module is active after the update.
+    pub new_is_active: bool,
+    /// Discovery visibility after the update.
+    pub visibility: u8,
+    /// Unix timestamp of the update.
+    pub updated_at: i64,
+}
+
+/// Emitted by `verify_module` and `unverify_module` when a module's
+/// verified badge changes.
+#[event]
+pub struct ModuleVerificationChanged {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// Whether the module is verified after the change.
+    pub is_verified: bool,
+    /// Admin that changed the badge.
+    pub admin: Pubkey,
+    /// Unix timestamp of the change.
+    pub changed_at: i64,
+}
+
+/// Emitted when someone files an ownership claim on a module.
+#[event]
+pub struct ModuleClaimFiled {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// Key asking to become the module authority.
+    pub claimant: Pubkey,
+    /// `https://` URL of the domain proof.
+    pub proof_uri: String,
+    /// Unix timestamp when the claim was filed.
+    pub filed_at: i64,
+}
+
+/// Emitted when the admin approves or rejects a module ownership claim.
+#[event]
+pub struct ModuleClaimResolved {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// Key that filed the claim.
+    pub claimant: Pubkey,
+    /// Whether the module was handed to the claimant.
+    pub approved: bool,
+    /// Module authority when the claim was filed.
+    pub previous_authority: Pubkey,
+    /// Admin that resolved the claim.
+    pub resolved_by: Pubkey,
+    /// Unix timestamp of the decision.
+    pub resolved_at: i64,
 }
 
 /// Emitted when a module is linked to a repository or relinked from one
@@ -151,42 +361,158 @@ pub struct ModuleLinkedToRepo {
     pub module: Pubkey,
     /// PDA of the repository the module is linked to.
     pub repo: Pubkey,
+    /// Authority that created or refreshed the link.
+    pub linked_by: Pubkey,
+    /// Whether the link is the module's primary association.
+    pub is_primary: bool,
+    /// Whether the link is in effect (false while awaiting confirmation).
+    pub is_active: bool,
     /// Unix timestamp of the link operation.
     pub linked_at: i64,
+    /// Unix timestamp from which the link no longer applies (0 = never).
+    pub expires_at: i64,
 }
 
-/// Emitted when a module is explicitly marked as active or inactive.
-///
-/// This event is not currently wired into the provided handler, but you can
-/// emit it inside `update_module` once you treat `is_active` toggles.
+/// Emitted when the second authority confirms a pending module link.
+#[event]
+pub struct ModuleLinkConfirmed {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// PDA of the repository the module is linked to.
+    pub repo: Pubkey,
+    /// Authority that confirmed the link.
+    pub confirmed_by: Pubkey,
+    /// Unix timestamp of the confirmation.
+    pub confirmed_at: i64,
+}
+
+/// Emitted by `expire_link` when an expired module link is closed.
+#[event]
+pub struct ModuleLinkExpired {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// PDA of the repository the module was linked to.
+    pub repo: Pubkey,
+    /// Signer that closed the link.
+    pub closed_by: Pubkey,
+    /// Expiry the link reached.
+    pub expires_at: i64,
+    /// Unix timestamp of the close.
+    pub closed_at: i64,
+}
+
+/// Emitted by `update_module` when a module is activated, deactivated,
+/// deprecated or undeprecated.
 #[event]
 pub struct ModuleActivationChanged {
     /// PDA of the module account.
     pub module: Pubkey,
     /// Whether the module is now active.
     pub is_active: bool,
+    /// Whether the module is now deprecated.
+    pub is_deprecated: bool,
+    /// Recommended replacement (`Pubkey::default()` when none).
+    pub replaced_by: Pubkey,
     /// Unix timestamp of the change.
     pub updated_at: i64,
 }
 
+/// Emitted when a module is closed and its rent reclaimed.
+#[event]
+pub struct ModuleClosed {
+    /// PDA of the closed module account.
+    pub module: Pubkey,
+    /// PDA of the repository that owned the module.
+    pub repo: Pubkey,
+    /// Repository authority that closed the module.
+    pub authority: Pubkey,
+    /// Account that received the reclaimed rent.
+    pub refund_to: Pubkey,
+    /// Unix timestamp of the close.
+    pub closed_at: i64,
+}
+
 // ---------------------------------------------------------------------------
 // Module Version Events (optional, for version history tracking)
 // ---------------------------------------------------------------------------
 
-/// Emitted when a new module version entry is created.
+/// Emitted when a beta module version is promoted to stable.
+#[event]
+pub struct ModuleVersionPromoted {
+    /// PDA of the parent module.
+    pub module: Pubkey,
+    /// PDA of the promoted `ModuleVersion`.
+    pub module_version: Pubkey,
+    /// Semantic version: major component.
+    pub major_version: u16,
+    /// Semantic version: minor component.
+    pub minor_version: u16,
+    /// Semantic version: patch component.
+    pub patch_version: u16,
+    /// Whether the module's latest-stable pointer now targets this version.
+    pub is_latest_stable: bool,
+}
+
+/// Emitted when a `ModuleVersion` snapshot is stored by `register_module`
+/// or `update_module`; this is the only event for new versions.
 ///
-/// If you track `ModuleVersion` accounts, this helps indexers reconstruct
-/// a full lineage of versions.
+/// Built by `register_module::module_version_registered_event`. The
+/// version's `metadata_uri` and `created_at` are read from the
+/// `ModuleVersion` account itself.
+#[event]
+pub struct ModuleVersionRegistered {
+    /// PDA of the parent module.
+    pub module: Pubkey,
+    /// Semantic version: major component.
+    pub major_version: u16,
+    /// Semantic version: minor component.
+    pub minor_version: u16,
+    /// Semantic version: patch component.
+    pub patch_version: u16,
+    /// Whether the version was created as stable.
+    pub is_stable: bool,
+    /// Release signer, or `Pubkey::default()` when not attested.
+    pub attested_by: Pubkey,
+}
+
+/// Emitted when an existing module version is marked as attested.
 #[event]
-pub struct ModuleVersionCreated {
+pub struct ModuleVersionAttested {
     /// PDA of the parent module.
     pub module: Pubkey,
-    /// Version number that was created.
-    pub version: u32,
-    /// Metadata URI associated with this version.
-    pub metadata_uri: String,
-    /// Unix timestamp of the creation time.
-    pub created_at: i64,
+    /// PDA of the attested `ModuleVersion`.
+    pub module_version: Pubkey,
+    /// Key recorded as the release signer.
+    pub attested_by: Pubkey,
+    /// Reference to the attestation.
+    pub attestation_uri: String,
+    /// Signer of the `mark_version_attested` instruction.
+    pub marked_by: Pubkey,
+}
+
+/// One version in a `ChangelogAssembled` listing.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ChangelogEntry {
+    /// Semantic version: major component.
+    pub major_version: u16,
+    /// Semantic version: minor component.
+    pub minor_version: u16,
+    /// Semantic version: patch component.
+    pub patch_version: u16,
+    /// Changelog URI recorded on the version (may be empty).
+    pub changelog_uri: String,
+}
+
+/// Emitted by `collect_changelog` with a module's versions in ascending
+/// version order.
+#[event]
+pub struct ChangelogAssembled {
+    /// PDA of the module.
+    pub module: Pubkey,
+    /// Supplied versions, ascending and without duplicates.
+    pub entries: Vec<ChangelogEntry>,
+    /// Unix timestamp of the assembly.
+    pub assembled_at: i64,
 }
 
 // ---------------------------------------------------------------------------
@@ -207,6 +533,12 @@ pub struct ForkCreated {
     pub owner: Pubkey,
     /// Human-readable label describing the fork.
     pub label: String,
+    /// Fork fee charged to the payer, in lamports (0 when no fee is set).
+    pub fee_lamports: u64,
+    /// First fork of the new fork's lineage (the fork itself for a root).
+    pub root: Pubkey,
+    /// Position among the owner's forks (`Fork::owner_fork_index`).
+    pub owner_fork_index: u32,
 }
 
 /// Emitted when the active state of a fork is toggled or when important
@@ -215,8 +547,10 @@ pub struct ForkCreated {
 pub struct ForkStateUpdated {
     /// PDA of the fork account.
     pub fork: Pubkey,
-    /// Whether the fork is currently active.
+    /// Whether the fork is currently active (derived from `status`).
     pub active: bool,
+    /// Readiness status after the update, as a `ForkStatus` discriminant.
+    pub status: u8,
 }
 
 /// Emitted when the owner of a fork is rotated.
@@ -234,6 +568,21 @@ pub struct ForkOwnerChanged {
     pub changed_at: i64,
 }
 
+/// Emitted by `compute_fork_diff` with the size of the module difference.
+#[event]
+pub struct ForkDiffComputed {
+    /// Fork whose module set was compared.
+    pub fork: Pubkey,
+    /// Fork it was compared against (typically its parent).
+    pub base: Pubkey,
+    /// Modules present in `fork` but not in `base`.
+    pub added_count: u32,
+    /// Modules present in `base` but not in `fork`.
+    pub removed_count: u32,
+    /// Unix timestamp of the comparison.
+    pub computed_at: i64,
+}
+
 // ---------------------------------------------------------------------------
 // Observation and Metrics Events
 // ---------------------------------------------------------------------------
@@ -253,6 +602,66 @@ pub struct ObservationRecorded {
     pub lines_of_code: u64,
     /// Number of files processed in this observation run.
     pub files_processed: u32,
+    /// Repository `total_lines_of_code` before this observation.
+    pub prev_total_loc: u64,
+    /// Repository `total_lines_of_code` after this observation; the
+    /// difference from `prev_total_loc` equals `lines_of_code`.
+    pub new_total_loc: u64,
+    /// Whether this was a historical backfill, counted in the totals only.
+    pub backfill: bool,
+    /// Repository health after this observation (`Repo::compute_health`).
+    pub health_score: u8,
+    /// Commits covered by this observation (0 when not reported).
+    pub commits: u64,
+    /// Repository `total_commits_observed` after this observation.
+    pub total_commits_observed: u64,
+}
+
+/// Emitted instead of `ObservationRecorded` when an observation reports the
+/// same content hash as the previous one: only the repository's observation
+/// timestamp moved, and no lines of code were added to any total.
+#[event]
+pub struct ObservationUnchanged {
+    /// PDA of the repository that was observed.
+    pub repo: Pubkey,
+    /// Slot at which the observation was recorded.
+    pub slot: u64,
+    /// Content hash reported by the worker, equal to the stored one.
+    pub content_hash: [u8; 32],
+    /// Unix timestamp of the observation.
+    pub observed_at: i64,
+}
+
+/// Emitted by `rotate_observer_key` after an observer's tallies moved to a
+/// new key.
+#[event]
+pub struct ObserverKeyRotated {
+    /// PDA of the observed repository.
+    pub repo: Pubkey,
+    /// Observer key whose stats account was closed.
+    pub old_observer: Pubkey,
+    /// Observer key that now holds the tallies.
+    pub new_observer: Pubkey,
+    /// Observation count under the new key after the rotation.
+    pub observation_count: u64,
+    /// Lines of code under the new key after the rotation.
+    pub total_lines_of_code: u64,
+    /// Signer that rotated the key (old observer or admin).
+    pub rotated_by: Pubkey,
+}
+
+/// Emitted by `claim_observer_reward` when an observer withdraws its
+/// accrued observation rewards.
+#[event]
+pub struct ObserverRewardClaimed {
+    /// PDA of the observed repository.
+    pub repo: Pubkey,
+    /// Observer key that received the reward.
+    pub observer: Pubkey,
+    /// Lamports paid out of the rewards vault.
+    pub amount: u64,
+    /// Unix timestamp of the claim.
+    pub claimed_at: i64,
 }
 
 /// Emitted when aggregate metrics are updated in bulk.
@@ -283,6 +692,75 @@ pub struct MetricsLimitReached {
     pub observed_at: i64,
 }
 
+/// Emitted when an observation crosses a soft anomaly threshold.
+///
+/// Unlike `MetricsLimitReached`, the observation was accepted; this only
+/// flags it as suspicious for off-chain review.
+#[event]
+pub struct ObservationAnomalyDetected {
+    /// PDA of the observed repository.
+    pub repo: Pubkey,
+    /// `ObservationAnomalyKind` discriminant.
+    pub kind: u8,
+    /// Offending value (seconds, lines of code, or LOC per file by kind).
+    pub value: u64,
+    /// Unix timestamp of the observation.
+    pub detected_at: i64,
+}
+
+/// Operation whose rejection is reported by `OperationRejected`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectedOperation {
+    /// `record_observation` arguments failed validation.
+    Observation = 0,
+    /// `register_repo` was refused by the creator allowlist.
+    Registration = 1,
+    /// `create_fork` was refused by the forker allowlist.
+    ForkCreation = 2,
+}
+
+impl RejectedOperation {
+    /// Convert `RejectedOperation` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Emitted right before an instruction returns a policy or threshold error.
+///
+/// The failing transaction is rolled back, so counters on `Metrics` could
+/// never record these; the event survives in the transaction logs and lets
+/// operators measure rejection rates when tuning thresholds.
+#[event]
+pub struct OperationRejected {
+    /// `RejectedOperation` discriminant.
+    pub operation: u8,
+    /// Account the operation targeted (repository or would-be authority).
+    pub subject: Pubkey,
+    /// Numeric error code returned by the instruction.
+    pub reason: u32,
+    /// Unix timestamp of the rejection.
+    pub rejected_at: i64,
+}
+
+impl OperationRejected {
+    /// Build the event for `error`, returned while performing `operation`.
+    pub fn new(operation: RejectedOperation, subject: Pubkey, error: &Error, now: i64) -> Self {
+        let reason = match error {
+            Error::AnchorError(anchor_error) => anchor_error.error_code_number,
+            Error::ProgramError(_) => 0,
+        };
+
+        Self {
+            operation: operation.as_u8(),
+            subject,
+            reason,
+            rejected_at: now,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Global Metadata and Lifecycle Events
 // ---------------------------------------------------------------------------
@@ -297,10 +775,14 @@ pub struct MetricsLimitReached {
 pub struct GlobalMetadataUpdated {
     /// Admin that performed this update.
     pub admin: Pubkey,
-    /// Short description string summary (truncated for event payload).
-    pub description_preview: String,
-    /// Tags string summary (truncated for event payload).
-    pub tags_preview: String,
+    /// Short description summary (truncated for event payload).
+    ///
+    /// `None` when the description was not changed by this update.
+    pub description_preview: Option<String>,
+    /// Tags summary (truncated for event payload).
+    ///
+    /// `None` when the tags were not changed by this update.
+    pub tags_preview: Option<String>,
     /// Unix timestamp of the update.
     pub updated_at: i64,
 }
@@ -370,3 +852,190 @@ pub struct Unit09Log {
     /// Unix timestamp when the log was emitted.
     pub logged_at: i64,
 }
+
+// ---------------------------------------------------------------------------
+// Wire-format pinning
+// ---------------------------------------------------------------------------
+//
+// Indexers decode these events straight from transaction logs, so any change
+// to field order or types is a breaking change for them. Each test below
+// serializes a representative event and compares its length and FNV-1a hash
+// against pinned values. When a field is added on purpose, update the pinned
+// numbers in the same commit so the wire change is visible in review.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    /// 64-bit FNV-1a; small, dependency-free and stable across platforms.
+    fn fnv1a(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    fn assert_wire<T: AnchorSerialize>(event: &T, expected_len: usize, expected_hash: u64) {
+        let bytes = event.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), expected_len, "serialized length changed");
+        assert_eq!(fnv1a(&bytes), expected_hash, "serialized bytes changed");
+    }
+
+    #[test]
+    fn config_updated_layout() {
+        let event = ConfigUpdated {
+            admin: key(1),
+            fee_bps: 250,
+            max_modules_per_repo: 128,
+        };
+        assert_wire(&event, 38, 0x2eb2955936a27987);
+    }
+
+    #[test]
+    fn repo_registered_layout() {
+        let event = RepoRegistered {
+            repo: key(2),
+            owner: key(3),
+            url: "https://github.com/unit09-labs/unit09".to_string(),
+            visibility: 1,
+        };
+        assert_wire(&event, 106, 0xbdf322b1795c66c7);
+    }
+
+    #[test]
+    fn repo_updated_layout() {
+        let event = RepoUpdated {
+            repo: key(2),
+            url: "https://github.com/unit09-labs/unit09-core".to_string(),
+            notify_uri: "https://relay.unit09.org/hooks/core".to_string(),
+            visibility: 0,
+        };
+        assert_wire(&event, 118, 0xea7d81c5e27fad06);
+    }
+
+    #[test]
+    fn module_registered_layout() {
+        let event = ModuleRegistered {
+            module: key(4),
+            repo: key(2),
+            authority: key(3),
+            name: "unit09-router".to_string(),
+            category: "program".to_string(),
+            major_version: 1,
+            minor_version: 2,
+            patch_version: 3,
+            visibility: 2,
+            parent_module: key(7),
+            is_derivative: true,
+        };
+        assert_wire(&event, 164, 0x513e6515bc275007);
+    }
+
+    #[test]
+    fn module_version_registered_layout() {
+        let event = ModuleVersionRegistered {
+            module: key(4),
+            major_version: 1,
+            minor_version: 2,
+            patch_version: 3,
+            is_stable: true,
+            attested_by: key(9),
+        };
+        assert_wire(&event, 71, 0xe1e4e53e619b1314);
+    }
+
+    #[test]
+    fn module_updated_layout() {
+        let event = ModuleUpdated {
+            module: key(4),
+            repo: key(2),
+            previous_major_version: 1,
+            previous_minor_version: 2,
+            previous_patch_version: 3,
+            new_major_version: 1,
+            new_minor_version: 3,
+            new_patch_version: 0,
+            previous_is_active: true,
+            new_is_active: false,
+            visibility: 2,
+            updated_at: 1_700_000_000,
+        };
+        assert_wire(&event, 87, 0x59b40e2735e848bb);
+    }
+
+    #[test]
+    fn fork_created_layout() {
+        let event = ForkCreated {
+            fork: key(5),
+            parent: key(6),
+            owner: key(3),
+            label: "unit09-fork".to_string(),
+            fee_lamports: 5_000,
+            root: key(8),
+            owner_fork_index: 1,
+        };
+        assert_wire(&event, 155, 0xe889035faf5fe942);
+    }
+
+    #[test]
+    fn fork_state_updated_layout() {
+        let event = ForkStateUpdated {
+            fork: key(5),
+            active: true,
+            status: 2,
+        };
+        assert_wire(&event, 34, 0x523eb1b4a1ecc5ca);
+    }
+
+    #[test]
+    fn observation_recorded_layout() {
+        let event = ObservationRecorded {
+            repo: key(2),
+            slot: 123_456,
+            lines_of_code: 42_000,
+            files_processed: 310,
+            prev_total_loc: 1_000_000,
+            new_total_loc: 1_042_000,
+            backfill: true,
+            health_score: 87,
+            commits: 14,
+            total_commits_observed: 2_300,
+        };
+        assert_wire(&event, 86, 0x1324abcfab5bbd55);
+    }
+
+    #[test]
+    fn metrics_updated_layout() {
+        let event = MetricsUpdated {
+            total_repos: 7,
+            total_modules: 21,
+            total_forks: 3,
+            total_observations: 99,
+        };
+        assert_wire(&event, 32, 0xb8d60c4c77fdbf97);
+    }
+
+    #[test]
+    fn global_metadata_updated_layout() {
+        let event = GlobalMetadataUpdated {
+            admin: key(1),
+            description_preview: Some("Unit09 consumes Solana code".to_string()),
+            tags_preview: None,
+            updated_at: 1_700_000_000,
+        };
+        assert_wire(&event, 73, 0x412f0ec9ce2e361f);
+    }
+
+    #[test]
+    fn unit09_log_layout() {
+        let event = Unit09Log {
+            category: "metrics_consistency".to_string(),
+            message: "ok".to_string(),
+            logged_at: 1_700_000_000,
+        };
+        assert_wire(&event, 37, 0x8032f42d01fc6bf3);
+    }
+}
@@ -0,0 +1,106 @@
+//! ===========================================================================
+//! Unit09 – Accept Repo Transfer Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/accept_repo_transfer.rs
+//!
+//! Second half of the two-step repository authority transfer.
+//!
+//! The key proposed by `initiate_repo_transfer` signs this instruction to
+//! take over the repository. Proving control of the key at this point is
+//! what protects against transfers to a mistyped address.
+//!
+//! On success this instruction:
+//! - moves `repo.pending_authority` into `repo.authority`
+//! - clears the pending slot
+//! - emits `RepoTransferAccepted`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - a transfer must be pending and the signer must be the pending authority
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::RepoTransferAccepted;
+use crate::state::{Config, Lifecycle, Repo};
+
+/// Accounts required for the `accept_repo_transfer` instruction.
+#[derive(Accounts)]
+pub struct AcceptRepoTransfer<'info> {
+    /// Proposed authority; must match `repo.pending_authority`.
+    pub new_authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository whose authority is being transferred.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `accept_repo_transfer` instruction.
+pub fn handle(ctx: Context<AcceptRepoTransfer>) -> Result<()> {
+    let AcceptRepoTransfer {
+        new_authority,
+        config,
+        lifecycle,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Apply (also verifies the signer is the pending authority)
+    // -----------------------------------------------------------------------
+
+    let previous_authority = repo.accept_transfer(&new_authority.key(), clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoTransferAccepted {
+        repo: repo.key(),
+        previous_authority,
+        new_authority: repo.authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,337 @@
+//! ===========================================================================
+//! Unit09 – Admin Close Account Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/admin_close_account.rs
+//!
+//! Escape hatch for removing orphaned or malformed program accounts that the
+//! regular close instructions cannot handle (for example, accounts left
+//! behind by a bug or a failed migration).
+//!
+//! This is a destructive operation. Guards:
+//! - only the current `Config::admin` may call it
+//! - the target must be owned by this program
+//! - the deployment singletons (config, lifecycle, metrics) are refused
+//! - when `expected_discriminator` is given, the target's first eight bytes
+//!   must match it
+//! - `confirmation_nonce` must equal `admin_close_confirmation(target)`, so
+//!   the operator has to derive it for the exact account being closed
+//! - the account counting the target must be passed, and nothing else: the
+//!   home `repo` of a module, the `parent_fork` of a fork created under one
+//!
+//! On success this instruction:
+//! - moves all lamports to `destination` and closes the target
+//! - decrements the matching `Metrics` counter when the target is a repo,
+//!   module or fork (via `Metrics::on_entity_removed`)
+//! - decrements `repo.module_count` when a module is closed, or
+//!   `parent_fork.child_count` when a fork is, unless that account was
+//!   closed already
+//! - emits a `Unit09Log` (category `admin_close`) naming the closed account
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::Unit09Log;
+use crate::errors::Unit09Error;
+use crate::state::{Config, EntityKind, Fork, Metrics, Module, Repo};
+use crate::utils::assert_admin_closable;
+
+/// Log category used for the emitted `Unit09Log`.
+pub const ADMIN_CLOSE_LOG_CATEGORY: &str = "admin_close";
+
+/// Arguments for the `admin_close_account` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AdminCloseAccountArgs {
+    /// Optional Anchor discriminator the target must carry (e.g. that of
+    /// `Repo`). `None` skips the check for accounts too malformed to match.
+    pub expected_discriminator: Option<[u8; 8]>,
+
+    /// Must equal `admin_close_confirmation(target)`.
+    pub confirmation_nonce: u64,
+}
+
+/// Accounts required for the `admin_close_account` instruction.
+#[derive(Accounts)]
+pub struct AdminCloseAccount<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, adjusted when a counted entity is closed.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Account to close.
+    ///
+    /// CHECK: ownership, discriminator and confirmation are verified in the
+    /// handler via `assert_admin_closable`.
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// Recipient of the reclaimed lamports.
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    /// Home repository of the target, required when the target is a
+    /// module. Must be the account named by the module's `repo`; its
+    /// `module_count` is decremented.
+    ///
+    /// CHECK: matched against the target in the handler, and only read as
+    /// a `Repo` while this program still owns it.
+    #[account(mut)]
+    pub repo: Option<UncheckedAccount<'info>>,
+
+    /// Parent of the target, required when the target is a fork created
+    /// under a parent fork. Must be the account named by the target's
+    /// `Fork::parent`; its `child_count` is decremented.
+    ///
+    /// CHECK: matched against the target in the handler, and only read as
+    /// a `Fork` while this program still owns it.
+    #[account(mut)]
+    pub parent_fork: Option<UncheckedAccount<'info>>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `admin_close_account` instruction.
+pub fn handle(ctx: Context<AdminCloseAccount>, args: AdminCloseAccountArgs) -> Result<()> {
+    let AdminCloseAccount {
+        admin,
+        config,
+        metrics,
+        target,
+        destination,
+        repo,
+        parent_fork,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    let (removed_kind, counted_by) = {
+        let data = target.try_borrow_data()?;
+        assert_admin_closable(
+            &target.key(),
+            target.owner,
+            &data,
+            args.expected_discriminator,
+            args.confirmation_nonce,
+            ctx.program_id,
+        )?;
+        let kind = EntityKind::from_discriminator(&data);
+        (kind, counted_by(kind, &target.key(), &data)?)
+    };
+    check_counting_account(
+        repo.as_ref(),
+        counted_by.home_repo,
+        Unit09Error::ModuleRepoMismatch,
+    )?;
+    check_counting_account(
+        parent_fork.as_ref(),
+        counted_by.parent_fork,
+        Unit09Error::InvalidForkParent,
+    )?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(kind) = removed_kind {
+        metrics.on_entity_removed(kind)?;
+        metrics.updated_at = now;
+    }
+
+    // A counting account that was closed already has been handed back to
+    // the system program.
+    if let Some(repo) = repo.as_ref().filter(|info| info.owner == ctx.program_id) {
+        let mut data = repo.try_borrow_mut_data()?;
+        let mut home = Repo::try_deserialize(&mut &data[..])?;
+        home.decrement_module_count()?;
+        home.try_serialize(&mut &mut data[..])?;
+    }
+    if let Some(parent_fork) = parent_fork.as_ref().filter(|info| info.owner == ctx.program_id) {
+        let mut data = parent_fork.try_borrow_mut_data()?;
+        let mut parent = Fork::try_deserialize(&mut &data[..])?;
+        parent.remove_child()?;
+        parent.try_serialize(&mut &mut data[..])?;
+    }
+
+    // The target is untyped, so it cannot use `close =`: move its lamports,
+    // hand it back to the system program and drop its data.
+    let lamports = target.get_lamports();
+    target.sub_lamports(lamports)?;
+    destination.add_lamports(lamports)?;
+    target.assign(&System::id());
+    target.realloc(0, false)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(Unit09Log {
+        category: ADMIN_CLOSE_LOG_CATEGORY.to_string(),
+        message: format!("closed {} by {}", target.key(), admin.key()),
+        logged_at: now,
+    });
+
+    Ok(())
+}
+
+/// Accounts whose counters include the target of `admin_close_account`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CountedBy {
+    /// `Module::repo` of a module target.
+    pub home_repo: Option<Pubkey>,
+    /// `Fork::parent` of a fork target created under a parent fork.
+    pub parent_fork: Option<Pubkey>,
+}
+
+/// Find the accounts counting `target`, an account of `kind` holding `data`.
+///
+/// A module's `repo` is read from the raw data (`Module::stored_repo`), so
+/// modules that no longer deserialize can still be closed.
+pub fn counted_by(kind: Option<EntityKind>, target: &Pubkey, data: &[u8]) -> Result<CountedBy> {
+    let counted_by = match kind {
+        Some(EntityKind::Module) => CountedBy {
+            home_repo: Some(
+                Module::stored_repo(data).ok_or(Unit09Error::InvalidAccountDiscriminator)?,
+            ),
+            ..CountedBy::default()
+        },
+        Some(EntityKind::Fork) => {
+            let fork = Fork::try_deserialize(&mut &data[..])?;
+            CountedBy {
+                parent_fork: fork.has_parent_fork(target).then_some(fork.parent),
+                ..CountedBy::default()
+            }
+        }
+        _ => CountedBy::default(),
+    };
+    Ok(counted_by)
+}
+
+/// Ensure `account` is passed exactly when `expected` names one, and is
+/// that account; a wrong one fails with `mismatch`.
+pub fn check_counting_account(
+    account: Option<&UncheckedAccount>,
+    expected: Option<Pubkey>,
+    mismatch: Unit09Error,
+) -> Result<()> {
+    match (account.map(|account| account.key()), expected) {
+        (None, None) => Ok(()),
+        (None, Some(_)) => err!(Unit09Error::MissingRequiredAccount),
+        (Some(key), Some(expected)) if key == expected => Ok(()),
+        (Some(_), _) => Err(mismatch.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{new_module, zeroed};
+
+    fn serialized<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn fork_data(parent: Pubkey, root: Pubkey) -> Vec<u8> {
+        let mut fork: Fork = zeroed(Fork::LEN);
+        fork.parent = parent;
+        fork.root = root;
+        serialized(&fork)
+    }
+
+    #[test]
+    fn module_is_counted_by_its_home_repo() {
+        let repo = Pubkey::new_unique();
+        let module = new_module(repo, Pubkey::new_unique(), "unit09-router", &Clock::default());
+        let data = serialized(&module);
+
+        let counted = counted_by(Some(EntityKind::Module), &Pubkey::new_unique(), &data).unwrap();
+        assert_eq!(counted.home_repo, Some(repo));
+        assert_eq!(counted.parent_fork, None);
+
+        // Only the leading fields are read, so a truncated module works too.
+        let counted =
+            counted_by(Some(EntityKind::Module), &Pubkey::new_unique(), &data[..72]).unwrap();
+        assert_eq!(counted.home_repo, Some(repo));
+        assert!(counted_by(Some(EntityKind::Module), &Pubkey::new_unique(), &data[..40]).is_err());
+    }
+
+    #[test]
+    fn fork_is_counted_only_by_a_parent_fork() {
+        let key = Pubkey::new_unique();
+        let parent = Pubkey::new_unique();
+
+        let child = fork_data(parent, parent);
+        let counted = counted_by(Some(EntityKind::Fork), &key, &child).unwrap();
+        assert_eq!(counted.parent_fork, Some(parent));
+        assert_eq!(counted.home_repo, None);
+
+        // A lineage root names itself; its parent is an identity.
+        let root = fork_data(parent, key);
+        assert_eq!(counted_by(Some(EntityKind::Fork), &key, &root).unwrap(), CountedBy::default());
+
+        let untracked = fork_data(parent, Pubkey::default());
+        let counted = counted_by(Some(EntityKind::Fork), &key, &untracked).unwrap();
+        assert_eq!(counted, CountedBy::default());
+    }
+
+    #[test]
+    fn counting_account_must_match_when_required() {
+        let expected = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let owner = crate::ID;
+        let (mut lamports, mut other_lamports) = (0u64, 0u64);
+        let (mut data, mut other_data) = (Vec::new(), Vec::new());
+        let info =
+            AccountInfo::new(&expected, false, true, &mut lamports, &mut data, &owner, false, 0);
+        let other_info = AccountInfo::new(
+            &other,
+            false,
+            true,
+            &mut other_lamports,
+            &mut other_data,
+            &owner,
+            false,
+            0,
+        );
+        let account = UncheckedAccount::try_from(&info);
+        let other_account = UncheckedAccount::try_from(&other_info);
+        let mismatch = Unit09Error::ModuleRepoMismatch;
+
+        assert!(check_counting_account(None, None, mismatch).is_ok());
+        assert!(check_counting_account(Some(&account), Some(expected), mismatch).is_ok());
+        assert_eq!(
+            check_counting_account(None, Some(expected), mismatch).unwrap_err(),
+            Unit09Error::MissingRequiredAccount.into()
+        );
+        assert_eq!(
+            check_counting_account(Some(&other_account), Some(expected), mismatch).unwrap_err(),
+            mismatch.into()
+        );
+        assert_eq!(
+            check_counting_account(Some(&account), None, mismatch).unwrap_err(),
+            mismatch.into()
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! ===========================================================================
+//! Unit09 – Cancel Repo Transfer Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/cancel_repo_transfer.rs
+//!
+//! Withdraws a pending repository authority transfer before it is accepted.
+//!
+//! Either the current repository authority or the deployment admin may
+//! cancel. The admin path exists so that operators can clean up transfers
+//! proposed to an address that turned out to be wrong or compromised. While
+//! the kill switch is engaged, or the deployment is paused or deactivated
+//! (`Config::assert_active`), only the admin may cancel.
+//!
+//! On success this instruction:
+//! - clears `repo.pending_authority`
+//! - emits `RepoTransferCancelled`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoTransferCancelled;
+use crate::state::{Config, Lifecycle, Repo};
+
+/// Accounts required for the `cancel_repo_transfer` instruction.
+#[derive(Accounts)]
+pub struct CancelRepoTransfer<'info> {
+    /// Repository authority or `config.admin`.
+    pub signer: Signer<'info>,
+
+    /// Global configuration account, used to recognize the admin.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository with the pending transfer.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `cancel_repo_transfer` instruction.
+pub fn handle(ctx: Context<CancelRepoTransfer>) -> Result<()> {
+    let CancelRepoTransfer {
+        signer,
+        config,
+        lifecycle,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+
+    let signer_key = signer.key();
+    if signer_key != repo.authority && signer_key != config.admin {
+        return err!(Unit09Error::InvalidAuthority);
+    }
+    // Only the admin path survives the kill switch, a pause or a
+    // deactivation.
+    if signer_key != config.admin {
+        config.assert_not_killed()?;
+        config.assert_active(clock_ref.unix_timestamp)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let pending_authority = repo.cancel_transfer(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoTransferCancelled {
+        repo: repo.key(),
+        pending_authority,
+        cancelled_by: signer_key,
+    });
+
+    Ok(())
+}
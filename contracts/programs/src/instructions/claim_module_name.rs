@@ -0,0 +1,147 @@
+//! ===========================================================================
+//! Unit09 – Claim Module Name Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/claim_module_name.rs
+//!
+//! Files an ownership claim on a module whose name was taken by someone
+//! else. The claimant points at a domain proof (see `ModuleClaim`) and the
+//! config admin settles the claim with `resolve_claim`.
+//!
+//! Filing a claim changes nothing about the module itself; the current
+//! authority keeps full control until the admin approves the claim.
+//!
+//! On success this instruction:
+//! - creates the claimant's `ModuleClaim` PDA for the module, or reuses it
+//!   once their previous claim was resolved, and records a pending claim
+//! - emits `ModuleClaimFiled`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the claimant's previous claim on the module must not be pending
+//!   (`ModuleClaimPending`); other claimants' claims do not block it
+//! - the claimant must not already own the module (`InvalidAuthority`)
+//! - the proof must be a non-empty `https://` URL of at most
+//!   `MAX_CLAIM_PROOF_URI_LEN` bytes
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ModuleClaimFiled;
+use crate::state::{Config, Lifecycle, Module, ModuleClaim};
+
+/// Arguments for the `claim_module_name` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimModuleNameArgs {
+    /// `https://` URL of the domain proof naming the claimant's key.
+    pub proof_uri: String,
+}
+
+/// Accounts required for the `claim_module_name` instruction.
+#[derive(Accounts)]
+pub struct ClaimModuleName<'info> {
+    /// Key asking to become the module authority; pays for the claim
+    /// account.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Module being claimed.
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Claim PDA for this module and claimant.
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = ModuleClaim::LEN,
+        seeds = [
+            MODULE_CLAIM_SEED.as_bytes(),
+            module.key().as_ref(),
+            claimant.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim: Account<'info, ModuleClaim>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `claim_module_name` instruction.
+pub fn handle(ctx: Context<ClaimModuleName>, args: ClaimModuleNameArgs) -> Result<()> {
+    let ClaimModuleName {
+        claimant,
+        config,
+        lifecycle,
+        module,
+        claim,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // File the claim
+    // -----------------------------------------------------------------------
+
+    claim.file(
+        module.key(),
+        module,
+        claimant.key(),
+        args.proof_uri,
+        ctx.bumps.claim,
+        clock_ref,
+    )?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleClaimFiled {
+        module: claim.module,
+        claimant: claim.claimant,
+        proof_uri: claim.proof_uri.clone(),
+        filed_at: claim.filed_at,
+    });
+
+    Ok(())
+}
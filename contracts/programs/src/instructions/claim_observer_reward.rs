@@ -0,0 +1,146 @@
+//! ===========================================================================
+//! Unit09 – Claim Observer Reward Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/claim_observer_reward.rs
+//!
+//! Pays an observer the rewards accrued on its `ObserverStats` for one
+//! repository (see `Config::observation_reward_lamports`).
+//!
+//! Rewards come out of the lamport-only rewards vault PDA
+//! (`REWARDS_VAULT_SEED`), which the deployment funds with plain transfers.
+//! The vault always keeps its rent-exempt minimum, so only lamports above
+//! it can be paid out. Claims are all-or-nothing: when the vault cannot
+//! cover the whole balance, nothing is paid and the balance stays.
+//!
+//! On success this instruction:
+//! - transfers `ObserverStats::accrued_reward` lamports from the vault to
+//!   the observer and clears the balance
+//! - emits `ObserverRewardClaimed`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the signer must be the observer of the stats account (PDA seeds)
+//! - there must be a reward to claim (`NoRewardAccrued`)
+//! - the vault must cover it (`RewardsVaultInsufficient`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::constants::*;
+use crate::events::ObserverRewardClaimed;
+use crate::state::{Config, Lifecycle, ObserverStats};
+
+/// Accounts required for the `claim_observer_reward` instruction.
+#[derive(Accounts)]
+pub struct ClaimObserverReward<'info> {
+    /// Observer claiming its rewards; receives the lamports.
+    #[account(mut)]
+    pub observer: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// The observer's stats for the repository the rewards were earned on.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_STATS_SEED.as_bytes(), repo, observer.key()]
+    ///   bump  = observer_stats.bump
+    #[account(
+        mut,
+        seeds = [
+            OBSERVER_STATS_SEED.as_bytes(),
+            observer_stats.repo.as_ref(),
+            observer.key().as_ref(),
+        ],
+        bump = observer_stats.bump,
+    )]
+    pub observer_stats: Account<'info, ObserverStats>,
+
+    /// Lamport-only vault the rewards are paid from.
+    #[account(
+        mut,
+        seeds = [REWARDS_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub rewards_vault: SystemAccount<'info>,
+
+    /// System program, used for the payout transfer.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `claim_observer_reward` instruction.
+pub fn handle(ctx: Context<ClaimObserverReward>) -> Result<()> {
+    let ClaimObserverReward {
+        observer,
+        config,
+        lifecycle,
+        observer_stats,
+        rewards_vault,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Settle and pay out
+    // -----------------------------------------------------------------------
+
+    let rent_floor = Rent::get()?.minimum_balance(0);
+    let vault_available = rewards_vault.lamports().saturating_sub(rent_floor);
+    let amount = observer_stats.take_reward(vault_available)?;
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: rewards_vault.to_account_info(),
+                to: observer.to_account_info(),
+            },
+            &[&[REWARDS_VAULT_SEED.as_bytes(), &[ctx.bumps.rewards_vault]]],
+        ),
+        amount,
+    )?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ObserverRewardClaimed {
+        repo: observer_stats.repo,
+        observer: observer.key(),
+        amount,
+        claimed_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! ===========================================================================
+//! Unit09 – Close Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/close_module.rs
+//!
+//! This instruction permanently removes a `Module` account and reclaims its
+//! rent.
+//!
+//! On success this instruction:
+//! - decrements the owning repository's module counter and its
+//!   `module_categories` bucket, and unpins the module if it was pinned
+//! - decrements the parent module's `derivative_count` for a derivative
+//! - decrements the global module counter in `Metrics` and removes the
+//!   module from `Metrics::modules_by_version_count`
+//! - closes the `Module` PDA
+//! - sends the reclaimed lamports to `refund_to` when provided, otherwise to
+//!   the repository authority
+//! - emits `ModuleClosed`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only the repository authority may close its modules
+//! - a derivative's `parent_module` account must be passed
+//!   (`InvalidModuleParent`); a parent that was closed already is skipped
+//!
+//! `ModuleVersion` snapshots are not closed here; they remain as historical
+//! records.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleClosed;
+use crate::state::{Config, EntityKind, Lifecycle, Metrics, Module, Repo};
+
+/// Accounts required for the `close_module` instruction.
+#[derive(Accounts)]
+pub struct CloseModule<'info> {
+    /// Authority of the repository; must match `repo.authority`.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository that owns the module.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Module being closed.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            repo.key().as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+        constraint = module.repo == repo.key() @ Unit09Error::ModuleRepoMismatch,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Module that `module` was derived from, whose `derivative_count` is
+    /// decremented. Required when `module.is_derivative`.
+    ///
+    /// CHECK: address-constrained to `module.parent_module`; only read and
+    /// written as a `Module` while this program still owns it.
+    #[account(
+        mut,
+        address = module.parent_module @ Unit09Error::InvalidModuleParent,
+    )]
+    pub parent_module: Option<UncheckedAccount<'info>>,
+
+    /// Optional destination for the reclaimed rent (e.g. a treasury).
+    ///
+    /// Must be a system-owned account. When omitted, rent is refunded to
+    /// `authority`.
+    #[account(mut)]
+    pub refund_to: Option<SystemAccount<'info>>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `close_module` instruction.
+pub fn handle(ctx: Context<CloseModule>) -> Result<()> {
+    let CloseModule {
+        authority,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        module,
+        parent_module,
+        refund_to,
+    } = ctx.accounts;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(now)?;
+    repo.assert_authority(authority)?;
+    let parent_module = if module.is_derivative {
+        Some(parent_module.as_ref().ok_or(Unit09Error::InvalidModuleParent)?)
+    } else {
+        None
+    };
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let module_key = module.key();
+
+    repo.decrement_module_count()?;
+    repo.remove_module_category(&module.category);
+    if repo.is_pinned(&module_key) {
+        repo.unpin_module(&module_key, &clock)?;
+    }
+    repo.touch(&clock);
+
+    // A closed parent has been handed back to the system program.
+    if let Some(parent_module) = parent_module.filter(|info| info.owner == ctx.program_id) {
+        let mut data = parent_module.try_borrow_mut_data()?;
+        let mut parent = Module::try_deserialize(&mut &data[..])?;
+        parent.remove_derivative()?;
+        parent.try_serialize(&mut &mut data[..])?;
+    }
+
+    metrics.on_entity_removed(EntityKind::Module)?;
+    metrics.record_module_version_count(module.version_count, 0)?;
+    metrics.updated_at = now;
+
+    let destination = match refund_to {
+        Some(refund_to) => refund_to.to_account_info(),
+        None => authority.to_account_info(),
+    };
+    let refund_to = destination.key();
+    module.close(destination)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleClosed {
+        module: module_key,
+        repo: repo.key(),
+        authority: authority.key(),
+        refund_to,
+        closed_at: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+//! ===========================================================================
+//! Unit09 – Close Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/close_repo.rs
+//!
+//! This instruction permanently removes a `Repo` account and reclaims its
+//! rent.
+//!
+//! On success this instruction:
+//! - decrements the global repository counter in `Metrics`
+//! - closes the `Repo` PDA, and its `RepoUrlIndex` and `RepoLanguageStats`
+//!   when passed
+//! - sends the reclaimed lamports to `refund_to` when provided, otherwise to
+//!   the repository authority
+//! - closes each passed `ObserverStats`, refunding its rent to the observer
+//!   who paid for it
+//! - emits `RepoClosed`
+//!
+//! `remaining_accounts` carries one `(observer_stats, observer)` pair per
+//! observer of the repository, both writable:
+//! - `observer_stats` – the `ObserverStats` of `observer` for this repository
+//! - `observer`       – the wallet named by `observer_stats.observer`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only `Repo::authority` may close the repository
+//! - the repository must not have any registered modules left
+//! - the passed url index and stats accounts must belong to this repository
+//!   (`RepoKeyMismatch`)
+//! - no passed `ObserverStats` may hold an unclaimed reward
+//!   (`ObserverRewardUnclaimed`)
+//!
+//! Organizations commonly pass their treasury as `refund_to` so that rent
+//! does not accumulate in the operator wallet that happens to sign.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoClosed;
+use crate::state::{
+    Config, EntityKind, Lifecycle, Metrics, ObserverStats, Repo, RepoLanguageStats, RepoUrlIndex,
+};
+use crate::utils::assert_remaining_accounts_within_cap;
+
+/// Accounts required for the `close_repo` instruction.
+///
+/// The `(observer_stats, observer)` account pairs follow as
+/// `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CloseRepo<'info> {
+    /// Authority of the repository; must match `repo.authority`.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository being closed.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Index claiming the repository's URL, if one was created.
+    ///
+    /// Looked up by its stored hash, since `update_repo` does not re-index
+    /// URL changes.
+    #[account(
+        mut,
+        seeds = [
+            REPO_URL_INDEX_SEED.as_bytes(),
+            url_index.url_hash.as_ref(),
+        ],
+        bump = url_index.bump,
+        constraint = url_index.repo == repo.key() @ Unit09Error::RepoKeyMismatch,
+    )]
+    pub url_index: Option<Account<'info, RepoUrlIndex>>,
+
+    /// Per-language totals for the repository, if any were recorded.
+    #[account(
+        mut,
+        seeds = [
+            REPO_LANGUAGE_STATS_SEED.as_bytes(),
+            repo.key().as_ref(),
+        ],
+        bump = language_stats.bump,
+    )]
+    pub language_stats: Option<Account<'info, RepoLanguageStats>>,
+
+    /// Optional destination for the reclaimed rent (e.g. a treasury).
+    ///
+    /// Must be a system-owned account. When omitted, rent is refunded to
+    /// `authority`.
+    #[account(mut)]
+    pub refund_to: Option<SystemAccount<'info>>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `close_repo` instruction.
+pub fn handle(ctx: Context<CloseRepo>) -> Result<()> {
+    let CloseRepo {
+        authority,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        url_index,
+        language_stats,
+        refund_to,
+    } = ctx.accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(now)?;
+    repo.assert_authority(authority)?;
+
+    if repo.module_count > 0 {
+        return err!(Unit09Error::RepoHasModules);
+    }
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    if ctx.remaining_accounts.len() % 2 != 0 {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let repo_key = repo.key();
+
+    metrics.on_entity_removed(EntityKind::Repo)?;
+    metrics.updated_at = now;
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let (stats_info, observer_info) = (&accounts[0], &accounts[1]);
+        close_observer_stats(ctx.program_id, &repo_key, stats_info, observer_info)?;
+    }
+
+    let destination = match refund_to {
+        Some(refund_to) => refund_to.to_account_info(),
+        None => authority.to_account_info(),
+    };
+    if let Some(url_index) = url_index {
+        url_index.close(destination.clone())?;
+    }
+    if let Some(language_stats) = language_stats {
+        language_stats.close(destination.clone())?;
+    }
+    let refund_to = destination.key();
+    repo.close(destination)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoClosed {
+        repo: repo_key,
+        authority: authority.key(),
+        refund_to,
+        observer_stats_closed: (ctx.remaining_accounts.len() / 2) as u16,
+        closed_at: now,
+    });
+
+    Ok(())
+}
+
+/// Close `stats_info`, the `ObserverStats` of `observer_info` for `repo`,
+/// refunding its rent to the observer.
+///
+/// The stats are untyped remaining accounts, so they cannot use `close =`:
+/// move the lamports, hand the account back to the system program and drop
+/// its data.
+fn close_observer_stats(
+    program_id: &Pubkey,
+    repo: &Pubkey,
+    stats_info: &AccountInfo,
+    observer_info: &AccountInfo,
+) -> Result<()> {
+    if stats_info.owner != program_id {
+        return err!(Unit09Error::InvalidAccountOwner);
+    }
+    let stats = ObserverStats::try_deserialize(&mut &stats_info.try_borrow_data()?[..])?;
+    require_keys_eq!(stats.repo, *repo, Unit09Error::RepoKeyMismatch);
+    require_keys_eq!(stats.observer, *observer_info.key, Unit09Error::InvalidAuthority);
+    if stats.accrued_reward > 0 {
+        return err!(Unit09Error::ObserverRewardUnclaimed);
+    }
+
+    let lamports = stats_info.get_lamports();
+    stats_info.sub_lamports(lamports)?;
+    observer_info.add_lamports(lamports)?;
+    stats_info.assign(&System::id());
+    stats_info.realloc(0, false)?;
+    Ok(())
+}
@@ -0,0 +1,238 @@
+//! ===========================================================================
+//! Unit09 – Collect Changelog Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/collect_changelog.rs
+//!
+//! Read-only helper that assembles a module's changelog from its
+//! `ModuleVersion` accounts, so off-chain tools do not have to fetch and
+//! sort every version themselves.
+//!
+//! The versions are passed as `remaining_accounts`, in any order.
+//!
+//! On success this instruction:
+//! - orders the versions by `(major, minor, patch)`, dropping duplicates
+//! - emits `ChangelogAssembled` listing each version and its changelog URI
+//!
+//! Guards:
+//! - at most `MAX_REMAINING_ACCOUNTS` remaining accounts
+//!   (`TooManyRemainingAccounts`)
+//! - at most `MAX_CHANGELOG_VERSIONS` versions may be supplied
+//! - every version account must be a `ModuleVersion` owned by this program
+//!   and belonging to `module` (`ModuleVersionNotFound` otherwise)
+//! - every version account must sit at the `ModuleVersion` PDA for its own
+//!   module and version (`InvalidPda` otherwise)
+//!
+//! No account is mutated, and anyone may call it.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::{ChangelogAssembled, ChangelogEntry};
+use crate::state::{Module, ModuleVersion};
+use crate::utils::{assert_pdas, assert_remaining_accounts_within_cap};
+
+/// Accounts required for the `collect_changelog` instruction.
+///
+/// The `ModuleVersion` accounts to include follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CollectChangelog<'info> {
+    /// Module whose versions are listed.
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `collect_changelog` instruction.
+pub fn handle(ctx: Context<CollectChangelog>) -> Result<()> {
+    let CollectChangelog { module, clock } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    if ctx.remaining_accounts.len() > MAX_CHANGELOG_VERSIONS {
+        return err!(Unit09Error::ChangelogTooLarge);
+    }
+
+    let mut versions = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        if info.owner != ctx.program_id {
+            return err!(Unit09Error::InvalidAccountOwner);
+        }
+        let data = info.try_borrow_data()?;
+        versions.push(ModuleVersion::try_deserialize(&mut &data[..])?);
+    }
+
+    let encoded: Vec<[[u8; 2]; 3]> = versions
+        .iter()
+        .map(|version| {
+            [
+                version.major_version.to_le_bytes(),
+                version.minor_version.to_le_bytes(),
+                version.patch_version.to_le_bytes(),
+            ]
+        })
+        .collect();
+    let seeds: Vec<[&[u8]; 5]> = versions
+        .iter()
+        .zip(&encoded)
+        .map(|(version, [major, minor, patch])| {
+            [
+                MODULE_VERSION_SEED.as_bytes(),
+                version.module.as_ref(),
+                &major[..],
+                &minor[..],
+                &patch[..],
+            ]
+        })
+        .collect();
+    let pairs: Vec<(&Pubkey, &[&[u8]])> = ctx
+        .remaining_accounts
+        .iter()
+        .zip(&seeds)
+        .map(|(info, seeds)| (info.key, &seeds[..]))
+        .collect();
+    assert_pdas(&pairs, ctx.program_id)?;
+
+    // -----------------------------------------------------------------------
+    // Assemble
+    // -----------------------------------------------------------------------
+
+    let entries = assemble_changelog(&module.key(), versions)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ChangelogAssembled {
+        module: module.key(),
+        entries,
+        assembled_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Changelog entries for `versions` of `module`, in ascending version order.
+///
+/// Fails with `ModuleVersionNotFound` if any version belongs to another
+/// module. A version supplied more than once is listed once.
+pub fn assemble_changelog(
+    module: &Pubkey,
+    mut versions: Vec<ModuleVersion>,
+) -> Result<Vec<ChangelogEntry>> {
+    if versions.iter().any(|version| version.module != *module) {
+        return err!(Unit09Error::ModuleVersionNotFound);
+    }
+
+    versions.sort_by_key(ModuleVersion::version);
+    versions.dedup_by_key(|version| version.version());
+
+    Ok(versions
+        .into_iter()
+        .map(|version| ChangelogEntry {
+            major_version: version.major_version,
+            minor_version: version.minor_version,
+            patch_version: version.patch_version,
+            changelog_uri: version.changelog_uri,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::zeroed;
+
+    fn new_version(module: Pubkey, version: (u16, u16, u16)) -> ModuleVersion {
+        let mut module_version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        let (major, minor, patch) = version;
+        module_version
+            .init(
+                module,
+                Pubkey::new_unique(),
+                version,
+                format!("https://unit09.org/metadata/router/{major}.{minor}.{patch}.json"),
+                format!("https://unit09.org/changelog/router/{major}.{minor}.{patch}.md"),
+                String::new(),
+                true,
+                [0u8; 32],
+                0,
+                String::new(),
+                None,
+                Vec::new(),
+                255,
+                &Clock::default(),
+            )
+            .unwrap();
+        module_version
+    }
+
+    fn listed(entries: &[ChangelogEntry]) -> Vec<(u16, u16, u16)> {
+        entries
+            .iter()
+            .map(|entry| (entry.major_version, entry.minor_version, entry.patch_version))
+            .collect()
+    }
+
+    #[test]
+    fn out_of_order_versions_are_listed_ascending() {
+        let module = Pubkey::new_unique();
+        let versions = [(1, 10, 0), (0, 9, 1), (1, 2, 3), (1, 2, 0)]
+            .into_iter()
+            .map(|version| new_version(module, version))
+            .collect();
+
+        let entries = assemble_changelog(&module, versions).unwrap();
+        assert_eq!(
+            listed(&entries),
+            vec![(0, 9, 1), (1, 2, 0), (1, 2, 3), (1, 10, 0)]
+        );
+        assert_eq!(
+            entries[0].changelog_uri,
+            "https://unit09.org/changelog/router/0.9.1.md"
+        );
+    }
+
+    #[test]
+    fn repeated_versions_are_listed_once() {
+        let module = Pubkey::new_unique();
+        let versions = vec![
+            new_version(module, (2, 0, 0)),
+            new_version(module, (1, 0, 0)),
+            new_version(module, (2, 0, 0)),
+        ];
+
+        let entries = assemble_changelog(&module, versions).unwrap();
+        assert_eq!(listed(&entries), vec![(1, 0, 0), (2, 0, 0)]);
+    }
+
+    #[test]
+    fn versions_of_another_module_are_rejected() {
+        let module = Pubkey::new_unique();
+        let versions = vec![
+            new_version(module, (1, 0, 0)),
+            new_version(Pubkey::new_unique(), (1, 1, 0)),
+        ];
+
+        assert!(assemble_changelog(&module, versions).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! ===========================================================================
+//! Unit09 – Compute Fork Diff Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/compute_fork_diff.rs
+//!
+//! Read-only helper that compares the module composition of two forks,
+//! typically a fork and its parent, for UIs that want to show what changed.
+//!
+//! `Fork` accounts do not store their module sets, so the caller supplies
+//! both lists; the two fork accounts anchor the result to real forks.
+//!
+//! On success this instruction:
+//! - runs `fork_module_diff(base_modules, fork_modules)`
+//! - emits `ForkDiffComputed` with the added / removed counts
+//!
+//! No account is mutated, and anyone may call it.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ForkDiffComputed;
+use crate::state::{fork_module_diff, Fork};
+
+/// Arguments for the `compute_fork_diff` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ComputeForkDiffArgs {
+    /// Module keys composing `fork`.
+    pub fork_modules: Vec<Pubkey>,
+
+    /// Module keys composing `base`.
+    pub base_modules: Vec<Pubkey>,
+}
+
+/// Accounts required for the `compute_fork_diff` instruction.
+#[derive(Accounts)]
+pub struct ComputeForkDiff<'info> {
+    /// Fork being compared.
+    #[account(
+        seeds = [
+            FORK_SEED.as_bytes(),
+            fork.fork_key.as_ref(),
+        ],
+        bump = fork.bump,
+    )]
+    pub fork: Account<'info, Fork>,
+
+    /// Fork to compare against.
+    #[account(
+        seeds = [
+            FORK_SEED.as_bytes(),
+            base.fork_key.as_ref(),
+        ],
+        bump = base.bump,
+    )]
+    pub base: Account<'info, Fork>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `compute_fork_diff` instruction.
+pub fn handle(ctx: Context<ComputeForkDiff>, args: ComputeForkDiffArgs) -> Result<()> {
+    let ComputeForkDiff { fork, base, clock } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    if args.fork_modules.len() > MAX_FORK_DIFF_MODULES
+        || args.base_modules.len() > MAX_FORK_DIFF_MODULES
+    {
+        return err!(Unit09Error::ForkDiffTooLarge);
+    }
+
+    // -----------------------------------------------------------------------
+    // Compare
+    // -----------------------------------------------------------------------
+
+    let (added, removed) = fork_module_diff(&args.base_modules, &args.fork_modules);
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    // Both lengths are bounded by MAX_FORK_DIFF_MODULES, so the casts are safe.
+    emit!(ForkDiffComputed {
+        fork: fork.key(),
+        base: base.key(),
+        added_count: added.len() as u32,
+        removed_count: removed.len() as u32,
+        computed_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! ===========================================================================
+//! Unit09 – Confirm Module Link Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/confirm_module_link.rs
+//!
+//! Second half of a mutual-consent module link.
+//!
+//! When a repo requires mutual consent, `link_module_to_repo` creates links
+//! across authorities in a pending, inactive state and records which side
+//! still has to agree. That authority signs this instruction to activate
+//! the link.
+//!
+//! On success this instruction:
+//! - clears `link.pending_confirmation_from` and sets `link.is_active`
+//! - emits `ModuleLinkConfirmed`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - the link must be pending and the signer must be the awaited authority
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ModuleLinkConfirmed;
+use crate::state::{Config, Lifecycle, ModuleRepoLink};
+
+/// Accounts required for the `confirm_module_link` instruction.
+#[derive(Accounts)]
+pub struct ConfirmModuleLink<'info> {
+    /// Authority named in `link.pending_confirmation_from`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Pending link between a module and a repo.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_REPO_LINK_SEED.as_bytes(),
+            link.module.as_ref(),
+            link.repo.as_ref(),
+        ],
+        bump = link.bump,
+    )]
+    pub link: Account<'info, ModuleRepoLink>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `confirm_module_link` instruction.
+pub fn handle(ctx: Context<ConfirmModuleLink>) -> Result<()> {
+    let ConfirmModuleLink {
+        authority,
+        config,
+        lifecycle,
+        link,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Apply (also verifies the signer is the awaited authority)
+    // -----------------------------------------------------------------------
+
+    link.confirm(&authority.key(), clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleLinkConfirmed {
+        module: link.module,
+        repo: link.repo,
+        confirmed_by: authority.key(),
+        confirmed_at: link.confirmed_at,
+    });
+
+    Ok(())
+}
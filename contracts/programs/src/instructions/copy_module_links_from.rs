@@ -0,0 +1,337 @@
+//! ===========================================================================
+//! Unit09 – Copy Module Links From Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/copy_module_links_from.rs
+//!
+//! Imports an upstream repository's module registry into a downstream one,
+//! typically right after forking it: every listed module registered under
+//! `source_repo` gets a secondary `ModuleRepoLink` into `target_repo`.
+//!
+//! `remaining_accounts` carries one `(module, link)` pair per module:
+//! - `module` – a `Module` registered under `source_repo`, read-only
+//! - `link`   – the `ModuleRepoLink` PDA for `(module, target_repo)`,
+//!   writable; created here when it does not exist yet, even if its
+//!   address was pre-funded (see `create_pda_account`)
+//!
+//! Modules already linked to `target_repo` are left as they are, so running
+//! the copy again does not demote a primary link or overwrite its notes.
+//! New links follow the same rules as `link_module_to_repo`, including
+//! pending links when `target_repo` requires mutual consent.
+//!
+//! On success this instruction:
+//! - creates a secondary `ModuleRepoLink` for every module not yet linked
+//! - emits one `ModuleLinkedToRepo` per created link
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the signer must be `target_repo.authority` (`InvalidAuthority`)
+//! - `target_repo` must be active (`Repo::assert_active`)
+//! - at most `MAX_REMAINING_ACCOUNTS` remaining accounts
+//!   (`TooManyRemainingAccounts`), two per module (`MissingRequiredAccount`)
+//! - every module must be owned by this program (`InvalidAccountOwner`)
+//!   and registered under `source_repo`, which must differ from
+//!   `target_repo` (`ModuleRepoMismatch`)
+//! - every link account must be the PDA for its module and `target_repo`
+//!   (`InvalidPda`)
+//! - every module must be past its `active_from` embargo (`ModuleEmbargoed`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleLinkedToRepo;
+use crate::instructions::link_module_to_repo::{link_module, LinkModuleToRepoArgs};
+use crate::state::{Config, Lifecycle, Module, ModuleRepoLink, Repo};
+use crate::utils::{assert_remaining_accounts_within_cap, create_pda_account, module_repo_link_pda};
+
+/// Arguments for the `copy_module_links_from` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CopyModuleLinksFromArgs {
+    /// Notes stored on every link created by this call.
+    pub notes: String,
+}
+
+/// Accounts required for the `copy_module_links_from` instruction.
+///
+/// The `(module, link)` account pairs follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CopyModuleLinksFrom<'info> {
+    /// Payer for link accounts created by this call.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Authority of `target_repo`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Upstream repository whose modules are copied.
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            source_repo.repo_key.as_ref(),
+        ],
+        bump = source_repo.bump,
+    )]
+    pub source_repo: Account<'info, Repo>,
+
+    /// Downstream repository receiving the links.
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            target_repo.repo_key.as_ref(),
+        ],
+        bump = target_repo.bump,
+        constraint = target_repo.authority == authority.key() @ Unit09Error::InvalidAuthority,
+    )]
+    pub target_repo: Account<'info, Repo>,
+
+    /// System program, used to create new link accounts.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `copy_module_links_from` instruction.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CopyModuleLinksFrom<'info>>,
+    args: CopyModuleLinksFromArgs,
+) -> Result<()> {
+    let CopyModuleLinksFrom {
+        payer,
+        authority,
+        config,
+        lifecycle,
+        source_repo,
+        target_repo,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    target_repo.assert_active()?;
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    if ctx.remaining_accounts.len() % 2 != 0 {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+
+    // -----------------------------------------------------------------------
+    // Copy each module
+    // -----------------------------------------------------------------------
+
+    let source_key = source_repo.key();
+    let target_key = target_repo.key();
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let (module_info, link_info) = (&accounts[0], &accounts[1]);
+
+        if module_info.owner != ctx.program_id {
+            return err!(Unit09Error::InvalidAccountOwner);
+        }
+        let module = Module::try_deserialize(&mut &module_info.try_borrow_data()?[..])?;
+
+        let (link_address, link_bump) =
+            module_repo_link_pda(ctx.program_id, module_info.key, &target_key);
+        require_keys_eq!(*link_info.key, link_address, Unit09Error::InvalidPda);
+
+        let mut link = if link_info.owner == ctx.program_id {
+            ModuleRepoLink::try_deserialize(&mut &link_info.try_borrow_data()?[..])?
+        } else {
+            create_pda_account(
+                payer,
+                link_info,
+                system_program,
+                ModuleRepoLink::LEN,
+                &[
+                    MODULE_REPO_LINK_SEED.as_bytes(),
+                    module_info.key.as_ref(),
+                    target_key.as_ref(),
+                    &[link_bump],
+                ],
+                ctx.program_id,
+            )?;
+            let zeroed = [0u8; ModuleRepoLink::LEN];
+            ModuleRepoLink::deserialize(&mut &zeroed[..])?
+        };
+
+        let copied = copy_module_link(
+            &mut link,
+            *module_info.key,
+            &module,
+            source_key,
+            target_key,
+            target_repo,
+            authority.key(),
+            args.notes.clone(),
+            link_bump,
+            clock_ref,
+        )?;
+        if let Some(event) = copied {
+            link.try_serialize(&mut &mut link_info.try_borrow_mut_data()?[..])?;
+            emit!(event);
+        }
+    }
+
+    Ok(())
+}
+
+/// Link `module`, registered under `source_repo`, to `target_repo` as a
+/// secondary link.
+///
+/// Returns `None`, leaving `link` untouched, when the module is already
+/// linked to `target_repo`.
+pub fn copy_module_link(
+    link: &mut ModuleRepoLink,
+    module_key: Pubkey,
+    module: &Module,
+    source_repo: Pubkey,
+    target_key: Pubkey,
+    target_repo: &Repo,
+    signer: Pubkey,
+    notes: String,
+    bump: u8,
+    clock: &Clock,
+) -> Result<Option<ModuleLinkedToRepo>> {
+    if module.repo != source_repo || source_repo == target_key {
+        return err!(Unit09Error::ModuleRepoMismatch);
+    }
+    if link.is_initialized() {
+        return Ok(None);
+    }
+
+    let args = LinkModuleToRepoArgs {
+        is_primary: false,
+        notes,
+        expires_at: None,
+    };
+    let event = link_module(
+        link,
+        module_key,
+        module,
+        target_key,
+        target_repo,
+        signer,
+        args,
+        bump,
+        clock,
+    )?;
+    Ok(Some(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{new_module, new_repo, zeroed};
+
+    fn blank_link() -> ModuleRepoLink {
+        zeroed(ModuleRepoLink::LEN)
+    }
+
+    #[test]
+    fn upstream_modules_are_copied_as_secondary_links() {
+        let upstream_authority = Pubkey::new_unique();
+        let downstream_authority = Pubkey::new_unique();
+        let upstream_key = Pubkey::new_unique();
+        let downstream_key = Pubkey::new_unique();
+        let downstream = new_repo(downstream_authority, &Clock::default());
+
+        for name in ["router", "indexer"] {
+            let module = new_module(upstream_key, upstream_authority, name, &Clock::default());
+            let module_key = Pubkey::new_unique();
+            let mut link = blank_link();
+
+            let event = copy_module_link(
+                &mut link,
+                module_key,
+                &module,
+                upstream_key,
+                downstream_key,
+                &downstream,
+                downstream_authority,
+                "copied from upstream".to_string(),
+                254,
+                &Clock::default(),
+            )
+            .unwrap()
+            .unwrap();
+
+            assert!(link.is_active && !link.is_primary);
+            assert_eq!((link.module, link.repo), (module_key, downstream_key));
+            assert_eq!(link.linked_by, downstream_authority);
+            assert_eq!(link.notes, "copied from upstream");
+            assert_eq!((event.module, event.repo), (module_key, downstream_key));
+        }
+    }
+
+    #[test]
+    fn existing_links_and_foreign_modules_are_not_copied() {
+        let authority = Pubkey::new_unique();
+        let upstream_key = Pubkey::new_unique();
+        let downstream_key = Pubkey::new_unique();
+        let downstream = new_repo(authority, &Clock::default());
+        let module = new_module(upstream_key, authority, "router", &Clock::default());
+        let copy = |link: &mut ModuleRepoLink, source_repo| {
+            copy_module_link(
+                link,
+                Pubkey::new_unique(),
+                &module,
+                source_repo,
+                downstream_key,
+                &downstream,
+                authority,
+                String::new(),
+                254,
+                &Clock::default(),
+            )
+        };
+
+        // An existing primary link keeps its flags.
+        let mut primary = blank_link();
+        primary
+            .init(
+                Pubkey::new_unique(),
+                downstream_key,
+                authority,
+                true,
+                "home".to_string(),
+                None,
+                253,
+                &Clock::default(),
+            )
+            .unwrap();
+        assert!(copy(&mut primary, upstream_key).unwrap().is_none());
+        assert!(primary.is_primary);
+        assert_eq!(primary.notes, "home");
+
+        // The module is not registered under the claimed source repo.
+        assert!(copy(&mut blank_link(), Pubkey::new_unique()).is_err());
+    }
+}
@@ -13,18 +13,39 @@
 //! On success this instruction:
 //! - initializes a `Fork` PDA
 //! - sets parent, depth, label, metadata URI, tags
+//! - records the parent fork's status at fork time
+//! - increments the parent fork's `child_count`
+//! - increments the global `Metrics::total_forks` counter and the fork's
+//!   `Metrics::forks_by_depth` bucket
+//! - records the lineage `root`: the parent fork's root, or the new fork
+//!   itself when it has no parent fork
+//! - numbers the fork among the owner's forks (`Fork::owner_fork_index`),
+//!   creating the owner's `OwnerForkCounter` on their first fork
 //! - marks the fork as active
-//! - emits `ForkCreated` event
+//! - sets `Fork::expires_at` when `expires_at` is given
+//! - charges `Config::fork_fee_lamports` to the payer (if non-zero)
+//! - emits `ForkCreated` event, including the fee charged
 //!
 //! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
-//! - any signer can become a fork owner by calling this instruction
+//! - the deployment must hold fewer forks than `Config::max_total_forks`,
+//!   when set (`GlobalLimitReached`, reported via `MetricsLimitReached`)
+//! - any signer can become a fork owner unless
+//!   `Config::restrict_fork_creation` is set, in which case the owner must
+//!   hold the "forker" role in its `Authority` registry entry
+//!   (`ForkCreationRestricted`, reported via `OperationRejected`)
+//! - a non-root fork with a `parent` must pass that parent as `parent_fork`;
+//!   a retired parent is refused unless `allow_retired_parent` is set
 //!
 //! PDA layout:
 //! - Fork:
 //!     seeds = [FORK_SEED.as_bytes(), args.fork_key.as_ref()]
 //!     bump  = fork.bump
+//! - OwnerForkCounter:
+//!     seeds = [OWNER_FORK_COUNTER_SEED.as_bytes(), owner.key().as_ref()]
+//!     bump  = owner_fork_counter.bump
 //!
 //! ===========================================================================
 
@@ -32,8 +53,9 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::ForkCreated;
-use crate::state::{Config, Fork, Lifecycle};
+use crate::events::{ForkCreated, OperationRejected, RejectedOperation};
+use crate::state::{Authority, Config, EntityKind, Fork, Lifecycle, Metrics, OwnerForkCounter};
+use crate::utils::{assert_global_limit, collect_fee, EventSink};
 
 /// Arguments for the `create_fork` instruction.
 ///
@@ -82,9 +104,18 @@ pub struct CreateForkArgs {
     /// - depth defaults to 0 when `is_root == true`
     /// - depth defaults to 1 when `is_root == false`
     pub depth: Option<u16>,
+
+    /// Allow forking from a retired parent (deliberate archaeology on an
+    /// archived variant). Without it, a retired `parent_fork` is rejected.
+    pub allow_retired_parent: bool,
+
+    /// Optional Unix timestamp after which anyone may retire the fork via
+    /// `expire_fork`. Must be in the future; `None` or 0 means never.
+    pub expires_at: Option<i64>,
 }
 
 /// Accounts required for the `create_fork` instruction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateFork<'info> {
     /// Payer for the newly created `Fork` account.
@@ -114,6 +145,23 @@ pub struct CreateFork<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account that aggregates deployment-wide counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Role registry entry of `owner`.
+    ///
+    /// Only required when `config.restrict_fork_creation` is enabled.
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes(), owner.key().as_ref()],
+        bump = forker_role.bump,
+    )]
+    pub forker_role: Option<Account<'info, Authority>>,
+
     /// Fork account to be created.
     ///
     /// PDA:
@@ -134,6 +182,38 @@ pub struct CreateFork<'info> {
     )]
     pub fork: Account<'info, Fork>,
 
+    /// Parent fork, required when `args.parent` is set on a non-root fork.
+    ///
+    /// `args.parent` must be this account's address. Root forks whose
+    /// parent is an identity rather than a fork omit it. Its `child_count`
+    /// is incremented.
+    #[account(mut)]
+    pub parent_fork: Option<Account<'info, Fork>>,
+
+    /// Counter numbering the owner's forks, created on their first fork.
+    ///
+    /// PDA:
+    ///   seeds = [OWNER_FORK_COUNTER_SEED.as_bytes(), owner.key()]
+    ///   bump  = owner_fork_counter.bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OwnerForkCounter::LEN,
+        seeds = [
+            OWNER_FORK_COUNTER_SEED.as_bytes(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub owner_fork_counter: Account<'info, OwnerForkCounter>,
+
+    /// Recipient of the fork fee: the treasury, or the fee vault PDA.
+    ///
+    /// CHECK: validated against `Config::fee_destination` by `collect_fee`
+    /// whenever a non-zero fork fee is charged.
+    #[account(mut)]
+    pub fee_destination: UncheckedAccount<'info>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -153,19 +233,27 @@ pub struct CreateFork<'info> {
 /// Steps:
 /// 1. Ensure lifecycle allows writes and config is active.
 /// 2. Validate label, metadata URI, and tags length.
-/// 3. Derive parent and depth values.
+/// 3. Derive parent and depth values; check the parent fork is not retired.
 /// 4. Initialize `Fork` account via `Fork::init`.
-/// 5. Emit `ForkCreated` event.
+/// 5. Charge the configured fork fee to the payer.
+/// 6. Emit `ForkCreated` event.
 pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     let CreateFork {
-        payer: _,
+        payer,
         owner,
         mut config,
         mut lifecycle,
+        metrics,
+        forker_role,
         mut fork,
-        system_program: _,
+        parent_fork,
+        owner_fork_counter,
+        fee_destination,
+        system_program,
         rent: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -174,8 +262,26 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    assert_global_limit(config, metrics, EntityKind::Fork, clock_ref.unix_timestamp)?;
+
+    // Enforce the forker allowlist when the deployment is restricted.
+    // Refusals are reported via `OperationRejected` before failing.
+    if let Err(error) = config.assert_can_create_fork(
+        &owner.key(),
+        forker_role.as_deref(),
+        clock_ref.unix_timestamp,
+    ) {
+        emit!(OperationRejected::new(
+            RejectedOperation::ForkCreation,
+            owner.key(),
+            &error,
+            clock_ref.unix_timestamp,
+        ));
+        return Err(error);
+    }
 
     // -----------------------------------------------------------------------
     // Early validation
@@ -206,7 +312,7 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     // Derive PDA bump from Anchor context
     // -----------------------------------------------------------------------
 
-    let fork_bump = *ctx.bumps.get("fork").ok_or(Unit09Error::InternalError)?;
+    let fork_bump = ctx.bumps.fork;
 
     // -----------------------------------------------------------------------
     // Compute parent and depth
@@ -214,6 +320,21 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
 
     let parent = args.parent.unwrap_or_else(Pubkey::default);
 
+    let parent_status = match parent_fork.as_ref() {
+        Some(parent_fork) => {
+            if parent_fork.key() != parent {
+                return err!(Unit09Error::InvalidForkParent);
+            }
+            Some(parent_fork.status_as_parent(args.allow_retired_parent)?)
+        }
+        None => {
+            if args.parent.is_some() && !args.is_root {
+                return err!(Unit09Error::InvalidForkParent);
+            }
+            None
+        }
+    };
+
     // If depth is not provided:
     // - for root forks: depth = 0
     // - for non-root forks: depth = 1
@@ -241,22 +362,58 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
         args.tags,
         args.is_root,
         depth,
+        parent_status,
         fork_bump,
         clock_ref,
     )?;
 
+    fork.set_expiry(args.expires_at.unwrap_or(0), clock_ref)?;
+
+    fork.root = Fork::lineage_root(
+        fork.key(),
+        parent_fork.as_ref().map(|parent_fork| (parent_fork.key(), &**parent_fork)),
+    );
+
+    if let Some(parent_fork) = parent_fork.as_mut() {
+        parent_fork.add_child()?;
+    }
+
+    if !owner_fork_counter.is_initialized() {
+        owner_fork_counter.init(owner.key(), ctx.bumps.owner_fork_counter, clock_ref);
+    }
+    fork.owner_fork_index = owner_fork_counter.next_index()?;
+
+    metrics.increment_forks()?;
+    metrics.record_fork_depth(fork.depth)?;
+    metrics.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Charge fork fee
+    // -----------------------------------------------------------------------
+
+    let fee_lamports = config.fork_fee_lamports;
+    collect_fee(
+        config,
+        payer,
+        &fee_destination.to_account_info(),
+        system_program,
+        fee_lamports,
+    )?;
+
     // -----------------------------------------------------------------------
     // Emit ForkCreated event
     // -----------------------------------------------------------------------
 
-    emit!(ForkCreated {
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(ForkCreated {
         fork: fork.key(),
-        owner: fork.owner,
         parent: fork.parent,
-        is_root: fork.is_root,
-        depth: fork.depth,
-        created_at: fork.created_at,
-    });
+        owner: fork.owner,
+        label: fork.label.clone(),
+        fee_lamports,
+        root: fork.root,
+        owner_fork_index: fork.owner_fork_index,
+    })?;
 
     Ok(())
 }
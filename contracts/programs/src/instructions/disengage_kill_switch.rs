@@ -0,0 +1,88 @@
+//! ===========================================================================
+//! Unit09 – Disengage Kill Switch Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/disengage_kill_switch.rs
+//!
+//! Lifts the kill switch set by `engage_kill_switch`, once the exploit has
+//! been dealt with. A pause set alongside it stays in place.
+//!
+//! Guards:
+//! - the current `Config::admin` must sign, joined by enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`);
+//!   co-signers are passed as `(signer, authority)` pairs in
+//!   `remaining_accounts` (see `utils::admin_co_signers`)
+//! - the kill switch must be engaged
+//!
+//! On success this instruction:
+//! - clears `Config::global_kill` and `killed_at`
+//! - emits `KillSwitchDisengaged`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::KillSwitchDisengaged;
+use crate::state::Config;
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Accounts required for the `disengage_kill_switch` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct DisengageKillSwitch<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `disengage_kill_switch` instruction.
+pub fn handle(ctx: Context<DisengageKillSwitch>) -> Result<()> {
+    let DisengageKillSwitch {
+        admin,
+        config,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    let co_signers =
+        admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+    config.assert_admin_quorum(admin.key(), &co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let engaged_at = config.disengage_kill_switch(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(KillSwitchDisengaged {
+        admin: admin.key(),
+        engaged_at,
+        disengaged_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
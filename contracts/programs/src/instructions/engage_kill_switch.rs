@@ -0,0 +1,90 @@
+//! ===========================================================================
+//! Unit09 – Engage Kill Switch Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/engage_kill_switch.rs
+//!
+//! Emergency stop for a confirmed exploit. Stronger than `pause`: there is
+//! no scheduled end, and every non-admin instruction that writes state
+//! fails `Config::assert_not_killed` with `KillSwitchEngaged` until
+//! `disengage_kill_switch` is called. Admin instructions keep working so
+//! operators can recover; read-only instructions are unaffected.
+//!
+//! Guards:
+//! - the current `Config::admin` must sign, joined by enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`);
+//!   co-signers are passed as `(signer, authority)` pairs in
+//!   `remaining_accounts` (see `utils::admin_co_signers`)
+//! - the kill switch must not already be engaged
+//!
+//! On success this instruction:
+//! - sets `Config::global_kill` and `killed_at`
+//! - emits `KillSwitchEngaged`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::KillSwitchEngaged;
+use crate::state::Config;
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Accounts required for the `engage_kill_switch` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct EngageKillSwitch<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `engage_kill_switch` instruction.
+pub fn handle(ctx: Context<EngageKillSwitch>) -> Result<()> {
+    let EngageKillSwitch {
+        admin,
+        config,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    let co_signers =
+        admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+    config.assert_admin_quorum(admin.key(), &co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    config.engage_kill_switch(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(KillSwitchEngaged {
+        admin: admin.key(),
+        engaged_at: config.killed_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! ===========================================================================
+//! Unit09 – Expire Fork Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/expire_fork.rs
+//!
+//! Retires a fork whose `Fork::expires_at` has passed. Short-lived forks,
+//! such as experiment forks created by CI, set an expiry at creation so they
+//! can be cleaned up without their owner.
+//!
+//! This instruction is permissionless: any signer may call it once the fork
+//! has expired.
+//!
+//! On success this instruction:
+//! - moves the fork to `ForkStatus::Retired`, deactivating it
+//! - emits a `ForkStateUpdated` event
+//! - emits a `Unit09Log` (category `fork_children_orphaned`) when an active
+//!   fork with children is retired
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the fork must have an expiry that has passed and must not already be
+//!   retired (`Fork::expire`)
+//! - retiring a fork with children is refused when
+//!   `Config::block_parent_fork_deactivation` is set (`Fork::check_deactivation`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::{ForkStateUpdated, Unit09Log};
+use crate::instructions::update_fork_state::FORK_CHILDREN_ORPHANED_LOG_CATEGORY;
+use crate::state::{Config, Fork, ForkStatus, Lifecycle};
+use crate::utils::EventSink;
+
+/// Accounts required for the `expire_fork` instruction.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireFork<'info> {
+    /// Any signer; expiry needs no authority.
+    pub caller: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global phases and freeze.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Fork to retire.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       FORK_SEED.as_bytes(),
+    ///       fork.fork_key.as_ref(),
+    ///   ]
+    ///   bump  = fork.bump
+    #[account(
+        mut,
+        seeds = [
+            FORK_SEED.as_bytes(),
+            fork.fork_key.as_ref(),
+        ],
+        bump = fork.bump,
+    )]
+    pub fork: Account<'info, Fork>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `expire_fork` instruction.
+pub fn handle(ctx: Context<ExpireFork>) -> Result<()> {
+    let ExpireFork {
+        caller: _,
+        config,
+        lifecycle,
+        fork,
+        clock,
+        event_authority,
+        program: _,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    let orphans_children =
+        fork.check_deactivation(ForkStatus::Retired, config.block_parent_fork_deactivation)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    fork.expire(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(ForkStateUpdated {
+        fork: fork.key(),
+        active: fork.is_active,
+        status: fork.status,
+    })?;
+
+    if orphans_children {
+        emit!(Unit09Log {
+            category: FORK_CHILDREN_ORPHANED_LOG_CATEGORY.to_string(),
+            message: format!(
+                "fork {} expired with {} child fork(s)",
+                fork.key(),
+                fork.child_count
+            ),
+            logged_at: clock_ref.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
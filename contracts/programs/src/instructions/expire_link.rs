@@ -0,0 +1,116 @@
+//! ===========================================================================
+//! Unit09 – Expire Link Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/expire_link.rs
+//!
+//! Closes a `ModuleRepoLink` whose `expires_at` has passed. Temporary links,
+//! such as "used in this audit", set an expiry when they are created or
+//! refreshed so they can be cleaned up without either authority.
+//!
+//! This instruction is permissionless: any signer may call it once the link
+//! has expired. The reclaimed rent goes to `link.linked_by`, the authority
+//! that last created or refreshed the link.
+//!
+//! On success this instruction:
+//! - closes the `ModuleRepoLink` PDA
+//! - emits `ModuleLinkExpired`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the link must have an expiry that has passed (`LinkNotExpired`)
+//! - `rent_recipient` must be `link.linked_by` (`InvalidAuthority`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleLinkExpired;
+use crate::state::{Config, Lifecycle, ModuleRepoLink};
+
+/// Accounts required for the `expire_link` instruction.
+#[derive(Accounts)]
+pub struct ExpireLink<'info> {
+    /// Any signer; expiry needs no authority.
+    pub caller: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Expired link between a module and a repo.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_REPO_LINK_SEED.as_bytes(),
+            link.module.as_ref(),
+            link.repo.as_ref(),
+        ],
+        bump = link.bump,
+        close = rent_recipient,
+    )]
+    pub link: Account<'info, ModuleRepoLink>,
+
+    /// Receives the link's rent; must be `link.linked_by`.
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == link.linked_by @ Unit09Error::InvalidAuthority,
+    )]
+    pub rent_recipient: SystemAccount<'info>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `expire_link` instruction.
+pub fn handle(ctx: Context<ExpireLink>) -> Result<()> {
+    let ExpireLink {
+        caller,
+        config,
+        lifecycle,
+        link,
+        rent_recipient: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    link.assert_expired(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Emit (the link is closed by Anchor when the instruction returns)
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleLinkExpired {
+        module: link.module,
+        repo: link.repo,
+        closed_by: caller.key(),
+        expires_at: link.expires_at,
+        closed_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
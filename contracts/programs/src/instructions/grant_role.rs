@@ -0,0 +1,166 @@
+//! ===========================================================================
+//! Unit09 – Grant Role Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/grant_role.rs
+//!
+//! This instruction adds a role to the `Authority` registry entry of a key.
+//!
+//! On success this instruction:
+//! - creates the `Authority` PDA for the target key on first use
+//! - adds (or refreshes) the requested role entry
+//! - emits `AuthorityRoleAssigned`
+//!
+//! Guards:
+//! - lifecycle must allow writes
+//! - only the current `Config::admin` may grant roles
+//! - the admin role (`ROLE_LABEL_ADMIN`) additionally needs enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`),
+//!   passed as `(signer, authority)` pairs in `remaining_accounts`
+//!
+//! PDA layout:
+//! - Authority:
+//!     seeds = [AUTHORITY_SEED.as_bytes(), target.key().as_ref()]
+//!     bump  = authority_entry.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::AuthorityRoleAssigned;
+use crate::state::{Authority, Config, Lifecycle};
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Arguments for the `grant_role` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GrantRoleArgs {
+    /// Role label to grant, for example "maintainer" or "observer".
+    pub role: String,
+
+    /// Unix timestamp after which the role lapses; zero for no expiry.
+    pub expires_at: i64,
+
+    /// Whether the authority entry applies to the whole deployment.
+    ///
+    /// Only used when the `Authority` account is created by this call.
+    pub is_global: bool,
+
+    /// Resource the authority entry is scoped to when `is_global` is false.
+    ///
+    /// Only used when the `Authority` account is created by this call.
+    pub resource_scope: Pubkey,
+}
+
+/// Accounts required for the `grant_role` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts` when the role is
+/// `ROLE_LABEL_ADMIN`.
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    /// Admin signer; must match `config.admin`. Pays for account creation.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Key receiving the role. Only its public key is used.
+    pub target: UncheckedAccount<'info>,
+
+    /// Authority registry entry for `target`.
+    ///
+    /// PDA:
+    ///   seeds = [AUTHORITY_SEED.as_bytes(), target.key().as_ref()]
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Authority::LEN,
+        seeds = [AUTHORITY_SEED.as_bytes(), target.key().as_ref()],
+        bump,
+    )]
+    pub authority_entry: Account<'info, Authority>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `grant_role` instruction.
+pub fn handle(ctx: Context<GrantRole>, args: GrantRoleArgs) -> Result<()> {
+    let GrantRole {
+        admin,
+        config,
+        lifecycle,
+        target,
+        authority_entry,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    if args.role == ROLE_LABEL_ADMIN {
+        assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+        let co_signers =
+            admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+        config.assert_admin_quorum(admin.key(), &co_signers)?;
+    } else {
+        config.assert_admin(admin)?;
+    }
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Initialize on first use
+    // -----------------------------------------------------------------------
+
+    if authority_entry.created_at == 0 {
+        let authority_bump = ctx.bumps.authority_entry;
+
+        authority_entry.init(
+            target.key(),
+            args.is_global,
+            args.resource_scope,
+            authority_bump,
+            clock_ref,
+        )?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    authority_entry.add_role(&args.role, args.expires_at, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(AuthorityRoleAssigned {
+        authority: authority_entry.authority,
+        role: args.role,
+        assigned_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -33,7 +33,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::state::{Config, Lifecycle, Metrics};
+use crate::state::{Config, Lifecycle, LifecyclePhase, Metrics};
 
 /// Arguments for the `initialize` instruction.
 ///
@@ -65,6 +65,12 @@ pub struct InitializeArgs {
     ///
     /// If not needed, pass `[0u8; 32]`.
     pub lifecycle_note_ref: [u8; 32],
+
+    /// Phase the deployment starts in, as a `LifecyclePhase` discriminant.
+    ///
+    /// Pass `0` (`Bootstrapping`) for the usual start, or e.g. `3`
+    /// (`Frozen`) to stage data before the deployment accepts writes.
+    pub initial_lifecycle_state: u8,
 }
 
 /// Accounts required for the `initialize` instruction.
@@ -173,23 +179,15 @@ pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
     if args.max_modules_per_repo == 0 {
         return err!(Unit09Error::ValueOutOfRange);
     }
+    let initial_phase = LifecyclePhase::try_from_u8(args.initial_lifecycle_state)?;
 
     // -----------------------------------------------------------------------
     // Derive PDA bumps from context
     // -----------------------------------------------------------------------
 
-    let config_bump = *ctx
-        .bumps
-        .get("config")
-        .ok_or(Unit09Error::InternalError)?;
-    let metrics_bump = *ctx
-        .bumps
-        .get("metrics")
-        .ok_or(Unit09Error::InternalError)?;
-    let lifecycle_bump = *ctx
-        .bumps
-        .get("lifecycle")
-        .ok_or(Unit09Error::InternalError)?;
+    let config_bump = ctx.bumps.config;
+    let metrics_bump = ctx.bumps.metrics;
+    let lifecycle_bump = ctx.bumps.lifecycle;
 
     // -----------------------------------------------------------------------
     // Initialize Config
@@ -214,7 +212,12 @@ pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
     // Initialize Lifecycle
     // -----------------------------------------------------------------------
 
-    lifecycle.init(lifecycle_bump, clock_ref, args.lifecycle_note_ref)?;
+    lifecycle.init(
+        initial_phase,
+        lifecycle_bump,
+        clock_ref,
+        args.lifecycle_note_ref,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,367 @@
+//! ===========================================================================
+//! Unit09 – Initialize Missing Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/initialize_missing.rs
+//!
+//! Idempotent companion to `initialize` for deployments that were only
+//! partially bootstrapped, for example when the config was created but the
+//! transaction creating the metrics account failed.
+//!
+//! Every singleton account is declared `init_if_needed`, and only the ones
+//! that do not exist yet are initialized:
+//!
+//! - `Config`        : from `args`, as in `initialize`
+//! - `Metrics`       : zeroed counters
+//! - `Lifecycle`     : `args.initial_lifecycle_state` and
+//!                     `args.lifecycle_note_ref`
+//! - `GlobalMetadata`: empty fields, to be filled by `set_metadata`
+//!
+//! Accounts that already exist are left untouched, so running this on a
+//! complete deployment is a no-op.
+//!
+//! Guards:
+//! - when `Config` exists, the signer must be `config.admin`; otherwise it
+//!   must be `args.admin`, the admin the new config will record
+//!   (`InvalidAdmin`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::instructions::initialize::InitializeArgs;
+use crate::state::{Config, GlobalMetadata, Lifecycle, LifecyclePhase, Metrics};
+
+/// Accounts required for the `initialize_missing` instruction.
+#[derive(Accounts)]
+pub struct InitializeMissing<'info> {
+    /// Payer for the accounts created by this call.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Deployment admin: `config.admin` when the config exists, otherwise
+    /// `args.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account (singleton).
+    ///
+    /// PDA: seeds = [CONFIG_SEED], bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Config::LEN,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account (singleton).
+    ///
+    /// PDA: seeds = [METRICS_SEED], bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Metrics::LEN,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Lifecycle account (singleton).
+    ///
+    /// PDA: seeds = [LIFECYCLE_SEED], bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Lifecycle::LEN,
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metadata account (singleton).
+    ///
+    /// PDA: seeds = [GLOBAL_METADATA_SEED], bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = GlobalMetadata::LEN,
+        seeds = [GLOBAL_METADATA_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_metadata: Account<'info, GlobalMetadata>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// The deployment's singleton accounts, some of which may still be blank.
+pub struct Singletons<'a> {
+    pub config: &'a mut Config,
+    pub metrics: &'a mut Metrics,
+    pub lifecycle: &'a mut Lifecycle,
+    pub global_metadata: &'a mut GlobalMetadata,
+}
+
+/// Which singleton accounts a call initialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InitializedAccounts {
+    pub config: bool,
+    pub metrics: bool,
+    pub lifecycle: bool,
+    pub global_metadata: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `initialize_missing` instruction.
+pub fn handle(ctx: Context<InitializeMissing>, args: InitializeArgs) -> Result<()> {
+    let InitializeMissing {
+        payer: _,
+        admin,
+        config,
+        metrics,
+        lifecycle,
+        global_metadata,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let singletons = Singletons {
+        config,
+        metrics,
+        lifecycle,
+        global_metadata,
+    };
+    initialize_missing_accounts(singletons, admin.key(), &args, &ctx.bumps, clock)?;
+
+    Ok(())
+}
+
+/// Initialize every blank account in `singletons`, leaving existing ones
+/// untouched.
+///
+/// `signer` must be the admin recorded in an existing config, or
+/// `args.admin` when the config is among the missing accounts.
+pub fn initialize_missing_accounts(
+    singletons: Singletons<'_>,
+    signer: Pubkey,
+    args: &InitializeArgs,
+    bumps: &InitializeMissingBumps,
+    clock: &Clock,
+) -> Result<InitializedAccounts> {
+    let Singletons {
+        config,
+        metrics,
+        lifecycle,
+        global_metadata,
+    } = singletons;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    let admin = if config.is_initialized() {
+        config.admin
+    } else {
+        args.admin
+    };
+    if signer != admin {
+        return err!(Unit09Error::InvalidAdmin);
+    }
+
+    let missing = InitializedAccounts {
+        config: !config.is_initialized(),
+        metrics: !metrics.is_initialized(),
+        lifecycle: !lifecycle.is_initialized(),
+        global_metadata: !global_metadata.is_initialized(),
+    };
+    let initial_phase = if missing.lifecycle {
+        Some(LifecyclePhase::try_from_u8(args.initial_lifecycle_state)?)
+    } else {
+        None
+    };
+
+    // -----------------------------------------------------------------------
+    // Initialize missing accounts
+    // -----------------------------------------------------------------------
+
+    if missing.config {
+        config.init(
+            args.admin,
+            args.fee_bps,
+            args.max_modules_per_repo,
+            args.policy_ref,
+            bumps.config,
+            clock,
+        )?;
+    }
+    if missing.metrics {
+        metrics.init(bumps.metrics, clock)?;
+    }
+    if let Some(initial_phase) = initial_phase {
+        lifecycle.init(initial_phase, bumps.lifecycle, clock, args.lifecycle_note_ref)?;
+    }
+    if missing.global_metadata {
+        global_metadata.init(
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            bumps.global_metadata,
+            clock,
+        )?;
+    }
+
+    Ok(missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn init_args(admin: Pubkey) -> InitializeArgs {
+        InitializeArgs {
+            admin,
+            fee_bps: 250,
+            max_modules_per_repo: 128,
+            policy_ref: [0u8; 32],
+            lifecycle_note_ref: [0u8; 32],
+            initial_lifecycle_state: LifecyclePhase::Bootstrapping.as_u8(),
+        }
+    }
+
+    fn bumps() -> InitializeMissingBumps {
+        InitializeMissingBumps {
+            config: 255,
+            metrics: 254,
+            lifecycle: 253,
+            global_metadata: 252,
+        }
+    }
+
+    /// Blank accounts, as `init_if_needed` hands them over when missing.
+    fn blank() -> (Config, Metrics, Lifecycle, GlobalMetadata) {
+        (
+            zeroed(Config::LEN),
+            zeroed(Metrics::LEN),
+            zeroed(Lifecycle::LEN),
+            zeroed(GlobalMetadata::LEN),
+        )
+    }
+
+    #[test]
+    fn partial_initialization_is_completed() {
+        let admin = Pubkey::new_unique();
+        let (mut config, mut metrics, mut lifecycle, mut global_metadata) = blank();
+        config
+            .init(admin, 100, 64, [0u8; 32], 255, &clock_at(100))
+            .unwrap();
+
+        let initialized = initialize_missing_accounts(
+            Singletons {
+                config: &mut config,
+                metrics: &mut metrics,
+                lifecycle: &mut lifecycle,
+                global_metadata: &mut global_metadata,
+            },
+            admin,
+            &init_args(Pubkey::new_unique()),
+            &bumps(),
+            &clock_at(200),
+        )
+        .unwrap();
+
+        assert_eq!(
+            initialized,
+            InitializedAccounts {
+                config: false,
+                metrics: true,
+                lifecycle: true,
+                global_metadata: true,
+            }
+        );
+        // The existing config keeps its own values, not those in `args`.
+        assert_eq!((config.admin, config.fee_bps, config.created_at), (admin, 100, 100));
+        assert!(metrics.is_initialized() && lifecycle.is_initialized());
+        assert_eq!((metrics.bump, lifecycle.bump), (254, 253));
+        assert_eq!(global_metadata.bump, 252);
+
+        // A second run finds nothing to do.
+        let again = initialize_missing_accounts(
+            Singletons {
+                config: &mut config,
+                metrics: &mut metrics,
+                lifecycle: &mut lifecycle,
+                global_metadata: &mut global_metadata,
+            },
+            admin,
+            &init_args(admin),
+            &bumps(),
+            &clock_at(300),
+        )
+        .unwrap();
+        assert_eq!(again, InitializedAccounts::default());
+        assert_eq!(metrics.created_at, 200);
+    }
+
+    #[test]
+    fn missing_config_is_created_for_the_requested_admin() {
+        let admin = Pubkey::new_unique();
+        let (mut config, mut metrics, mut lifecycle, mut global_metadata) = blank();
+
+        let initialized = initialize_missing_accounts(
+            Singletons {
+                config: &mut config,
+                metrics: &mut metrics,
+                lifecycle: &mut lifecycle,
+                global_metadata: &mut global_metadata,
+            },
+            admin,
+            &init_args(admin),
+            &bumps(),
+            &clock_at(100),
+        )
+        .unwrap();
+
+        assert!(initialized.config);
+        assert_eq!((config.admin, config.fee_bps, config.bump), (admin, 250, 255));
+    }
+
+    #[test]
+    fn non_admin_is_rejected() {
+        let admin = Pubkey::new_unique();
+        let intruder = Pubkey::new_unique();
+        let (mut config, mut metrics, mut lifecycle, mut global_metadata) = blank();
+        config
+            .init(admin, 100, 64, [0u8; 32], 255, &clock_at(100))
+            .unwrap();
+
+        // Naming themselves in `args` does not help once a config exists.
+        let result = initialize_missing_accounts(
+            Singletons {
+                config: &mut config,
+                metrics: &mut metrics,
+                lifecycle: &mut lifecycle,
+                global_metadata: &mut global_metadata,
+            },
+            intruder,
+            &init_args(intruder),
+            &bumps(),
+            &clock_at(200),
+        );
+
+        assert!(result.is_err());
+        assert!(!metrics.is_initialized());
+        assert_eq!(config.admin, admin);
+    }
+}
@@ -0,0 +1,116 @@
+//! ===========================================================================
+//! Unit09 – Initiate Repo Transfer Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/initiate_repo_transfer.rs
+//!
+//! First half of the two-step repository authority transfer.
+//!
+//! The current authority proposes a new authority, which is stored as
+//! `Repo::pending_authority`. Control does not change until the proposed key
+//! signs `accept_repo_transfer`, so a mistyped key can never take over (or
+//! strand) the repository.
+//!
+//! On success this instruction:
+//! - sets `repo.pending_authority`, replacing any earlier proposal
+//! - emits `RepoTransferInitiated`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only `Repo::authority` may initiate a transfer
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoTransferInitiated;
+use crate::state::{Config, Lifecycle, Repo};
+
+/// Arguments for the `initiate_repo_transfer` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitiateRepoTransferArgs {
+    /// Key that will become the repository authority once it accepts.
+    pub new_authority: Pubkey,
+}
+
+/// Accounts required for the `initiate_repo_transfer` instruction.
+#[derive(Accounts)]
+pub struct InitiateRepoTransfer<'info> {
+    /// Current authority of the repository; must match `repo.authority`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository whose authority is being transferred.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `initiate_repo_transfer` instruction.
+pub fn handle(ctx: Context<InitiateRepoTransfer>, args: InitiateRepoTransferArgs) -> Result<()> {
+    let InitiateRepoTransfer {
+        authority,
+        config,
+        lifecycle,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    repo.assert_authority(authority)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    repo.initiate_transfer(args.new_authority, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoTransferInitiated {
+        repo: repo.key(),
+        authority: repo.authority,
+        pending_authority: repo.pending_authority,
+    });
+
+    Ok(())
+}
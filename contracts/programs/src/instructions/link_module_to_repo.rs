@@ -22,12 +22,20 @@
 //! - optional notes useful for off-chain indexers or UIs
 //!
 //! On success this instruction:
-//! - ensures lifecycle and config allow writes
+//! - ensures lifecycle and config allow writes and the kill switch is off
 //! - ensures the target repo is active
 //! - ensures the signer is allowed to link this module
-//! - initializes or updates a `ModuleRepoLink` PDA
+//! - ensures the module is past its `active_from` embargo
+//! - initializes or updates a `ModuleRepoLink` PDA, including its optional
+//!   expiry
 //! - emits a `ModuleLinkedToRepo` event (for indexers and dashboards)
 //!
+//! Mutual consent
+//! --------------
+//! When the repo sets `require_mutual_consent` and the module belongs to a
+//! different authority, a new link is created pending and inactive. The
+//! other authority then activates it with `confirm_module_link`.
+//!
 //! PDA layout
 //! ----------
 //! - `ModuleRepoLink`:
@@ -48,6 +56,8 @@
 //!     * the module authority, OR
 //!     * the repo authority
 //!   so that either side can manage their own linkage graph.
+//! - `link_modules_to_repo` applies the same rules to a batch of modules
+//!   through the shared `link_module` helper.
 //!
 //! ===========================================================================
 
@@ -78,6 +88,13 @@ pub struct LinkModuleToRepoArgs {
     /// - "used for indexing events only"
     /// - "forked variant with modified filters"
     pub notes: String,
+
+    /// Optional expiry for a temporary link (Unix seconds).
+    ///
+    /// `None` keeps the current expiry (none for a new link); `Some(0)`
+    /// makes the link permanent. Expired links can be closed by anyone
+    /// with `expire_link`.
+    pub expires_at: Option<i64>,
 }
 
 /// Accounts required for the `link_module_to_repo` instruction.
@@ -131,17 +148,19 @@ pub struct LinkModuleToRepo<'info> {
     /// PDA:
     ///   seeds = [
     ///       MODULE_SEED.as_bytes(),
+    ///       module.repo.as_ref(),
     ///       module.module_key.as_ref(),
     ///   ]
     ///   bump  = module.bump
     ///
     /// Note:
-    /// - The module PDA is derived only from `MODULE_SEED` and `module_key`
-    ///   so that it can be associated with multiple repos via link accounts.
+    /// - `module.repo` is the module's home repo, which may differ from
+    ///   `repo`; additional repos are associated via link accounts.
     #[account(
         mut,
         seeds = [
             MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
             module.module_key.as_ref(),
         ],
         bump = module.bump,
@@ -193,9 +212,9 @@ pub struct LinkModuleToRepo<'info> {
 /// 1. Enforce lifecycle and config guards.
 /// 2. Enforce repo activity.
 /// 3. Enforce that the signer is either module or repo authority.
-/// 4. Validate notes length.
-/// 5. Initialize or update `ModuleRepoLink`.
-/// 6. Emit `ModuleLinkedToRepo` event.
+/// 4. Initialize (pending if mutual consent applies) or refresh
+///    `ModuleRepoLink`.
+/// 5. Emit `ModuleLinkedToRepo` event.
 pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Result<()> {
     let LinkModuleToRepo {
         payer: _,
@@ -216,77 +235,155 @@ pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Res
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
     repo.assert_active()?;
 
     // -----------------------------------------------------------------------
-    // Authorization: signer must be module or repo authority
+    // Derive bump from Anchor context
     // -----------------------------------------------------------------------
 
-    let signer_key = authority.key();
-
-    let is_module_authority = signer_key == module.authority;
-    let is_repo_authority = signer_key == repo.authority;
-
-    if !is_module_authority && !is_repo_authority {
-        return err!(Unit09Error::InvalidAuthority);
-    }
+    let link_bump = ctx.bumps.link;
 
     // -----------------------------------------------------------------------
-    // Basic validation for notes
+    // Authorize, initialize or update link account, emit
     // -----------------------------------------------------------------------
 
-    if args.notes.len() > ModuleRepoLink::MAX_NOTES_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // -----------------------------------------------------------------------
-    // Derive bump from Anchor context
-    // -----------------------------------------------------------------------
+    let event = link_module(
+        link,
+        module.key(),
+        module,
+        repo.key(),
+        repo,
+        authority.key(),
+        args,
+        link_bump,
+        clock_ref,
+    )?;
+    emit!(event);
 
-    let link_bump = *ctx.bumps.get("link").ok_or(Unit09Error::InternalError)?;
+    Ok(())
+}
 
-    // -----------------------------------------------------------------------
-    // Initialize or update link account
-    // -----------------------------------------------------------------------
+/// Authorize `signer` and initialize or refresh `link` between `module` and
+/// `repo`, returning the `ModuleLinkedToRepo` event to emit.
+///
+/// The signer must be the module or the repo authority, and the module must
+/// be past its `active_from` embargo. A new link starts
+/// pending when the repo requires mutual consent (see
+/// `Repo::link_confirmation_required_from`); an existing one keeps its
+/// consent state. Shared by `link_module_to_repo` and
+/// `link_modules_to_repo`.
+pub fn link_module(
+    link: &mut ModuleRepoLink,
+    module_key: Pubkey,
+    module: &Module,
+    repo_key: Pubkey,
+    repo: &Repo,
+    signer: Pubkey,
+    args: LinkModuleToRepoArgs,
+    bump: u8,
+    clock: &Clock,
+) -> Result<ModuleLinkedToRepo> {
+    if signer != module.authority && signer != repo.authority {
+        return err!(Unit09Error::InvalidAuthority);
+    }
+    module.assert_not_embargoed(clock.unix_timestamp)?;
 
-    let now = clock_ref.unix_timestamp;
-
-    // If this is a fresh account (default/zeroed), we treat it as init.
-    let is_new = link.module == Pubkey::default() && link.repo == Pubkey::default();
-
-    if is_new {
-        // First-time initialization of the link.
-        link.module = module.key();
-        link.repo = repo.key();
-        link.linked_by = signer_key;
-        link.is_primary = args.is_primary;
-        link.notes = args.notes;
-        link.created_at = now;
-        link.updated_at = now;
-        link.schema_version = CURRENT_SCHEMA_VERSION;
-        link.bump = link_bump;
-        link.reserved = [0u8; 63];
+    let expires_at = args.expires_at;
+    if link.is_initialized() {
+        // Existing link: refresh flags and notes, keeping its consent state.
+        link.refresh(signer, args.is_primary, args.notes, clock)?;
     } else {
-        // Existing link: refresh flags and notes.
-        link.is_primary = args.is_primary;
-        link.notes = args.notes;
-        link.linked_by = signer_key;
-        link.updated_at = now;
+        let pending_from = repo.link_confirmation_required_from(&module.authority, &signer);
+        link.init(
+            module_key,
+            repo_key,
+            signer,
+            args.is_primary,
+            args.notes,
+            pending_from,
+            bump,
+            clock,
+        )?;
+    }
+    if let Some(expires_at) = expires_at {
+        link.set_expiry(expires_at, clock)?;
     }
 
-    // -----------------------------------------------------------------------
-    // Emit ModuleLinkedToRepo event
-    // -----------------------------------------------------------------------
-
-    emit!(ModuleLinkedToRepo {
-        module: module.key(),
-        repo: repo.key(),
-        linked_by: signer_key,
+    Ok(ModuleLinkedToRepo {
+        module: module_key,
+        repo: repo_key,
+        linked_by: signer,
         is_primary: link.is_primary,
-        updated_at: link.updated_at,
-    });
+        is_active: link.is_active,
+        linked_at: link.updated_at,
+        expires_at: link.expires_at,
+    })
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{module_pda, module_repo_link_pda, repo_pda};
+    use crate::utils::test_utils::{new_module, new_repo, zeroed};
+
+    fn repo_owned_by(authority: Pubkey) -> (Pubkey, Repo) {
+        let mut repo = new_repo(authority, &Clock::default());
+        let (address, bump) = repo_pda(&crate::ID, &repo.repo_key);
+        repo.bump = bump;
+        (address, repo)
+    }
+
+    #[test]
+    fn registered_module_links_to_a_second_repo() {
+        let authority = Pubkey::new_unique();
+        let (home_address, _) = repo_owned_by(authority);
+        let (other_address, other_repo) = repo_owned_by(authority);
+
+        // As `register_module` derives it: seeds = [MODULE_SEED, repo.key(), module_key].
+        let mut module = new_module(home_address, authority, "unit09-router", &Clock::default());
+        let (module_address, module_bump) =
+            module_pda(&crate::ID, &home_address, &module.module_key);
+        module.bump = module_bump;
+
+        // `LinkModuleToRepo::module` re-derives the same address from what
+        // the module stores, although the target repo is a different one.
+        let seeds: &[&[u8]] = &[
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+            &[module.bump],
+        ];
+        assert_eq!(
+            Pubkey::create_program_address(seeds, &crate::ID).unwrap(),
+            module_address
+        );
+
+        let (_, link_bump) = module_repo_link_pda(&crate::ID, &module_address, &other_address);
+        let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+        let args = LinkModuleToRepoArgs {
+            is_primary: false,
+            notes: String::new(),
+            expires_at: None,
+        };
+        let event = link_module(
+            &mut link,
+            module_address,
+            &module,
+            other_address,
+            &other_repo,
+            authority,
+            args,
+            link_bump,
+            &Clock::default(),
+        )
+        .unwrap();
+
+        assert_eq!((link.module, link.repo), (module_address, other_address));
+        assert!(link.is_active);
+        assert_eq!((event.module, event.repo), (module_address, other_address));
+        assert_ne!(module.repo, other_address);
+    }
 }
@@ -0,0 +1,281 @@
+//! ===========================================================================
+//! Unit09 – Link Modules To Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/link_modules_to_repo.rs
+//!
+//! Bulk form of `link_module_to_repo`, for importing a whole module set into
+//! a repository in one transaction.
+//!
+//! `remaining_accounts` carries one `(module, link)` pair per entry of
+//! `args.links`, in the same order:
+//! - `module` – the `Module` account, read-only
+//! - `link`   – the `ModuleRepoLink` PDA for `(module, repo)`, writable;
+//!   created here when it does not exist yet, even if its address was
+//!   pre-funded (see `create_pda_account`)
+//!
+//! Each link follows the same rules as `link_module_to_repo` (see
+//! `link_module_to_repo::link_module`), including pending links under
+//! mutual consent.
+//!
+//! On success this instruction:
+//! - creates or refreshes every listed `ModuleRepoLink`
+//! - emits one `ModuleLinkedToRepo` per link
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - repo must be active (`Repo::assert_active`)
+//! - at most `MAX_REMAINING_ACCOUNTS` remaining accounts
+//!   (`TooManyRemainingAccounts`)
+//! - at most `MAX_LINKS_PER_BATCH` links (`LinkBatchTooLarge` otherwise)
+//! - exactly two remaining accounts per link (`MissingRequiredAccount`)
+//! - every module must be owned by this program and every link account
+//!   must be the PDA for its module and `repo` (`InvalidPda`)
+//! - the signer must be the module or repo authority for every link
+//! - every module must be past its `active_from` embargo (`ModuleEmbargoed`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::instructions::link_module_to_repo::{link_module, LinkModuleToRepoArgs};
+use crate::state::{Config, Lifecycle, Module, ModuleRepoLink, Repo};
+use crate::utils::{assert_remaining_accounts_within_cap, create_pda_account, module_repo_link_pda};
+
+/// Arguments for the `link_modules_to_repo` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LinkModulesToRepoArgs {
+    /// Link metadata, one entry per `(module, link)` account pair.
+    pub links: Vec<LinkModuleToRepoArgs>,
+}
+
+/// Accounts required for the `link_modules_to_repo` instruction.
+///
+/// The `(module, link)` account pairs follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct LinkModulesToRepo<'info> {
+    /// Payer for link accounts created by this call.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Signer authorized to create or update the links; the module or repo
+    /// authority of each one.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository to which the modules are being linked.
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// System program, used to create new link accounts.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `link_modules_to_repo` instruction.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LinkModulesToRepo<'info>>,
+    args: LinkModulesToRepoArgs,
+) -> Result<()> {
+    let LinkModulesToRepo {
+        payer,
+        authority,
+        config,
+        lifecycle,
+        repo,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    repo.assert_active()?;
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    check_link_batch(args.links.len(), ctx.remaining_accounts.len())?;
+
+    // -----------------------------------------------------------------------
+    // Link each module
+    // -----------------------------------------------------------------------
+
+    let repo_key = repo.key();
+
+    for (accounts, link_args) in ctx.remaining_accounts.chunks(2).zip(args.links) {
+        let (module_info, link_info) = (&accounts[0], &accounts[1]);
+
+        if module_info.owner != ctx.program_id {
+            return err!(Unit09Error::InvalidAccountOwner);
+        }
+        let module = Module::try_deserialize(&mut &module_info.try_borrow_data()?[..])?;
+
+        let (link_address, link_bump) =
+            module_repo_link_pda(ctx.program_id, module_info.key, &repo_key);
+        require_keys_eq!(*link_info.key, link_address, Unit09Error::InvalidPda);
+
+        let mut link = if link_info.owner == ctx.program_id {
+            ModuleRepoLink::try_deserialize(&mut &link_info.try_borrow_data()?[..])?
+        } else {
+            create_pda_account(
+                payer,
+                link_info,
+                system_program,
+                ModuleRepoLink::LEN,
+                &[
+                    MODULE_REPO_LINK_SEED.as_bytes(),
+                    module_info.key.as_ref(),
+                    repo_key.as_ref(),
+                    &[link_bump],
+                ],
+                ctx.program_id,
+            )?;
+            let zeroed = [0u8; ModuleRepoLink::LEN];
+            ModuleRepoLink::deserialize(&mut &zeroed[..])?
+        };
+
+        let event = link_module(
+            &mut link,
+            *module_info.key,
+            &module,
+            repo_key,
+            repo,
+            authority.key(),
+            link_args,
+            link_bump,
+            clock_ref,
+        )?;
+        link.try_serialize(&mut &mut link_info.try_borrow_mut_data()?[..])?;
+
+        emit!(event);
+    }
+
+    Ok(())
+}
+
+/// Ensure a batch of `link_count` links fits `MAX_LINKS_PER_BATCH` and
+/// comes with one `(module, link)` account pair per link.
+pub fn check_link_batch(link_count: usize, remaining_account_count: usize) -> Result<()> {
+    if link_count > MAX_LINKS_PER_BATCH {
+        return err!(Unit09Error::LinkBatchTooLarge);
+    }
+    if remaining_account_count != link_count * 2 {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, new_module, new_repo, zeroed};
+
+    #[test]
+    fn three_modules_are_linked_in_one_batch() {
+        let authority = Pubkey::new_unique();
+        let repo = new_repo(authority, &Clock::default());
+        let repo_key = Pubkey::new_unique();
+        let links = vec![
+            LinkModuleToRepoArgs {
+                is_primary: true,
+                notes: String::new(),
+                expires_at: None,
+            };
+            3
+        ];
+        check_link_batch(links.len(), 6).unwrap();
+
+        for (name, link_args) in ["router", "indexer", "worker"].into_iter().zip(links) {
+            let module = new_module(Pubkey::new_unique(), authority, name, &Clock::default());
+            let module_key = Pubkey::new_unique();
+            let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+
+            let event = link_module(
+                &mut link,
+                module_key,
+                &module,
+                repo_key,
+                &repo,
+                authority,
+                link_args,
+                255,
+                &Clock::default(),
+            )
+            .unwrap();
+
+            assert!(link.is_active);
+            assert_eq!((link.module, link.repo), (module_key, repo_key));
+            assert_eq!((event.module, event.repo), (module_key, repo_key));
+        }
+    }
+
+    #[test]
+    fn oversized_or_unpaired_batches_are_rejected() {
+        let too_many = MAX_LINKS_PER_BATCH + 1;
+        assert!(check_link_batch(too_many, too_many * 2).is_err());
+        assert!(check_link_batch(MAX_LINKS_PER_BATCH, MAX_LINKS_PER_BATCH * 2).is_ok());
+        assert!(check_link_batch(2, 3).is_err());
+    }
+
+    #[test]
+    fn embargoed_module_cannot_be_linked_before_launch() {
+        let authority = Pubkey::new_unique();
+        let repo = new_repo(authority, &clock_at(0));
+        let mut module = new_module(Pubkey::new_unique(), authority, "launchpad", &clock_at(0));
+        module.set_active_from(1_000).unwrap();
+        let link_args = LinkModuleToRepoArgs {
+            is_primary: false,
+            notes: String::new(),
+            expires_at: None,
+        };
+        let link_at = |unix_timestamp| {
+            let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+            let clock = clock_at(unix_timestamp);
+            link_module(
+                &mut link,
+                Pubkey::new_unique(),
+                &module,
+                Pubkey::new_unique(),
+                &repo,
+                authority,
+                link_args.clone(),
+                255,
+                &clock,
+            )
+        };
+
+        assert!(link_at(999).is_err());
+        assert!(link_at(1_000).is_ok());
+    }
+}
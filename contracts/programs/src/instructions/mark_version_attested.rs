@@ -0,0 +1,139 @@
+//! ===========================================================================
+//! Unit09 – Mark Version Attested Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/mark_version_attested.rs
+//!
+//! Records the signer of a release on an existing `ModuleVersion`.
+//!
+//! Versions can carry an attestation from creation (`register_module` /
+//! `update_module`). This instruction covers releases that are signed after
+//! the snapshot was published. Attestation is one-way, like promotion.
+//!
+//! On success this instruction:
+//! - sets `module_version.attested_by` (and `attestation_uri`, if given)
+//! - emits `ModuleVersionAttested`
+//!
+//! Guards:
+//! - lifecycle must allow writes
+//! - the signer must be the module authority or `config.admin`; only the
+//!   admin while the kill switch is engaged (`Config::assert_not_killed`)
+//!   or the deployment is paused or deactivated (`Config::assert_active`)
+//! - the version must not already be attested and must end up with a
+//!   non-empty attestation reference
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleVersionAttested;
+use crate::state::{Config, Lifecycle, Module, ModuleVersion};
+
+/// Arguments for the `mark_version_attested` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MarkVersionAttestedArgs {
+    /// Key that signed the release.
+    pub attested_by: Pubkey,
+
+    /// Attestation reference; `None` keeps the one stored at creation.
+    pub attestation_uri: Option<String>,
+}
+
+/// Accounts required for the `mark_version_attested` instruction.
+#[derive(Accounts)]
+pub struct MarkVersionAttested<'info> {
+    /// Module authority or `config.admin`.
+    pub signer: Signer<'info>,
+
+    /// Global configuration account, used to recognize the admin.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Module owning the version.
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Version snapshot being attested.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_VERSION_SEED.as_bytes(),
+            module.key().as_ref(),
+            &module_version.major_version.to_le_bytes(),
+            &module_version.minor_version.to_le_bytes(),
+            &module_version.patch_version.to_le_bytes(),
+        ],
+        bump = module_version.bump,
+        constraint = module_version.module == module.key() @ Unit09Error::ModuleVersionNotFound,
+    )]
+    pub module_version: Account<'info, ModuleVersion>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `mark_version_attested` instruction.
+pub fn handle(ctx: Context<MarkVersionAttested>, args: MarkVersionAttestedArgs) -> Result<()> {
+    let MarkVersionAttested {
+        signer,
+        config,
+        lifecycle,
+        module,
+        module_version,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+
+    let signer_key = signer.key();
+    if signer_key != module.authority && signer_key != config.admin {
+        return err!(Unit09Error::InvalidAuthority);
+    }
+    // Only the admin path survives the kill switch, a pause or a
+    // deactivation.
+    if signer_key != config.admin {
+        config.assert_not_killed()?;
+        config.assert_active(Clock::get()?.unix_timestamp)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    module_version.mark_attested(args.attested_by, args.attestation_uri)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleVersionAttested {
+        module: module.key(),
+        module_version: module_version.key(),
+        attested_by: module_version.attested_by,
+        attestation_uri: module_version.attestation_uri.clone(),
+        marked_by: signer_key,
+    });
+
+    Ok(())
+}
@@ -41,17 +41,50 @@ use anchor_lang::prelude::*;
 // ---------------------------------------------------------------------------
 
 pub mod initialize;
+pub mod initialize_missing;
 pub mod set_config;
+pub mod admin_close_account;
+pub mod set_schema_version;
+pub mod pause;
+pub mod unpause;
+pub mod engage_kill_switch;
+pub mod disengage_kill_switch;
+pub mod rotate_admin;
 pub mod register_repo;
 pub mod update_repo;
+pub mod close_repo;
+pub mod initiate_repo_transfer;
+pub mod accept_repo_transfer;
+pub mod cancel_repo_transfer;
 pub mod register_module;
 pub mod update_module;
+pub mod verify_module;
+pub mod unverify_module;
+pub mod claim_module_name;
+pub mod resolve_claim;
+pub mod promote_version_stable;
+pub mod mark_version_attested;
+pub mod collect_changelog;
+pub mod close_module;
+pub mod pin_module;
+pub mod unpin_module;
 pub mod link_module_to_repo;
+pub mod link_modules_to_repo;
+pub mod copy_module_links_from;
+pub mod confirm_module_link;
+pub mod expire_link;
 pub mod create_fork;
 pub mod update_fork_state;
+pub mod expire_fork;
+pub mod compute_fork_diff;
 pub mod record_observation;
+pub mod rotate_observer_key;
+pub mod claim_observer_reward;
 pub mod record_metrics;
+pub mod verify_metrics_consistency;
 pub mod set_metadata;
+pub mod grant_role;
+pub mod revoke_role;
 
 // ---------------------------------------------------------------------------
 // Public Re-exports
@@ -59,30 +92,65 @@ pub mod set_metadata;
 
 // Initialize
 pub use initialize::{Initialize, InitializeArgs};
+pub use initialize_missing::InitializeMissing;
 
 // Config
 pub use set_config::{SetConfig, SetConfigArgs};
+pub use admin_close_account::{AdminCloseAccount, AdminCloseAccountArgs};
+pub use set_schema_version::{SetSchemaVersion, SetSchemaVersionArgs};
+pub use pause::{Pause, PauseArgs};
+pub use unpause::Unpause;
+pub use engage_kill_switch::EngageKillSwitch;
+pub use disengage_kill_switch::DisengageKillSwitch;
+pub use rotate_admin::{RotateAdmin, RotateAdminArgs};
 
 // Repositories
 pub use register_repo::{RegisterRepo, RegisterRepoArgs};
 pub use update_repo::{UpdateRepo, UpdateRepoArgs};
+pub use close_repo::CloseRepo;
+pub use initiate_repo_transfer::{InitiateRepoTransfer, InitiateRepoTransferArgs};
+pub use accept_repo_transfer::AcceptRepoTransfer;
+pub use cancel_repo_transfer::CancelRepoTransfer;
 
 // Modules
 pub use register_module::{RegisterModule, RegisterModuleArgs};
 pub use update_module::{UpdateModule, UpdateModuleArgs};
+pub use verify_module::VerifyModule;
+pub use unverify_module::UnverifyModule;
+pub use claim_module_name::{ClaimModuleName, ClaimModuleNameArgs};
+pub use resolve_claim::{ResolveClaim, ResolveClaimArgs};
+pub use promote_version_stable::PromoteVersionStable;
+pub use mark_version_attested::{MarkVersionAttested, MarkVersionAttestedArgs};
+pub use collect_changelog::CollectChangelog;
+pub use close_module::CloseModule;
+pub use pin_module::PinModule;
+pub use unpin_module::{UnpinModule, UnpinModuleArgs};
 pub use link_module_to_repo::{LinkModuleToRepo, LinkModuleToRepoArgs};
+pub use link_modules_to_repo::{LinkModulesToRepo, LinkModulesToRepoArgs};
+pub use copy_module_links_from::{CopyModuleLinksFrom, CopyModuleLinksFromArgs};
+pub use confirm_module_link::ConfirmModuleLink;
+pub use expire_link::ExpireLink;
 
 // Forks
 pub use create_fork::{CreateFork, CreateForkArgs};
 pub use update_fork_state::{UpdateForkState, UpdateForkStateArgs};
+pub use expire_fork::ExpireFork;
+pub use compute_fork_diff::{ComputeForkDiff, ComputeForkDiffArgs};
 
 // Observations / Metrics
 pub use record_observation::{RecordObservation, RecordObservationArgs};
+pub use rotate_observer_key::{RotateObserverKey, RotateObserverKeyArgs};
+pub use claim_observer_reward::ClaimObserverReward;
 pub use record_metrics::{RecordMetrics, RecordMetricsArgs};
+pub use verify_metrics_consistency::VerifyMetricsConsistency;
 
 // Metadata
 pub use set_metadata::{SetMetadata, SetMetadataArgs};
 
+// Authorities / Roles
+pub use grant_role::{GrantRole, GrantRoleArgs};
+pub use revoke_role::{RevokeRole, RevokeRoleArgs};
+
 // ---------------------------------------------------------------------------
 // Instruction Routing Wrappers
 // ---------------------------------------------------------------------------
@@ -105,6 +173,12 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     initialize::handle(ctx, args)
 }
 
+/// Create whichever core singleton accounts a partial `initialize` left
+/// missing, leaving existing ones untouched.
+pub fn initialize_missing(ctx: Context<InitializeMissing>, args: InitializeArgs) -> Result<()> {
+    initialize_missing::handle(ctx, args)
+}
+
 /// Update the global configuration:
 /// - admin authority
 /// - fee basis points
@@ -115,6 +189,50 @@ pub fn set_config(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
     set_config::handle(ctx, args)
 }
 
+/// Force-close a malformed or orphaned program account (admin only).
+pub fn admin_close_account(
+    ctx: Context<AdminCloseAccount>,
+    args: AdminCloseAccountArgs,
+) -> Result<()> {
+    admin_close_account::handle(ctx, args)
+}
+
+/// Admin-only override of an account's `schema_version` (break-glass).
+pub fn set_schema_version<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetSchemaVersion<'info>>,
+    args: SetSchemaVersionArgs,
+) -> Result<()> {
+    set_schema_version::handle(ctx, args)
+}
+
+/// Pause the deployment with a reason and an optional scheduled unpause:
+/// - writes fail with `DeploymentPaused` until it lifts
+/// - emits `ConfigPaused`
+pub fn pause(ctx: Context<Pause>, args: PauseArgs) -> Result<()> {
+    pause::handle(ctx, args)
+}
+
+/// Lift a pause immediately and emit `ConfigUnpaused`.
+pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+    unpause::handle(ctx)
+}
+
+/// Engage the emergency kill switch and emit `KillSwitchEngaged`.
+pub fn engage_kill_switch(ctx: Context<EngageKillSwitch>) -> Result<()> {
+    engage_kill_switch::handle(ctx)
+}
+
+/// Lift the kill switch and emit `KillSwitchDisengaged`.
+pub fn disengage_kill_switch(ctx: Context<DisengageKillSwitch>) -> Result<()> {
+    disengage_kill_switch::handle(ctx)
+}
+
+/// Hand the deployment admin role to a new key, optionally changing the
+/// admin signature threshold.
+pub fn rotate_admin(ctx: Context<RotateAdmin>, args: RotateAdminArgs) -> Result<()> {
+    rotate_admin::handle(ctx, args)
+}
+
 /// Register a new repository:
 /// - create `Repo`
 /// - associate authority, name, URL, tags
@@ -131,6 +249,31 @@ pub fn update_repo(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()>
     update_repo::handle(ctx, args)
 }
 
+/// Close a repository and reclaim its rent:
+/// - refund to `refund_to` when provided, otherwise to the authority
+/// - decrement global repo metrics
+pub fn close_repo(ctx: Context<CloseRepo>) -> Result<()> {
+    close_repo::handle(ctx)
+}
+
+/// Propose a new repository authority (step one of a two-step transfer).
+pub fn initiate_repo_transfer(
+    ctx: Context<InitiateRepoTransfer>,
+    args: InitiateRepoTransferArgs,
+) -> Result<()> {
+    initiate_repo_transfer::handle(ctx, args)
+}
+
+/// Accept a pending repository authority transfer.
+pub fn accept_repo_transfer(ctx: Context<AcceptRepoTransfer>) -> Result<()> {
+    accept_repo_transfer::handle(ctx)
+}
+
+/// Cancel a pending repository authority transfer.
+pub fn cancel_repo_transfer(ctx: Context<CancelRepoTransfer>) -> Result<()> {
+    cancel_repo_transfer::handle(ctx)
+}
+
 /// Register a new module for a repository:
 /// - create `Module`
 /// - set metadata URI, category, tags
@@ -148,6 +291,63 @@ pub fn update_module(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Resu
     update_module::handle(ctx, args)
 }
 
+/// Grant a module the admin-controlled verified badge and emit
+/// `ModuleVerificationChanged`.
+pub fn verify_module(ctx: Context<VerifyModule>) -> Result<()> {
+    verify_module::handle(ctx)
+}
+
+/// Revoke a module's verified badge and emit `ModuleVerificationChanged`.
+pub fn unverify_module(ctx: Context<UnverifyModule>) -> Result<()> {
+    unverify_module::handle(ctx)
+}
+
+/// File an ownership claim on a module and emit `ModuleClaimFiled`.
+pub fn claim_module_name(ctx: Context<ClaimModuleName>, args: ClaimModuleNameArgs) -> Result<()> {
+    claim_module_name::handle(ctx, args)
+}
+
+/// Approve or reject a module claim and emit `ModuleClaimResolved`.
+pub fn resolve_claim(ctx: Context<ResolveClaim>, args: ResolveClaimArgs) -> Result<()> {
+    resolve_claim::handle(ctx, args)
+}
+
+/// Promote a beta module version to stable.
+pub fn promote_version_stable(ctx: Context<PromoteVersionStable>) -> Result<()> {
+    promote_version_stable::handle(ctx)
+}
+
+/// Record the release signer on an existing module version.
+pub fn mark_version_attested(
+    ctx: Context<MarkVersionAttested>,
+    args: MarkVersionAttestedArgs,
+) -> Result<()> {
+    mark_version_attested::handle(ctx, args)
+}
+
+/// Emit the changelog of a module from the `ModuleVersion` accounts passed
+/// as remaining accounts, in ascending version order.
+pub fn collect_changelog(ctx: Context<CollectChangelog>) -> Result<()> {
+    collect_changelog::handle(ctx)
+}
+
+/// Close a module and reclaim its rent:
+/// - refund to `refund_to` when provided, otherwise to the authority
+/// - decrement per-repo and global module counters
+pub fn close_module(ctx: Context<CloseModule>) -> Result<()> {
+    close_module::handle(ctx)
+}
+
+/// Pin a module as an entrypoint of its repository.
+pub fn pin_module(ctx: Context<PinModule>) -> Result<()> {
+    pin_module::handle(ctx)
+}
+
+/// Remove a module from its repository's pinned entrypoints.
+pub fn unpin_module(ctx: Context<UnpinModule>, args: UnpinModuleArgs) -> Result<()> {
+    unpin_module::handle(ctx, args)
+}
+
 /// Link a module to a repository (or relink between repositories).
 ///
 /// This is useful when a module is refactored or when combining modules
@@ -159,6 +359,34 @@ pub fn link_module_to_repo(
     link_module_to_repo::handle(ctx, args)
 }
 
+/// Link several modules to a repository in one call; module/link account
+/// pairs follow as `remaining_accounts`.
+pub fn link_modules_to_repo<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LinkModulesToRepo<'info>>,
+    args: LinkModulesToRepoArgs,
+) -> Result<()> {
+    link_modules_to_repo::handle(ctx, args)
+}
+
+/// Copy the modules registered under an upstream repository into a
+/// downstream one as secondary links.
+pub fn copy_module_links_from<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CopyModuleLinksFrom<'info>>,
+    args: CopyModuleLinksFromArgs,
+) -> Result<()> {
+    copy_module_links_from::handle(ctx, args)
+}
+
+/// Confirm a pending module-to-repo link.
+pub fn confirm_module_link(ctx: Context<ConfirmModuleLink>) -> Result<()> {
+    confirm_module_link::handle(ctx)
+}
+
+/// Close a module-to-repo link whose expiry has passed; callable by anyone.
+pub fn expire_link(ctx: Context<ExpireLink>) -> Result<()> {
+    expire_link::handle(ctx)
+}
+
 /// Create a new fork (Unit09 variant):
 /// - create `Fork` account
 /// - assign owner, parent, label
@@ -179,6 +407,16 @@ pub fn update_fork_state(
     update_fork_state::handle(ctx, args)
 }
 
+/// Retire a fork whose expiry has passed; callable by anyone.
+pub fn expire_fork(ctx: Context<ExpireFork>) -> Result<()> {
+    expire_fork::handle(ctx)
+}
+
+/// Compare the module sets of two forks and emit the counts.
+pub fn compute_fork_diff(ctx: Context<ComputeForkDiff>, args: ComputeForkDiffArgs) -> Result<()> {
+    compute_fork_diff::handle(ctx, args)
+}
+
 /// Record an observation run:
 /// - update per-repo observation statistics
 /// - aggregate metrics into `Metrics`
@@ -190,6 +428,21 @@ pub fn record_observation(
     record_observation::handle(ctx, args)
 }
 
+/// Move an observer's per-repo stats to a rotated key:
+/// - add the old tallies to the new key's `ObserverStats`
+/// - close the old `ObserverStats` PDA
+pub fn rotate_observer_key(
+    ctx: Context<RotateObserverKey>,
+    args: RotateObserverKeyArgs,
+) -> Result<()> {
+    rotate_observer_key::handle(ctx, args)
+}
+
+/// Pay an observer its accrued rewards and emit `ObserverRewardClaimed`.
+pub fn claim_observer_reward(ctx: Context<ClaimObserverReward>) -> Result<()> {
+    claim_observer_reward::handle(ctx)
+}
+
 /// Update aggregate metrics in bulk (admin/maintenance use only).
 ///
 /// This is intended for reconciliation with off-chain analytics, not for
@@ -198,6 +451,13 @@ pub fn record_metrics(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> R
     record_metrics::handle(ctx, args)
 }
 
+/// Check global metrics invariants and log any violations.
+///
+/// Read-only; never mutates state.
+pub fn verify_metrics_consistency(ctx: Context<VerifyMetricsConsistency>) -> Result<()> {
+    verify_metrics_consistency::handle(ctx)
+}
+
 /// Set or update global metadata:
 /// - description
 /// - tags
@@ -205,3 +465,15 @@ pub fn record_metrics(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> R
 pub fn set_metadata(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     set_metadata::handle(ctx, args)
 }
+
+/// Grant a role to a key in the `Authority` registry:
+/// - create the `Authority` entry on first use
+/// - add or refresh the role entry
+pub fn grant_role(ctx: Context<GrantRole>, args: GrantRoleArgs) -> Result<()> {
+    grant_role::handle(ctx, args)
+}
+
+/// Revoke a role from a key in the `Authority` registry.
+pub fn revoke_role(ctx: Context<RevokeRole>, args: RevokeRoleArgs) -> Result<()> {
+    revoke_role::handle(ctx, args)
+}
@@ -0,0 +1,124 @@
+//! ===========================================================================
+//! Unit09 – Pause Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/pause.rs
+//!
+//! Temporarily stops write activity across the deployment, recording why
+//! and, optionally, when activity resumes on its own.
+//!
+//! Pausing goes through `Config::assert_active`, so every handler that
+//! checks the config rejects writes with `DeploymentPaused` until the pause
+//! is lifted by `unpause` or `auto_unpause_at` passes. Admin instructions
+//! keep working.
+//!
+//! Guards:
+//! - only the current `Config::admin` may call it
+//! - `reason` must fit `MAX_PAUSE_REASON_LEN`
+//! - `auto_unpause_at` must be 0 or in the future
+//!
+//! On success this instruction:
+//! - sets `Config::paused`, `pause_reason` and `auto_unpause_at`
+//! - emits `ConfigPaused` carrying the reason and schedule
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ConfigPaused;
+use crate::state::Config;
+
+/// Arguments for the `pause` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PauseArgs {
+    /// Why the deployment is paused, for operators and indexers.
+    pub reason: String,
+
+    /// Unix timestamp at which the pause lifts by itself; 0 to pause until
+    /// `unpause` is called.
+    pub auto_unpause_at: i64,
+}
+
+/// Accounts required for the `pause` instruction.
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `pause` instruction.
+pub fn handle(ctx: Context<Pause>, args: PauseArgs) -> Result<()> {
+    let Pause {
+        admin,
+        config,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    config.pause(args.reason, args.auto_unpause_at, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(pause_event(admin.key(), config, clock_ref.unix_timestamp));
+
+    Ok(())
+}
+
+/// `ConfigPaused` describing the pause currently recorded on `config`.
+pub fn pause_event(admin: Pubkey, config: &Config, now: i64) -> ConfigPaused {
+    ConfigPaused {
+        admin,
+        reason: config.pause_reason.clone(),
+        auto_unpause_at: config.auto_unpause_at,
+        paused_at: now,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, new_config};
+
+    #[test]
+    fn event_surfaces_the_stored_reason() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        config
+            .pause("validator upgrade".to_string(), 4_600, &clock)
+            .unwrap();
+
+        let admin = config.admin;
+        let event = pause_event(admin, &config, clock.unix_timestamp);
+        assert_eq!(event.admin, admin);
+        assert_eq!(event.reason, "validator upgrade");
+        assert_eq!(event.auto_unpause_at, 4_600);
+        assert_eq!(event.paused_at, 1_000);
+    }
+}
@@ -0,0 +1,116 @@
+//! ===========================================================================
+//! Unit09 – Pin Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/pin_module.rs
+//!
+//! Highlights a module as a "start here" entrypoint of its repository.
+//!
+//! On success this instruction:
+//! - appends the module PDA to `repo.pinned_modules` (no-op if present)
+//! - emits `RepoModulePinChanged`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only the repository authority may pin
+//! - the module must be registered under the repository
+//! - at most `MAX_REPO_PINNED_MODULES` modules may be pinned
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoModulePinChanged;
+use crate::state::{Config, Lifecycle, Module, Repo};
+
+/// Accounts required for the `pin_module` instruction.
+#[derive(Accounts)]
+pub struct PinModule<'info> {
+    /// Authority of the repository; must match `repo.authority`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository pinning the module.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Module being pinned.
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `pin_module` instruction.
+pub fn handle(ctx: Context<PinModule>) -> Result<()> {
+    let PinModule {
+        authority,
+        config,
+        lifecycle,
+        repo,
+        module,
+    } = ctx.accounts;
+
+    let clock = Clock::get()?;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock.unix_timestamp)?;
+    repo.assert_authority(authority)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    let repo_key = repo.key();
+    repo.pin_module(&repo_key, module.key(), &module.repo, &clock)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoModulePinChanged {
+        repo: repo_key,
+        module: module.key(),
+        pinned: true,
+        pinned_count: repo.pinned_modules.len() as u8,
+    });
+
+    Ok(())
+}
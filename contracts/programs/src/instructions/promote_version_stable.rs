@@ -0,0 +1,133 @@
+//! ===========================================================================
+//! Unit09 – Promote Version Stable Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/promote_version_stable.rs
+//!
+//! Marks a beta `ModuleVersion` as stable once it has proven itself.
+//!
+//! Versions stay immutable apart from this flag: promotion only ever moves
+//! `is_stable` from false to true, and deprecated versions are refused.
+//!
+//! On success this instruction:
+//! - sets `module_version.is_stable`
+//! - moves `module.latest_stable_version` to this version when it is newer
+//!   than the current pointer (promoting an older patch leaves it alone)
+//! - emits `ModuleVersionPromoted`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only the module authority may promote its versions
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleVersionPromoted;
+use crate::state::{Config, Lifecycle, Module, ModuleVersion};
+
+/// Accounts required for the `promote_version_stable` instruction.
+#[derive(Accounts)]
+pub struct PromoteVersionStable<'info> {
+    /// Authority of the module; must match `module.authority`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Module owning the version.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Version snapshot being promoted.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_VERSION_SEED.as_bytes(),
+            module.key().as_ref(),
+            &module_version.major_version.to_le_bytes(),
+            &module_version.minor_version.to_le_bytes(),
+            &module_version.patch_version.to_le_bytes(),
+        ],
+        bump = module_version.bump,
+        constraint = module_version.module == module.key() @ Unit09Error::ModuleVersionNotFound,
+    )]
+    pub module_version: Account<'info, ModuleVersion>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `promote_version_stable` instruction.
+pub fn handle(ctx: Context<PromoteVersionStable>) -> Result<()> {
+    let PromoteVersionStable {
+        authority,
+        config,
+        lifecycle,
+        module,
+        module_version,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    module.assert_authority(authority)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    module_version.promote_to_stable()?;
+
+    let version = module_version.version();
+    let is_latest_stable = module.record_stable_version(module_version.key(), version, clock_ref);
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    let (major_version, minor_version, patch_version) = version;
+    emit!(ModuleVersionPromoted {
+        module: module.key(),
+        module_version: module_version.key(),
+        major_version,
+        minor_version,
+        patch_version,
+        is_latest_stable,
+    });
+
+    Ok(())
+}
@@ -136,7 +136,7 @@ pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()
 
     // Optionally ensure the deployment is marked active; you may relax this
     // if you want to allow metrics reconciliation even in inactive states.
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
 
     // -----------------------------------------------------------------------
     // Light validation on provided values
@@ -10,16 +10,84 @@
 //! - number of files processed
 //! - number of modules detected or updated
 //! - commit or revision identifier
+//! - optionally, the number of commits covered
+//! - optionally, lines of code per language
 //!
 //! On success this instruction:
-//! - updates per-repo observation stats on the `Repo` account
+//! - updates per-repo observation stats on the `Repo` account, including the
+//!   current codebase size (absolute, or via a signed `loc_delta`) and
+//!   `Repo::total_commits_observed` when `commits` is given
 //! - aggregates metrics into the global `Metrics` account
-//! - emits an `ObservationRecorded` event for indexers and dashboards
+//!   (`Metrics::category_loc` by the repo's dominant module category)
+//! - adds the run to the observer's `ObserverStats` for this repo, creating
+//!   it on the observer's first observation
+//! - accrues `Config::observation_reward_lamports` to the stats of the key
+//!   the observation is credited to (see "Rewards" below)
+//! - adds the `language_breakdown`, if any, to the repo's `RepoLanguageStats`
+//!   when that account is passed, creating it on first use
+//! - adds any `module_contributions` to each module's
+//!   `Module::observed_lines_of_code`
+//! - emits an `ObservationRecorded` event for indexers and dashboards, unless
+//!   the repo has opted out via `Repo::emit_observation_events`
+//! - emits one `ObservationAnomalyDetected` per soft anomaly (too frequent,
+//!   near a cap, implausible LOC per file); these never fail the observation
+//! - extends the repo's daily observation streak and emits a `Unit09Log`
+//!   (category `observation_streak`) when it reaches a milestone
+//! - refreshes the repo's cached `activity_tier` and emits a `Unit09Log`
+//!   (category `activity_tier`) when it changes
+//!
+//! A repo with `require_signed_observations` set only accepts observations
+//! vouched for by a registered worker: the transaction must carry an Ed25519
+//! program instruction, right before this one, verifying the worker's
+//! signature over `signed_observation_message` (repo, a hash of the full
+//! arguments and a recent slot), and the worker's `Authority` must hold the
+//! observer role for the repo. The runtime checks the signature itself; this
+//! instruction checks that it was made by that worker over exactly these
+//! arguments, and that its slot is later than the last one the repo accepted
+//! (`Repo::last_signed_observation_slot`), so it cannot be replayed.
+//!
+//! Rewards: a worker-signed observation is credited to the worker that
+//! signed it; otherwise the observer must pass their own `Authority`, as
+//! `worker_authority`, holding the observer role for the repo. Anyone else,
+//! and every backfill, earns nothing. A worker other than the observer is
+//! credited through `worker_stats`, their existing `ObserverStats` for the
+//! repo; when it is omitted the reward is skipped. Each key earns at most
+//! one reward per repo within `OBSERVATION_REWARD_INTERVAL_SECONDS`.
+//!
+//! An observation whose `content_hash` equals the repo's
+//! `last_content_hash` re-analyzed unchanged code: it only moves the repo's
+//! observation timestamp and streak, adds nothing to any total, and emits
+//! `ObservationUnchanged` instead of `ObservationRecorded`.
+//!
+//! A `backfill` observation imports historical data: it is added to the
+//! cumulative totals only, leaving the current codebase size, the daily
+//! metrics bucket, the observation streak and the last-observation
+//! timestamps unchanged.
+//!
+//! When the arguments are rejected (zero or over-cap values, oversized
+//! strings or breakdowns), an `OperationRejected` event is emitted before the
+//! error is returned. The transaction still fails and its state changes are
+//! rolled back, but its logs (and so the event) remain visible to indexers.
 //!
 //! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
 //! - repo must be active and allow observation (`Repo::assert_observable`)
+//! - the language breakdown is capped at `MAX_LANGUAGE_ENTRIES`; the length
+//!   is checked before any entry is inspected, so an oversized vector is
+//!   rejected at constant cost instead of running the transaction out of
+//!   compute units
+//! - when the repo requires signed observations, the worker signature and
+//!   `Authority` must be present (`MissingObservationSignature`), the
+//!   signer must hold the observer role for the repo
+//!   (`AuthorityRoleNotAllowed`) and the signed payload must match the
+//!   observation (`ObservationSignatureMismatch`) and must not have been
+//!   used before (`ObservationSignatureReplayed`)
+//! - at most `MAX_REMAINING_ACCOUNTS` remaining accounts
+//!   (`TooManyRemainingAccounts`)
+//! - each attributed module must be passed in `remaining_accounts`, be owned
+//!   by this program and belong to the observed repo (`ModuleRepoMismatch`)
 //! - any signer may perform an observation if the repo allows it
 //!
 //! Typical usage (off-chain worker):
@@ -30,11 +98,38 @@
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::ObservationRecorded;
-use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::events::{
+    ObservationAnomalyDetected, ObservationRecorded, ObservationUnchanged, OperationRejected,
+    RejectedOperation, Unit09Log,
+};
+use crate::state::{
+    Authority, Config, Lifecycle, Metrics, Module, ObservationCaps, ObserverStats, Repo,
+    RepoLanguageStats,
+};
+use crate::utils::{assert_remaining_accounts_within_cap, preceding_ed25519_message, EventSink};
+
+/// `Unit09Log` category used when a repo's observation streak reaches one of
+/// `OBSERVATION_STREAK_MILESTONES`.
+pub const OBSERVATION_STREAK_LOG_CATEGORY: &str = "observation_streak";
+
+/// `Unit09Log` category used when a repo moves to another activity tier
+/// (see `Repo::activity_tier`).
+pub const ACTIVITY_TIER_LOG_CATEGORY: &str = "activity_tier";
+
+/// Lines of code attributed to one language within an observation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LanguageLoc {
+    /// Language name, e.g. "rust" or "typescript".
+    pub language: String,
+
+    /// Lines of code in this language.
+    pub lines_of_code: u64,
+}
 
 /// Arguments for the `record_observation` instruction.
 ///
@@ -65,9 +160,52 @@ pub struct RecordObservationArgs {
     /// - "incremental diff since last observation"
     /// - "hot path refactor analysis"
     pub note: String,
+
+    /// Optional signed change in the repository's current size.
+    ///
+    /// When `None`, `lines_of_code` is taken as the repository's current
+    /// size. When `Some`, the delta is applied to the tracked size instead
+    /// (negative for deletions, clamped at zero). Either way, global metrics
+    /// keep accumulating `lines_of_code` as "total processed".
+    pub loc_delta: Option<i64>,
+
+    /// Optional per-language split of `lines_of_code`.
+    ///
+    /// At most `MAX_LANGUAGE_ENTRIES` entries; the entries must not add up
+    /// to more than `lines_of_code`.
+    pub language_breakdown: Option<Vec<LanguageLoc>>,
+
+    /// Optional lines of code attributed to individual modules of the repo,
+    /// as `(module PDA, lines_of_code)`.
+    ///
+    /// At most `MAX_MODULE_CONTRIBUTIONS` distinct modules, adding up to no
+    /// more than `lines_of_code`. The module accounts are passed writable as
+    /// `remaining_accounts`, in the same order.
+    pub module_contributions: Option<Vec<(Pubkey, u64)>>,
+
+    /// Whether this observation imports historical data.
+    ///
+    /// A backfill adds to the cumulative totals of the repo, global metrics
+    /// and observer stats, but leaves the daily bucket, the observation
+    /// streak, the current codebase size and `last_observation_at` alone, so
+    /// importing a repository's history does not read as recent activity.
+    pub backfill: bool,
+
+    /// Optional hash of the analyzed content, e.g. of the source tree.
+    ///
+    /// When it equals `Repo::last_content_hash`, the code has not changed
+    /// since the previous observation: only the observation timestamp and
+    /// streak move, nothing is added to any total, and `ObservationUnchanged`
+    /// is emitted instead of `ObservationRecorded`. Ignored by backfills.
+    pub content_hash: Option<[u8; 32]>,
+
+    /// Optional number of commits covered by this observation, added to
+    /// `Repo::total_commits_observed`. `None` leaves the total unchanged.
+    pub commits: Option<u64>,
 }
 
 /// Accounts required for the `record_observation` instruction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RecordObservation<'info> {
     /// Signer performing the observation.
@@ -118,6 +256,67 @@ pub struct RecordObservation<'info> {
     )]
     pub repo: Account<'info, Repo>,
 
+    /// The observer's tallies for this repository, paid for by the observer
+    /// on their first observation.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_STATS_SEED.as_bytes(), repo.key(), observer.key()]
+    ///   bump  = observer_stats.bump
+    #[account(
+        init_if_needed,
+        payer = observer,
+        space = ObserverStats::LEN,
+        seeds = [
+            OBSERVER_STATS_SEED.as_bytes(),
+            repo.key().as_ref(),
+            observer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub observer_stats: Account<'info, ObserverStats>,
+
+    /// Per-language totals for this repository, paid for by the observer
+    /// that first passes it. Optional: when omitted, a language breakdown
+    /// is validated but not stored.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_LANGUAGE_STATS_SEED.as_bytes(), repo.key()]
+    ///   bump  = language_stats.bump
+    #[account(
+        init_if_needed,
+        payer = observer,
+        space = RepoLanguageStats::LEN,
+        seeds = [
+            REPO_LANGUAGE_STATS_SEED.as_bytes(),
+            repo.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub language_stats: Option<Account<'info, RepoLanguageStats>>,
+
+    /// `Authority` of the worker key that signed the observation. Required
+    /// when `repo.require_signed_observations` is set.
+    ///
+    /// On other repos, the observer's own `Authority`; the observation
+    /// earns a reward only when it holds the observer role for the repo.
+    pub worker_authority: Option<Account<'info, Authority>>,
+
+    /// `ObserverStats` of the worker that signed the observation, credited
+    /// with its reward when the worker is not `observer`. Checked against
+    /// the repo and worker in the handler (`InvalidObserverStats`).
+    #[account(
+        mut,
+        constraint = worker_stats.key() != observer_stats.key()
+            @ Unit09Error::InvalidObserverStats,
+    )]
+    pub worker_stats: Option<Account<'info, ObserverStats>>,
+
+    /// CHECK: address-constrained to the instructions sysvar, read to find
+    /// the worker's Ed25519 signature. Required when
+    /// `repo.require_signed_observations` is set.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -136,7 +335,7 @@ pub struct RecordObservation<'info> {
 /// 2. Enforce that the repo is active and observable.
 /// 3. Validate numeric fields against configured bounds.
 /// 4. Apply per-repo observation update.
-/// 5. Aggregate values into global metrics.
+/// 5. Aggregate values into global metrics and the observer's stats.
 /// 6. Emit `ObservationRecorded` event.
 pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> Result<()> {
     let RecordObservation {
@@ -145,8 +344,15 @@ pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> R
         mut lifecycle,
         mut metrics,
         mut repo,
+        observer_stats,
+        language_stats,
+        worker_authority,
+        worker_stats,
+        instructions_sysvar,
         system_program: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -155,30 +361,396 @@ pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> R
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
 
     // Ensure repository is active and allows observation.
     repo.assert_active()?;
     repo.assert_observable()?;
 
+    // Cap the attributed modules before any state is touched.
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+
+    // -----------------------------------------------------------------------
+    // Argument validation (rejections are reported via `OperationRejected`)
+    // -----------------------------------------------------------------------
+
+    // Caps come from `Config` so large monorepos can be observed without
+    // recompiling the program.
+    let config_ref: &Config = config;
+    if let Err(error) = validate_observation_args(&args, &config_ref.observation_caps()) {
+        emit!(OperationRejected::new(
+            RejectedOperation::Observation,
+            repo.key(),
+            &error,
+            clock_ref.unix_timestamp,
+        ));
+        return Err(error);
+    }
+
+    // -----------------------------------------------------------------------
+    // Worker signature (repos requiring signed observations)
+    // -----------------------------------------------------------------------
+
+    let mut signed_by = None;
+    if repo.require_signed_observations {
+        let (Some(worker_authority), Some(instructions_sysvar)) =
+            (worker_authority.as_ref(), instructions_sysvar.as_ref())
+        else {
+            return err!(Unit09Error::MissingObservationSignature);
+        };
+        let (signer, message) = preceding_ed25519_message(instructions_sysvar)?;
+        let signed_slot = check_signed_observation(
+            &signer,
+            &message,
+            worker_authority,
+            &repo.key(),
+            &args,
+            clock_ref,
+        )?;
+        repo.accept_signed_observation_slot(signed_slot)?;
+        signed_by = Some(signer);
+    }
+
+    // -----------------------------------------------------------------------
+    // Unchanged content (same hash as the previous observation)
+    // -----------------------------------------------------------------------
+
+    if let Some(content_hash) = unchanged_content_hash(repo, &args) {
+        let streak_milestone = repo.record_observation_day(clock_ref.unix_timestamp)?;
+
+        if repo.emit_observation_events {
+            let sink = EventSink::new(config_ref, event_authority, ctx.bumps.event_authority);
+            sink.emit(ObservationUnchanged {
+                repo: repo.key(),
+                slot: clock_ref.slot,
+                content_hash,
+                observed_at: clock_ref.unix_timestamp,
+            })?;
+        }
+        if let Some(streak_days) = streak_milestone {
+            emit!(streak_log(repo.key(), streak_days, clock_ref.unix_timestamp));
+        }
+        if let Some(previous_tier) = repo.refresh_activity_tier(clock_ref.unix_timestamp) {
+            emit!(activity_tier_log(repo, previous_tier, clock_ref.unix_timestamp));
+        }
+        return Ok(());
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply per-repo observation update
+    // -----------------------------------------------------------------------
+
+    // Snapshot the running total so the event can report this run's
+    // contribution without indexers holding prior state.
+    let prev_total_loc = repo.total_lines_of_code;
+    // Backfills are imported in bursts, so they are not checked for
+    // frequency.
+    let seconds_since_last = if args.backfill {
+        None
+    } else {
+        repo.seconds_since_last_observation(clock_ref.unix_timestamp)
+    };
+
+    let streak_milestone =
+        apply_repo_observation(repo, &args, Some(config_ref), clock_ref.unix_timestamp)?;
+
+    // -----------------------------------------------------------------------
+    // Aggregate into global metrics
+    // -----------------------------------------------------------------------
+
+    if args.backfill {
+        metrics.record_backfill(args.lines_of_code, args.files_processed, Some(config_ref))?;
+    } else {
+        metrics.record_observation(
+            args.lines_of_code,
+            args.files_processed,
+            Some(config_ref),
+            clock_ref,
+        )?;
+    }
+    metrics.record_category_loc(repo.dominant_module_category(), args.lines_of_code)?;
+    metrics.updated_at = clock_ref.unix_timestamp;
+
+    if !observer_stats.is_initialized() {
+        observer_stats.init(repo.key(), observer.key(), ctx.bumps.observer_stats, clock_ref);
+    }
+    observer_stats.record_observation(args.lines_of_code, clock_ref)?;
+
+    let reward_to = reward_recipient(
+        &observer.key(),
+        signed_by,
+        worker_authority.as_deref(),
+        &repo.key(),
+        &args,
+        clock_ref.unix_timestamp,
+    );
+    let credited_stats = match reward_to {
+        Some(key) if key == observer.key() => Some(&mut **observer_stats),
+        Some(worker) => match worker_stats.as_deref_mut() {
+            Some(stats) if stats.repo == repo.key() && stats.observer == worker => Some(stats),
+            Some(_) => return err!(Unit09Error::InvalidObserverStats),
+            None => None,
+        },
+        None => None,
+    };
+    if let Some(stats) = credited_stats {
+        stats.accrue_reward(config_ref.observation_reward_lamports, clock_ref.unix_timestamp)?;
+    }
+
+    if let (Some(language_stats), Some(breakdown)) =
+        (language_stats.as_mut(), args.language_breakdown.as_ref())
+    {
+        if !language_stats.is_initialized() {
+            let language_stats_bump =
+                ctx.bumps.language_stats.ok_or(Unit09Error::InternalError)?;
+            language_stats.init(repo.key(), language_stats_bump, clock_ref);
+        }
+        language_stats.record_breakdown(
+            breakdown
+                .iter()
+                .map(|entry| (entry.language.as_str(), entry.lines_of_code)),
+            clock_ref,
+        )?;
+    }
+
     // -----------------------------------------------------------------------
-    // Early validation on numeric fields
+    // Attribute lines of code to modules
     // -----------------------------------------------------------------------
 
+    let contributions = args.module_contributions.as_deref().unwrap_or_default();
+    if ctx.remaining_accounts.len() != contributions.len() {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+    for (module_info, (module_key, lines_of_code)) in
+        ctx.remaining_accounts.iter().zip(contributions)
+    {
+        require_keys_eq!(*module_info.key, *module_key, Unit09Error::MissingRequiredAccount);
+        if module_info.owner != ctx.program_id {
+            return err!(Unit09Error::InvalidAccountOwner);
+        }
+        let mut module = Module::try_deserialize(&mut &module_info.try_borrow_data()?[..])?;
+        module.add_observed_loc(&repo.key(), *lines_of_code)?;
+        module.try_serialize(&mut &mut module_info.try_borrow_mut_data()?[..])?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Emit ObservationRecorded event
+    // -----------------------------------------------------------------------
+
+    if let Some(event) = observation_event(
+        repo.key(),
+        repo,
+        clock_ref.slot,
+        &args,
+        prev_total_loc,
+        clock_ref.unix_timestamp,
+    ) {
+        let sink = EventSink::new(config_ref, event_authority, ctx.bumps.event_authority);
+        sink.emit(event)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Emit soft anomalies (informational; the observation stands)
+    // -----------------------------------------------------------------------
+
+    for event in anomaly_events(
+        repo.key(),
+        &config_ref.observation_caps(),
+        &args,
+        seconds_since_last,
+        clock_ref.unix_timestamp,
+    ) {
+        emit!(event);
+    }
+
+    if let Some(streak_days) = streak_milestone {
+        emit!(streak_log(repo.key(), streak_days, clock_ref.unix_timestamp));
+    }
+    if let Some(previous_tier) = repo.refresh_activity_tier(clock_ref.unix_timestamp) {
+        emit!(activity_tier_log(repo, previous_tier, clock_ref.unix_timestamp));
+    }
+
+    Ok(())
+}
+
+/// `Unit09Log` announcing that `repo` reached a streak milestone.
+fn streak_log(repo: Pubkey, streak_days: u32, now: i64) -> Unit09Log {
+    Unit09Log {
+        category: OBSERVATION_STREAK_LOG_CATEGORY.to_string(),
+        message: format!("repo {repo} observed {streak_days} days in a row"),
+        logged_at: now,
+    }
+}
+
+/// `Unit09Log` announcing that `repo` moved from `previous_tier` to its
+/// current `activity_tier`.
+fn activity_tier_log(repo: &Account<Repo>, previous_tier: u8, now: i64) -> Unit09Log {
+    Unit09Log {
+        category: ACTIVITY_TIER_LOG_CATEGORY.to_string(),
+        message: format!(
+            "repo {} moved from {} to {}",
+            repo.key(),
+            activity_tier_name(previous_tier),
+            activity_tier_name(repo.activity_tier),
+        ),
+        logged_at: now,
+    }
+}
+
+/// Human-readable name of an `ACTIVITY_TIER_*` value.
+fn activity_tier_name(tier: u8) -> &'static str {
+    match tier {
+        ACTIVITY_TIER_DORMANT => "dormant",
+        ACTIVITY_TIER_ACTIVE => "active",
+        ACTIVITY_TIER_HOT => "hot",
+        _ => "unknown",
+    }
+}
+
+/// The observation's content hash when it matches the repo's latest one,
+/// so the observation only repeats the previous one. Backfills are never
+/// considered unchanged.
+pub fn unchanged_content_hash(repo: &Repo, args: &RecordObservationArgs) -> Option<[u8; 32]> {
+    if args.backfill {
+        return None;
+    }
+    args.content_hash
+        .filter(|content_hash| repo.is_unchanged_content(content_hash))
+}
+
+/// Key credited with the reward for this observation of `repo`, if any.
+///
+/// A worker-signed observation (`signed_by`) is credited to the worker.
+/// Otherwise `authority` must be the observer's own and hold the observer
+/// role for `repo` at `now`. Backfills are never rewarded.
+pub fn reward_recipient(
+    observer: &Pubkey,
+    signed_by: Option<Pubkey>,
+    authority: Option<&Authority>,
+    repo: &Pubkey,
+    args: &RecordObservationArgs,
+    now: i64,
+) -> Option<Pubkey> {
+    if args.backfill {
+        return None;
+    }
+    if signed_by.is_some() {
+        return signed_by;
+    }
+    authority
+        .filter(|authority| {
+            authority.authority == *observer
+                && authority.matches_resource(repo)
+                && authority.has_role(ROLE_LABEL_OBSERVER, now)
+        })
+        .map(|_| *observer)
+}
+
+/// Payload a worker signs to vouch for an observation of `repo`:
+/// `SIGNED_OBSERVATION_DOMAIN`, the repo PDA, the SHA-256 hash of the
+/// Borsh-serialized `args`, then the slot at signing time, little-endian.
+///
+/// Hashing the whole of `args` binds every field to the signature, not just
+/// the counts.
+pub fn signed_observation_message(
+    repo: &Pubkey,
+    args: &RecordObservationArgs,
+    slot: u64,
+) -> Result<Vec<u8>> {
+    let args_hash = hash(&args.try_to_vec()?);
+
+    let mut message = SIGNED_OBSERVATION_DOMAIN.to_vec();
+    message.extend_from_slice(repo.as_ref());
+    message.extend_from_slice(&args_hash.to_bytes());
+    message.extend_from_slice(&slot.to_le_bytes());
+    Ok(message)
+}
+
+/// Check that `message`, signed by `signer`, vouches for exactly this
+/// observation of `repo`, returning the slot it was signed at.
+///
+/// `worker` must be the signer's `Authority` and hold the observer role for
+/// `repo`. The message must equal `signed_observation_message` for the
+/// observation's arguments, at a slot no more than
+/// `SIGNED_OBSERVATION_MAX_AGE_SLOTS` before the current one. Replays are
+/// rejected separately, by `Repo::accept_signed_observation_slot`.
+pub fn check_signed_observation(
+    signer: &Pubkey,
+    message: &[u8],
+    worker: &Authority,
+    repo: &Pubkey,
+    args: &RecordObservationArgs,
+    clock: &Clock,
+) -> Result<u64> {
+    let registered = worker.authority == *signer
+        && worker.matches_resource(repo)
+        && worker.has_role(ROLE_LABEL_OBSERVER, clock.unix_timestamp);
+    if !registered {
+        return err!(Unit09Error::AuthorityRoleNotAllowed);
+    }
+
+    // The slot is the only field the program cannot rebuild on its own.
+    let slot_at = message
+        .len()
+        .checked_sub(8)
+        .ok_or(Unit09Error::ObservationSignatureMismatch)?;
+    let mut slot_bytes = [0u8; 8];
+    slot_bytes.copy_from_slice(&message[slot_at..]);
+    let slot = u64::from_le_bytes(slot_bytes);
+
+    let expected = signed_observation_message(repo, args, slot)?;
+    let fresh = slot <= clock.slot && clock.slot - slot <= SIGNED_OBSERVATION_MAX_AGE_SLOTS;
+    if message != expected.as_slice() || !fresh {
+        return err!(Unit09Error::ObservationSignatureMismatch);
+    }
+    Ok(slot)
+}
+
+/// Apply an observation to `repo`, returning the streak milestone reached,
+/// if any.
+///
+/// A backfill describes the past: it is added to the totals, commits
+/// included, but neither
+/// moves the current size, nor records its content hash, nor counts as a
+/// day of activity.
+pub fn apply_repo_observation(
+    repo: &mut Repo,
+    args: &RecordObservationArgs,
+    config: Option<&Config>,
+    now: i64,
+) -> Result<Option<u32>> {
+    repo.record_observation(args.lines_of_code, args.files_processed, config)?;
+    if let Some(commits) = args.commits {
+        repo.add_commits(commits)?;
+    }
+    if args.backfill {
+        return Ok(None);
+    }
+
+    repo.track_current_loc(args.lines_of_code, args.loc_delta)?;
+    if let Some(content_hash) = args.content_hash {
+        repo.last_content_hash = content_hash;
+    }
+    repo.record_observation_day(now)
+}
+
+/// Validate the numeric and string arguments of an observation against
+/// `caps` and the program-wide bounds.
+pub fn validate_observation_args(
+    args: &RecordObservationArgs,
+    caps: &ObservationCaps,
+) -> Result<()> {
     if args.lines_of_code == 0 {
         return err!(Unit09Error::ValueOutOfRange);
     }
-    if args.lines_of_code > MAX_LOC_PER_OBSERVATION {
-        return err!(Unit09Error::ObservationDataTooLarge);
-    }
 
     if args.files_processed == 0 {
         return err!(Unit09Error::ValueOutOfRange);
     }
-    if args.files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-        return err!(Unit09Error::ObservationDataTooLarge);
-    }
+
+    caps.check(args.lines_of_code, args.files_processed)?;
 
     // `modules_touched` can be zero (for example, metadata-only runs), but
     // we still enforce an upper bound to avoid nonsensical values.
@@ -186,10 +758,6 @@ pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> R
         return err!(Unit09Error::ObservationDataTooLarge);
     }
 
-    // -----------------------------------------------------------------------
-    // Basic validation on string fields
-    // -----------------------------------------------------------------------
-
     if args.revision.len() > Repo::MAX_REVISION_LEN {
         return err!(Unit09Error::StringTooLong);
     }
@@ -198,41 +766,478 @@ pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> R
         return err!(Unit09Error::StringTooLong);
     }
 
-    // -----------------------------------------------------------------------
-    // Apply per-repo observation update
-    // -----------------------------------------------------------------------
+    if let Some(breakdown) = &args.language_breakdown {
+        validate_language_breakdown(breakdown, args.lines_of_code)?;
+    }
 
-    repo.record_observation(
-        args.lines_of_code,
-        args.files_processed,
-        args.modules_touched,
-        args.revision.clone(),
-        args.note.clone(),
-        observer.key(),
-        clock_ref,
-    )?;
+    if let Some(contributions) = &args.module_contributions {
+        validate_module_contributions(contributions, args.lines_of_code)?;
+    }
 
-    // -----------------------------------------------------------------------
-    // Aggregate into global metrics
-    // -----------------------------------------------------------------------
+    Ok(())
+}
 
-    metrics.record_observation(args.lines_of_code, args.files_processed, clock_ref)?;
-    metrics.updated_at = clock_ref.unix_timestamp;
+/// Validate an observation's per-language breakdown.
+///
+/// The entry count is checked first, so the per-entry loop below is bounded
+/// by `MAX_LANGUAGE_ENTRIES` regardless of what the caller submits.
+pub fn validate_language_breakdown(breakdown: &[LanguageLoc], lines_of_code: u64) -> Result<()> {
+    if breakdown.len() > MAX_LANGUAGE_ENTRIES {
+        return err!(Unit09Error::ValueOutOfRange);
+    }
 
-    // -----------------------------------------------------------------------
-    // Emit ObservationRecorded event
-    // -----------------------------------------------------------------------
+    let mut total: u64 = 0;
+    for entry in breakdown {
+        if entry.language.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if entry.language.len() > MAX_LANGUAGE_NAME_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        total = total
+            .checked_add(entry.lines_of_code)
+            .ok_or(Unit09Error::CounterOverflow)?;
+    }
+
+    if total > lines_of_code {
+        return err!(Unit09Error::ValueOutOfRange);
+    }
+    Ok(())
+}
+
+/// Validate an observation's per-module attribution.
+///
+/// As with the language breakdown, the entry count is checked before any
+/// entry is inspected.
+pub fn validate_module_contributions(
+    contributions: &[(Pubkey, u64)],
+    lines_of_code: u64,
+) -> Result<()> {
+    if contributions.len() > MAX_MODULE_CONTRIBUTIONS {
+        return err!(Unit09Error::ObservationDataTooLarge);
+    }
+
+    let mut total: u64 = 0;
+    for (index, (module, module_loc)) in contributions.iter().enumerate() {
+        if contributions[..index].iter().any(|(earlier, _)| earlier == module) {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        total = total
+            .checked_add(*module_loc)
+            .ok_or(Unit09Error::CounterOverflow)?;
+    }
+
+    if total > lines_of_code {
+        return err!(Unit09Error::ValueOutOfRange);
+    }
+    Ok(())
+}
+
+/// Build one `ObservationAnomalyDetected` per soft anomaly in `args`.
+pub fn anomaly_events(
+    repo_key: Pubkey,
+    caps: &ObservationCaps,
+    args: &RecordObservationArgs,
+    seconds_since_last: Option<i64>,
+    now: i64,
+) -> Vec<ObservationAnomalyDetected> {
+    caps.anomalies(args.lines_of_code, args.files_processed, seconds_since_last)
+        .into_iter()
+        .map(|(kind, value)| ObservationAnomalyDetected {
+            repo: repo_key,
+            kind: kind.as_u8(),
+            value,
+            detected_at: now,
+        })
+        .collect()
+}
+
+/// Build the `ObservationRecorded` event for an applied observation.
+///
+/// Returns `None` when the repository has turned off per-observation events.
+/// The reported health is `Repo::compute_health` at `now`, after the
+/// observation was applied.
+pub fn observation_event(
+    repo_key: Pubkey,
+    repo: &Repo,
+    slot: u64,
+    args: &RecordObservationArgs,
+    prev_total_loc: u64,
+    now: i64,
+) -> Option<ObservationRecorded> {
+    if !repo.emit_observation_events {
+        return None;
+    }
 
-    emit!(ObservationRecorded {
-        repo: repo.key(),
-        observer: observer.key(),
+    Some(ObservationRecorded {
+        repo: repo_key,
+        slot,
         lines_of_code: args.lines_of_code,
         files_processed: args.files_processed,
-        modules_touched: args.modules_touched,
-        revision: args.revision,
-        note: args.note,
-        observed_at: repo.last_observed_at,
-    });
+        prev_total_loc,
+        new_total_loc: repo.total_lines_of_code,
+        backfill: args.backfill,
+        health_score: repo.compute_health(now),
+        commits: args.commits.unwrap_or(0),
+        total_commits_observed: repo.total_commits_observed,
+    })
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ObservationAnomalyKind;
+    use crate::utils::test_utils::{clock_at_slot, new_authority, new_module, new_repo};
+
+    fn args(lines_of_code: u64, files_processed: u32) -> RecordObservationArgs {
+        RecordObservationArgs {
+            lines_of_code,
+            files_processed,
+            modules_touched: 0,
+            revision: String::new(),
+            note: String::new(),
+            loc_delta: None,
+            language_breakdown: None,
+            module_contributions: None,
+            backfill: false,
+            content_hash: None,
+            commits: None,
+        }
+    }
+
+    fn breakdown(entries: usize) -> Vec<LanguageLoc> {
+        (0..entries)
+            .map(|i| LanguageLoc {
+                language: format!("lang-{i}"),
+                lines_of_code: 10,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn events_are_emitted_by_default() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        let args = args(1_000, 10);
+        repo.record_observation(args.lines_of_code, args.files_processed, None)
+            .unwrap();
+
+        let event = observation_event(Pubkey::new_unique(), &repo, 7, &args, 0, 0).unwrap();
+        assert_eq!(event.new_total_loc, 1_000);
+        assert_eq!(event.slot, 7);
+    }
+
+    #[test]
+    fn event_reports_the_health_after_the_observation() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        repo.module_count = 2;
+        let now = 10 * SECONDS_PER_DAY + 60;
+        let args = args(1_000, 10);
+        let before = repo.compute_health(now);
+        apply_repo_observation(&mut repo, &args, None, now).unwrap();
+
+        let event = observation_event(Pubkey::new_unique(), &repo, 7, &args, 0, now).unwrap();
+        assert_eq!(event.health_score, repo.compute_health(now));
+        assert!(event.health_score > before);
+    }
+
+    #[test]
+    fn disabled_events_still_update_counters() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        repo.emit_observation_events = false;
+
+        let args = args(1_000, 10);
+        let prev_total_loc = repo.total_lines_of_code;
+        repo.record_observation(args.lines_of_code, args.files_processed, None)
+            .unwrap();
+
+        assert_eq!(repo.observation_count, 1);
+        assert_eq!(repo.total_lines_of_code, 1_000);
+        assert_eq!(repo.total_files_processed, 10);
+        let event = observation_event(Pubkey::new_unique(), &repo, 7, &args, prev_total_loc, 0);
+        assert!(event.is_none());
+    }
+
+    #[test]
+    fn backfill_adds_to_totals_but_not_to_the_streak() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        let day = |n: i64| n * SECONDS_PER_DAY + 60;
+        apply_repo_observation(&mut repo, &args(1_000, 10), None, day(10)).unwrap();
+        apply_repo_observation(&mut repo, &args(1_200, 12), None, day(11)).unwrap();
+        assert_eq!(repo.current_streak_days, 2);
+
+        let mut backfill = args(50_000, 400);
+        backfill.backfill = true;
+        let milestone = apply_repo_observation(&mut repo, &backfill, None, day(12)).unwrap();
+
+        assert!(milestone.is_none());
+        assert_eq!(repo.observation_count, 3);
+        assert_eq!(repo.total_lines_of_code, 52_200);
+        assert_eq!(repo.total_files_processed, 422);
+        assert_eq!(repo.current_lines_of_code, 1_200);
+        assert_eq!(repo.current_streak_days, 2);
+        assert_eq!(repo.last_observation_at, day(11));
+    }
+
+    #[test]
+    fn commit_counts_accumulate_when_reported() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        let with_commits = |commits| RecordObservationArgs {
+            commits: Some(commits),
+            ..args(1_000, 10)
+        };
+
+        apply_repo_observation(&mut repo, &with_commits(12), None, 100).unwrap();
+        apply_repo_observation(&mut repo, &with_commits(5), None, 200).unwrap();
+        assert_eq!(repo.total_commits_observed, 17);
+
+        // Observations without a count leave the total alone.
+        let event_args = args(1_000, 10);
+        apply_repo_observation(&mut repo, &event_args, None, 300).unwrap();
+        assert_eq!(repo.total_commits_observed, 17);
+        let event = observation_event(Pubkey::new_unique(), &repo, 7, &event_args, 0, 300).unwrap();
+        assert_eq!((event.commits, event.total_commits_observed), (0, 17));
+
+        repo.total_commits_observed = u64::MAX;
+        assert!(apply_repo_observation(&mut repo, &with_commits(1), None, 400).is_err());
+    }
+
+    fn hashed(lines_of_code: u64, content_hash: [u8; 32]) -> RecordObservationArgs {
+        RecordObservationArgs {
+            content_hash: Some(content_hash),
+            ..args(lines_of_code, 10)
+        }
+    }
+
+    #[test]
+    fn identical_content_hash_takes_the_unchanged_path() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        apply_repo_observation(&mut repo, &hashed(1_000, [7u8; 32]), None, 100).unwrap();
+        assert_eq!(repo.last_content_hash, [7u8; 32]);
+
+        let rerun = hashed(1_000, [7u8; 32]);
+        assert_eq!(unchanged_content_hash(&repo, &rerun), Some([7u8; 32]));
+
+        // The handler then only moves the timestamp.
+        repo.record_observation_day(200).unwrap();
+        assert_eq!(repo.observation_count, 1);
+        assert_eq!(repo.total_lines_of_code, 1_000);
+        assert_eq!(repo.last_observation_at, 200);
+
+        // Backfills always go through the full path.
+        let backfill = RecordObservationArgs {
+            backfill: true,
+            ..hashed(1_000, [7u8; 32])
+        };
+        assert!(unchanged_content_hash(&repo, &backfill).is_none());
+    }
+
+    #[test]
+    fn changed_content_hash_takes_the_full_path() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        // Nothing is stored yet, so even a zero hash is not a repeat.
+        assert!(unchanged_content_hash(&repo, &hashed(1_000, [0u8; 32])).is_none());
+        apply_repo_observation(&mut repo, &hashed(1_000, [7u8; 32]), None, 100).unwrap();
+
+        let changed = hashed(1_100, [8u8; 32]);
+        assert!(unchanged_content_hash(&repo, &changed).is_none());
+        assert!(unchanged_content_hash(&repo, &args(1_100, 10)).is_none());
+        apply_repo_observation(&mut repo, &changed, None, 200).unwrap();
+
+        assert_eq!(repo.observation_count, 2);
+        assert_eq!(repo.total_lines_of_code, 2_100);
+        assert_eq!(repo.last_content_hash, [8u8; 32]);
+    }
+
+    /// `Authority` for a worker holding the observer role on `repo` only.
+    fn worker_for(repo: Pubkey) -> Authority {
+        let clock = Clock::default();
+        let mut worker = new_authority(Pubkey::new_unique(), false, repo, &clock);
+        worker.add_role(ROLE_LABEL_OBSERVER, 0, &clock).unwrap();
+        worker
+    }
+
+    #[test]
+    fn signed_observation_matching_its_payload_is_accepted() {
+        let repo = Pubkey::new_unique();
+        let worker = worker_for(repo);
+        let message = signed_observation_message(&repo, &args(1_000, 10), 500).unwrap();
+
+        let check = |args: &RecordObservationArgs, slot| {
+            check_signed_observation(
+                &worker.authority,
+                &message,
+                &worker,
+                &repo,
+                args,
+                &clock_at_slot(slot),
+            )
+        };
+
+        assert_eq!(check(&args(1_000, 10), 510).unwrap(), 500);
+        // A tampered count no longer matches what the worker signed.
+        assert!(check(&args(1_001, 10), 510).is_err());
+        assert!(check(&args(1_000, 11), 510).is_err());
+        // Nor does any other tampered field.
+        let mut backfill = args(1_000, 10);
+        backfill.backfill = true;
+        assert!(check(&backfill, 510).is_err());
+        let mut contributions = args(1_000, 10);
+        contributions.module_contributions = Some(vec![(Pubkey::new_unique(), 1_000)]);
+        assert!(check(&contributions, 510).is_err());
+        // Neither does a stale signature.
+        assert!(check(&args(1_000, 10), 500 + SIGNED_OBSERVATION_MAX_AGE_SLOTS + 1).is_err());
+    }
+
+    #[test]
+    fn signed_observation_needs_a_registered_worker_for_the_repo() {
+        let repo = Pubkey::new_unique();
+        let worker = worker_for(repo);
+        let message = signed_observation_message(&repo, &args(1_000, 10), 500).unwrap();
+        let clock = clock_at_slot(510);
+
+        // Signed by some other key.
+        let stranger = Pubkey::new_unique();
+        assert!(
+            check_signed_observation(&stranger, &message, &worker, &repo, &args(1_000, 10), &clock)
+                .is_err()
+        );
+
+        // A worker registered for another repo, signing for that repo.
+        let other_repo = Pubkey::new_unique();
+        let message = signed_observation_message(&other_repo, &args(1_000, 10), 500).unwrap();
+        assert!(check_signed_observation(
+            &worker.authority,
+            &message,
+            &worker,
+            &other_repo,
+            &args(1_000, 10),
+            &clock,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rewards_go_to_the_signing_worker_or_a_registered_observer() {
+        let repo = Pubkey::new_unique();
+        let worker = worker_for(repo);
+        let observer = worker.authority;
+        let relayer = Pubkey::new_unique();
+        let live = args(1_000, 10);
+        let mut backfill = args(1_000, 10);
+        backfill.backfill = true;
+
+        // A signed observation credits the worker, whoever submits it.
+        let signed_by = Some(worker.authority);
+        assert_eq!(
+            reward_recipient(&relayer, signed_by, None, &repo, &live, 0),
+            Some(worker.authority)
+        );
+
+        // Unsigned: only an observer holding the observer role for the repo.
+        let registered = Some(&worker);
+        assert_eq!(
+            reward_recipient(&observer, None, registered, &repo, &live, 0),
+            Some(observer)
+        );
+        assert_eq!(reward_recipient(&relayer, None, registered, &repo, &live, 0), None);
+        assert_eq!(reward_recipient(&observer, None, None, &repo, &live, 0), None);
+        let other_repo = Pubkey::new_unique();
+        assert_eq!(reward_recipient(&observer, None, registered, &other_repo, &live, 0), None);
+
+        // Backfills never earn a reward.
+        assert_eq!(reward_recipient(&relayer, signed_by, None, &repo, &backfill, 0), None);
+        assert_eq!(reward_recipient(&observer, None, registered, &repo, &backfill, 0), None);
+    }
+
+    #[test]
+    fn borderline_large_observation_is_flagged_but_accepted() {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        let caps = ObservationCaps::default();
+        let borderline = caps.max_loc * OBSERVATION_SOFT_LIMIT_PCT / 100 + 1;
+        let args = args(borderline, caps.max_files / 2);
+
+        caps.check(args.lines_of_code, args.files_processed).unwrap();
+        repo.record_observation(args.lines_of_code, args.files_processed, None)
+            .unwrap();
+        assert_eq!(repo.total_lines_of_code, borderline);
+
+        let events = anomaly_events(Pubkey::new_unique(), &caps, &args, None, 100);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, ObservationAnomalyKind::TooLarge.as_u8());
+        assert_eq!(events[0].value, borderline);
+    }
+
+    #[test]
+    fn ordinary_observation_has_no_anomalies() {
+        let caps = ObservationCaps::default();
+        let args = args(1_000, 10);
+        assert!(anomaly_events(Pubkey::new_unique(), &caps, &args, Some(3_600), 100).is_empty());
+    }
+
+    #[test]
+    fn language_breakdown_at_cap_is_accepted() {
+        let entries = breakdown(MAX_LANGUAGE_ENTRIES);
+        validate_language_breakdown(&entries, 10 * MAX_LANGUAGE_ENTRIES as u64).unwrap();
+    }
+
+    #[test]
+    fn language_breakdown_over_cap_is_rejected() {
+        let entries = breakdown(MAX_LANGUAGE_ENTRIES + 1);
+        assert!(validate_language_breakdown(&entries, u64::MAX).is_err());
+        assert!(validate_language_breakdown(&entries[1..], u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn over_cap_observation_is_rejected_with_event() {
+        let caps = ObservationCaps::default();
+        let repo_key = Pubkey::new_unique();
+        let over_cap = args(caps.max_loc + 1, 10);
+
+        let error = validate_observation_args(&over_cap, &caps).unwrap_err();
+        let event = OperationRejected::new(RejectedOperation::Observation, repo_key, &error, 100);
+
+        assert_eq!(event.operation, RejectedOperation::Observation.as_u8());
+        assert_eq!(event.subject, repo_key);
+        assert_eq!(event.reason, u32::from(Unit09Error::ObservationDataTooLarge));
+        assert_eq!(event.rejected_at, 100);
+
+        assert!(validate_observation_args(&args(caps.max_loc, 10), &caps).is_ok());
+    }
+
+    #[test]
+    fn loc_is_attributed_to_each_module() {
+        let (repo_key, authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let clock = Clock::default();
+        let mut router = new_module(repo_key, authority, "router", &clock);
+        let mut indexer = new_module(repo_key, authority, "indexer", &clock);
+        let (router_key, indexer_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut args = args(1_000, 10);
+        args.module_contributions = Some(vec![(router_key, 600), (indexer_key, 250)]);
+        validate_observation_args(&args, &ObservationCaps::default()).unwrap();
+
+        for _ in 0..2 {
+            router.add_observed_loc(&repo_key, 600).unwrap();
+            indexer.add_observed_loc(&repo_key, 250).unwrap();
+        }
+        assert_eq!(router.observed_lines_of_code, 1_200);
+        assert_eq!(indexer.observed_lines_of_code, 500);
+
+        let mut foreign = new_module(Pubkey::new_unique(), authority, "worker", &clock);
+        assert!(foreign.add_observed_loc(&repo_key, 100).is_err());
+        assert_eq!(foreign.observed_lines_of_code, 0);
+    }
+
+    #[test]
+    fn invalid_module_contributions_are_rejected() {
+        let module = Pubkey::new_unique();
+        assert!(validate_module_contributions(&[(module, 500), (module, 100)], 1_000).is_err());
+        assert!(validate_module_contributions(&[(module, 1_001)], 1_000).is_err());
+
+        let oversized: Vec<_> = (0..=MAX_MODULE_CONTRIBUTIONS)
+            .map(|_| (Pubkey::new_unique(), 1))
+            .collect();
+        assert!(validate_module_contributions(&oversized, 1_000).is_err());
+        assert!(validate_module_contributions(&oversized[1..], 1_000).is_ok());
+    }
 }
@@ -14,13 +14,25 @@
 //! - creates and initializes a `Module` PDA
 //! - optionally creates a `ModuleVersion` PDA for the initial version
 //! - increments per-repo module counters and global module metrics
+//! - counts the module's category in `Repo::module_categories`
+//! - embargoes the module until `args.active_from` when it is set
+//! - for a derivative (`args.parent_module` set), records the parent on the
+//!   new module and increments the parent's `derivative_count`
 //! - emits `ModuleRegistered` and `ModuleVersionRegistered` events
 //!
 //! Guards:
+//! - The kill switch must not be engaged (`Config::assert_not_killed`)
 //! - Lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - Global config must be active (`Config::assert_active`)
 //! - Target repo must be active (`Repo::assert_active`)
+//! - The deployment must hold fewer modules than `Config::max_total_modules`,
+//!   when set (`GlobalLimitReached`, reported via `MetricsLimitReached`)
+//! - With `Config::enforce_content_addressed_modules` set, `module_key` must
+//!   be derived from `metadata_checksum` and the repo
+//!   (`ModuleKeyNotContentAddressed`)
 //! - Only the repo authority can register modules for that repo
+//! - A derivative's `parent_module` account must be passed and match
+//!   `args.parent_module` (`InvalidModuleParent`)
 //!
 //! PDA layout:
 //! - Module:
@@ -37,7 +49,10 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::{ModuleRegistered, ModuleVersionRegistered};
-use crate::state::{Config, Lifecycle, Metrics, Module, ModuleVersion, Repo};
+use crate::state::{
+    Config, DependencyPin, EntityKind, Lifecycle, Metrics, Module, ModuleVersion, Repo,
+};
+use crate::utils::{assert_global_limit, EventSink};
 
 /// Arguments for the `register_module` instruction.
 ///
@@ -47,6 +62,9 @@ use crate::state::{Config, Lifecycle, Metrics, Module, ModuleVersion, Repo};
 pub struct RegisterModuleArgs {
     /// Arbitrary key used together with `MODULE_SEED` and the repo key
     /// to derive the `Module` PDA.
+    ///
+    /// When `Config::enforce_content_addressed_modules` is set this must be
+    /// `Module::content_addressed_key(metadata_checksum, repo)`.
     pub module_key: Pubkey,
 
     /// Human-readable module name.
@@ -61,6 +79,8 @@ pub struct RegisterModuleArgs {
 
     /// Category classification for this module.
     ///
+    /// Empty uses `Config::default_category`.
+    ///
     /// Example:
     /// - "program"
     /// - "library"
@@ -73,6 +93,11 @@ pub struct RegisterModuleArgs {
     /// Example: "solana,anchor,token,module"
     pub tags: String,
 
+    /// SPDX license identifier of the module source; empty if unknown.
+    ///
+    /// Example: "Apache-2.0"
+    pub license_spdx: String,
+
     /// Initial semantic version for this module.
     ///
     /// (major, minor, patch)
@@ -92,13 +117,50 @@ pub struct RegisterModuleArgs {
     /// Whether this initial version is considered stable.
     pub is_stable: bool,
 
+    /// SHA-256 digest of the initial build artifact, or all zeros if none.
+    pub artifact_checksum: [u8; 32],
+
+    /// Size of the initial build artifact in bytes.
+    pub artifact_size: u64,
+
+    /// Reference to the initial release's attestation, or empty if none.
+    pub attestation_uri: String,
+
+    /// Key that signed the initial release, if it is attested.
+    ///
+    /// Requires a non-empty `attestation_uri`.
+    pub attested_by: Option<Pubkey>,
+
+    /// Dependency modules and the exact versions the initial release was
+    /// built against.
+    pub dependency_lock: Vec<DependencyPin>,
+
     /// Whether to create a `ModuleVersion` snapshot for the initial version.
     ///
     /// If false, only the `Module` account is created.
     pub create_initial_version_snapshot: bool,
+
+    /// Discovery visibility as a `Visibility` discriminant
+    /// (0 = public, 1 = unlisted, 2 = private).
+    ///
+    /// `None` uses `Config::default_visibility`.
+    pub visibility: Option<u8>,
+
+    /// Module PDA this module is derived from, or `Pubkey::default()` for a
+    /// root module. When set, the `parent_module` account must be passed.
+    pub parent_module: Pubkey,
+
+    /// Launch time (Unix seconds) before which the module cannot be used or
+    /// linked; 0 makes it usable immediately.
+    pub active_from: i64,
+
+    /// SHA-256 digest of the metadata document at `metadata_uri`, stored as
+    /// `Module::metadata_checksum`. Required for content-addressed modules.
+    pub metadata_checksum: Option<[u8; 32]>,
 }
 
 /// Accounts required for the `register_module` instruction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterModule<'info> {
     /// Payer for the newly created accounts.
@@ -173,6 +235,19 @@ pub struct RegisterModule<'info> {
     )]
     pub module: Account<'info, Module>,
 
+    /// Module the new one derives from; required when
+    /// `args.parent_module` is set, and must be that module.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            parent_module.repo.as_ref(),
+            parent_module.module_key.as_ref(),
+        ],
+        bump = parent_module.bump,
+    )]
+    pub parent_module: Option<Account<'info, Module>>,
+
     /// Optional module version snapshot for the initial version.
     ///
     /// When `args.create_initial_version_snapshot` is true, this account
@@ -235,10 +310,13 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         mut metrics,
         mut repo,
         mut module,
+        parent_module,
         mut module_version,
         system_program: _,
         rent: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -247,9 +325,13 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
     repo.assert_active()?;
+    assert_global_limit(config, metrics, EntityKind::Module, clock_ref.unix_timestamp)?;
+    let metadata_checksum = args.metadata_checksum.unwrap_or_default();
+    config.assert_module_key_allowed(&args.module_key, &metadata_checksum, &repo.key())?;
 
     // `has_one = authority` already enforces authority, but we check again
     // defensively for clarity.
@@ -275,11 +357,12 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         return err!(Unit09Error::StringTooLong);
     }
 
-    // Category
-    if args.category.is_empty() {
+    // Category (falls back to the configured default)
+    let category = config.module_category_or_default(args.category);
+    if category.is_empty() {
         return err!(Unit09Error::StringEmpty);
     }
-    if args.category.len() > Module::MAX_CATEGORY_LEN {
+    if category.len() > Module::MAX_CATEGORY_LEN {
         return err!(Unit09Error::StringTooLong);
     }
 
@@ -288,6 +371,11 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         return err!(Unit09Error::StringTooLong);
     }
 
+    // License
+    if args.license_spdx.len() > Module::MAX_LICENSE_SPDX_LEN {
+        return err!(Unit09Error::StringTooLong);
+    }
+
     // Version label (for ModuleVersion)
     if args.version_label.len() > ModuleVersion::MAX_LABEL_LEN {
         return err!(Unit09Error::StringTooLong);
@@ -298,6 +386,9 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         return err!(Unit09Error::StringTooLong);
     }
 
+    // Visibility (falls back to the configured default)
+    let visibility = config.module_visibility_or_default(args.visibility)?;
+
     // Version sanity
     let version = args.version;
     {
@@ -311,10 +402,7 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     // Derive PDA bumps from Anchor context
     // -----------------------------------------------------------------------
 
-    let module_bump = *ctx.bumps.get("module").ok_or(Unit09Error::InternalError)?;
-
-    // For `init_if_needed` we only use the bump if we actually create/init.
-    let module_version_bump = ctx.bumps.get("module_version").copied();
+    let module_bump = ctx.bumps.module;
 
     // -----------------------------------------------------------------------
     // Initialize Module account
@@ -326,19 +414,37 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         authority.key(),
         args.name,
         args.metadata_uri,
-        args.category,
+        category,
         args.tags,
+        args.license_spdx,
         version,
+        visibility,
         module_bump,
         clock_ref,
     )?;
+    module.set_active_from(args.active_from)?;
+    module.metadata_checksum = metadata_checksum;
+
+    // -----------------------------------------------------------------------
+    // Record derivation from a parent module (optional)
+    // -----------------------------------------------------------------------
+
+    if args.parent_module != Pubkey::default() {
+        let parent = parent_module
+            .as_mut()
+            .filter(|parent| parent.key() == args.parent_module)
+            .ok_or(Unit09Error::InvalidModuleParent)?;
+        let module_key = module.key();
+        module.derive_from(&module_key, args.parent_module, parent)?;
+    }
 
     // -----------------------------------------------------------------------
     // Optionally initialize ModuleVersion snapshot
     // -----------------------------------------------------------------------
 
     if args.create_initial_version_snapshot {
-        let bump = module_version_bump.ok_or(Unit09Error::InternalError)?;
+        // For `init_if_needed` we only use the bump if we actually create/init.
+        let bump = ctx.bumps.module_version;
 
         module_version.init(
             module.key(),
@@ -348,17 +454,17 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
             args.changelog_uri,
             args.version_label,
             args.is_stable,
+            args.artifact_checksum,
+            args.artifact_size,
+            args.attestation_uri,
+            args.attested_by,
+            args.dependency_lock,
             bump,
             clock_ref,
         )?;
+        module.record_version_created(module_version.is_stable, clock_ref);
 
-        emit!(ModuleVersionRegistered {
-            module: module.key(),
-            major_version: version.0,
-            minor_version: version.1,
-            patch_version: version.2,
-            is_stable: module_version.is_stable,
-        });
+        emit!(module_version_registered_event(module_version));
     }
 
     // -----------------------------------------------------------------------
@@ -366,24 +472,132 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     // -----------------------------------------------------------------------
 
     repo.increment_module_count()?;
-    repo.updated_at = clock_ref.unix_timestamp;
+    repo.add_module_category(&module.category)?;
+    repo.touch(clock_ref);
 
-    metrics.increment_modules()?;
+    metrics.record_module_registration(clock_ref)?;
+    metrics.record_module_version_count(0, module.version_count)?;
     metrics.updated_at = clock_ref.unix_timestamp;
 
     // -----------------------------------------------------------------------
     // Emit ModuleRegistered event
     // -----------------------------------------------------------------------
 
-    emit!(ModuleRegistered {
-        module: module.key(),
-        repo: repo.key(),
-        owner: module.authority,
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(module_registered_event(module.key(), module))?;
+
+    Ok(())
+}
+
+/// Build the `ModuleRegistered` event for the freshly registered `module`
+/// at `module_key`.
+pub fn module_registered_event(module_key: Pubkey, module: &Module) -> ModuleRegistered {
+    ModuleRegistered {
+        module: module_key,
+        repo: module.repo,
+        authority: module.authority,
+        name: module.name.clone(),
         category: module.category.clone(),
         major_version: module.major_version,
         minor_version: module.minor_version,
         patch_version: module.patch_version,
-    });
+        visibility: module.visibility,
+        parent_module: module.parent_module,
+        is_derivative: module.is_derivative,
+    }
+}
 
-    Ok(())
+/// Build the `ModuleVersionRegistered` event for a freshly stored
+/// `module_version`. Shared with `update_module`.
+pub fn module_version_registered_event(module_version: &ModuleVersion) -> ModuleVersionRegistered {
+    ModuleVersionRegistered {
+        module: module_version.module,
+        major_version: module_version.major_version,
+        minor_version: module_version.minor_version,
+        patch_version: module_version.patch_version,
+        is_stable: module_version.is_stable,
+        attested_by: module_version.attested_by,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Visibility;
+    use crate::utils::test_utils::{clock_at, new_module, zeroed};
+
+    #[test]
+    fn registered_event_carries_the_module_as_stored() {
+        let mut module = new_module(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "unit09-router",
+            &clock_at(100),
+        );
+        module.visibility = Visibility::Unlisted.as_u8();
+        (module.major_version, module.minor_version, module.patch_version) = (1, 2, 3);
+        let module_key = Pubkey::new_unique();
+
+        // Spelled out field by field so any layout change fails to compile
+        // here as well as in the wire-pinning tests in `events.rs`.
+        let ModuleRegistered {
+            module: event_module,
+            repo,
+            authority,
+            name,
+            category,
+            major_version,
+            minor_version,
+            patch_version,
+            visibility,
+            parent_module,
+            is_derivative,
+        } = module_registered_event(module_key, &module);
+
+        assert_eq!((event_module, repo, authority), (module_key, module.repo, module.authority));
+        assert_eq!((name.as_str(), category.as_str()), ("unit09-router", "program"));
+        assert_eq!((major_version, minor_version, patch_version), (1, 2, 3));
+        assert_eq!(visibility, Visibility::Unlisted.as_u8());
+        assert_eq!((parent_module, is_derivative), (Pubkey::default(), false));
+    }
+
+    #[test]
+    fn version_registered_event_carries_the_snapshot_as_stored() {
+        let module_key = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let mut module_version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        module_version
+            .init(
+                module_key,
+                Pubkey::new_unique(),
+                (2, 0, 1),
+                "https://unit09.org/metadata/router/2.0.1.json".to_string(),
+                String::new(),
+                String::new(),
+                true,
+                [0u8; 32],
+                0,
+                "https://unit09.org/attestations/router/2.0.1.json".to_string(),
+                Some(signer),
+                Vec::new(),
+                255,
+                &Clock::default(),
+            )
+            .unwrap();
+
+        // Spelled out field by field, like `ModuleRegistered` above, so the
+        // event cannot drift from what the handlers emit.
+        let ModuleVersionRegistered {
+            module,
+            major_version,
+            minor_version,
+            patch_version,
+            is_stable,
+            attested_by,
+        } = module_version_registered_event(&module_version);
+
+        assert_eq!(module, module_key);
+        assert_eq!((major_version, minor_version, patch_version), (2, 0, 1));
+        assert_eq!((is_stable, attested_by), (true, signer));
+    }
 }
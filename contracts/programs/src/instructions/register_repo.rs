@@ -11,13 +11,26 @@
 //!
 //! On success this instruction:
 //! - creates and initializes a `Repo` PDA
+//! - records the manifest pointer and checksum when a manifest is given
+//! - claims the URL in a `RepoUrlIndex` PDA when one is passed
 //! - increments the global `Metrics::total_repos` counter
 //! - emits a `RepoRegistered` event
 //!
 //! Design notes:
-//! - Any signer can become a repository authority (no admin gate by default)
+//! - Any signer can become a repository authority unless
+//!   `Config::restrict_repo_creation` is set, in which case the authority
+//!   must hold the "creator" role in its `Authority` registry entry
 //! - The deployment must be active (`Config`) and writable (`Lifecycle`)
+//! - The kill switch must not be engaged (`Config::assert_not_killed`)
+//! - The deployment must hold fewer repositories than
+//!   `Config::max_total_repos`, when set (`GlobalLimitReached`, reported
+//!   via `MetricsLimitReached`)
 //! - Basic string and bounds validation is handled by `Repo::init`
+//! - When `Config::enforce_unique_url` is set the `url_index` account is
+//!   required, and a URL already claimed by another repo fails with
+//!   `RepoUrlTaken`
+//! - A manifest URI and checksum must be passed together; the manifest
+//!   itself is never read on-chain (see `Repo::verify_manifest`)
 //!
 //! ===========================================================================
 
@@ -25,8 +38,11 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::RepoRegistered;
-use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::events::{OperationRejected, RejectedOperation, RepoRegistered};
+use crate::state::{
+    Authority, Config, EntityKind, Lifecycle, Metrics, Repo, RepoUrlIndex, Visibility,
+};
+use crate::utils::{assert_global_limit, EventSink};
 
 /// Arguments for the `register_repo` instruction.
 ///
@@ -57,9 +73,24 @@ pub struct RegisterRepoArgs {
 
     /// Whether automated observation is allowed for this repository.
     pub allow_observation: bool,
+
+    /// Discovery visibility as a `Visibility` discriminant
+    /// (0 = public, 1 = unlisted, 2 = private).
+    pub visibility: u8,
+
+    /// Optional URI of an off-chain manifest describing the repository.
+    ///
+    /// Descriptive fields such as tags and languages can then live in the
+    /// manifest; `tags` may be left empty. Must be paired with
+    /// `manifest_checksum`.
+    pub manifest_uri: Option<String>,
+
+    /// SHA-256 checksum of the manifest at `manifest_uri`.
+    pub manifest_checksum: Option<[u8; 32]>,
 }
 
 /// Accounts required for the `register_repo` instruction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterRepo<'info> {
     /// Payer for the newly created `Repo` account.
@@ -100,6 +131,15 @@ pub struct RegisterRepo<'info> {
     )]
     pub metrics: Account<'info, Metrics>,
 
+    /// Role registry entry of `authority`.
+    ///
+    /// Only required when `config.restrict_repo_creation` is enabled.
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes(), authority.key().as_ref()],
+        bump = creator_role.bump,
+    )]
+    pub creator_role: Option<Account<'info, Authority>>,
+
     /// The repository account to be created.
     ///
     /// PDA:
@@ -117,6 +157,26 @@ pub struct RegisterRepo<'info> {
     )]
     pub repo: Account<'info, Repo>,
 
+    /// Index claiming `args.url` for this repository.
+    ///
+    /// Required when `config.enforce_unique_url` is set; may be passed
+    /// otherwise to claim the URL anyway.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_URL_INDEX_SEED.as_bytes(), RepoUrlIndex::url_hash(&args.url)]
+    ///   bump  = url_index.bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RepoUrlIndex::LEN,
+        seeds = [
+            REPO_URL_INDEX_SEED.as_bytes(),
+            RepoUrlIndex::url_hash(&args.url).as_ref(),
+        ],
+        bump,
+    )]
+    pub url_index: Option<Account<'info, RepoUrlIndex>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -135,10 +195,13 @@ pub struct RegisterRepo<'info> {
 ///
 /// Steps:
 /// 1. Ensure lifecycle allows write operations.
-/// 2. Ensure global config is active (if enforced).
-/// 3. Initialize the `Repo` account with validated metadata.
-/// 4. Increment global repository counter in `Metrics`.
-/// 5. Emit `RepoRegistered` event.
+/// 2. Ensure global config is active and, if restricted, that the
+///    authority is an approved creator.
+/// 3. Initialize the `Repo` account with validated metadata and attach the
+///    manifest pointer, if given.
+/// 4. Claim the URL in the `RepoUrlIndex`, if passed or required.
+/// 5. Increment global repository counter in `Metrics`.
+/// 6. Emit `RepoRegistered` event.
 pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()> {
     let RegisterRepo {
         payer: _,
@@ -146,10 +209,14 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
         mut config,
         mut lifecycle,
         mut metrics,
+        creator_role,
         mut repo,
+        url_index,
         system_program: _,
         rent: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -159,10 +226,28 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
     // -----------------------------------------------------------------------
 
     // Ensure writes are allowed for this deployment.
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
 
     // Ensure the configuration is currently active.
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    assert_global_limit(config, metrics, EntityKind::Repo, clock_ref.unix_timestamp)?;
+
+    // Enforce the creator allowlist when the deployment is restricted.
+    // Refusals are reported via `OperationRejected` before failing.
+    if let Err(error) = config.assert_can_create_repo(
+        &authority.key(),
+        creator_role.as_deref(),
+        clock_ref.unix_timestamp,
+    ) {
+        emit!(OperationRejected::new(
+            RejectedOperation::Registration,
+            authority.key(),
+            &error,
+            clock_ref.unix_timestamp,
+        ));
+        return Err(error);
+    }
 
     // -----------------------------------------------------------------------
     // Basic early argument validation (string length sanity checks)
@@ -193,7 +278,7 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
     // Derive bump from Anchor context
     // -----------------------------------------------------------------------
 
-    let repo_bump = *ctx.bumps.get("repo").ok_or(Unit09Error::InternalError)?;
+    let repo_bump = ctx.bumps.repo;
 
     // -----------------------------------------------------------------------
     // Initialize Repo account
@@ -206,10 +291,30 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
         args.url,
         args.tags,
         args.allow_observation,
+        Visibility::try_from_u8(args.visibility)?,
         repo_bump,
         clock_ref,
     )?;
 
+    match (args.manifest_uri, args.manifest_checksum) {
+        (Some(uri), Some(checksum)) => repo.attach_manifest(uri, checksum)?,
+        (None, None) => {}
+        _ => return err!(Unit09Error::MetadataMissing),
+    }
+
+    // -----------------------------------------------------------------------
+    // Claim the repository URL
+    // -----------------------------------------------------------------------
+
+    match url_index.as_mut() {
+        Some(url_index) => {
+            let index_bump = ctx.bumps.url_index.ok_or(Unit09Error::InternalError)?;
+            url_index.claim(&repo.url, repo.key(), index_bump, clock_ref)?;
+        }
+        None if config.enforce_unique_url => return err!(Unit09Error::MissingRequiredAccount),
+        None => {}
+    }
+
     // -----------------------------------------------------------------------
     // Update global metrics
     // -----------------------------------------------------------------------
@@ -221,11 +326,13 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
     // Emit RepoRegistered event
     // -----------------------------------------------------------------------
 
-    emit!(RepoRegistered {
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(RepoRegistered {
         repo: repo.key(),
         owner: repo.authority,
         url: repo.url.clone(),
-    });
+        visibility: repo.visibility,
+    })?;
 
     Ok(())
 }
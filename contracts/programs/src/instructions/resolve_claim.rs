@@ -0,0 +1,128 @@
+//! ===========================================================================
+//! Unit09 – Resolve Claim Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/resolve_claim.rs
+//!
+//! Settles a pending `ModuleClaim` filed with `claim_module_name`. The
+//! config admin checks the claimant's domain proof off-chain, then approves
+//! the claim, handing the module to the claimant, or rejects it.
+//!
+//! On success this instruction:
+//! - marks the claim approved or rejected
+//! - on approval, sets `Module::authority` to the claimant
+//! - emits `ModuleClaimResolved`
+//!
+//! Guards:
+//! - the current `Config::admin` must sign, joined by enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`);
+//!   co-signers are passed as `(signer, authority)` pairs in
+//!   `remaining_accounts` (see `utils::admin_co_signers`)
+//! - the claim must belong to the module and be pending
+//!   (`ModuleClaimNotPending`)
+//! - approval needs the module to still have the authority it had when the
+//!   claim was filed (`ModuleClaimStale`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ModuleClaimResolved;
+use crate::state::{Config, Module, ModuleClaim};
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Arguments for the `resolve_claim` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ResolveClaimArgs {
+    /// `true` hands the module to the claimant; `false` rejects the claim.
+    pub approve: bool,
+}
+
+/// Accounts required for the `resolve_claim` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct ResolveClaim<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Module the claim is about.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Claim PDA for this module and the claimant it names.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_CLAIM_SEED.as_bytes(),
+            module.key().as_ref(),
+            claim.claimant.as_ref(),
+        ],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, ModuleClaim>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `resolve_claim` instruction.
+pub fn handle(ctx: Context<ResolveClaim>, args: ResolveClaimArgs) -> Result<()> {
+    let ResolveClaim {
+        admin,
+        config,
+        module,
+        claim,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    let co_signers =
+        admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+    config.assert_admin_quorum(admin.key(), &co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    claim.resolve(module, &admin.key(), &config.admin, args.approve, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ModuleClaimResolved {
+        module: claim.module,
+        claimant: claim.claimant,
+        approved: args.approve,
+        previous_authority: claim.previous_authority,
+        resolved_by: claim.resolved_by,
+        resolved_at: claim.resolved_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! ===========================================================================
+//! Unit09 – Revoke Role Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/revoke_role.rs
+//!
+//! This instruction removes a role from an existing `Authority` registry
+//! entry and emits `AuthorityRoleRevoked`.
+//!
+//! Guards:
+//! - lifecycle must allow writes
+//! - only the current `Config::admin` may revoke roles
+//! - the admin role (`ROLE_LABEL_ADMIN`) additionally needs enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`),
+//!   passed as `(signer, authority)` pairs in `remaining_accounts`
+//! - the role must currently be held (expired entries can still be revoked)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::AuthorityRoleRevoked;
+use crate::state::{Authority, Config, Lifecycle};
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Arguments for the `revoke_role` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RevokeRoleArgs {
+    /// Role label to remove.
+    pub role: String,
+}
+
+/// Accounts required for the `revoke_role` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts` when the role is
+/// `ROLE_LABEL_ADMIN`.
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Authority registry entry losing the role.
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes(), authority_entry.authority.as_ref()],
+        bump = authority_entry.bump,
+    )]
+    pub authority_entry: Account<'info, Authority>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `revoke_role` instruction.
+pub fn handle(ctx: Context<RevokeRole>, args: RevokeRoleArgs) -> Result<()> {
+    let RevokeRole {
+        admin,
+        config,
+        lifecycle,
+        authority_entry,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    if args.role == ROLE_LABEL_ADMIN {
+        assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+        let co_signers =
+            admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+        config.assert_admin_quorum(admin.key(), &co_signers)?;
+    } else {
+        config.assert_admin(admin)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    authority_entry.remove_role(&args.role, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(AuthorityRoleRevoked {
+        authority: authority_entry.authority,
+        role: args.role,
+        revoked_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
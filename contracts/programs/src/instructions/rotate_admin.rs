@@ -0,0 +1,143 @@
+//! ===========================================================================
+//! Unit09 – Rotate Admin Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/rotate_admin.rs
+//!
+//! Hands `Config::admin` to a new key and, optionally, changes how many
+//! admins must sign high-risk admin instructions (`Config::admin_threshold`).
+//!
+//! The new admin does not need to sign; operators should double-check the
+//! key, as a mistyped one cannot be recovered from.
+//!
+//! On success this instruction:
+//! - sets `Config::admin` to `args.new_admin`
+//! - sets `Config::admin_threshold` when `args.admin_threshold` is given
+//! - revokes the outgoing admin's `ROLE_LABEL_ADMIN` role on its
+//!   `Authority` entry, so the old key can no longer co-sign
+//! - emits `AdminRotated`, and `AuthorityRoleRevoked` when a role was removed
+//!
+//! Guards:
+//! - the current `Config::admin` must sign, joined by enough admin
+//!   co-signers to meet `Config::admin_threshold` (`AdminQuorumNotMet`);
+//!   co-signers are passed as `(signer, authority)` pairs in
+//!   `remaining_accounts` (see `utils::admin_co_signers`)
+//! - the new admin must not be the default key (`InvalidAdmin`)
+//! - the new threshold must be between 1 and `MAX_ADMIN_THRESHOLD`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::{AdminRotated, AuthorityRoleRevoked};
+use crate::state::{Authority, Config};
+use crate::utils::{admin_co_signers, assert_remaining_accounts_within_cap};
+
+/// Arguments for the `rotate_admin` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RotateAdminArgs {
+    /// Key that becomes `Config::admin`.
+    pub new_admin: Pubkey,
+
+    /// New `Config::admin_threshold`, or `None` to keep the current one.
+    pub admin_threshold: Option<u8>,
+}
+
+/// Accounts required for the `rotate_admin` instruction.
+///
+/// Admin co-signers follow as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RotateAdmin<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Authority registry entry of the outgoing admin, whose admin role is
+    /// revoked. A key that was never granted a role has no entry yet.
+    ///
+    /// CHECK: seeds-constrained to the signing admin's `Authority` PDA; only
+    /// read and written as an `Authority` while this program owns it.
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes(), admin.key().as_ref()],
+        bump,
+    )]
+    pub admin_authority: UncheckedAccount<'info>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `rotate_admin` instruction.
+pub fn handle(ctx: Context<RotateAdmin>, args: RotateAdminArgs) -> Result<()> {
+    let RotateAdmin {
+        admin,
+        config,
+        admin_authority,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    let co_signers =
+        admin_co_signers(ctx.program_id, ctx.remaining_accounts, clock_ref.unix_timestamp)?;
+    config.assert_admin_quorum(admin.key(), &co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    if let Some(admin_threshold) = args.admin_threshold {
+        config.set_admin_threshold(admin_threshold, clock_ref)?;
+    }
+    let old_admin = config.rotate_admin(args.new_admin, clock_ref)?;
+
+    // Rotating to the same key keeps its role; an entry that was never
+    // created is still owned by the system program.
+    let mut admin_role_revoked = false;
+    if old_admin != config.admin && admin_authority.owner == ctx.program_id {
+        let mut data = admin_authority.try_borrow_mut_data()?;
+        let mut authority = Authority::try_deserialize(&mut &data[..])?;
+        if authority.roles.iter().any(|entry| entry.role == ROLE_LABEL_ADMIN) {
+            authority.remove_role(ROLE_LABEL_ADMIN, clock_ref)?;
+            authority.try_serialize(&mut &mut data[..])?;
+            admin_role_revoked = true;
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(AdminRotated {
+        old_admin,
+        new_admin: config.admin,
+        admin_threshold: config.admin_threshold,
+        rotated_at: clock_ref.unix_timestamp,
+    });
+
+    if admin_role_revoked {
+        emit!(AuthorityRoleRevoked {
+            authority: old_admin,
+            role: ROLE_LABEL_ADMIN.to_string(),
+            revoked_at: clock_ref.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
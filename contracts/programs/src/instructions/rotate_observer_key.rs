@@ -0,0 +1,184 @@
+//! ===========================================================================
+//! Unit09 – Rotate Observer Key Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/rotate_observer_key.rs
+//!
+//! Moves an observer's `ObserverStats` for one repository to a new observer
+//! key. Workers rotate their signing keys; without this the tallies recorded
+//! under the old key and those recorded under the new one would show up as
+//! two separate observers.
+//!
+//! Either the old observer key or the deployment admin may rotate. The admin
+//! path covers keys that were lost or compromised. While the kill switch is
+//! engaged, only the admin may rotate.
+//!
+//! On success this instruction:
+//! - creates the `ObserverStats` PDA for `args.new_observer` if needed and
+//!   adds the old account's observation count, lines of code and unclaimed
+//!   reward to it
+//! - closes the old `ObserverStats` PDA, refunding its rent to `payer`
+//! - emits `ObserverKeyRotated`
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - the signer must be the old observer or `config.admin`
+//!   (`InvalidAuthority`)
+//! - the kill switch must not be engaged unless the signer is the admin
+//! - global config must be active unless the signer is the admin
+//!   (`Config::assert_active`)
+//! - the new key must differ from the old one (`InvalidObserverRotation`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ObserverKeyRotated;
+use crate::state::{Config, Lifecycle, ObserverStats, Repo};
+
+/// Arguments for the `rotate_observer_key` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RotateObserverKeyArgs {
+    /// Observer key that takes over the tallies.
+    pub new_observer: Pubkey,
+}
+
+/// Accounts required for the `rotate_observer_key` instruction.
+#[derive(Accounts)]
+#[instruction(args: RotateObserverKeyArgs)]
+pub struct RotateObserverKey<'info> {
+    /// Old observer key or `config.admin`.
+    pub signer: Signer<'info>,
+
+    /// Pays for the new stats account and receives the old one's rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global configuration account, used to recognize the admin.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository the stats belong to.
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Stats under the old observer key; closed by this instruction.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_STATS_SEED.as_bytes(), repo.key(), old_stats.observer]
+    ///   bump  = old_stats.bump
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            OBSERVER_STATS_SEED.as_bytes(),
+            repo.key().as_ref(),
+            old_stats.observer.as_ref(),
+        ],
+        bump = old_stats.bump,
+    )]
+    pub old_stats: Account<'info, ObserverStats>,
+
+    /// Stats under the new observer key; created when the new key has not
+    /// observed this repository yet.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_STATS_SEED.as_bytes(), repo.key(), args.new_observer]
+    ///   bump  = new_stats.bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ObserverStats::LEN,
+        seeds = [
+            OBSERVER_STATS_SEED.as_bytes(),
+            repo.key().as_ref(),
+            args.new_observer.as_ref(),
+        ],
+        bump,
+    )]
+    pub new_stats: Account<'info, ObserverStats>,
+
+    /// System program, used to create the new stats account.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `rotate_observer_key` instruction.
+pub fn handle(ctx: Context<RotateObserverKey>, args: RotateObserverKeyArgs) -> Result<()> {
+    let RotateObserverKey {
+        signer,
+        payer: _,
+        config,
+        lifecycle,
+        repo,
+        old_stats,
+        new_stats,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+
+    let signer_key = signer.key();
+    if signer_key != old_stats.observer && signer_key != config.admin {
+        return err!(Unit09Error::InvalidAuthority);
+    }
+    // Only the admin path survives the kill switch, a pause or a
+    // deactivation.
+    if signer_key != config.admin {
+        config.assert_not_killed()?;
+        config.assert_active(clock_ref.unix_timestamp)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    if !new_stats.is_initialized() {
+        new_stats.init(repo.key(), args.new_observer, ctx.bumps.new_stats, clock_ref);
+    }
+    new_stats.absorb(old_stats)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ObserverKeyRotated {
+        repo: repo.key(),
+        old_observer: old_stats.observer,
+        new_observer: args.new_observer,
+        observation_count: new_stats.observation_count,
+        total_lines_of_code: new_stats.total_lines_of_code,
+        rotated_by: signer_key,
+    });
+
+    Ok(())
+}
@@ -8,13 +8,22 @@
 //! - adjust protocol-wide fee basis points
 //! - change the maximum modules-per-repository limit
 //! - toggle the active flag
+//! - restrict repo registration to holders of the creator role
 //! - update an off-chain policy reference hash
+//! - set or clear the treasury wallet that receives fees
+//! - raise or lower the per-observation LOC and file caps
+//! - set the cooldown between module renames
+//! - set the reward accrued to observers per observation
+//! - restrict fork creation to holders of the "forker" role
+//! - require content-addressed module keys on registration
 //!
 //! Notes:
 //! - Only the current `Config::admin` is allowed to call this instruction.
 //! - All fields are optional; only provided values are updated.
-//! - Bounds and validity checks are delegated to `Config::apply_update`.
-//! - A `ConfigUpdated` event is emitted for indexers and dashboards.
+//! - Bounds and validity checks are delegated to `Config::apply_update`,
+//!   which applies either every provided field or none of them.
+//! - A `ConfigUpdated` event is emitted for indexers and dashboards,
+//!   followed by a `ConfigDiff` carrying the previous fee and module limit.
 //!
 //! ===========================================================================
 
@@ -22,8 +31,9 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::ConfigUpdated;
-use crate::state::Config;
+use crate::events::{ConfigDiff, ConfigUpdated};
+use crate::state::{Config, ConfigUpdate};
+use crate::utils::EventSink;
 
 /// Arguments for the `set_config` instruction.
 ///
@@ -45,16 +55,131 @@ pub struct SetConfigArgs {
     /// Optional new active flag.
     ///
     /// If `Some(false)`, the deployment can be marked inactive. Handlers
-    /// that call `Config::assert_active` will start failing after this.
+    /// that call `Config::assert_active` start failing once the grace period
+    /// (see `deactivation_grace_seconds`) has elapsed.
     pub is_active: Option<bool>,
 
+    /// Grace period in seconds applied when `is_active` is set to false.
+    ///
+    /// `None` uses `DEFAULT_DEACTIVATION_GRACE_SECONDS`; `Some(0)` takes
+    /// effect immediately. Ignored unless the deployment is being deactivated.
+    pub deactivation_grace_seconds: Option<u32>,
+
+    /// Optional new repo-creation restriction flag.
+    ///
+    /// If `Some(true)`, only authorities holding the "creator" role may
+    /// register new repositories.
+    pub restrict_repo_creation: Option<bool>,
+
     /// Optional new policy reference (hash or opaque bytes).
     ///
     /// If not provided, the existing policy reference is left unchanged.
     pub policy_ref: Option<[u8; 32]>,
+
+    /// Optional new treasury wallet for collected fees.
+    ///
+    /// `Some(Pubkey::default())` clears the treasury so fees fall back to
+    /// the fee vault PDA.
+    pub treasury: Option<Pubkey>,
+
+    /// Optional new cap on `lines_of_code` per observation (non-zero).
+    pub max_loc_per_observation: Option<u64>,
+
+    /// Optional new cap on `files_processed` per observation (non-zero).
+    pub max_files_per_observation: Option<u32>,
+
+    /// Optional new flat fee, in lamports, charged by `create_fork`.
+    ///
+    /// `Some(0)` disables the fork fee.
+    pub fork_fee_lamports: Option<u64>,
+
+    /// Optional new default category for modules registered without one.
+    ///
+    /// `Some(String::new())` clears the default.
+    pub default_category: Option<String>,
+
+    /// Optional new default visibility for modules registered without one,
+    /// as a `Visibility` discriminant.
+    pub default_visibility: Option<u8>,
+
+    /// Optional new flag routing core events through a self-CPI.
+    pub use_cpi_events: Option<bool>,
+
+    /// Optional new flag refusing deactivation of forks that have children.
+    pub block_parent_fork_deactivation: Option<bool>,
+
+    /// Optional new flag requiring a unique URL per repository.
+    pub enforce_unique_url: Option<bool>,
+
+    /// Optional new flag freezing repo, module and global metadata edits.
+    pub metadata_frozen: Option<bool>,
+
+    /// Optional new minimum time, in seconds, between two renames of a
+    /// module. `Some(0)` disables the cooldown.
+    pub rename_cooldown_secs: Option<u32>,
+
+    /// Optional new flag requiring verified modules to release a fork.
+    pub require_verified_modules_for_fork: Option<bool>,
+
+    /// Optional new cap on the number of repositories. `Some(0)` removes it.
+    pub max_total_repos: Option<u64>,
+
+    /// Optional new cap on the number of modules. `Some(0)` removes it.
+    pub max_total_modules: Option<u64>,
+
+    /// Optional new cap on the number of forks. `Some(0)` removes it.
+    pub max_total_forks: Option<u64>,
+
+    /// Optional new per-observation reward in lamports. `Some(0)` stops
+    /// accrual.
+    pub observation_reward_lamports: Option<u64>,
+
+    /// Optional new fork-creation restriction flag.
+    ///
+    /// If `Some(true)`, only owners holding the "forker" role may create
+    /// new forks.
+    pub restrict_fork_creation: Option<bool>,
+
+    /// Optional new content-addressed module flag.
+    ///
+    /// If `Some(true)`, `register_module` only accepts module keys derived
+    /// with `Module::content_addressed_key`.
+    pub enforce_content_addressed_modules: Option<bool>,
+}
+
+impl From<SetConfigArgs> for ConfigUpdate {
+    fn from(args: SetConfigArgs) -> Self {
+        Self {
+            fee_bps: args.fee_bps,
+            max_modules_per_repo: args.max_modules_per_repo,
+            is_active: args.is_active,
+            deactivation_grace_seconds: args.deactivation_grace_seconds,
+            restrict_repo_creation: args.restrict_repo_creation,
+            policy_ref: args.policy_ref,
+            treasury: args.treasury,
+            max_loc_per_observation: args.max_loc_per_observation,
+            max_files_per_observation: args.max_files_per_observation,
+            fork_fee_lamports: args.fork_fee_lamports,
+            default_category: args.default_category,
+            default_visibility: args.default_visibility,
+            use_cpi_events: args.use_cpi_events,
+            block_parent_fork_deactivation: args.block_parent_fork_deactivation,
+            enforce_unique_url: args.enforce_unique_url,
+            metadata_frozen: args.metadata_frozen,
+            rename_cooldown_secs: args.rename_cooldown_secs,
+            require_verified_modules_for_fork: args.require_verified_modules_for_fork,
+            max_total_repos: args.max_total_repos,
+            max_total_modules: args.max_total_modules,
+            max_total_forks: args.max_total_forks,
+            observation_reward_lamports: args.observation_reward_lamports,
+            restrict_fork_creation: args.restrict_fork_creation,
+            enforce_content_addressed_modules: args.enforce_content_addressed_modules,
+        }
+    }
 }
 
 /// Accounts required for the `set_config` instruction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetConfig<'info> {
     /// Admin signer that is authorized to modify the global configuration.
@@ -99,6 +224,8 @@ pub fn handle(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
         mut config,
         system_program: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -129,23 +256,61 @@ pub fn handle(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
     // Apply updates to Config
     // -----------------------------------------------------------------------
 
-    config.apply_update(
-        args.fee_bps,
-        args.max_modules_per_repo,
-        args.is_active,
-        args.policy_ref,
-        clock_ref,
-    )?;
+    let previous = (config.fee_bps, config.max_modules_per_repo);
+    config.apply_update(ConfigUpdate::from(args), clock_ref)?;
 
     // -----------------------------------------------------------------------
-    // Emit ConfigUpdated event
+    // Emit ConfigUpdated and ConfigDiff events
     // -----------------------------------------------------------------------
 
-    emit!(ConfigUpdated {
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(ConfigUpdated {
         admin: config.admin,
         fee_bps: config.fee_bps,
         max_modules_per_repo: config.max_modules_per_repo,
-    });
+    })?;
+    sink.emit(config_diff(previous, config))?;
 
     Ok(())
 }
+
+/// `ConfigDiff` between `previous`, the `(fee_bps, max_modules_per_repo)`
+/// read before the update, and the updated `config`.
+pub fn config_diff(previous: (u16, u32), config: &Config) -> ConfigDiff {
+    let (previous_fee_bps, previous_max_modules_per_repo) = previous;
+    ConfigDiff {
+        admin: config.admin,
+        previous_fee_bps,
+        fee_bps: config.fee_bps,
+        previous_max_modules_per_repo,
+        max_modules_per_repo: config.max_modules_per_repo,
+        updated_at: config.updated_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, new_config};
+
+    #[test]
+    fn fee_change_reports_old_and_new_values() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+
+        let previous = (config.fee_bps, config.max_modules_per_repo);
+        config
+            .apply_update(
+                ConfigUpdate {
+                    fee_bps: Some(400),
+                    ..ConfigUpdate::default()
+                },
+                &clock_at(2_000),
+            )
+            .unwrap();
+        let diff = config_diff(previous, &config);
+
+        assert_eq!((diff.previous_fee_bps, diff.fee_bps), (250, 400));
+        assert_eq!((diff.previous_max_modules_per_repo, diff.max_modules_per_repo), (128, 128));
+        assert_eq!((diff.admin, diff.updated_at), (config.admin, 2_000));
+    }
+}
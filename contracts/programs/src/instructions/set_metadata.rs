@@ -14,12 +14,15 @@
 //! On success this instruction:
 //! - creates `GlobalMetadata` PDA on first run (if it does not exist)
 //! - updates high-level descriptive fields on subsequent runs
+//! - applies individual tag removals, then additions (`remove_tags`,
+//!   `add_tags`) after any full `tags` replacement
 //! - bumps the `updated_at` timestamp
 //! - emits `GlobalMetadataUpdated` event for indexers and UIs
 //!
 //! Guards:
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - only the current `Config::admin` is allowed to modify metadata
+//! - metadata must not be frozen (`Config::assert_metadata_editable`)
 //!
 //! PDA layout:
 //! - GlobalMetadata:
@@ -33,12 +36,14 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::GlobalMetadataUpdated;
-use crate::state::{Config, GlobalMetadata, Lifecycle};
+use crate::state::{Config, GlobalMetadata, GlobalMetadataChanges, Lifecycle};
 
 /// Arguments for the `set_metadata` instruction.
 ///
 /// All fields are optional; only non-`None` values are applied. This makes it
-/// safe to perform partial updates without rewriting the entire structure.
+/// safe to perform partial updates without rewriting the entire structure,
+/// e.g. changing `tags` without re-supplying (and risking clobbering)
+/// `description`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct SetMetadataArgs {
     /// Optional high-level description for the deployment.
@@ -46,38 +51,47 @@ pub struct SetMetadataArgs {
     /// Example:
     /// "Unit09 is a story-driven on-chain AI raccoon that consumes Solana code,
     ///  generates runnable modules, and evolves through forks."
-    pub description: Option<String>;
+    pub description: Option<String>,
 
-    /// Optional comma-separated tag string.
+    /// Optional comma-separated tag string, replacing all current tags.
+    ///
+    /// Normalized like module tags: entries are trimmed, and empty entries
+    /// and duplicates are dropped.
     ///
     /// Example:
     /// "solana,ai,module,framework,story"
-    pub tags: Option<String>;
+    pub tags: Option<String>,
+
+    /// Individual tags to remove (applied before additions).
+    pub remove_tags: Vec<String>,
+
+    /// Individual tags to add, up to `GlobalMetadata::MAX_TAGS` in total.
+    pub add_tags: Vec<String>,
 
     /// Optional canonical website URL.
     ///
     /// Example:
     /// "https://unit09.org"
-    pub website_url: Option<String>;
+    pub website_url: Option<String>,
 
     /// Optional documentation URL.
     ///
     /// Example:
     /// "https://docs.unit09.org"
-    pub docs_url: Option<String>;
+    pub docs_url: Option<String>,
 
     /// Optional dashboard URL (metrics, explorers, etc.).
     ///
     /// Example:
     /// "https://unit09.org/dashboard"
-    pub dashboard_url: Option<String>;
+    pub dashboard_url: Option<String>,
 
     /// Optional icon or logo URI.
     ///
     /// Example:
     /// - "https://unit09.org/assets/icon.png"
     /// - "ipfs://Qm..."
-    pub icon_uri: Option<String>;
+    pub icon_uri: Option<String>,
 
     /// Optional extra JSON payload, stored as a string.
     ///
@@ -86,7 +100,7 @@ pub struct SetMetadataArgs {
     ///
     /// Example:
     /// "{ \"theme\": \"wasteland\", \"chapter\": 2 }"
-    pub extra_json: Option<String>;
+    pub extra_json: Option<String>,
 }
 
 /// Accounts required for the `set_metadata` instruction.
@@ -180,7 +194,8 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     config.assert_admin(admin)?;
 
     // Optional: require active deployment to change metadata.
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    config.assert_metadata_editable()?;
 
     // -----------------------------------------------------------------------
     // Early validation on provided fields
@@ -258,10 +273,7 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     // Derive bump from Anchor context
     // -----------------------------------------------------------------------
 
-    let metadata_bump = *ctx
-        .bumps
-        .get("global_metadata")
-        .ok_or(Unit09Error::InternalError)?;
+    let metadata_bump = ctx.bumps.global_metadata;
 
     // -----------------------------------------------------------------------
     // Initialize or update GlobalMetadata
@@ -271,7 +283,7 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     // treat it as first-time initialization.
     let is_new = global_metadata.created_at == 0 && global_metadata.updated_at == 0;
 
-    if is_new {
+    let mut changes = if is_new {
         global_metadata.init(
             args.description.unwrap_or_default(),
             args.tags.unwrap_or_default(),
@@ -283,6 +295,12 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
             metadata_bump,
             clock_ref,
         )?;
+
+        // First write: everything that was set counts as changed.
+        GlobalMetadataChanges {
+            description: !global_metadata.description.is_empty(),
+            tags: !global_metadata.tags_vec.is_empty(),
+        }
     } else {
         global_metadata.apply_update(
             args.description,
@@ -293,20 +311,32 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
             args.icon_uri,
             args.extra_json,
             clock_ref,
-        )?;
+        )?
+    };
+
+    if !args.remove_tags.is_empty() || !args.add_tags.is_empty() {
+        let tags_before = global_metadata.tags_vec.clone();
+        for tag in &args.remove_tags {
+            global_metadata.remove_tag(tag, clock_ref)?;
+        }
+        for tag in &args.add_tags {
+            global_metadata.add_tag(tag, clock_ref)?;
+        }
+        changes.tags |= global_metadata.tags_vec != tags_before;
     }
 
     // -----------------------------------------------------------------------
     // Emit GlobalMetadataUpdated event
     // -----------------------------------------------------------------------
 
+    // Only fields that changed carry a preview; previews are taken from the
+    // final account state rather than the raw arguments.
     emit!(GlobalMetadataUpdated {
         admin: config.admin,
-        description: global_metadata.description.clone(),
-        website_url: global_metadata.website_url.clone(),
-        docs_url: global_metadata.docs_url.clone(),
-        dashboard_url: global_metadata.dashboard_url.clone(),
-        icon_uri: global_metadata.icon_uri.clone(),
+        description_preview: changes
+            .description
+            .then(|| global_metadata.description_preview()),
+        tags_preview: changes.tags.then(|| global_metadata.tags_preview()),
         updated_at: global_metadata.updated_at,
     });
 
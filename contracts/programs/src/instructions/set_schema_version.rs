@@ -0,0 +1,469 @@
+//! ===========================================================================
+//! Unit09 – Set Schema Version Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/set_schema_version.rs
+//!
+//! Break-glass override for an account's stored `schema_version`.
+//!
+//! Migrations bump `schema_version` as they rewrite accounts. If a buggy
+//! migration leaves an account with the wrong value, off-chain tooling will
+//! misread it and later migrations may skip or re-apply work. This
+//! instruction lets the admin correct that single byte without touching any
+//! other field.
+//!
+//! It is also how accounts written at schema v1 reach the current layout.
+//! A target still as long as its type's `V1_LEN` is grown to `LEN`, the
+//! admin topping up rent, before the version is written:
+//! - `Config` and `Repo` kept their v1 fields as a prefix, so the fields
+//!   appended in v2 just get their defaults
+//! - `Module` did not, so it is rewritten from its `ModuleV1` contents
+//!
+//! No other instruction can load a v1 `Config`, so it must be migrated
+//! first after an upgrade. That is why `config` is read here without
+//! deserializing it (`Config::stored_admin`).
+//!
+//! The target is passed as the first remaining account rather than a named
+//! one: loading it as its typed `Account` needs a reference that lives for
+//! the whole instruction, which only `remaining_accounts` provides.
+//!
+//! Guards:
+//! - only the current `Config::admin` may call it
+//! - the target must be passed (`MissingRequiredAccount`)
+//! - `account_kind` names the expected account type; the target must be
+//!   owned by this program and carry that type's discriminator
+//! - the new version must lie in `MIN_SCHEMA_VERSION..=CURRENT_SCHEMA_VERSION`,
+//!   and be `CURRENT_SCHEMA_VERSION` when a v1 account is migrated
+//!
+//! On success this instruction:
+//! - grows and migrates a v1 `Config`, `Repo` or `Module` target
+//! - rewrites `schema_version` on the target account
+//! - emits a `Unit09Log` (category `schema_override`) with the old and new
+//!   values
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::Unit09Log;
+use crate::state::{
+    Authority, Config, Fork, GlobalMetadata, Lifecycle, Metrics, Module, ModuleRepoLink,
+    ModuleV1, ModuleVersion, Repo,
+};
+
+/// Log category used for the emitted `Unit09Log`.
+pub const SCHEMA_OVERRIDE_LOG_CATEGORY: &str = "schema_override";
+
+/// Account types whose `schema_version` can be overridden.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaAccountKind {
+    Config = 0,
+    Metrics = 1,
+    Lifecycle = 2,
+    GlobalMetadata = 3,
+    Authority = 4,
+    Repo = 5,
+    Module = 6,
+    ModuleVersion = 7,
+    Fork = 8,
+    ModuleRepoLink = 9,
+}
+
+impl SchemaAccountKind {
+    /// Convert from raw `u8` to `SchemaAccountKind`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(SchemaAccountKind::Config),
+            1 => Some(SchemaAccountKind::Metrics),
+            2 => Some(SchemaAccountKind::Lifecycle),
+            3 => Some(SchemaAccountKind::GlobalMetadata),
+            4 => Some(SchemaAccountKind::Authority),
+            5 => Some(SchemaAccountKind::Repo),
+            6 => Some(SchemaAccountKind::Module),
+            7 => Some(SchemaAccountKind::ModuleVersion),
+            8 => Some(SchemaAccountKind::Fork),
+            9 => Some(SchemaAccountKind::ModuleRepoLink),
+            _ => None,
+        }
+    }
+
+    /// Convert from raw `u8`, failing with `ValueOutOfRange`.
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        Self::from_u8(value).ok_or_else(|| error!(Unit09Error::ValueOutOfRange))
+    }
+
+    /// Convert `SchemaAccountKind` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Accounts that carry a `schema_version` byte.
+pub trait SchemaVersioned {
+    /// Mutable access to the stored schema version.
+    fn schema_version_mut(&mut self) -> &mut u8;
+}
+
+macro_rules! impl_schema_versioned {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl SchemaVersioned for $ty {
+                fn schema_version_mut(&mut self) -> &mut u8 {
+                    &mut self.schema_version
+                }
+            }
+        )*
+    };
+}
+
+impl_schema_versioned!(
+    Config,
+    Metrics,
+    Lifecycle,
+    GlobalMetadata,
+    Authority,
+    Repo,
+    Module,
+    ModuleVersion,
+    Fork,
+    ModuleRepoLink,
+);
+
+/// Arguments for the `set_schema_version` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetSchemaVersionArgs {
+    /// Expected type of the target account (`SchemaAccountKind` as `u8`).
+    pub account_kind: u8,
+
+    /// Version to store on the target account.
+    pub new_schema_version: u8,
+}
+
+/// Accounts required for the `set_schema_version` instruction.
+///
+/// The (writable) account to correct follows as the first of
+/// `remaining_accounts`; deserializing it as the type named by
+/// `args.account_kind` verifies its owner and discriminator.
+#[derive(Accounts)]
+pub struct SetSchemaVersion<'info> {
+    /// Admin signer; must match `config.admin`. Pays the extra rent when a
+    /// v1 account is grown.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account, in either the v1 or current layout.
+    ///
+    /// CHECK: only the address is constrained, since a v1 `Config` does not
+    /// deserialize as `Account<Config>`; `Config::stored_admin` checks the
+    /// owner and discriminator.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// System program, used to top up rent for a grown account.
+    pub system_program: Program<'info, System>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `set_schema_version` instruction.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetSchemaVersion<'info>>,
+    args: SetSchemaVersionArgs,
+) -> Result<()> {
+    let SetSchemaVersion {
+        admin,
+        config,
+        system_program,
+    } = ctx.accounts;
+    let target = ctx
+        .remaining_accounts
+        .first()
+        .ok_or(Unit09Error::MissingRequiredAccount)?;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    if admin.key() != Config::stored_admin(config)? {
+        return err!(Unit09Error::InvalidAdmin);
+    }
+    let kind = SchemaAccountKind::try_from_u8(args.account_kind)?;
+    validate_schema_version(args.new_schema_version)?;
+    let migrate = has_v1_layout(kind, target);
+    if migrate && args.new_schema_version != CURRENT_SCHEMA_VERSION {
+        return err!(Unit09Error::ValueOutOfRange);
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    if migrate {
+        migrate_from_v1_of_kind(kind, target, admin, system_program)?;
+    }
+    let previous = overwrite_schema_version_of_kind(kind, target, args.new_schema_version)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(Unit09Log {
+        category: SCHEMA_OVERRIDE_LOG_CATEGORY.to_string(),
+        message: format!(
+            "{:?} {} schema_version {} -> {}{} by {}",
+            kind,
+            target.key(),
+            previous,
+            args.new_schema_version,
+            if migrate { " (migrated from v1 layout)" } else { "" },
+            admin.key()
+        ),
+        logged_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Ensure `version` is one this program knows how to read.
+pub fn validate_schema_version(version: u8) -> Result<()> {
+    if !(MIN_SCHEMA_VERSION..=CURRENT_SCHEMA_VERSION).contains(&version) {
+        return err!(Unit09Error::ValueOutOfRange);
+    }
+    Ok(())
+}
+
+/// Whether `target` still has the schema v1 length of the type named by
+/// `kind`. Only `Config`, `Repo` and `Module` changed layout in v2.
+pub fn has_v1_layout(kind: SchemaAccountKind, target: &AccountInfo) -> bool {
+    let v1_len = match kind {
+        SchemaAccountKind::Config => Config::V1_LEN,
+        SchemaAccountKind::Repo => Repo::V1_LEN,
+        SchemaAccountKind::Module => Module::V1_LEN,
+        _ => return false,
+    };
+    target.data_len() == v1_len
+}
+
+/// Grow a schema v1 account of the type named by `kind` to its current
+/// `LEN` and migrate its contents.
+///
+/// `payer` covers any rent shortfall for the larger account. The realloc
+/// zero-fills the new bytes, so fields appended in v2 deserialize as empty
+/// before the type's `migrate_from_v1` sets the non-zero defaults.
+pub fn migrate_from_v1_of_kind<'info>(
+    kind: SchemaAccountKind,
+    target: &'info AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    match kind {
+        SchemaAccountKind::Config => {
+            grow_account(target, &Config::DISCRIMINATOR, Config::LEN, payer, system_program)?;
+            let mut config = Account::<Config>::try_from(target)?;
+            config.migrate_from_v1();
+            config.exit(&crate::ID)
+        }
+        SchemaAccountKind::Repo => {
+            grow_account(target, &Repo::DISCRIMINATOR, Repo::LEN, payer, system_program)?;
+            let mut repo = Account::<Repo>::try_from(target)?;
+            repo.migrate_from_v1();
+            repo.exit(&crate::ID)
+        }
+        SchemaAccountKind::Module => {
+            grow_account(target, &Module::DISCRIMINATOR, Module::LEN, payer, system_program)?;
+            // The v1 fields are not a prefix of the current layout: read
+            // them, then rewrite the account from zero.
+            let v1 = {
+                let mut data = target.try_borrow_mut_data()?;
+                let body = &mut data[Module::DISCRIMINATOR_LEN..];
+                let v1 = ModuleV1::deserialize(&mut &body[..])
+                    .map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))?;
+                body.fill(0);
+                v1
+            };
+            let mut module = Account::<Module>::try_from(target)?;
+            module.migrate_from_v1(v1);
+            module.exit(&crate::ID)
+        }
+        _ => err!(Unit09Error::ValueOutOfRange),
+    }
+}
+
+/// Check that `target` is a program account carrying `discriminator`, top
+/// up its rent from `payer` for `new_len` bytes and realloc it.
+pub fn grow_account<'info>(
+    target: &'info AccountInfo<'info>,
+    discriminator: &[u8],
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(target.owner == &crate::ID, Unit09Error::InvalidAccountOwner);
+    require!(
+        target.try_borrow_data()?[..8] == *discriminator,
+        Unit09Error::InvalidAccountDiscriminator
+    );
+
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    target.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Dispatch `overwrite_schema_version` on the account type named by `kind`.
+pub fn overwrite_schema_version_of_kind<'info>(
+    kind: SchemaAccountKind,
+    target: &'info AccountInfo<'info>,
+    new_schema_version: u8,
+) -> Result<u8> {
+    match kind {
+        SchemaAccountKind::Config => overwrite_schema_version::<Config>(target, new_schema_version),
+        SchemaAccountKind::Metrics => {
+            overwrite_schema_version::<Metrics>(target, new_schema_version)
+        }
+        SchemaAccountKind::Lifecycle => {
+            overwrite_schema_version::<Lifecycle>(target, new_schema_version)
+        }
+        SchemaAccountKind::GlobalMetadata => {
+            overwrite_schema_version::<GlobalMetadata>(target, new_schema_version)
+        }
+        SchemaAccountKind::Authority => {
+            overwrite_schema_version::<Authority>(target, new_schema_version)
+        }
+        SchemaAccountKind::Repo => overwrite_schema_version::<Repo>(target, new_schema_version),
+        SchemaAccountKind::Module => overwrite_schema_version::<Module>(target, new_schema_version),
+        SchemaAccountKind::ModuleVersion => {
+            overwrite_schema_version::<ModuleVersion>(target, new_schema_version)
+        }
+        SchemaAccountKind::Fork => overwrite_schema_version::<Fork>(target, new_schema_version),
+        SchemaAccountKind::ModuleRepoLink => {
+            overwrite_schema_version::<ModuleRepoLink>(target, new_schema_version)
+        }
+    }
+}
+
+/// Rewrite `schema_version` on `target`, interpreted as a `T`.
+///
+/// Deserializing as `Account<T>` checks the owner and discriminator, and
+/// writing back through `exit` leaves every other field byte-for-byte
+/// unchanged. Returns the previous version.
+pub fn overwrite_schema_version<'info, T>(
+    target: &'info AccountInfo<'info>,
+    new_schema_version: u8,
+) -> Result<u8>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone + SchemaVersioned,
+{
+    let mut account = Account::<T>::try_from(target)?;
+    let previous = std::mem::replace(account.schema_version_mut(), new_schema_version);
+    account.exit(&crate::ID)?;
+    Ok(previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::new_repo;
+
+    fn repo_bytes(schema_version: u8) -> Vec<u8> {
+        let mut repo = new_repo(Pubkey::new_unique(), &Clock::default());
+        repo.schema_version = schema_version;
+
+        let mut data = Vec::with_capacity(Repo::LEN);
+        repo.try_serialize(&mut data).unwrap();
+        data.resize(Repo::LEN, 0);
+        data
+    }
+
+    #[test]
+    fn admin_override_corrects_schema_version() {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 1_000_000u64;
+        let mut data = repo_bytes(0);
+        let before = data.clone();
+        {
+            let info =
+                AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+
+            let kind = SchemaAccountKind::try_from_u8(SchemaAccountKind::Repo.as_u8()).unwrap();
+            validate_schema_version(CURRENT_SCHEMA_VERSION).unwrap();
+            let previous =
+                overwrite_schema_version_of_kind(kind, &info, CURRENT_SCHEMA_VERSION).unwrap();
+            assert_eq!(previous, 0);
+        }
+
+        let repo = Repo::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(repo.schema_version, CURRENT_SCHEMA_VERSION);
+
+        let changed: Vec<usize> = (0..data.len()).filter(|&i| data[i] != before[i]).collect();
+        assert_eq!(changed.len(), 1);
+    }
+
+    #[test]
+    fn out_of_range_schema_version_is_rejected() {
+        assert!(validate_schema_version(MIN_SCHEMA_VERSION - 1).is_err());
+        assert!(validate_schema_version(CURRENT_SCHEMA_VERSION + 1).is_err());
+        assert!(validate_schema_version(u8::MAX).is_err());
+        assert!(SchemaAccountKind::try_from_u8(10).is_err());
+    }
+
+    #[test]
+    fn only_v1_lengths_of_changed_kinds_are_migrated() {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 1_000_000u64;
+
+        for (kind, v1_len, len) in [
+            (SchemaAccountKind::Config, Config::V1_LEN, Config::LEN),
+            (SchemaAccountKind::Repo, Repo::V1_LEN, Repo::LEN),
+            (SchemaAccountKind::Module, Module::V1_LEN, Module::LEN),
+        ] {
+            let mut data = vec![0u8; v1_len];
+            let info =
+                AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+            assert!(has_v1_layout(kind, &info));
+
+            let mut data = vec![0u8; len];
+            let info =
+                AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+            assert!(!has_v1_layout(kind, &info));
+        }
+
+        let mut data = vec![0u8; Repo::V1_LEN];
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        assert!(!has_v1_layout(SchemaAccountKind::Fork, &info));
+    }
+
+    #[test]
+    fn wrong_account_kind_is_rejected() {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 1_000_000u64;
+        let mut data = repo_bytes(0);
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+
+        assert!(overwrite_schema_version_of_kind(SchemaAccountKind::Fork, &info, 1).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! ===========================================================================
+//! Unit09 – Unpause Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/unpause.rs
+//!
+//! Lifts a pause set by `pause` before its scheduled end, or ends a pause
+//! that has no schedule.
+//!
+//! Guards:
+//! - only the current `Config::admin` may call it
+//!
+//! On success this instruction:
+//! - clears `Config::paused`, `pause_reason` and `auto_unpause_at`
+//! - emits `ConfigUnpaused`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ConfigUnpaused;
+use crate::state::Config;
+
+/// Accounts required for the `unpause` instruction.
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `unpause` instruction.
+pub fn handle(ctx: Context<Unpause>) -> Result<()> {
+    let Unpause {
+        admin,
+        config,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    config.unpause(clock_ref);
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(ConfigUnpaused {
+        admin: admin.key(),
+        unpaused_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
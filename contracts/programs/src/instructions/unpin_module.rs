@@ -0,0 +1,112 @@
+//! ===========================================================================
+//! Unit09 – Unpin Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/unpin_module.rs
+//!
+//! Removes a module from its repository's pinned entrypoints.
+//!
+//! The module is identified by key rather than by account so that pins of
+//! closed modules can still be cleaned up.
+//!
+//! On success this instruction:
+//! - removes the module PDA from `repo.pinned_modules`
+//! - emits `RepoModulePinChanged`
+//!
+//! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
+//! - lifecycle must allow writes
+//! - global config must be active (`Config::assert_active`)
+//! - only the repository authority may unpin
+//! - the module must currently be pinned
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoModulePinChanged;
+use crate::state::{Config, Lifecycle, Repo};
+
+/// Arguments for the `unpin_module` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UnpinModuleArgs {
+    /// PDA of the module to unpin.
+    pub module: Pubkey,
+}
+
+/// Accounts required for the `unpin_module` instruction.
+#[derive(Accounts)]
+pub struct UnpinModule<'info> {
+    /// Authority of the repository; must match `repo.authority`.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account, checked for the kill switch.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Repository unpinning the module.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `unpin_module` instruction.
+pub fn handle(ctx: Context<UnpinModule>, args: UnpinModuleArgs) -> Result<()> {
+    let UnpinModule {
+        authority,
+        config,
+        lifecycle,
+        repo,
+    } = ctx.accounts;
+
+    let clock = Clock::get()?;
+
+    // -----------------------------------------------------------------------
+    // Guards
+    // -----------------------------------------------------------------------
+
+    config.assert_not_killed()?;
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active(clock.unix_timestamp)?;
+    repo.assert_authority(authority)?;
+
+    // -----------------------------------------------------------------------
+    // Apply
+    // -----------------------------------------------------------------------
+
+    repo.unpin_module(&args.module, &clock)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    emit!(RepoModulePinChanged {
+        repo: repo.key(),
+        module: args.module,
+        pinned: false,
+        pinned_count: repo.pinned_modules.len() as u8,
+    });
+
+    Ok(())
+}
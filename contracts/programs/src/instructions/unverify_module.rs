@@ -0,0 +1,88 @@
+//! ===========================================================================
+//! Unit09 – Unverify Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/unverify_module.rs
+//!
+//! Revokes a module's verified badge, for example when a reviewed module
+//! turns out to be unsafe or is superseded. Only the config admin may call
+//! it, mirroring `verify_module`.
+//!
+//! Guards:
+//! - only the current `Config::admin` may call it
+//!
+//! On success this instruction:
+//! - clears `Module::is_verified` and `Module::verified_at`
+//! - emits `ModuleVerificationChanged`, unless the module was not verified
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ModuleVerificationChanged;
+use crate::state::{Config, Module};
+
+/// Accounts required for the `unverify_module` instruction.
+#[derive(Accounts)]
+pub struct UnverifyModule<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Module losing the badge.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `unverify_module` instruction.
+pub fn handle(ctx: Context<UnverifyModule>) -> Result<()> {
+    let UnverifyModule {
+        admin,
+        config,
+        module,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards and apply
+    // -----------------------------------------------------------------------
+
+    let changed = module.set_verified(&admin.key(), &config.admin, false, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    if changed {
+        emit!(ModuleVerificationChanged {
+            module: module.key(),
+            is_verified: false,
+            admin: admin.key(),
+            changed_at: clock_ref.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
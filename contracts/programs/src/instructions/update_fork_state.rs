@@ -9,17 +9,28 @@
 //! - label (human-readable identity)
 //! - metadata URI (off-chain configuration / story manifest)
 //! - tags (for discovery and analytics)
-//! - activation flag (whether this fork should be used in new flows)
+//! - readiness status (draft / testing / released / retired), from which the
+//!   activation flag is derived
 //!
 //! On success this instruction:
 //! - mutates selected fields on the `Fork` account
 //! - updates timestamps
-//! - emits a `ForkUpdated` event for indexers and dashboards
+//! - emits a `ForkStateUpdated` event for indexers and dashboards
+//! - emits a `Unit09Log` (category `fork_children_orphaned`) when an active
+//!   fork with children is deactivated
 //!
 //! Guards:
+//! - the kill switch must not be engaged (`Config::assert_not_killed`)
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
 //! - only the fork owner may update the fork (`Fork::assert_owner`)
+//! - deactivating a fork with children is refused when
+//!   `Config::block_parent_fork_deactivation` is set (`Fork::check_deactivation`)
+//! - when `Config::require_verified_modules_for_fork` is set, releasing the
+//!   fork needs its `Module` accounts in `remaining_accounts`, all owned by
+//!   this program (`InvalidAccountOwner`) and verified
+//!   (`Fork::check_activation`); at most `MAX_REMAINING_ACCOUNTS` of them
+//!   (`TooManyRemainingAccounts`)
 //!
 //! Design notes:
 //! - All fields in `UpdateForkStateArgs` are optional; only provided values
@@ -27,6 +38,8 @@
 //! - Parent, depth, and root status are *not* modified by this instruction.
 //!   Those require more explicit migration semantics and are handled by
 //!   separate flows if needed.
+//! - `Fork` accounts do not store their module sets, so the owner supplies
+//!   the modules checked by the verified-modules policy.
 //!
 //! ===========================================================================
 
@@ -34,8 +47,12 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::ForkUpdated;
-use crate::state::{Config, Fork, Lifecycle};
+use crate::events::{ForkStateUpdated, Unit09Log};
+use crate::state::{Config, Fork, ForkStatus, Lifecycle, Module};
+use crate::utils::{assert_remaining_accounts_within_cap, EventSink};
+
+/// Log category used when a fork with children is deactivated.
+pub const FORK_CHILDREN_ORPHANED_LOG_CATEGORY: &str = "fork_children_orphaned";
 
 /// Arguments for the `update_fork_state` instruction.
 ///
@@ -59,14 +76,19 @@ pub struct UpdateForkStateArgs {
     /// Example: "story,alpha,high-risk"
     pub tags: Option<String>,
 
-    /// Optional new activation flag.
+    /// Optional new readiness status, as a `ForkStatus` discriminant.
     ///
-    /// - true  => fork is active and may be selected for new flows
-    /// - false => fork is inactive and should not be used in new flows
-    pub is_active: Option<bool>,
+    /// Must be a legal transition from the current status (see
+    /// `ForkStatus::can_transition_to`). The fork becomes active exactly
+    /// when it is released.
+    pub status: Option<u8>,
 }
 
 /// Accounts required for the `update_fork_state` instruction.
+///
+/// Under `Config::require_verified_modules_for_fork`, the fork's `Module`
+/// accounts follow as `remaining_accounts`.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateForkState<'info> {
     /// Owner of the fork.
@@ -129,8 +151,9 @@ pub struct UpdateForkState<'info> {
 /// 1. Ensure lifecycle allows writes and config is active.
 /// 2. Ensure caller is the fork owner.
 /// 3. Validate any provided label / metadata / tags values.
-/// 4. Apply updates via `Fork::apply_update`.
-/// 5. Emit `ForkUpdated` event.
+/// 4. Check a status change against the fork's modules and children.
+/// 5. Apply updates via `Fork::apply_update`.
+/// 6. Emit `ForkStateUpdated`, plus a `Unit09Log` if children were orphaned.
 pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Result<()> {
     let UpdateForkState {
         owner,
@@ -139,6 +162,8 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
         mut fork,
         system_program: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -147,8 +172,9 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
 
     // Ensure the signer is the fork owner. This is already enforced by
     // `has_one = owner` but we keep the explicit check for clarity.
@@ -192,7 +218,22 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
         }
     }
 
-    let previous_is_active = fork.is_active;
+    let maybe_status = args.status.map(ForkStatus::try_from_u8).transpose()?;
+
+    assert_remaining_accounts_within_cap(ctx.remaining_accounts.len())?;
+    let fork_modules = if config.require_verified_modules_for_fork {
+        read_modules(ctx.program_id, ctx.remaining_accounts)?
+    } else {
+        Vec::new()
+    };
+
+    let orphans_children = match maybe_status {
+        Some(next) => {
+            fork.check_activation(next, config.require_verified_modules_for_fork, &fork_modules)?;
+            fork.check_deactivation(next, config.block_parent_fork_deactivation)?
+        }
+        None => false,
+    };
 
     // -----------------------------------------------------------------------
     // Apply updates to Fork
@@ -202,21 +243,45 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
         args.label,
         args.metadata_uri,
         args.tags,
-        args.is_active,
+        maybe_status,
         clock_ref,
     )?;
 
     // -----------------------------------------------------------------------
-    // Emit ForkUpdated event
+    // Emit ForkStateUpdated event
     // -----------------------------------------------------------------------
 
-    emit!(ForkUpdated {
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(ForkStateUpdated {
         fork: fork.key(),
-        owner: fork.owner,
-        previous_is_active,
-        new_is_active: fork.is_active,
-        updated_at: fork.updated_at,
-    });
+        active: fork.is_active,
+        status: fork.status,
+    })?;
+
+    if orphans_children {
+        emit!(Unit09Log {
+            category: FORK_CHILDREN_ORPHANED_LOG_CATEGORY.to_string(),
+            message: format!(
+                "fork {} deactivated with {} child fork(s)",
+                fork.key(),
+                fork.child_count
+            ),
+            logged_at: clock_ref.unix_timestamp,
+        });
+    }
 
     Ok(())
 }
+
+/// Deserialize the `Module` accounts passed in `accounts`.
+fn read_modules(program_id: &Pubkey, accounts: &[AccountInfo]) -> Result<Vec<Module>> {
+    accounts
+        .iter()
+        .map(|account| {
+            if account.owner != program_id {
+                return err!(Unit09Error::InvalidAccountOwner);
+            }
+            Module::try_deserialize(&mut &account.try_borrow_data()?[..])
+        })
+        .collect()
+}
@@ -9,24 +9,44 @@
 //!
 //! Allowed updates include:
 //! - name
-//! - metadata URI
+//! - metadata URI and its checksum
 //! - category
 //! - tags
 //! - activation / deprecation flags
+//! - the recommended replacement of a module being deprecated
 //! - semantic version changes
 //!
+//! A category change also moves the module between buckets of
+//! `Repo::module_categories`.
+//!
 //! When a semantic version bump is requested, the instruction may also create
-//! a `ModuleVersion` snapshot representing that historical state.
+//! a `ModuleVersion` snapshot representing that historical state. The new
+//! snapshot can move the module to another `Metrics::modules_by_version_count`
+//! bucket.
 //!
 //! Events emitted:
 //! - `ModuleUpdated` (always)
+//! - `ModuleActivationChanged` (only when the activation, deprecation or
+//!   replacement changes)
 //! - `ModuleVersionRegistered` (only when version snapshot is created)
 //!
 //! Guards:
+//! - The kill switch must not be engaged (`Config::assert_not_killed`)
 //! - Lifecycle must allow writes
 //! - Global config must be active
+//! - Metadata must not be frozen (`Config::metadata_frozen`)
 //! - Repo must be active
 //! - Only repo authority may update its modules
+//! - A rename must come at least `Config::rename_cooldown_secs` after the
+//!   module's previous rename (`ModuleRenameCooldown`)
+//! - A replacement may only be named while deprecating, must be passed as
+//!   `replacement_module`, and must be another module that is not
+//!   deprecated (`InvalidModuleReplacement`)
+//! - With `Config::enforce_content_addressed_modules` set, a metadata change
+//!   must leave `module_key` derived from the stored checksum and the repo
+//!   (`ModuleKeyNotContentAddressed`)
+//! - A snapshot needs `module_version`, the PDA of `new_version` under the
+//!   module (`MissingRequiredAccount`)
 //!
 //! ===========================================================================
 
@@ -34,8 +54,13 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::{ModuleUpdated, ModuleVersionRegistered};
-use crate::state::{Config, Lifecycle, Module, ModuleVersion, Repo};
+use crate::events::{ModuleActivationChanged, ModuleUpdated};
+use crate::instructions::register_module::module_version_registered_event;
+use crate::state::{
+    Config, DependencyPin, ExternalRef, Lifecycle, Metrics, Module, ModuleVersion, Repo,
+    Visibility,
+};
+use crate::utils::EventSink;
 
 /// Arguments for the `update_module` instruction.
 ///
@@ -57,6 +82,27 @@ pub struct UpdateModuleArgs {
     /// Optional activation flag.
     pub is_active: Option<bool>,
 
+    /// Optional deprecation flag. Undeprecating clears `replaced_by`.
+    pub is_deprecated: Option<bool>,
+
+    /// Module recommended in place of this one.
+    ///
+    /// Only allowed together with `is_deprecated: Some(true)`; the
+    /// `replacement_module` account must be passed and match.
+    pub replaced_by: Option<Pubkey>,
+
+    /// Optional discovery visibility as a `Visibility` discriminant.
+    pub visibility: Option<u8>,
+
+    /// Optional new SPDX license identifier.
+    pub license_spdx: Option<String>,
+
+    /// External registry references to remove (applied before additions).
+    pub remove_external_refs: Vec<ExternalRef>,
+
+    /// External registry references to add.
+    pub add_external_refs: Vec<ExternalRef>,
+
     /// Request to create a version snapshot.
     ///
     /// When true, a new `ModuleVersion` PDA must be provided and initialized.
@@ -75,10 +121,50 @@ pub struct UpdateModuleArgs {
 
     /// Whether the version is considered stable.
     pub is_stable: Option<bool>,
+
+    /// SHA-256 digest of the snapshot's build artifact.
+    pub artifact_checksum: Option<[u8; 32]>,
+
+    /// Size of the snapshot's build artifact in bytes.
+    pub artifact_size: Option<u64>,
+
+    /// Reference to the snapshot's release attestation.
+    pub attestation_uri: Option<String>,
+
+    /// Key that signed the snapshot's release, if it is attested.
+    ///
+    /// Requires `attestation_uri`.
+    pub attested_by: Option<Pubkey>,
+
+    /// Dependency modules and the exact versions the snapshot was built
+    /// against.
+    pub dependency_lock: Vec<DependencyPin>,
+
+    /// `module.updated_at` as last read by the caller.
+    ///
+    /// When set, the update fails with `ConcurrentModification` if the
+    /// module has changed since.
+    pub expected_updated_at: Option<i64>,
+
+    /// SHA-256 digest of the document at the new `metadata_uri`, or at the
+    /// current one when only the document changed.
+    ///
+    /// A new `metadata_uri` without a checksum clears the stored one.
+    pub metadata_checksum: Option<[u8; 32]>,
+}
+
+impl UpdateModuleArgs {
+    /// Version the `module_version` PDA is derived from: `new_version`, or
+    /// 0.0.0 when none is given (rejected if a snapshot is requested).
+    pub fn snapshot_version(&self) -> (u16, u16, u16) {
+        self.new_version.unwrap_or_default()
+    }
 }
 
 /// Accounts required for the `update_module` instruction.
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: UpdateModuleArgs)]
 pub struct UpdateModule<'info> {
     /// Authority of the repository; must match `repo.authority`.
     #[account(mut)]
@@ -100,6 +186,14 @@ pub struct UpdateModule<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account; tracks modules per version-count bucket.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Repository that owns this module.
     #[account(
         mut,
@@ -124,19 +218,33 @@ pub struct UpdateModule<'info> {
     )]
     pub module: Account<'info, Module>,
 
-    /// ModuleVersion PDA – required only when a version snapshot is created.
+    /// Recommended replacement; required when `args.replaced_by` is set,
+    /// and must be that module.
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            replacement_module.repo.as_ref(),
+            replacement_module.module_key.as_ref(),
+        ],
+        bump = replacement_module.bump,
+    )]
+    pub replacement_module: Option<Account<'info, Module>>,
+
+    /// ModuleVersion PDA – required only when a version snapshot is created
+    /// (`args.create_version_snapshot`), and omitted otherwise.
     ///
-    /// This account will be initialized ONLY when:
-    ///     args.create_version_snapshot == true
+    /// The version seeds come from `args.new_version`, matching
+    /// `utils::module_version_pda`.
     ///
     /// PDA Seeds:
     ///   seeds = [
     ///       MODULE_VERSION_SEED.as_bytes(),
     ///       module.key().as_ref(),
-    ///       &version.0.to_le_bytes(),
-    ///       &version.1.to_le_bytes(),
-    ///       &version.2.to_le_bytes(),
+    ///       &args.new_version.0.to_le_bytes(),
+    ///       &args.new_version.1.to_le_bytes(),
+    ///       &args.new_version.2.to_le_bytes(),
     ///   ]
+    ///   bump  = module_version.bump
     #[account(
         init_if_needed,
         payer = authority,
@@ -144,19 +252,13 @@ pub struct UpdateModule<'info> {
         seeds = [
             MODULE_VERSION_SEED.as_bytes(),
             module.key().as_ref(),
-            // We will fill version bytes inside handler after validation
-            // anchor does not allow dynamic seeds here in code comments
-            // but we will derive bump using ctx.bumps if needed
-            // see handler below
-            // placeholder bytes replaced in handler logic
-            // dummy for compile
-            &[0u8; 2],
-            &[0u8; 2],
-            &[0u8; 2],
+            &args.snapshot_version().0.to_le_bytes(),
+            &args.snapshot_version().1.to_le_bytes(),
+            &args.snapshot_version().2.to_le_bytes(),
         ],
         bump,
     )]
-    pub module_version: Account<'info, ModuleVersion>,
+    pub module_version: Option<Account<'info, ModuleVersion>>,
 
     /// System program.
     pub system_program: Program<'info, System>,
@@ -174,11 +276,15 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         authority: _,
         mut config,
         mut lifecycle,
+        metrics,
         mut repo,
         mut module,
-        mut module_version,
+        replacement_module,
+        module_version,
         system_program: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -187,10 +293,13 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
     // Guards
     // -----------------------------------------------------------------------
 
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+    config.assert_metadata_editable()?;
     repo.assert_active()?;
     repo.assert_authority(&ctx.accounts.authority)?;
+    module.assert_unchanged_since(args.expected_updated_at)?;
 
     // -----------------------------------------------------------------------
     // Early validation
@@ -229,6 +338,12 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         }
     }
 
+    if let Some(ref license_spdx) = args.license_spdx {
+        if license_spdx.len() > Module::MAX_LICENSE_SPDX_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+    }
+
     if args.create_version_snapshot {
         // Version must be provided when snapshotting.
         let version = args
@@ -260,31 +375,68 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
     // -----------------------------------------------------------------------
 
     let previous_is_active = module.is_active;
+    let previous_activation = activation_state(module);
     let previous_version = (module.major_version, module.minor_version, module.patch_version);
+    let previous_category = module.category.clone();
+    let snapshot_version = args.snapshot_version();
+    let metadata_changed = args.metadata_uri.is_some() || args.metadata_checksum.is_some();
 
     module.apply_update(
         args.name,
         args.metadata_uri,
+        args.metadata_checksum,
         args.category,
         args.tags,
         args.is_active,
+        args.is_deprecated,
         args.new_version,
+        args.visibility.map(Visibility::try_from_u8).transpose()?,
+        args.license_spdx,
+        config.rename_cooldown_secs,
         clock_ref,
     )?;
+    if metadata_changed {
+        config.assert_module_key_allowed(
+            &module.module_key,
+            &module.metadata_checksum,
+            &repo.key(),
+        )?;
+    }
+
+    if let Some(replaced_by) = args.replaced_by {
+        if args.is_deprecated != Some(true) {
+            return err!(Unit09Error::InvalidModuleReplacement);
+        }
+        let replacement = replacement_module
+            .as_ref()
+            .filter(|replacement| replacement.key() == replaced_by)
+            .ok_or(Unit09Error::InvalidModuleReplacement)?;
+        let module_key = module.key();
+        module.set_replaced_by(&module_key, replaced_by, replacement)?;
+    }
+
+    for external_ref in &args.remove_external_refs {
+        module.remove_external_ref(external_ref, clock_ref)?;
+    }
+    for external_ref in args.add_external_refs {
+        module.add_external_ref(external_ref, clock_ref)?;
+    }
+
+    if module.category != previous_category {
+        repo.remove_module_category(&previous_category);
+        repo.add_module_category(&module.category)?;
+    }
 
     // -----------------------------------------------------------------------
     // Create ModuleVersion snapshot (optional)
 // -----------------------------------------------------------------------
 
     if args.create_version_snapshot {
-        let version = args.new_version.unwrap();
-        let (major, minor, patch) = version;
-
-        // Recompute PDA bump since our seeds are dynamic
-        let bump = *ctx
-            .bumps
-            .get("module_version")
-            .ok_or(Unit09Error::InternalError)?;
+        let version = snapshot_version;
+        let module_version = module_version
+            .as_mut()
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        let bump = ctx.bumps.module_version.ok_or(Unit09Error::InternalError)?;
 
         module_version.init(
             module.key(),
@@ -294,26 +446,54 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
             args.changelog_uri.unwrap_or_else(|| "".to_string()),
             args.version_label.unwrap_or_else(|| "".to_string()),
             args.is_stable.unwrap_or(false),
+            args.artifact_checksum.unwrap_or([0u8; 32]),
+            args.artifact_size.unwrap_or(0),
+            args.attestation_uri.unwrap_or_default(),
+            args.attested_by,
+            args.dependency_lock,
             bump,
             clock_ref,
         )?;
+        let previous_version_count = module.version_count;
+        module.record_version_created(module_version.is_stable, clock_ref);
+        metrics.record_module_version_count(previous_version_count, module.version_count)?;
+        metrics.updated_at = clock_ref.unix_timestamp;
 
-        emit!(ModuleVersionRegistered {
-            module: module.key(),
-            major_version: major,
-            minor_version: minor,
-            patch_version: patch,
-            is_stable: module_version.is_stable,
-        });
+        emit!(module_version_registered_event(module_version));
     }
 
     // -----------------------------------------------------------------------
-    // Emit ModuleUpdated
+    // Emit ModuleActivationChanged (optional) and ModuleUpdated
     // -----------------------------------------------------------------------
 
-    emit!(ModuleUpdated {
-        module: module.key(),
-        repo: repo.key(),
+    if let Some(event) = activation_changed_event(module.key(), module, previous_activation) {
+        emit!(event);
+    }
+
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(module_updated_event(
+        module.key(),
+        repo.key(),
+        module,
+        previous_version,
+        previous_is_active,
+    ))?;
+
+    Ok(())
+}
+
+/// Build the `ModuleUpdated` event for `module` at `module_key`, updated
+/// through `repo_key` from `previous_version` and `previous_is_active`.
+pub fn module_updated_event(
+    module_key: Pubkey,
+    repo_key: Pubkey,
+    module: &Module,
+    previous_version: (u16, u16, u16),
+    previous_is_active: bool,
+) -> ModuleUpdated {
+    ModuleUpdated {
+        module: module_key,
+        repo: repo_key,
         previous_major_version: previous_version.0,
         previous_minor_version: previous_version.1,
         previous_patch_version: previous_version.2,
@@ -322,8 +502,243 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         new_patch_version: module.patch_version,
         previous_is_active,
         new_is_active: module.is_active,
+        visibility: module.visibility,
         updated_at: module.updated_at,
-    });
+    }
+}
 
-    Ok(())
+/// Activation, deprecation and replacement of `module`, as compared by
+/// `activation_changed_event`.
+pub fn activation_state(module: &Module) -> (bool, bool, Pubkey) {
+    (module.is_active, module.is_deprecated, module.replaced_by)
+}
+
+/// Build the `ModuleActivationChanged` event for `module` at `module_key`
+/// when its `activation_state` differs from `previous`.
+pub fn activation_changed_event(
+    module_key: Pubkey,
+    module: &Module,
+    previous: (bool, bool, Pubkey),
+) -> Option<ModuleActivationChanged> {
+    if activation_state(module) == previous {
+        return None;
+    }
+
+    Some(ModuleActivationChanged {
+        module: module_key,
+        is_active: module.is_active,
+        is_deprecated: module.is_deprecated,
+        replaced_by: module.replaced_by,
+        updated_at: module.updated_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{module_pda, module_version_pda};
+    use crate::utils::test_utils::{clock_at, new_module, zeroed};
+
+    /// Apply only a deprecation flag, as `handle` does, and return the
+    /// event it would emit.
+    fn set_deprecated(
+        module: &mut Module,
+        module_key: Pubkey,
+        is_deprecated: Option<bool>,
+        now: i64,
+    ) -> Option<ModuleActivationChanged> {
+        let previous = activation_state(module);
+        let clock = clock_at(now);
+        module
+            .apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                is_deprecated,
+                None,
+                None,
+                None,
+                0,
+                &clock,
+            )
+            .unwrap();
+        activation_changed_event(module_key, module, previous)
+    }
+
+    #[test]
+    fn deprecate_then_reactivate_reports_each_transition() {
+        let module_key = Pubkey::new_unique();
+        let mut module = new_module(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "unit09-router",
+            &clock_at(100),
+        );
+
+        let event = set_deprecated(&mut module, module_key, Some(true), 200).unwrap();
+        assert!(module.is_deprecated);
+        assert_eq!(module.updated_at, 200);
+        assert_eq!(event.module, module_key);
+        assert!(event.is_deprecated && event.is_active);
+        assert_eq!(event.updated_at, 200);
+
+        // Repeating the flag, or leaving it out, is not a transition.
+        assert!(set_deprecated(&mut module, module_key, Some(true), 300).is_none());
+        assert!(set_deprecated(&mut module, module_key, None, 400).is_none());
+        assert_eq!(module.updated_at, 400);
+
+        let event = set_deprecated(&mut module, module_key, Some(false), 500).unwrap();
+        assert!(!module.is_deprecated);
+        assert!(!event.is_deprecated);
+        assert_eq!(event.updated_at, 500);
+    }
+
+    #[test]
+    fn updated_event_carries_previous_and_new_state() {
+        let (module_key, repo_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut module = new_module(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "unit09-router",
+            &clock_at(100),
+        );
+        let previous_version = (module.major_version, module.minor_version, module.patch_version);
+        let previous_is_active = module.is_active;
+        module
+            .apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(false),
+                None,
+                Some((1, 1, 0)),
+                None,
+                None,
+                0,
+                &clock_at(200),
+            )
+            .unwrap();
+
+        // Spelled out field by field so any layout change fails to compile
+        // here as well as in the wire-pinning tests in `events.rs`.
+        let ModuleUpdated {
+            module: event_module,
+            repo,
+            previous_major_version,
+            previous_minor_version,
+            previous_patch_version,
+            new_major_version,
+            new_minor_version,
+            new_patch_version,
+            previous_is_active,
+            new_is_active,
+            visibility,
+            updated_at,
+        } = module_updated_event(
+            module_key,
+            repo_key,
+            &module,
+            previous_version,
+            previous_is_active,
+        );
+
+        assert_eq!((event_module, repo), (module_key, repo_key));
+        assert_eq!(
+            (previous_major_version, previous_minor_version, previous_patch_version),
+            (1, 0, 0)
+        );
+        assert_eq!((new_major_version, new_minor_version, new_patch_version), (1, 1, 0));
+        assert_eq!((previous_is_active, new_is_active), (true, false));
+        assert_eq!((visibility, updated_at), (Visibility::Public.as_u8(), 200));
+    }
+
+    fn snapshot_args(new_version: (u16, u16, u16)) -> UpdateModuleArgs {
+        UpdateModuleArgs {
+            name: None,
+            metadata_uri: None,
+            category: None,
+            tags: None,
+            is_active: None,
+            is_deprecated: None,
+            replaced_by: None,
+            visibility: None,
+            license_spdx: None,
+            remove_external_refs: Vec::new(),
+            add_external_refs: Vec::new(),
+            create_version_snapshot: true,
+            new_version: Some(new_version),
+            version_label: None,
+            changelog_uri: None,
+            is_stable: Some(true),
+            artifact_checksum: None,
+            artifact_size: None,
+            attestation_uri: None,
+            attested_by: None,
+            dependency_lock: Vec::new(),
+            expected_updated_at: None,
+            metadata_checksum: None,
+        }
+    }
+
+    #[test]
+    fn snapshot_account_is_the_module_version_pda() {
+        let module = new_module(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "unit09-router",
+            &clock_at(100),
+        );
+        let (module_address, _) = module_pda(&crate::ID, &module.repo, &module.module_key);
+        let args = snapshot_args((1, 3, 0x0102));
+
+        // The seeds of `UpdateModule::module_version`.
+        let version = args.snapshot_version();
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                MODULE_VERSION_SEED.as_bytes(),
+                module_address.as_ref(),
+                &version.0.to_le_bytes(),
+                &version.1.to_le_bytes(),
+                &version.2.to_le_bytes(),
+            ],
+            &crate::ID,
+        );
+
+        let mut snapshot: ModuleVersion = zeroed(ModuleVersion::LEN);
+        snapshot
+            .init(
+                module_address,
+                module.authority,
+                version,
+                module.metadata_uri.clone(),
+                String::new(),
+                String::new(),
+                true,
+                [0u8; 32],
+                0,
+                String::new(),
+                None,
+                Vec::new(),
+                bump,
+                &clock_at(200),
+            )
+            .unwrap();
+
+        // Off-chain tools re-derive the snapshot from what it stores.
+        let (major, minor, patch) = snapshot.version();
+        assert_eq!(
+            module_version_pda(&crate::ID, &snapshot.module, major, minor, patch),
+            (address, snapshot.bump)
+        );
+        assert_ne!(
+            address,
+            module_version_pda(&crate::ID, &module_address, 0, 0, 0).0,
+            "the version must be part of the seeds"
+        );
+    }
 }
@@ -10,12 +10,17 @@
 //!
 //! - change the human-readable name
 //! - update the canonical URL
+//! - set or clear the `notify_uri` relay endpoint
 //! - adjust tags used for discovery
 //! - toggle `is_active`
 //! - toggle `allow_observation`
+//! - change discovery `visibility` (public / unlisted / private)
+//! - require observations to be signed by a registered worker key
 //!
 //! On success this instruction:
 //! - mutates the `Repo` account fields via `Repo::apply_update`
+//! - moves the URL claim from the old `RepoUrlIndex` PDA to the new one
+//!   when the URL changes and the index accounts are passed
 //! - updates the `updated_at` timestamp
 //! - emits:
 //!     * `RepoUpdated` (always)
@@ -24,7 +29,12 @@
 //! Design notes:
 //! - Only the current `Repo::authority` may perform updates
 //! - Deployment must be active (`Config`) and writable (`Lifecycle`)
+//! - The kill switch must not be engaged (`Config::assert_not_killed`)
+//! - Metadata must not be frozen (`Config::metadata_frozen`)
 //! - All arguments are optional; only provided fields are updated
+//! - With `Config::enforce_unique_url` set, a URL change must pass both
+//!   index accounts, and fails with `RepoUrlTaken` if another repository
+//!   holds the new URL
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
@@ -32,7 +42,8 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::{RepoActivationChanged, RepoUpdated};
-use crate::state::{Config, Lifecycle, Repo};
+use crate::state::{Config, Lifecycle, Repo, RepoUrlIndex, Visibility};
+use crate::utils::EventSink;
 
 /// Arguments for the `update_repo` instruction.
 ///
@@ -50,6 +61,11 @@ pub struct UpdateRepoArgs {
     /// Example: "https://github.com/unit09-labs/unit09"
     pub url: Option<String>,
 
+    /// Optional new notification endpoint for off-chain relays.
+    ///
+    /// Must be an HTTPS URL; pass an empty string to clear it.
+    pub notify_uri: Option<String>,
+
     /// Optional new tags for search and discovery.
     ///
     /// Example: "solana,anchor,protocol"
@@ -66,10 +82,30 @@ pub struct UpdateRepoArgs {
     /// - true  => automated observation is allowed
     /// - false => automated observation should be disabled
     pub allow_observation: Option<bool>,
+
+    /// Optional new discovery visibility as a `Visibility` discriminant.
+    pub visibility: Option<u8>,
+
+    /// Optional toggle for per-observation `ObservationRecorded` events.
+    pub emit_observation_events: Option<bool>,
+
+    /// Optional toggle requiring both authorities to agree on module links.
+    pub require_mutual_consent: Option<bool>,
+
+    /// Optional toggle requiring worker-signed observations.
+    pub require_signed_observations: Option<bool>,
+
+    /// `repo.updated_at` as last read by the caller.
+    ///
+    /// When set, the update fails with `ConcurrentModification` if the
+    /// repository has changed since.
+    pub expected_updated_at: Option<i64>,
 }
 
 /// Accounts required for the `update_repo` instruction.
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: UpdateRepoArgs)]
 pub struct UpdateRepo<'info> {
     /// Authority that owns this repository entry.
     ///
@@ -112,6 +148,48 @@ pub struct UpdateRepo<'info> {
     )]
     pub repo: Account<'info, Repo>,
 
+    /// Index for the URL the repository has before the update, released
+    /// when it holds this repository's claim.
+    ///
+    /// Required with `new_url_index` when `config.enforce_unique_url` is set
+    /// and `args.url` changes the URL; only accepted in that case. A
+    /// repository registered without an index passes the empty PDA.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_URL_INDEX_SEED.as_bytes(), RepoUrlIndex::url_hash(&repo.url)]
+    ///
+    /// CHECK: only the address is constrained; the handler reads it as a
+    /// `RepoUrlIndex` when this program owns it (see `url_claim_held_by`).
+    #[account(
+        mut,
+        seeds = [
+            REPO_URL_INDEX_SEED.as_bytes(),
+            RepoUrlIndex::url_hash(&repo.url).as_ref(),
+        ],
+        bump,
+    )]
+    pub old_url_index: Option<UncheckedAccount<'info>>,
+
+    /// Index claiming `args.url` for this repository.
+    ///
+    /// Required with `old_url_index` when `config.enforce_unique_url` is set
+    /// and `args.url` changes the URL; only accepted in that case.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_URL_INDEX_SEED.as_bytes(), RepoUrlIndex::url_hash(&args.url)]
+    ///   bump  = new_url_index.bump
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RepoUrlIndex::LEN,
+        seeds = [
+            REPO_URL_INDEX_SEED.as_bytes(),
+            RepoUrlIndex::url_hash(args.url.as_deref().unwrap_or_default()).as_ref(),
+        ],
+        bump,
+    )]
+    pub new_url_index: Option<Account<'info, RepoUrlIndex>>,
+
     /// System program (required by Anchor for some flows).
     pub system_program: Program<'info, System>,
 
@@ -129,16 +207,21 @@ pub struct UpdateRepo<'info> {
 /// 1. Ensure lifecycle allows writes and config is active.
 /// 2. Ensure caller is the repository authority.
 /// 3. Perform early string length validation on provided values.
-/// 4. Call `Repo::apply_update` to mutate fields.
-/// 5. Emit `RepoUpdated` and optionally `RepoActivationChanged`.
+/// 4. Move the URL claim when the URL changes.
+/// 5. Call `Repo::apply_update` to mutate fields.
+/// 6. Emit `RepoUpdated` and optionally `RepoActivationChanged`.
 pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     let UpdateRepo {
         authority: _,
         mut config,
         mut lifecycle,
         mut repo,
+        old_url_index,
+        mut new_url_index,
         system_program: _,
         clock,
+        event_authority,
+        program: _,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
@@ -148,15 +231,20 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     // -----------------------------------------------------------------------
 
     // Ensure writes are allowed for this deployment.
+    config.assert_not_killed()?;
     lifecycle.assert_writes_allowed()?;
 
     // Ensure the configuration is currently active.
-    config.assert_active()?;
+    config.assert_active(clock_ref.unix_timestamp)?;
+
+    // Metadata edits are refused while `Config::metadata_frozen` is set.
+    config.assert_metadata_editable()?;
 
     // `has_one = authority` in the account constraint already enforces that
     // the signer is the repo authority, but we keep an explicit check for
     // clarity and defensiveness in case constraints are modified later.
     repo.assert_authority(&ctx.accounts.authority)?;
+    repo.assert_unchanged_since(args.expected_updated_at)?;
 
     // -----------------------------------------------------------------------
     // Early validation on provided arguments
@@ -186,6 +274,41 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
         }
     }
 
+    // -----------------------------------------------------------------------
+    // Move the URL claim
+    // -----------------------------------------------------------------------
+
+    let url_changed = args.url.as_ref().is_some_and(|url| *url != repo.url);
+    if !url_changed {
+        if old_url_index.is_some() || new_url_index.is_some() {
+            return err!(Unit09Error::UnexpectedUrlIndex);
+        }
+    } else if config.enforce_unique_url && (old_url_index.is_none() || new_url_index.is_none()) {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+
+    if let (Some(url), Some(new_url_index)) = (args.url.as_deref(), new_url_index.as_mut()) {
+        // A claim this repository left behind on an earlier URL change is
+        // already its own.
+        if new_url_index.repo != repo.key() {
+            let index_bump = ctx.bumps.new_url_index.ok_or(Unit09Error::InternalError)?;
+            new_url_index.claim(url, repo.key(), index_bump, clock_ref)?;
+        }
+    }
+
+    if let Some(old_url_index) = old_url_index {
+        if url_claim_held_by(old_url_index, repo.key(), ctx.program_id)? {
+            // The index is untyped, so it cannot use `close =`: move its
+            // lamports, hand it back to the system program and drop its data.
+            let destination = ctx.accounts.authority.to_account_info();
+            let lamports = old_url_index.get_lamports();
+            old_url_index.sub_lamports(lamports)?;
+            destination.add_lamports(lamports)?;
+            old_url_index.assign(&System::id());
+            old_url_index.realloc(0, false)?;
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Detect activation changes for event emission
     // -----------------------------------------------------------------------
@@ -199,9 +322,14 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     repo.apply_update(
         args.name,
         args.url,
+        args.notify_uri,
         args.tags,
         args.is_active,
         args.allow_observation,
+        args.visibility.map(Visibility::try_from_u8).transpose()?,
+        args.emit_observation_events,
+        args.require_mutual_consent,
+        args.require_signed_observations,
         clock_ref,
     )?;
 
@@ -209,10 +337,13 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     // Emit RepoUpdated event (always)
     // -----------------------------------------------------------------------
 
-    emit!(RepoUpdated {
+    let sink = EventSink::new(config, event_authority, ctx.bumps.event_authority);
+    sink.emit(RepoUpdated {
         repo: repo.key(),
         url: repo.url.clone(),
-    });
+        notify_uri: repo.notify_uri.clone(),
+        visibility: repo.visibility,
+    })?;
 
     // -----------------------------------------------------------------------
     // Emit RepoActivationChanged event (only when is_active changed)
@@ -228,3 +359,56 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Whether `index` holds `repo`'s claim on its URL.
+///
+/// An index that was never created, or that another repository claimed
+/// while URLs were not unique, is not this repository's to release.
+pub fn url_claim_held_by(index: &AccountInfo, repo: Pubkey, program_id: &Pubkey) -> Result<bool> {
+    if index.owner != program_id || index.data_is_empty() {
+        return Ok(false);
+    }
+    let claim = RepoUrlIndex::try_deserialize(&mut &index.try_borrow_data()?[..])?;
+    Ok(claim.repo == repo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::repo_url_index_pda;
+    use crate::utils::test_utils::zeroed;
+
+    const URL: &str = "https://github.com/unit09-labs/unit09";
+
+    fn index_bytes(repo: Pubkey) -> Vec<u8> {
+        let mut index: RepoUrlIndex = zeroed(RepoUrlIndex::LEN);
+        index.claim(URL, repo, 255, &Clock::default()).unwrap();
+        let mut data = Vec::with_capacity(RepoUrlIndex::LEN);
+        index.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn only_this_repos_claim_is_released() {
+        let key = repo_url_index_pda(&crate::ID, URL).0;
+        let (repo, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 1_000_000u64;
+
+        let mut data = index_bytes(repo);
+        let owner = crate::ID;
+        let index = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        assert!(url_claim_held_by(&index, repo, &crate::ID).unwrap());
+        assert!(!url_claim_held_by(&index, other, &crate::ID).unwrap());
+    }
+
+    #[test]
+    fn missing_index_holds_no_claim() {
+        let key = repo_url_index_pda(&crate::ID, URL).0;
+        let mut lamports = 0u64;
+        let mut data = [0u8; 0];
+        let owner = System::id();
+        let index = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+
+        assert!(!url_claim_held_by(&index, Pubkey::new_unique(), &crate::ID).unwrap());
+    }
+}
@@ -0,0 +1,77 @@
+//! ===========================================================================
+//! Unit09 – Verify Metrics Consistency Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/verify_metrics_consistency.rs
+//!
+//! Read-only diagnostic that checks the global `Metrics` account for
+//! internal contradictions, such as files processed with no recorded
+//! observations or a last-observation timestamp in the future.
+//!
+//! This is mainly useful after `record_metrics` reconciliations, where
+//! counters are overwritten by hand and can drift out of step.
+//!
+//! On success this instruction:
+//! - evaluates `Metrics::consistency_violations` against the current clock
+//! - emits a single `Unit09Log` (category `metrics_consistency`) listing
+//!   every violation, or `ok` when none were found
+//!
+//! No account is mutated, and anyone may call it.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::Unit09Log;
+use crate::state::Metrics;
+
+/// Log category used for the emitted `Unit09Log`.
+pub const METRICS_CONSISTENCY_LOG_CATEGORY: &str = "metrics_consistency";
+
+/// Accounts required for the `verify_metrics_consistency` instruction.
+#[derive(Accounts)]
+pub struct VerifyMetricsConsistency<'info> {
+    /// Global metrics account (read-only).
+    #[account(
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used as the reference for "future" timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `verify_metrics_consistency` instruction.
+pub fn handle(ctx: Context<VerifyMetricsConsistency>) -> Result<()> {
+    let VerifyMetricsConsistency { metrics, clock } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Evaluate invariants
+    // -----------------------------------------------------------------------
+
+    let violations = metrics.consistency_violations(clock_ref.unix_timestamp);
+
+    let message = if violations.is_empty() {
+        "ok".to_string()
+    } else {
+        violations.join(",")
+    };
+
+    // -----------------------------------------------------------------------
+    // Emit Unit09Log
+    // -----------------------------------------------------------------------
+
+    emit!(Unit09Log {
+        category: METRICS_CONSISTENCY_LOG_CATEGORY.to_string(),
+        message,
+        logged_at: clock_ref.unix_timestamp,
+    });
+
+    Ok(())
+}
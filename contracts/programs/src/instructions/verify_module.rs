@@ -0,0 +1,89 @@
+//! ===========================================================================
+//! Unit09 – Verify Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/verify_module.rs
+//!
+//! Grants a module the verified badge, the Unit09 team's signal that it was
+//! reviewed. Unlike the fields edited through `update_module`, the badge is
+//! controlled by the config admin alone; `unverify_module` revokes it.
+//!
+//! Guards:
+//! - only the current `Config::admin` may call it
+//!
+//! On success this instruction:
+//! - sets `Module::is_verified` and stamps `Module::verified_at`
+//! - emits `ModuleVerificationChanged`, unless the module was already
+//!   verified
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ModuleVerificationChanged;
+use crate::state::{Config, Module};
+
+/// Accounts required for the `verify_module` instruction.
+#[derive(Accounts)]
+pub struct VerifyModule<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Module receiving the badge.
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `verify_module` instruction.
+pub fn handle(ctx: Context<VerifyModule>) -> Result<()> {
+    let VerifyModule {
+        admin,
+        config,
+        module,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    // -----------------------------------------------------------------------
+    // Guards and apply
+    // -----------------------------------------------------------------------
+
+    let changed = module.set_verified(&admin.key(), &config.admin, true, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit
+    // -----------------------------------------------------------------------
+
+    if changed {
+        emit!(ModuleVerificationChanged {
+            module: module.key(),
+            is_verified: true,
+            admin: admin.key(),
+            changed_at: clock_ref.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -71,6 +71,14 @@ pub mod unit09_program {
         instructions::initialize::handler(ctx, args)
     }
 
+    /// Complete a partially initialized deployment.
+    ///
+    /// Creates only the missing ones among `Config`, `Metrics`, `Lifecycle` and
+    /// `GlobalMetadata`. Once `Config` exists, only its admin may run this.
+    pub fn initialize_missing(ctx: Context<InitializeMissing>, args: InitializeArgs) -> Result<()> {
+        instructions::initialize_missing::handle(ctx, args)
+    }
+
     /// Update configuration values such as fee basis points or module limits.
     ///
     /// Only the admin defined in the `Config` account is allowed to call this.
@@ -82,6 +90,95 @@ pub mod unit09_program {
         instructions::set_config::handler(ctx, args)
     }
 
+    /// Force-close a program-owned account that regular instructions cannot
+    /// clean up (admin only).
+    ///
+    /// Requires a confirmation nonce derived from the target key and emits a
+    /// `Unit09Log`. Use with care; the metrics, repo or parent fork counting
+    /// the target are adjusted.
+    ///
+    /// Accounts:
+    /// - `target` – program-owned account to close
+    /// - `destination` – recipient of the reclaimed lamports
+    /// - `admin` – must match `config.admin`
+    /// - `repo` – home repo, when the target is a module
+    /// - `parent_fork` – parent fork, when the target is a fork created
+    ///   under one
+    pub fn admin_close_account(
+        ctx: Context<AdminCloseAccount>,
+        args: AdminCloseAccountArgs,
+    ) -> Result<()> {
+        instructions::admin_close_account::handle(ctx, args)
+    }
+
+    /// Break-glass override of a single account's `schema_version`; also
+    /// migrates a schema v1 `Config`, `Repo` or `Module` to the current
+    /// layout.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`; pays rent for a grown account
+    /// - `config` – global configuration, in either layout
+    /// - `system_program` – system program
+    /// - remaining account 0 – writable account to correct, of the type
+    ///   named by `args.account_kind`
+    pub fn set_schema_version<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetSchemaVersion<'info>>,
+        args: SetSchemaVersionArgs,
+    ) -> Result<()> {
+        instructions::set_schema_version::handle(ctx, args)
+    }
+
+    /// Pause the deployment, recording a reason and optional auto-unpause time.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`
+    /// - `config` – global configuration
+    pub fn pause(ctx: Context<Pause>, args: PauseArgs) -> Result<()> {
+        instructions::pause::handle(ctx, args)
+    }
+
+    /// Lift a pause before its scheduled end.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`
+    /// - `config` – global configuration
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        instructions::unpause::handle(ctx)
+    }
+
+    /// Block every non-admin instruction until the kill switch is disengaged.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`
+    /// - `config` – global configuration
+    /// - admin co-signers as `(signer, authority)` pairs in remaining accounts
+    pub fn engage_kill_switch(ctx: Context<EngageKillSwitch>) -> Result<()> {
+        instructions::engage_kill_switch::handle(ctx)
+    }
+
+    /// Lift the emergency kill switch.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`
+    /// - `config` – global configuration
+    /// - admin co-signers as `(signer, authority)` pairs in remaining accounts
+    pub fn disengage_kill_switch(ctx: Context<DisengageKillSwitch>) -> Result<()> {
+        instructions::disengage_kill_switch::handle(ctx)
+    }
+
+    /// Hand `Config::admin` to a new key and optionally change the number of
+    /// admins that must sign high-risk admin instructions.
+    ///
+    /// Accounts:
+    /// - `admin` – must match `config.admin`
+    /// - `config` – global configuration
+    /// - `admin_authority` – outgoing admin's authority PDA; its admin role
+    ///   is revoked
+    /// - admin co-signers as `(signer, authority)` pairs in remaining accounts
+    pub fn rotate_admin(ctx: Context<RotateAdmin>, args: RotateAdminArgs) -> Result<()> {
+        instructions::rotate_admin::handle(ctx, args)
+    }
+
     // -------------------------------------------------------------------------
     //  Repository Management
     // -------------------------------------------------------------------------
@@ -115,6 +212,57 @@ pub mod unit09_program {
         instructions::update_repo::handler(ctx, args)
     }
 
+    /// Close a repository that has no modules left.
+    ///
+    /// Rent is refunded to `refund_to` (e.g. a treasury) when provided, and to
+    /// the authority otherwise.
+    ///
+    /// Accounts:
+    /// - `repo`           – repository PDA to close
+    /// - `metrics`        – global metrics PDA
+    /// - `authority`      – signer, must match `repo.authority`
+    /// - `url_index`      – optional `RepoUrlIndex` of the repository, closed
+    /// - `language_stats` – optional `RepoLanguageStats`, closed
+    /// - `refund_to`      – optional system account receiving the rent
+    /// - remaining        – `(observer_stats, observer)` pairs; each stats
+    ///   account is closed and its rent refunded to the observer
+    pub fn close_repo(ctx: Context<CloseRepo>) -> Result<()> {
+        instructions::close_repo::handle(ctx)
+    }
+
+    /// Propose a new authority for a repository.
+    ///
+    /// The transfer only takes effect once the proposed key signs
+    /// `accept_repo_transfer`.
+    ///
+    /// Accounts:
+    /// - `repo` – repository PDA
+    /// - `authority` – current repository authority (signer)
+    pub fn initiate_repo_transfer(
+        ctx: Context<InitiateRepoTransfer>,
+        args: InitiateRepoTransferArgs,
+    ) -> Result<()> {
+        instructions::initiate_repo_transfer::handle(ctx, args)
+    }
+
+    /// Accept a pending repository authority transfer.
+    ///
+    /// Accounts:
+    /// - `repo` – repository PDA
+    /// - `new_authority` – the pending authority (signer)
+    pub fn accept_repo_transfer(ctx: Context<AcceptRepoTransfer>) -> Result<()> {
+        instructions::accept_repo_transfer::handle(ctx)
+    }
+
+    /// Cancel a pending repository authority transfer.
+    ///
+    /// Accounts:
+    /// - `repo` – repository PDA
+    /// - `signer` – repository authority or deployment admin
+    pub fn cancel_repo_transfer(ctx: Context<CancelRepoTransfer>) -> Result<()> {
+        instructions::cancel_repo_transfer::handle(ctx)
+    }
+
     // -------------------------------------------------------------------------
     //  Module Management
     // -------------------------------------------------------------------------
@@ -148,6 +296,101 @@ pub mod unit09_program {
         instructions::update_module::handler(ctx, args)
     }
 
+    /// Mark a module as reviewed by the Unit09 team.
+    ///
+    /// Accounts:
+    /// - `admin`  – must match `config.admin`
+    /// - `module` – module PDA receiving the badge
+    pub fn verify_module(ctx: Context<VerifyModule>) -> Result<()> {
+        instructions::verify_module::handle(ctx)
+    }
+
+    /// Revoke a module's verified badge.
+    ///
+    /// Accounts:
+    /// - `admin`  – must match `config.admin`
+    /// - `module` – module PDA losing the badge
+    pub fn unverify_module(ctx: Context<UnverifyModule>) -> Result<()> {
+        instructions::unverify_module::handle(ctx)
+    }
+
+    /// File an ownership claim on a module, backed by a domain proof URL.
+    ///
+    /// Accounts:
+    /// - `claimant` – key asking to own the module; pays for the claim
+    /// - `module`   – module PDA being claimed
+    /// - `claim`    – `ModuleClaim` PDA for the module and claimant
+    pub fn claim_module_name(
+        ctx: Context<ClaimModuleName>,
+        args: ClaimModuleNameArgs,
+    ) -> Result<()> {
+        instructions::claim_module_name::handle(ctx, args)
+    }
+
+    /// Approve or reject a pending module claim; approval hands the
+    /// module to the claimant.
+    ///
+    /// Accounts:
+    /// - `admin`  – must match `config.admin`
+    /// - `module` – module PDA the claim is about
+    /// - `claim`  – pending `ModuleClaim` PDA for the module and claimant
+    /// - admin co-signers as `(signer, authority)` pairs in remaining accounts
+    pub fn resolve_claim(ctx: Context<ResolveClaim>, args: ResolveClaimArgs) -> Result<()> {
+        instructions::resolve_claim::handle(ctx, args)
+    }
+
+    /// Promote a module version to stable and advance the module's
+    /// latest-stable pointer when it is newer.
+    pub fn promote_version_stable(ctx: Context<PromoteVersionStable>) -> Result<()> {
+        instructions::promote_version_stable::handle(ctx)
+    }
+
+    /// Record the signer of a release on an existing module version.
+    ///
+    /// Accounts:
+    /// - `signer` – module authority or `config.admin`
+    /// - `module` / `module_version` – the version being attested
+    pub fn mark_version_attested(
+        ctx: Context<MarkVersionAttested>,
+        args: MarkVersionAttestedArgs,
+    ) -> Result<()> {
+        instructions::mark_version_attested::handle(ctx, args)
+    }
+
+    /// List a module's versions and changelog URIs in ascending order.
+    pub fn collect_changelog(ctx: Context<CollectChangelog>) -> Result<()> {
+        instructions::collect_changelog::handle(ctx)
+    }
+
+    /// Close a module.
+    ///
+    /// Rent is refunded to `refund_to` (e.g. a treasury) when provided, and to
+    /// the authority otherwise.
+    ///
+    /// Accounts:
+    /// - `repo`      – repository owning the module
+    /// - `module`    – module PDA to close
+    /// - `metrics`   – global metrics PDA
+    /// - `authority` – signer, must match `repo.authority`
+    /// - `parent_module` – parent of a derivative module, if still open
+    /// - `refund_to` – optional system account receiving the rent
+    pub fn close_module(ctx: Context<CloseModule>) -> Result<()> {
+        instructions::close_module::handle(ctx)
+    }
+
+    /// Pin a module as a "start here" entrypoint of its repository.
+    ///
+    /// Only the repository authority may pin, and only modules registered
+    /// under that repository.
+    pub fn pin_module(ctx: Context<PinModule>) -> Result<()> {
+        instructions::pin_module::handle(ctx)
+    }
+
+    /// Remove a module from its repository's pinned entrypoints.
+    pub fn unpin_module(ctx: Context<UnpinModule>, args: UnpinModuleArgs) -> Result<()> {
+        instructions::unpin_module::handle(ctx, args)
+    }
+
     /// Link an existing module to a repository.
     ///
     /// This is useful when a module was registered first and later assigned
@@ -161,6 +404,42 @@ pub mod unit09_program {
         instructions::link_module_to_repo::handler(ctx)
     }
 
+    /// Link up to `MAX_LINKS_PER_BATCH` modules to a repository at once.
+    ///
+    /// Accounts:
+    /// - `repo`      – target repository
+    /// - `authority` – signer, module or repo authority of every link
+    /// - remaining accounts – one `(module, link)` pair per entry of `args.links`
+    pub fn link_modules_to_repo<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LinkModulesToRepo<'info>>,
+        args: LinkModulesToRepoArgs,
+    ) -> Result<()> {
+        instructions::link_modules_to_repo::handle(ctx, args)
+    }
+
+    /// Copy the modules registered under an upstream repository into a
+    /// downstream one as secondary links, e.g. after forking it.
+    ///
+    /// The `(module, link)` account pairs follow as `remaining_accounts`.
+    pub fn copy_module_links_from<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CopyModuleLinksFrom<'info>>,
+        args: CopyModuleLinksFromArgs,
+    ) -> Result<()> {
+        instructions::copy_module_links_from::handle(ctx, args)
+    }
+
+    /// Confirm a pending module-to-repo link on behalf of the authority
+    /// whose consent it is waiting for.
+    pub fn confirm_module_link(ctx: Context<ConfirmModuleLink>) -> Result<()> {
+        instructions::confirm_module_link::handle(ctx)
+    }
+
+    /// Close an expired module-to-repo link. Permissionless once
+    /// `ModuleRepoLink::expires_at` has passed; the rent goes to `linked_by`.
+    pub fn expire_link(ctx: Context<ExpireLink>) -> Result<()> {
+        instructions::expire_link::handle(ctx)
+    }
+
     // -------------------------------------------------------------------------
     //  Fork Management
     // -------------------------------------------------------------------------
@@ -195,6 +474,20 @@ pub mod unit09_program {
         instructions::update_fork_state::handler(ctx, args)
     }
 
+    /// Retire an expired fork. Permissionless once `Fork::expires_at` has passed.
+    pub fn expire_fork(ctx: Context<ExpireFork>) -> Result<()> {
+        instructions::expire_fork::handle(ctx)
+    }
+
+    /// Compare the module sets of two forks (typically a fork and its parent)
+    /// and emit the added / removed counts.
+    pub fn compute_fork_diff(
+        ctx: Context<ComputeForkDiff>,
+        args: ComputeForkDiffArgs,
+    ) -> Result<()> {
+        instructions::compute_fork_diff::handle(ctx, args)
+    }
+
     // -------------------------------------------------------------------------
     //  Observations and Metrics
     // -------------------------------------------------------------------------
@@ -210,10 +503,40 @@ pub mod unit09_program {
     /// - `repo`      – repository being observed
     /// - `metrics`   – global metrics PDA
     /// - `observer`  – signer (worker, operator, or automation key)
+    ///
+    /// Repos with `require_signed_observations` set also need the worker's
+    /// `Authority` and the instructions sysvar, preceded by an Ed25519
+    /// program instruction carrying the worker's signature.
+    ///
+    /// Observation rewards go to the signing worker (through `worker_stats`
+    /// when it is not the observer), or to an observer passing their own
+    /// `Authority` with the observer role; backfills earn nothing.
     pub fn record_observation(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> Result<()> {
         instructions::record_observation::handler(ctx, args)
     }
 
+    /// Move an observer's `ObserverStats` for a repository to a new key.
+    ///
+    /// The old observer key or the admin may rotate; the old stats account is
+    /// closed and its tallies are added to the new key's account.
+    pub fn rotate_observer_key(
+        ctx: Context<RotateObserverKey>,
+        args: RotateObserverKeyArgs,
+    ) -> Result<()> {
+        instructions::rotate_observer_key::handle(ctx, args)
+    }
+
+    /// Pay an observer the observation rewards accrued on its stats for a
+    /// repository, out of the rewards vault.
+    ///
+    /// Accounts:
+    /// - `observer`       – observer key; receives the reward
+    /// - `observer_stats` – the observer's `ObserverStats` PDA
+    /// - `rewards_vault`  – rewards vault PDA (`REWARDS_VAULT_SEED`)
+    pub fn claim_observer_reward(ctx: Context<ClaimObserverReward>) -> Result<()> {
+        instructions::claim_observer_reward::handle(ctx)
+    }
+
     /// Manually adjust aggregate metrics.
     ///
     /// This is an escape hatch for:
@@ -227,6 +550,17 @@ pub mod unit09_program {
         instructions::record_metrics::handler(ctx, args)
     }
 
+    /// Check the global metrics account for internal inconsistencies.
+    ///
+    /// Emits a `Unit09Log` listing every violated invariant (or `ok`).
+    /// No state is mutated, so anyone may call this.
+    ///
+    /// Accounts:
+    /// - `metrics` – metrics PDA (read-only)
+    pub fn verify_metrics_consistency(ctx: Context<VerifyMetricsConsistency>) -> Result<()> {
+        instructions::verify_metrics_consistency::handle(ctx)
+    }
+
     // -------------------------------------------------------------------------
     //  Global Metadata
     // -------------------------------------------------------------------------
@@ -245,6 +579,34 @@ pub mod unit09_program {
     pub fn set_metadata(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
         instructions::set_metadata::handler(ctx, args)
     }
+
+    // -------------------------------------------------------------------------
+    //  Authorities and Roles
+    // -------------------------------------------------------------------------
+
+    /// Grant a role (optionally expiring) to a key.
+    ///
+    /// Accounts:
+    /// - `admin`           – signer, must match `config.admin`
+    /// - `target`          – key receiving the role
+    /// - `authority_entry` – authority registry PDA (init if needed)
+    /// - `system_program`
+    /// - admin co-signers as `(signer, authority)` pairs in remaining
+    ///   accounts when granting the admin role
+    pub fn grant_role(ctx: Context<GrantRole>, args: GrantRoleArgs) -> Result<()> {
+        instructions::grant_role::handle(ctx, args)
+    }
+
+    /// Revoke a role previously granted to a key.
+    ///
+    /// Accounts:
+    /// - `admin`           – signer, must match `config.admin`
+    /// - `authority_entry` – authority registry PDA
+    /// - admin co-signers as `(signer, authority)` pairs in remaining
+    ///   accounts when revoking the admin role
+    pub fn revoke_role(ctx: Context<RevokeRole>, args: RevokeRoleArgs) -> Result<()> {
+        instructions::revoke_role::handle(ctx, args)
+    }
 }
 
 // ===================================================================================
@@ -258,21 +620,39 @@ pub mod unit09_program {
 pub mod state {
     pub mod config;
     pub mod repo;
+    pub mod repo_url_index;
+    pub mod observer_stats;
+    pub mod repo_language_stats;
     pub mod module;
     pub mod module_version;
+    pub mod module_repo_link;
+    pub mod module_claim;
     pub mod fork;
+    pub mod owner_fork_counter;
     pub mod lifecycle;
     pub mod metrics;
     pub mod authority;
+    pub mod global_metadata;
+    pub mod visibility;
+    pub mod category_histogram;
 
     pub use config::*;
     pub use repo::*;
+    pub use repo_url_index::*;
+    pub use observer_stats::*;
+    pub use repo_language_stats::*;
     pub use module::*;
     pub use module_version::*;
+    pub use module_repo_link::*;
+    pub use module_claim::*;
     pub use fork::*;
+    pub use owner_fork_counter::*;
     pub use lifecycle::*;
     pub use metrics::*;
     pub use authority::*;
+    pub use global_metadata::*;
+    pub use visibility::*;
+    pub use category_histogram::*;
 }
 
 /// Utility helpers re-export.
@@ -281,14 +661,27 @@ pub mod state {
 /// - PDA seeds
 /// - Common validators
 /// - Time utilities
+/// - Semantic version constraints
 pub mod utils {
     pub mod seeds;
     pub mod validators;
     pub mod time;
+    pub mod fees;
+    pub mod event_sink;
+    pub mod ed25519;
+    pub mod semver;
+    pub mod accounts;
+    #[cfg(test)]
+    pub(crate) mod test_utils;
 
     pub use seeds::*;
     pub use validators::*;
     pub use time::*;
+    pub use fees::*;
+    pub use event_sink::*;
+    pub use ed25519::*;
+    pub use semver::*;
+    pub use accounts::*;
 }
 
 /// Instruction module re-export (already used above, but also available to
@@ -11,10 +11,11 @@
 //! provides a centralized, role-based view that off-chain tooling can index.
 //!
 //! Responsibilities:
-//! - Map authority public keys to role bitmasks (admin, maintainer, observer)
+//! - Map authority public keys to a bounded, enumerable set of roles
+//!   (admin, maintainer, observer, or any deployment-specific label)
 //! - Track which resources an authority is scoped to (global or per-repo)
 //! - Provide helpers for:
-//!     * checking roles
+//!     * checking roles (including per-role expiry)
 //!     * checking whether a role applies to a specific target
 //!     * role assignment and revocation
 //!
@@ -22,23 +23,39 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
 use crate::errors::Unit09Error;
 
-/// Roles are represented as a bitmask for compact storage and flexible checks.
+/// A single role held by an authority.
 ///
-/// Multiple roles can be combined using bitwise OR:
-/// - ADMIN | MAINTAINER
-/// - MAINTAINER | OBSERVER
-///
-/// These constants define the standard roles used in the protocol. Off-chain
-/// tooling can interpret them for governance and UI.
-pub mod role_flags {
-    pub const ADMIN: u64 = 1 << 0;
-    pub const MAINTAINER: u64 = 1 << 1;
-    pub const OBSERVER: u64 = 1 << 2;
-
-    /// Convenience mask for "any role".
-    pub const ANY: u64 = ADMIN | MAINTAINER | OBSERVER;
+/// Roles are identified by a short label (see `ROLE_LABEL_*` in
+/// `constants.rs` for the standard ones). Each entry carries its own grant
+/// time and optional expiry so that temporary roles can lapse without an
+/// explicit revocation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoleEntry {
+    /// Role label, for example "admin", "maintainer" or "observer".
+    pub role: String,
+
+    /// Unix timestamp when this role was (last) granted.
+    pub granted_at: i64,
+
+    /// Unix timestamp after which this role is no longer valid.
+    ///
+    /// Zero means the role never expires.
+    pub expires_at: i64,
+}
+
+impl RoleEntry {
+    /// Serialized size of a single entry at maximum label length.
+    pub const LEN: usize = 4 + MAX_ROLE_LABEL_LEN // role: String
+        + 8 // granted_at: i64
+        + 8; // expires_at: i64
+
+    /// Returns true if this entry is valid at `now`.
+    pub fn is_active_at(&self, now: i64) -> bool {
+        self.expires_at == 0 || now < self.expires_at
+    }
 }
 
 /// Authority account tracked by Unit09.
@@ -54,10 +71,10 @@ pub struct Authority {
     /// The public key that this authority entry represents.
     pub authority: Pubkey,
 
-    /// Bitmask of roles assigned to this authority.
+    /// Roles currently assigned to this authority.
     ///
-    /// See `role_flags` for available roles.
-    pub roles: u64,
+    /// Bounded by `MAX_AUTHORITY_ROLES`; each label is unique.
+    pub roles: Vec<RoleEntry>,
 
     /// Optional resource scope for this authority entry.
     ///
@@ -95,10 +112,16 @@ impl Authority {
     /// Discriminator length used by Anchor.
     pub const DISCRIMINATOR_LEN: usize = 8;
 
+    /// Maximum number of role entries per authority.
+    pub const MAX_ROLES: usize = MAX_AUTHORITY_ROLES;
+
     /// Total serialized length for the `Authority` account.
+    ///
+    /// `roles` is encoded as a 4-byte length prefix followed by up to
+    /// `MAX_ROLES` entries.
     pub const LEN: usize = Self::DISCRIMINATOR_LEN
         + 32 // authority: Pubkey
-        + 8  // roles: u64
+        + 4 + Self::MAX_ROLES * RoleEntry::LEN // roles: Vec<RoleEntry>
         + 1  // is_global: bool
         + 32 // resource_scope: Pubkey
         + 8  // created_at: i64
@@ -111,25 +134,22 @@ impl Authority {
     // Initialization
     // -----------------------------------------------------------------------
 
-    /// Initialize a new authority entry.
+    /// Initialize a new authority entry with no roles.
     ///
-    /// This is typically called from an admin-only instruction that assigns
-    /// roles to an authority for the first time.
+    /// This is typically called from an admin-only instruction right before
+    /// the first `add_role`.
     pub fn init(
         &mut self,
         authority: Pubkey,
-        roles: u64,
         is_global: bool,
         resource_scope: Pubkey,
         bump: u8,
         clock: &Clock,
     ) -> Result<()> {
-        Self::validate_roles(roles)?;
-
         let now = clock.unix_timestamp;
 
         self.authority = authority;
-        self.roles = roles;
+        self.roles = Vec::new();
         self.is_global = is_global;
         self.resource_scope = if is_global {
             Pubkey::default()
@@ -138,7 +158,7 @@ impl Authority {
         };
         self.created_at = now;
         self.updated_at = now;
-        self.schema_version = 1;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
         self.reserved = [0u8; 62];
 
@@ -149,38 +169,52 @@ impl Authority {
     // Role Management
     // -----------------------------------------------------------------------
 
-    /// Assign one or more roles (bitwise OR) to this authority.
+    /// Grant `role` to this authority.
     ///
-    /// This operation is additive and does not clear existing roles unless
-    /// explicitly requested via `clear_roles`.
-    pub fn grant_roles(&mut self, roles_to_grant: u64, clock: &Clock) -> Result<()> {
-        Self::validate_roles(roles_to_grant)?;
+    /// If the role is already present its `granted_at` and `expires_at` are
+    /// refreshed instead of adding a duplicate entry.
+    pub fn add_role(&mut self, role: &str, expires_at: i64, clock: &Clock) -> Result<()> {
+        Self::validate_role(role)?;
 
-        self.roles |= roles_to_grant;
-        self.updated_at = clock.unix_timestamp;
-        Ok(())
-    }
+        let now = clock.unix_timestamp;
+        if expires_at != 0 && expires_at <= now {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
 
-    /// Revoke one or more roles from this authority.
-    ///
-    /// Roles to revoke are cleared from the bitmask.
-    pub fn revoke_roles(&mut self, roles_to_revoke: u64, clock: &Clock) -> Result<()> {
-        self.roles &= !roles_to_revoke;
-        self.updated_at = clock.unix_timestamp;
+        if let Some(entry) = self.roles.iter_mut().find(|entry| entry.role == role) {
+            entry.granted_at = now;
+            entry.expires_at = expires_at;
+        } else {
+            if self.roles.len() >= Self::MAX_ROLES {
+                return err!(Unit09Error::AuthorityRoleLimitReached);
+            }
+            self.roles.push(RoleEntry {
+                role: role.to_string(),
+                granted_at: now,
+                expires_at,
+            });
+        }
+
+        self.updated_at = now;
         Ok(())
     }
 
-    /// Replace the current role set entirely with a new bitmask.
-    pub fn set_roles(&mut self, new_roles: u64, clock: &Clock) -> Result<()> {
-        Self::validate_roles(new_roles)?;
-        self.roles = new_roles;
+    /// Remove `role` from this authority.
+    pub fn remove_role(&mut self, role: &str, clock: &Clock) -> Result<()> {
+        let index = self
+            .roles
+            .iter()
+            .position(|entry| entry.role == role)
+            .ok_or(Unit09Error::AuthorityRoleNotFound)?;
+
+        self.roles.remove(index);
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
-    /// Clear all roles from this authority, effectively disabling it.
+    /// Remove every role from this authority, effectively disabling it.
     pub fn clear_roles(&mut self, clock: &Clock) -> Result<()> {
-        self.roles = 0;
+        self.roles.clear();
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
@@ -213,15 +247,27 @@ impl Authority {
     // Role Checks
     // -----------------------------------------------------------------------
 
-    /// Returns true if this authority entry has at least one of the requested
-    /// roles in its bitmask.
-    pub fn has_any_role(&self, roles_mask: u64) -> bool {
-        self.roles & roles_mask != 0
+    /// Returns true if this authority holds an unexpired `role` at `now`.
+    pub fn has_role(&self, role: &str, now: i64) -> bool {
+        self.roles
+            .iter()
+            .any(|entry| entry.role == role && entry.is_active_at(now))
+    }
+
+    /// Returns true if this authority holds at least one of `roles` at `now`.
+    pub fn has_any_role(&self, roles: &[&str], now: i64) -> bool {
+        roles.iter().any(|role| self.has_role(role, now))
     }
 
-    /// Returns true if this authority entry has all of the requested roles.
-    pub fn has_all_roles(&self, roles_mask: u64) -> bool {
-        (self.roles & roles_mask) == roles_mask
+    /// Returns true if this authority holds every one of `roles` at `now`.
+    pub fn has_all_roles(&self, roles: &[&str], now: i64) -> bool {
+        roles.iter().all(|role| self.has_role(role, now))
+    }
+
+    /// Returns true if this authority holds the admin role globally at
+    /// `now`, which lets it co-sign high-risk admin instructions.
+    pub fn is_global_admin(&self, now: i64) -> bool {
+        self.is_global && self.has_role(ROLE_LABEL_ADMIN, now)
     }
 
     /// Returns true if this entry applies to the given resource.
@@ -238,13 +284,14 @@ impl Authority {
     }
 
     /// Check whether the given signer, represented by this authority entry,
-    /// is allowed to perform an action requiring any of the roles in
-    /// `required_roles` on a specific resource.
+    /// is allowed to perform an action requiring any of `required_roles` on
+    /// a specific resource.
     pub fn assert_allowed_for_resource(
         &self,
         signer: &Signer,
-        required_roles: u64,
+        required_roles: &[&str],
         resource: &Pubkey,
+        clock: &Clock,
     ) -> Result<()> {
         if signer.key() != self.authority {
             return err!(Unit09Error::InvalidAuthority);
@@ -252,7 +299,7 @@ impl Authority {
         if !self.matches_resource(resource) {
             return err!(Unit09Error::AuthorityRoleNotAllowed);
         }
-        if !self.has_any_role(required_roles) {
+        if !self.has_any_role(required_roles, clock.unix_timestamp) {
             return err!(Unit09Error::AuthorityRoleNotAllowed);
         }
         Ok(())
@@ -262,15 +309,82 @@ impl Authority {
     // Validation Helpers
     // -----------------------------------------------------------------------
 
-    /// Validate that a roles bitmask contains only known roles.
-    fn validate_roles(roles: u64) -> Result<()> {
-        let known_mask = role_flags::ANY;
+    /// Validate a role label.
+    fn validate_role(role: &str) -> Result<()> {
+        if role.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if role.len() > MAX_ROLE_LABEL_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, new_authority};
 
-        if roles & !known_mask != 0 {
-            // Unknown bits are set; treat this as invalid.
-            return err!(Unit09Error::AuthorityRoleNotAllowed);
+    #[test]
+    fn grant_two_roles_then_revoke_one() {
+        let clock = clock_at(1_000);
+        let mut authority = new_authority(Pubkey::new_unique(), true, Pubkey::default(), &clock);
+
+        authority.add_role(ROLE_LABEL_MAINTAINER, 0, &clock).unwrap();
+        authority.add_role(ROLE_LABEL_OBSERVER, 0, &clock).unwrap();
+
+        assert!(authority.has_role(ROLE_LABEL_MAINTAINER, 1_000));
+        assert!(authority.has_role(ROLE_LABEL_OBSERVER, 1_000));
+        assert!(authority.has_all_roles(&[ROLE_LABEL_MAINTAINER, ROLE_LABEL_OBSERVER], 1_000));
+        assert!(!authority.has_role(ROLE_LABEL_ADMIN, 1_000));
+
+        authority.remove_role(ROLE_LABEL_OBSERVER, &clock).unwrap();
+
+        assert!(authority.has_role(ROLE_LABEL_MAINTAINER, 1_000));
+        assert!(!authority.has_role(ROLE_LABEL_OBSERVER, 1_000));
+        assert_eq!(authority.roles.len(), 1);
+    }
+
+    #[test]
+    fn regranting_refreshes_instead_of_duplicating() {
+        let mut authority =
+            new_authority(Pubkey::new_unique(), true, Pubkey::default(), &clock_at(1_000));
+
+        authority
+            .add_role(ROLE_LABEL_OBSERVER, 2_000, &clock_at(1_000))
+            .unwrap();
+        authority
+            .add_role(ROLE_LABEL_OBSERVER, 0, &clock_at(1_500))
+            .unwrap();
+
+        assert_eq!(authority.roles.len(), 1);
+        assert_eq!(authority.roles[0].granted_at, 1_500);
+        assert!(authority.has_role(ROLE_LABEL_OBSERVER, 5_000));
+    }
+
+    #[test]
+    fn expired_role_is_not_held() {
+        let mut authority =
+            new_authority(Pubkey::new_unique(), true, Pubkey::default(), &clock_at(1_000));
+        authority
+            .add_role(ROLE_LABEL_OBSERVER, 2_000, &clock_at(1_000))
+            .unwrap();
+
+        assert!(authority.has_role(ROLE_LABEL_OBSERVER, 1_999));
+        assert!(!authority.has_role(ROLE_LABEL_OBSERVER, 2_000));
+    }
+
+    #[test]
+    fn role_set_is_bounded() {
+        let clock = clock_at(1_000);
+        let mut authority = new_authority(Pubkey::new_unique(), true, Pubkey::default(), &clock);
+
+        for i in 0..Authority::MAX_ROLES {
+            authority.add_role(&format!("role-{i}"), 0, &clock).unwrap();
         }
 
-        Ok(())
+        assert!(authority.add_role("one-too-many", 0, &clock).is_err());
+        assert!(authority.remove_role("never-granted", &clock).is_err());
     }
 }
@@ -0,0 +1,178 @@
+//! ===========================================================================
+//! Unit09 – Category Histogram
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/category_histogram.rs
+//!
+//! Small bounded histogram keyed by module category, stored inline in other
+//! accounts as a `Vec<CategoryTally>`:
+//!
+//! - `Repo::module_categories` counts a repository's modules per category
+//! - `Metrics::category_loc` sums observed lines of code per category
+//!
+//! A histogram holds at most `MAX_CATEGORY_BUCKETS` named categories.
+//! Amounts for any further category are folded into the `"other"` bucket,
+//! which takes one extra slot, so accounts can reserve a fixed size.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Bucket that collects categories once the histogram is full.
+pub const OTHER_CATEGORY: &str = "other";
+
+/// A single histogram bucket.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CategoryTally {
+    /// Module category this bucket counts.
+    pub category: String,
+
+    /// Accumulated amount for the category.
+    pub value: u64,
+}
+
+impl CategoryTally {
+    /// Serialized length of one bucket.
+    pub const LEN: usize = 4 + MAX_MODULE_CATEGORY_LEN // category: String
+        + 8; // value: u64
+
+    /// Maximum number of buckets, including the `"other"` bucket.
+    pub const MAX_BUCKETS: usize = MAX_CATEGORY_BUCKETS + 1;
+
+    /// Serialized length of a full `Vec<CategoryTally>` histogram.
+    pub const HISTOGRAM_LEN: usize = 4 + Self::MAX_BUCKETS * Self::LEN;
+}
+
+/// Add `amount` to the bucket for `category`.
+///
+/// A new category gets its own bucket while fewer than
+/// `MAX_CATEGORY_BUCKETS` named buckets exist, and is counted under
+/// `"other"` afterwards.
+pub fn histogram_add(entries: &mut Vec<CategoryTally>, category: &str, amount: u64) -> Result<()> {
+    let index = match bucket_index(entries, category) {
+        Some(index) => index,
+        None => {
+            let named = entries
+                .iter()
+                .filter(|entry| entry.category != OTHER_CATEGORY)
+                .count();
+            let bucket = if named < MAX_CATEGORY_BUCKETS {
+                category
+            } else {
+                OTHER_CATEGORY
+            };
+            match bucket_index(entries, bucket) {
+                Some(index) => index,
+                None => {
+                    entries.push(CategoryTally {
+                        category: bucket.to_string(),
+                        value: 0,
+                    });
+                    entries.len() - 1
+                }
+            }
+        }
+    };
+
+    let entry = &mut entries[index];
+    entry.value = entry
+        .value
+        .checked_add(amount)
+        .ok_or(Unit09Error::CounterOverflow)?;
+    Ok(())
+}
+
+/// Remove `amount` from the bucket holding `category`, dropping the bucket
+/// once it reaches zero.
+///
+/// A category with no named bucket is taken from `"other"`. Amounts that
+/// were never tallied, such as modules registered before the histogram
+/// existed, are ignored rather than treated as an error.
+pub fn histogram_remove(entries: &mut Vec<CategoryTally>, category: &str, amount: u64) {
+    let index = bucket_index(entries, category).or_else(|| bucket_index(entries, OTHER_CATEGORY));
+    if let Some(index) = index {
+        let remaining = entries[index].value.saturating_sub(amount);
+        if remaining == 0 {
+            entries.remove(index);
+        } else {
+            entries[index].value = remaining;
+        }
+    }
+}
+
+/// Category with the largest amount, or `None` for an empty histogram.
+///
+/// Ties go to the bucket that was created first.
+pub fn histogram_dominant(entries: &[CategoryTally]) -> Option<&str> {
+    entries
+        .iter()
+        .fold(None, |best: Option<&CategoryTally>, entry| match best {
+            Some(best) if best.value >= entry.value => Some(best),
+            _ => Some(entry),
+        })
+        .map(|entry| entry.category.as_str())
+}
+
+/// Current amount recorded for `category` (0 if absent).
+pub fn histogram_value(entries: &[CategoryTally], category: &str) -> u64 {
+    bucket_index(entries, category)
+        .map(|index| entries[index].value)
+        .unwrap_or(0)
+}
+
+fn bucket_index(entries: &[CategoryTally], category: &str) -> Option<usize> {
+    entries.iter().position(|entry| entry.category == category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow_categories_fold_into_other() {
+        let mut entries = Vec::new();
+        for i in 0..MAX_CATEGORY_BUCKETS {
+            histogram_add(&mut entries, &format!("category-{i}"), 1).unwrap();
+        }
+        histogram_add(&mut entries, "late", 2).unwrap();
+        histogram_add(&mut entries, "later", 3).unwrap();
+
+        assert_eq!(entries.len(), CategoryTally::MAX_BUCKETS);
+        assert_eq!(histogram_value(&entries, OTHER_CATEGORY), 5);
+        assert_eq!(histogram_value(&entries, "late"), 0);
+
+        histogram_remove(&mut entries, "late", 2);
+        assert_eq!(histogram_value(&entries, OTHER_CATEGORY), 3);
+    }
+
+    #[test]
+    fn emptied_buckets_are_dropped() {
+        let mut entries = Vec::new();
+        histogram_add(&mut entries, "program", 2).unwrap();
+        histogram_add(&mut entries, "sdk", 1).unwrap();
+
+        histogram_remove(&mut entries, "sdk", 1);
+        histogram_remove(&mut entries, "missing", 1);
+        assert_eq!(
+            entries,
+            vec![CategoryTally {
+                category: "program".to_string(),
+                value: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn dominant_category_prefers_earliest_on_ties() {
+        let mut entries = Vec::new();
+        assert_eq!(histogram_dominant(&entries), None);
+
+        histogram_add(&mut entries, "sdk", 1).unwrap();
+        histogram_add(&mut entries, "program", 1).unwrap();
+        assert_eq!(histogram_dominant(&entries), Some("sdk"));
+
+        histogram_add(&mut entries, "program", 1).unwrap();
+        assert_eq!(histogram_dominant(&entries), Some("program"));
+    }
+}
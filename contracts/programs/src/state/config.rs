@@ -9,6 +9,11 @@
 //!     * admin authority
 //!     * fee basis points
 //!     * maximum modules per repository
+//!     * whether repo creation is restricted to approved creators
+//!     * the treasury wallet that receives collected fees
+//!     * per-observation LOC and file caps
+//!     * default category and visibility for new modules
+//!     * whether core events are emitted through a self-CPI
 //!     * schema version
 //! - Provide helper methods for:
 //!     * admin checks
@@ -21,9 +26,12 @@
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::{Authority, EntityKind, Module, Visibility};
+use crate::utils::fee_vault_pda;
 
 /// Global configuration account for the Unit09 protocol.
 ///
@@ -77,21 +85,233 @@ pub struct Config {
     /// Bump used for PDA derivation of this account.
     pub bump: u8,
 
-    /// Reserved bytes for future upgrades.
+    /// Unix timestamp from which a cleared `is_active` takes effect.
     ///
-    /// Keeping a reserved area allows new fields to be introduced in-place
-    /// without breaking the account size, which simplifies migrations.
-    pub reserved: [u8; 63],
+    /// Set when the deployment is deactivated so that in-flight clients get
+    /// a grace period to drain; `assert_active` keeps passing until then.
+    /// Zero while active.
+    pub deactivation_effective_at: i64,
+
+    /// Whether `register_repo` is restricted to approved creators.
+    ///
+    /// When true, the registering authority must hold an `Authority` PDA
+    /// carrying the `ROLE_LABEL_CREATOR` role. Defaults to false (open).
+    pub restrict_repo_creation: bool,
+
+    /// Whether `create_fork` is restricted to approved forkers.
+    ///
+    /// When true, the fork owner must hold an `Authority` PDA carrying the
+    /// `ROLE_LABEL_FORKER` role. Defaults to false (open).
+    pub restrict_fork_creation: bool,
+
+    /// Whether module keys must be content-addressed.
+    ///
+    /// When true, `register_module` only accepts the key given by
+    /// `Module::content_addressed_key` for the module's metadata checksum
+    /// and repo, so the module PDA can be checked against its content.
+    /// Defaults to false (any key).
+    pub enforce_content_addressed_modules: bool,
+
+    /// Wallet that receives collected fees.
+    ///
+    /// `Pubkey::default()` means unset, in which case fees go to the fee
+    /// vault PDA (`FEE_VAULT_SEED`). See `fee_destination`.
+    pub treasury: Pubkey,
+
+    /// Largest `files_processed` a single observation may report.
+    ///
+    /// Defaults to `MAX_FILES_PER_OBSERVATION`.
+    pub max_files_per_observation: u32,
+
+    /// Visibility given to modules registered without one, as a
+    /// `Visibility` discriminant. Defaults to public.
+    pub default_visibility: u8,
+
+    /// Whether core events are emitted through a self-CPI (`emit_cpi!`)
+    /// instead of program logs.
+    ///
+    /// Logs can be truncated in busy transactions; CPI events survive that
+    /// at the cost of extra compute. See `utils::EventSink`.
+    pub use_cpi_events: bool,
+
+    /// Whether `update_fork_state` refuses to deactivate a fork that still
+    /// has child forks.
+    ///
+    /// When unset the deactivation goes through and a `Unit09Log` warns
+    /// that the children are left under an inactive parent.
+    pub block_parent_fork_deactivation: bool,
+
+    /// Whether `register_repo` must claim a `RepoUrlIndex` for the URL, so
+    /// two repositories cannot share one. Off by default.
+    pub enforce_unique_url: bool,
+
+    /// Whether the deployment was paused by the admin via `pause`.
+    ///
+    /// See `is_paused` for how `auto_unpause_at` ends a pause.
+    pub paused: bool,
+
+    /// Whether descriptive metadata is frozen, for example during an audit.
+    ///
+    /// Blocks `update_repo`, `update_module` and `set_metadata` while
+    /// observations keep flowing. Unlike the lifecycle freeze, no other
+    /// writes are affected.
+    pub metadata_frozen: bool,
+
+    /// Emergency kill switch, engaged by the admin via `engage_kill_switch`
+    /// on a confirmed exploit.
+    ///
+    /// Stronger than a pause: it has no schedule, and every non-admin
+    /// instruction that writes state fails `assert_not_killed` while it is
+    /// set. Admin instructions keep working so the deployment can recover.
+    pub global_kill: bool,
+
+    /// Minimum time, in seconds, between two renames of the same module.
+    ///
+    /// 0 disables the cooldown.
+    pub rename_cooldown_secs: u32,
+
+    /// Whether `update_fork_state` only releases a fork once every module
+    /// passed alongside it is verified (`Module::is_verified`).
+    pub require_verified_modules_for_fork: bool,
+
+    /// Number of distinct admins that must sign high-risk admin
+    /// instructions (`rotate_admin`, the kill switch, granting or revoking
+    /// the admin role, and resolving module claims).
+    ///
+    /// `admin` counts as one; the others are co-signers holding the global
+    /// `ROLE_LABEL_ADMIN` role on their `Authority` account. 0, as found in
+    /// accounts created before this field, means `admin` alone, like 1.
+    pub admin_threshold: u8,
+
+    /// Reserved space for future fields.
+    ///
+    /// Everything up to here keeps the schema v1 layout (`Config::V1_LEN`
+    /// bytes): the fields after `bump` were carved out of v1's
+    /// `reserved: [u8; 63]`. The fields below were appended in schema v2 and
+    /// exist once `set_schema_version` has migrated the account.
+    pub reserved: [u8; 3],
+
+    /// Largest `lines_of_code` a single observation may report.
+    ///
+    /// Defaults to `MAX_LOC_PER_OBSERVATION`; raise it for huge monorepos.
+    pub max_loc_per_observation: u64,
+
+    /// Flat fee, in lamports, charged by `create_fork`.
+    ///
+    /// Routed like every other fee (see `fee_destination`); zero disables it.
+    pub fork_fee_lamports: u64,
+
+    /// Unix timestamp at which a pause lifts by itself, or 0 when the pause
+    /// lasts until `unpause` is called.
+    pub auto_unpause_at: i64,
+
+    /// Unix timestamp when the kill switch was engaged, or 0.
+    pub killed_at: i64,
+
+    /// Deployment-wide cap on `Metrics::total_repos`; 0 means no cap.
+    ///
+    /// Together with the module and fork caps, bounds how far anyone can
+    /// bloat the deployment by registering accounts.
+    pub max_total_repos: u64,
+
+    /// Deployment-wide cap on `Metrics::total_modules`; 0 means no cap.
+    pub max_total_modules: u64,
+
+    /// Deployment-wide cap on `Metrics::total_forks`; 0 means no cap.
+    pub max_total_forks: u64,
+
+    /// Lamports accrued to an observer's `ObserverStats::accrued_reward`
+    /// per recorded observation; 0 disables rewards.
+    ///
+    /// Rewards are paid out of the rewards vault PDA
+    /// (`REWARDS_VAULT_SEED`) by `claim_observer_reward`.
+    pub observation_reward_lamports: u64,
+
+    /// Category given to modules registered with an empty one.
+    ///
+    /// Empty means no default: such registrations are rejected.
+    pub default_category: String,
+
+    /// Operator-supplied reason for the current pause; empty when not
+    /// paused.
+    pub pause_reason: String,
+}
+
+/// Changes applied by `Config::apply_update`.
+///
+/// Every field is optional; `None` leaves the stored value unchanged.
+/// Build one with struct-update syntax so only the fields being changed
+/// are named:
+///
+/// ```ignore
+/// config.apply_update(
+///     ConfigUpdate {
+///         fee_bps: Some(100),
+///         ..ConfigUpdate::default()
+///     },
+///     &clock,
+/// )?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigUpdate {
+    /// New `Config::fee_bps`, at most `MAX_FEE_BPS`.
+    pub fee_bps: Option<u16>,
+    /// New `Config::max_modules_per_repo`, non-zero.
+    pub max_modules_per_repo: Option<u32>,
+    /// New `Config::is_active`; turning it off starts a grace period.
+    pub is_active: Option<bool>,
+    /// Grace period before a deactivation takes effect; defaults to
+    /// `DEFAULT_DEACTIVATION_GRACE_SECONDS`. Only read with
+    /// `is_active: Some(false)`.
+    pub deactivation_grace_seconds: Option<u32>,
+    /// New `Config::restrict_repo_creation`.
+    pub restrict_repo_creation: Option<bool>,
+    /// New `Config::policy_ref`.
+    pub policy_ref: Option<[u8; 32]>,
+    /// New `Config::treasury`; `Pubkey::default()` clears it.
+    pub treasury: Option<Pubkey>,
+    /// New `Config::max_loc_per_observation`, non-zero.
+    pub max_loc_per_observation: Option<u64>,
+    /// New `Config::max_files_per_observation`, non-zero.
+    pub max_files_per_observation: Option<u32>,
+    /// New `Config::fork_fee_lamports`.
+    pub fork_fee_lamports: Option<u64>,
+    /// New `Config::default_category`.
+    pub default_category: Option<String>,
+    /// New `Config::default_visibility`, a `Visibility` discriminant.
+    pub default_visibility: Option<u8>,
+    /// New `Config::use_cpi_events`.
+    pub use_cpi_events: Option<bool>,
+    /// New `Config::block_parent_fork_deactivation`.
+    pub block_parent_fork_deactivation: Option<bool>,
+    /// New `Config::enforce_unique_url`.
+    pub enforce_unique_url: Option<bool>,
+    /// New `Config::metadata_frozen`.
+    pub metadata_frozen: Option<bool>,
+    /// New `Config::rename_cooldown_secs`.
+    pub rename_cooldown_secs: Option<u32>,
+    /// New `Config::require_verified_modules_for_fork`.
+    pub require_verified_modules_for_fork: Option<bool>,
+    /// New `Config::max_total_repos`.
+    pub max_total_repos: Option<u64>,
+    /// New `Config::max_total_modules`.
+    pub max_total_modules: Option<u64>,
+    /// New `Config::max_total_forks`.
+    pub max_total_forks: Option<u64>,
+    /// New `Config::observation_reward_lamports`.
+    pub observation_reward_lamports: Option<u64>,
+    /// New `Config::restrict_fork_creation`.
+    pub restrict_fork_creation: Option<bool>,
+    /// New `Config::enforce_content_addressed_modules`.
+    pub enforce_content_addressed_modules: Option<bool>,
 }
 
 impl Config {
     /// Discriminator length for Anchor accounts.
     pub const DISCRIMINATOR_LEN: usize = 8;
 
-    /// Total serialized length of the `Config` account.
-    ///
-    /// This is used when performing rent-exempt allocations.
-    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+    /// Serialized length of a schema v1 `Config`, which ends at `reserved`.
+    pub const V1_LEN: usize = Self::DISCRIMINATOR_LEN
         + 32  // admin: Pubkey
         + 2   // fee_bps: u16
         + 4   // max_modules_per_repo: u32
@@ -101,7 +321,38 @@ impl Config {
         + 8   // updated_at: i64
         + 32  // policy_ref: [u8; 32]
         + 1   // bump: u8
-        + 63; // reserved: [u8; 63]
+        + 8   // deactivation_effective_at: i64
+        + 1   // restrict_repo_creation: bool
+        + 1   // restrict_fork_creation: bool
+        + 1   // enforce_content_addressed_modules: bool
+        + 32  // treasury: Pubkey
+        + 4   // max_files_per_observation: u32
+        + 1   // default_visibility: u8
+        + 1   // use_cpi_events: bool
+        + 1   // block_parent_fork_deactivation: bool
+        + 1   // enforce_unique_url: bool
+        + 1   // paused: bool
+        + 1   // metadata_frozen: bool
+        + 1   // global_kill: bool
+        + 4   // rename_cooldown_secs: u32
+        + 1   // require_verified_modules_for_fork: bool
+        + 1   // admin_threshold: u8
+        + 3;  // reserved: [u8; 3]
+
+    /// Total serialized length of the `Config` account.
+    ///
+    /// This is used when performing rent-exempt allocations.
+    pub const LEN: usize = Self::V1_LEN
+        + 8   // max_loc_per_observation: u64
+        + 8   // fork_fee_lamports: u64
+        + 8   // auto_unpause_at: i64
+        + 8   // killed_at: i64
+        + 8   // max_total_repos: u64
+        + 8   // max_total_modules: u64
+        + 8   // max_total_forks: u64
+        + 8   // observation_reward_lamports: u64
+        + 4 + MAX_MODULE_CATEGORY_LEN // default_category: String
+        + 4 + MAX_PAUSE_REASON_LEN; // pause_reason: String
 
     /// Initialize the configuration account with sane defaults and values
     /// provided at deployment time.
@@ -126,41 +377,219 @@ impl Config {
         self.updated_at = clock.unix_timestamp;
         self.policy_ref = policy_ref;
         self.bump = bump;
-        self.reserved = [0u8; 63];
+        self.deactivation_effective_at = 0;
+        self.restrict_repo_creation = false;
+        self.restrict_fork_creation = false;
+        self.enforce_content_addressed_modules = false;
+        self.treasury = Pubkey::default();
+        self.max_files_per_observation = MAX_FILES_PER_OBSERVATION;
+        self.default_visibility = Visibility::Public.as_u8();
+        self.use_cpi_events = false;
+        self.block_parent_fork_deactivation = false;
+        self.enforce_unique_url = false;
+        self.paused = false;
+        self.metadata_frozen = false;
+        self.global_kill = false;
+        self.rename_cooldown_secs = 0;
+        self.require_verified_modules_for_fork = false;
+        self.admin_threshold = 1;
+        self.reserved = [0u8; 3];
+        self.max_loc_per_observation = MAX_LOC_PER_OBSERVATION;
+        self.fork_fee_lamports = 0;
+        self.auto_unpause_at = 0;
+        self.killed_at = 0;
+        self.max_total_repos = 0;
+        self.max_total_modules = 0;
+        self.max_total_forks = 0;
+        self.observation_reward_lamports = 0;
+        self.default_category = String::new();
+        self.pause_reason = String::new();
 
         Ok(())
     }
 
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.admin != Pubkey::default()
+    }
+
+    /// Give a schema v1 account that was just grown to `LEN` the values
+    /// `init` would have set.
+    ///
+    /// Every field v1 lacks reads as zero after the realloc, which already
+    /// matches `init` for all of them but the observation caps and
+    /// `admin_threshold`.
+    pub fn migrate_from_v1(&mut self) {
+        self.max_loc_per_observation = MAX_LOC_PER_OBSERVATION;
+        self.max_files_per_observation = MAX_FILES_PER_OBSERVATION;
+        self.admin_threshold = 1;
+    }
+
+    /// Read `admin` straight from the data of a `Config` account.
+    ///
+    /// `admin` leads both the v1 and the current layout, so this also works
+    /// on an account `set_schema_version` has yet to migrate, which cannot
+    /// be loaded as `Account<Config>`.
+    pub fn stored_admin(info: &AccountInfo) -> Result<Pubkey> {
+        require!(info.owner == &crate::ID, Unit09Error::InvalidAccountOwner);
+
+        let data = info.try_borrow_data()?;
+        let admin_end = Self::DISCRIMINATOR_LEN + 32;
+        require!(
+            data.len() >= admin_end && data[..Self::DISCRIMINATOR_LEN] == Self::DISCRIMINATOR,
+            Unit09Error::InvalidAccountDiscriminator
+        );
+        Pubkey::try_from(&data[Self::DISCRIMINATOR_LEN..admin_end])
+            .map_err(|_| error!(Unit09Error::InvalidAccountDiscriminator))
+    }
+
     /// Apply an update to the configuration account.
     ///
     /// This does not modify fields that are not explicitly passed in; it only
-    /// updates values that are provided as `Some(...)` in the args.
-    pub fn apply_update(
-        &mut self,
-        maybe_fee_bps: Option<u16>,
-        maybe_max_modules_per_repo: Option<u32>,
-        maybe_is_active: Option<bool>,
-        maybe_policy_ref: Option<[u8; 32]>,
-        clock: &Clock,
-    ) -> Result<()> {
-        if let Some(fee_bps) = maybe_fee_bps {
+    /// updates values that are provided as `Some(...)` in `update`.
+    ///
+    /// The update is all-or-nothing: every provided field is validated
+    /// before any is written, so an error leaves the account untouched.
+    pub fn apply_update(&mut self, update: ConfigUpdate, clock: &Clock) -> Result<()> {
+        // Validate every provided field before mutating anything, so a
+        // rejected field leaves the whole account unchanged.
+
+        if let Some(fee_bps) = update.fee_bps {
             Self::validate_fee_bps(fee_bps)?;
-            self.fee_bps = fee_bps;
         }
 
-        if let Some(max_modules) = maybe_max_modules_per_repo {
+        if let Some(max_modules) = update.max_modules_per_repo {
             Self::validate_max_modules(max_modules)?;
+        }
+
+        // Deactivating an active config starts a grace period; compute its
+        // end now so an invalid grace is caught before any write.
+        let deactivation_effective_at = match update.is_active {
+            Some(true) => Some(0),
+            Some(false) if self.is_active => {
+                let grace = update.deactivation_grace_seconds
+                    .unwrap_or(DEFAULT_DEACTIVATION_GRACE_SECONDS);
+                if grace > MAX_DEACTIVATION_GRACE_SECONDS {
+                    return err!(Unit09Error::ValueOutOfRange);
+                }
+                let effective_at = clock
+                    .unix_timestamp
+                    .checked_add(i64::from(grace))
+                    .ok_or(Unit09Error::CounterOverflow)?;
+                Some(effective_at)
+            }
+            _ => None,
+        };
+
+        if update.max_loc_per_observation == Some(0) || update.max_files_per_observation == Some(0) {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+
+        if let Some(category) = &update.default_category {
+            Self::validate_default_category(category)?;
+        }
+
+        if let Some(visibility) = update.default_visibility {
+            Visibility::try_from_u8(visibility)?;
+        }
+
+        // Apply.
+
+        if let Some(fee_bps) = update.fee_bps {
+            self.fee_bps = fee_bps;
+        }
+
+        if let Some(max_modules) = update.max_modules_per_repo {
             self.max_modules_per_repo = max_modules;
         }
 
-        if let Some(is_active) = maybe_is_active {
+        if let Some(effective_at) = deactivation_effective_at {
+            self.deactivation_effective_at = effective_at;
+        }
+        if let Some(is_active) = update.is_active {
             self.is_active = is_active;
         }
 
-        if let Some(policy_ref) = maybe_policy_ref {
+        if let Some(restrict) = update.restrict_repo_creation {
+            self.restrict_repo_creation = restrict;
+        }
+
+        if let Some(policy_ref) = update.policy_ref {
             self.policy_ref = policy_ref;
         }
 
+        if let Some(treasury) = update.treasury {
+            self.treasury = treasury;
+        }
+
+        if let Some(max_loc) = update.max_loc_per_observation {
+            self.max_loc_per_observation = max_loc;
+        }
+
+        if let Some(max_files) = update.max_files_per_observation {
+            self.max_files_per_observation = max_files;
+        }
+
+        if let Some(fork_fee) = update.fork_fee_lamports {
+            self.fork_fee_lamports = fork_fee;
+        }
+
+        if let Some(category) = update.default_category {
+            self.default_category = category;
+        }
+
+        if let Some(visibility) = update.default_visibility {
+            self.default_visibility = visibility;
+        }
+
+        if let Some(use_cpi_events) = update.use_cpi_events {
+            self.use_cpi_events = use_cpi_events;
+        }
+
+        if let Some(block) = update.block_parent_fork_deactivation {
+            self.block_parent_fork_deactivation = block;
+        }
+
+        if let Some(enforce) = update.enforce_unique_url {
+            self.enforce_unique_url = enforce;
+        }
+
+        if let Some(frozen) = update.metadata_frozen {
+            self.metadata_frozen = frozen;
+        }
+
+        if let Some(cooldown) = update.rename_cooldown_secs {
+            self.rename_cooldown_secs = cooldown;
+        }
+
+        if let Some(require) = update.require_verified_modules_for_fork {
+            self.require_verified_modules_for_fork = require;
+        }
+
+        if let Some(max_total) = update.max_total_repos {
+            self.max_total_repos = max_total;
+        }
+
+        if let Some(max_total) = update.max_total_modules {
+            self.max_total_modules = max_total;
+        }
+
+        if let Some(max_total) = update.max_total_forks {
+            self.max_total_forks = max_total;
+        }
+
+        if let Some(reward) = update.observation_reward_lamports {
+            self.observation_reward_lamports = reward;
+        }
+
+        if let Some(restrict) = update.restrict_fork_creation {
+            self.restrict_fork_creation = restrict;
+        }
+
+        if let Some(enforce) = update.enforce_content_addressed_modules {
+            self.enforce_content_addressed_modules = enforce;
+        }
+
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
@@ -173,16 +602,309 @@ impl Config {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Admin Quorum
+    // -----------------------------------------------------------------------
+
+    /// Ensure `admin` and `co_signers` together meet `admin_threshold`.
+    ///
+    /// `admin` must be the stored admin and counts as one approval; each
+    /// distinct co-signer other than the admin adds one. Callers pass only
+    /// co-signers that signed and hold the global admin role (see
+    /// `utils::admin_co_signers`).
+    pub fn assert_admin_quorum(&self, admin: Pubkey, co_signers: &[Pubkey]) -> Result<()> {
+        if admin != self.admin {
+            return err!(Unit09Error::InvalidAdmin);
+        }
+
+        let mut approvers = vec![admin];
+        for co_signer in co_signers {
+            if !approvers.contains(co_signer) {
+                approvers.push(*co_signer);
+            }
+        }
+        if approvers.len() < usize::from(self.admin_threshold) {
+            return err!(Unit09Error::AdminQuorumNotMet);
+        }
+        Ok(())
+    }
+
+    /// Hand the admin role to `new_admin`, returning the previous admin.
+    pub fn rotate_admin(&mut self, new_admin: Pubkey, clock: &Clock) -> Result<Pubkey> {
+        if new_admin == Pubkey::default() {
+            return err!(Unit09Error::InvalidAdmin);
+        }
+
+        let old_admin = self.admin;
+        self.admin = new_admin;
+        self.updated_at = clock.unix_timestamp;
+        Ok(old_admin)
+    }
+
+    /// Set how many distinct admins must sign high-risk admin instructions.
+    ///
+    /// Must be between 1 and `MAX_ADMIN_THRESHOLD`.
+    pub fn set_admin_threshold(&mut self, admin_threshold: u8, clock: &Clock) -> Result<()> {
+        if admin_threshold == 0 || admin_threshold > MAX_ADMIN_THRESHOLD {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+
+        self.admin_threshold = admin_threshold;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether the deployment still accepts activity at `now`.
+    ///
+    /// True while `is_active` is set, and also during the grace period
+    /// after it was cleared.
+    pub fn is_effectively_active(&self, now: i64) -> bool {
+        self.is_active || now < self.deactivation_effective_at
+    }
+
     /// Ensure that the configuration is currently active.
     ///
-    /// Handlers may call this at the start of critical instructions.
-    pub fn assert_active(&self) -> Result<()> {
-        if !self.is_active {
+    /// Handlers may call this at the start of critical instructions. A
+    /// deactivation only starts failing this check once its grace period
+    /// has elapsed. A paused deployment fails it too (see
+    /// `assert_not_paused`).
+    pub fn assert_active(&self, now: i64) -> Result<()> {
+        if !self.is_effectively_active(now) {
             return err!(Unit09Error::InvalidLifecycleState);
         }
+        self.assert_not_paused(now)
+    }
+
+    // -----------------------------------------------------------------------
+    // Pausing
+    // -----------------------------------------------------------------------
+
+    /// Pause the deployment, recording why.
+    ///
+    /// `auto_unpause_at` schedules the end of the pause; 0 keeps it in
+    /// place until `unpause`. A schedule that is not in the future is
+    /// rejected. Pausing again replaces the reason and schedule.
+    pub fn pause(&mut self, reason: String, auto_unpause_at: i64, clock: &Clock) -> Result<()> {
+        if reason.len() > MAX_PAUSE_REASON_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        if auto_unpause_at != 0 && auto_unpause_at <= clock.unix_timestamp {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+
+        self.paused = true;
+        self.pause_reason = reason;
+        self.auto_unpause_at = auto_unpause_at;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Lift a pause immediately and clear its reason and schedule.
+    pub fn unpause(&mut self, clock: &Clock) {
+        self.paused = false;
+        self.pause_reason = String::new();
+        self.auto_unpause_at = 0;
+        self.updated_at = clock.unix_timestamp;
+    }
+
+    /// Whether the deployment is paused at `now`.
+    ///
+    /// A scheduled pause counts as lifted once `now` reaches
+    /// `auto_unpause_at`, without anyone calling `unpause`.
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused && (self.auto_unpause_at == 0 || now < self.auto_unpause_at)
+    }
+
+    /// Ensure the deployment is not paused at `now`.
+    pub fn assert_not_paused(&self, now: i64) -> Result<()> {
+        if self.is_paused(now) {
+            return err!(Unit09Error::DeploymentPaused);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Kill Switch
+    // -----------------------------------------------------------------------
+
+    /// Engage the kill switch. Fails with `KillSwitchEngaged` if it already
+    /// is, so the original `killed_at` is kept.
+    pub fn engage_kill_switch(&mut self, clock: &Clock) -> Result<()> {
+        self.assert_not_killed()?;
+
+        self.global_kill = true;
+        self.killed_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Disengage the kill switch, returning when it was engaged.
+    pub fn disengage_kill_switch(&mut self, clock: &Clock) -> Result<i64> {
+        if !self.global_kill {
+            return err!(Unit09Error::KillSwitchNotEngaged);
+        }
+
+        let killed_at = self.killed_at;
+        self.global_kill = false;
+        self.killed_at = 0;
+        self.updated_at = clock.unix_timestamp;
+        Ok(killed_at)
+    }
+
+    /// Ensure the kill switch is not engaged.
+    ///
+    /// Every non-admin instruction that writes state calls this before its
+    /// other guards.
+    pub fn assert_not_killed(&self) -> Result<()> {
+        if self.global_kill {
+            return err!(Unit09Error::KillSwitchEngaged);
+        }
+        Ok(())
+    }
+
+    /// Ensure descriptive metadata may be edited.
+    ///
+    /// Fails with `MetadataFrozen` while `metadata_frozen` is set.
+    /// Observation paths do not call this.
+    pub fn assert_metadata_editable(&self) -> Result<()> {
+        if self.metadata_frozen {
+            return err!(Unit09Error::MetadataFrozen);
+        }
+        Ok(())
+    }
+
+    /// Ensure the registering authority may create a repository.
+    ///
+    /// With `restrict_repo_creation` off this always succeeds. Otherwise
+    /// `creator` must be the authority's registry entry and must currently
+    /// hold the `ROLE_LABEL_CREATOR` role.
+    pub fn assert_can_create_repo(
+        &self,
+        authority: &Pubkey,
+        creator: Option<&Authority>,
+        now: i64,
+    ) -> Result<()> {
+        if !self.restrict_repo_creation {
+            return Ok(());
+        }
+
+        match creator {
+            Some(entry)
+                if entry.authority == *authority && entry.has_role(ROLE_LABEL_CREATOR, now) =>
+            {
+                Ok(())
+            }
+            _ => err!(Unit09Error::RepoCreationRestricted),
+        }
+    }
+
+    /// Ensure the owner of a new fork may create it.
+    ///
+    /// With `restrict_fork_creation` off this always succeeds. Otherwise
+    /// `forker` must be the owner's registry entry and must currently hold
+    /// the `ROLE_LABEL_FORKER` role.
+    pub fn assert_can_create_fork(
+        &self,
+        owner: &Pubkey,
+        forker: Option<&Authority>,
+        now: i64,
+    ) -> Result<()> {
+        if !self.restrict_fork_creation {
+            return Ok(());
+        }
+
+        match forker {
+            Some(entry) if entry.authority == *owner && entry.has_role(ROLE_LABEL_FORKER, now) => {
+                Ok(())
+            }
+            _ => err!(Unit09Error::ForkCreationRestricted),
+        }
+    }
+
+    /// Ensure a new module's key is allowed under the content-addressing
+    /// policy.
+    ///
+    /// With `enforce_content_addressed_modules` off this always succeeds.
+    /// Otherwise `metadata_checksum` must be set and `module_key` must equal
+    /// `Module::content_addressed_key(metadata_checksum, repo)`.
+    pub fn assert_module_key_allowed(
+        &self,
+        module_key: &Pubkey,
+        metadata_checksum: &[u8; 32],
+        repo: &Pubkey,
+    ) -> Result<()> {
+        if !self.enforce_content_addressed_modules {
+            return Ok(());
+        }
+
+        if *metadata_checksum == [0u8; 32]
+            || *module_key != Module::content_addressed_key(metadata_checksum, repo)
+        {
+            return err!(Unit09Error::ModuleKeyNotContentAddressed);
+        }
+        Ok(())
+    }
+
+    /// Deployment-wide cap on the number of `kind` entities; 0 means no cap.
+    pub fn max_total(&self, kind: EntityKind) -> u64 {
+        match kind {
+            EntityKind::Repo => self.max_total_repos,
+            EntityKind::Module => self.max_total_modules,
+            EntityKind::Fork => self.max_total_forks,
+        }
+    }
+
+    /// Ensure one more `kind` entity fits its `max_total_*` cap, given the
+    /// `current_total` counted in `Metrics`.
+    ///
+    /// Fails with `GlobalLimitReached` once the cap is reached.
+    pub fn assert_below_max_total(&self, kind: EntityKind, current_total: u64) -> Result<()> {
+        let max_total = self.max_total(kind);
+        if max_total != 0 && current_total >= max_total {
+            return err!(Unit09Error::GlobalLimitReached);
+        }
         Ok(())
     }
 
+    /// Per-observation caps configured for this deployment.
+    pub fn observation_caps(&self) -> ObservationCaps {
+        ObservationCaps {
+            max_loc: self.max_loc_per_observation,
+            max_files: self.max_files_per_observation,
+        }
+    }
+
+    /// Whether a treasury wallet has been configured.
+    pub fn has_treasury(&self) -> bool {
+        self.treasury != Pubkey::default()
+    }
+
+    /// Account that collected fees must be sent to: the treasury when set,
+    /// otherwise the program's fee vault PDA.
+    pub fn fee_destination(&self, program_id: &Pubkey) -> Pubkey {
+        if self.has_treasury() {
+            self.treasury
+        } else {
+            fee_vault_pda(program_id).0
+        }
+    }
+
+    /// Category for a new module: `category` itself, or the configured
+    /// default when it is empty.
+    pub fn module_category_or_default(&self, category: String) -> String {
+        if category.is_empty() {
+            self.default_category.clone()
+        } else {
+            category
+        }
+    }
+
+    /// Visibility for a new module: the requested one, or the configured
+    /// default when none was given.
+    pub fn module_visibility_or_default(&self, visibility: Option<u8>) -> Result<Visibility> {
+        Visibility::try_from_u8(visibility.unwrap_or(self.default_visibility))
+    }
+
     /// Validate that a given fee value is within allowable bounds.
     fn validate_fee_bps(fee_bps: u16) -> Result<()> {
         if fee_bps > MAX_FEE_BPS {
@@ -191,6 +913,14 @@ impl Config {
         Ok(())
     }
 
+    /// Validate the default module category. Empty clears the default.
+    fn validate_default_category(category: &str) -> Result<()> {
+        if category.len() > MAX_MODULE_CATEGORY_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
     /// Validate that the maximum modules per repository value is non-zero
     /// and within a reasonable bound.
     fn validate_max_modules(max_modules: u32) -> Result<()> {
@@ -200,3 +930,789 @@ impl Config {
         Ok(())
     }
 }
+
+/// Kind of soft anomaly flagged on an accepted observation.
+///
+/// Encoded as a `u8` in `ObservationAnomalyDetected`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservationAnomalyKind {
+    /// Arrived within `MIN_OBSERVATION_INTERVAL_SECONDS` of the previous one.
+    TooFrequent = 0,
+    /// Above `OBSERVATION_SOFT_LIMIT_PCT` of a hard cap.
+    TooLarge = 1,
+    /// Average lines per file above `MAX_PLAUSIBLE_LOC_PER_FILE`.
+    BadRatio = 2,
+    /// Replayed or out-of-order submission. Reserved: observations do not
+    /// carry a nonce yet, so this kind is never produced on-chain.
+    StaleNonce = 3,
+}
+
+impl ObservationAnomalyKind {
+    /// Convert from raw `u8` to `ObservationAnomalyKind`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ObservationAnomalyKind::TooFrequent),
+            1 => Some(ObservationAnomalyKind::TooLarge),
+            2 => Some(ObservationAnomalyKind::BadRatio),
+            3 => Some(ObservationAnomalyKind::StaleNonce),
+            _ => None,
+        }
+    }
+
+    /// Convert `ObservationAnomalyKind` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Upper bounds applied to a single observation's reported values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObservationCaps {
+    pub max_loc: u64,
+    pub max_files: u32,
+}
+
+impl Default for ObservationCaps {
+    /// The compile-time caps, used when no `Config` is available.
+    fn default() -> Self {
+        Self {
+            max_loc: MAX_LOC_PER_OBSERVATION,
+            max_files: MAX_FILES_PER_OBSERVATION,
+        }
+    }
+}
+
+impl ObservationCaps {
+    /// Caps from `config` when given, otherwise the compile-time defaults.
+    pub fn from_config(config: Option<&Config>) -> Self {
+        config.map(Config::observation_caps).unwrap_or_default()
+    }
+
+    /// Reject observations that exceed either cap.
+    pub fn check(&self, lines_of_code: u64, files_processed: u32) -> Result<()> {
+        if lines_of_code > self.max_loc || files_processed > self.max_files {
+            return err!(Unit09Error::ObservationDataTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Soft anomalies for an observation that passed `check`.
+    ///
+    /// `seconds_since_last` is the gap to the repository's previous
+    /// observation, or `None` for the first one. Each entry pairs the kind
+    /// with the offending value.
+    pub fn anomalies(
+        &self,
+        lines_of_code: u64,
+        files_processed: u32,
+        seconds_since_last: Option<i64>,
+    ) -> Vec<(ObservationAnomalyKind, u64)> {
+        let mut found = Vec::new();
+
+        if let Some(gap) = seconds_since_last {
+            if gap < MIN_OBSERVATION_INTERVAL_SECONDS {
+                found.push((ObservationAnomalyKind::TooFrequent, gap.max(0) as u64));
+            }
+        }
+
+        let soft = |cap: u64| cap.saturating_mul(OBSERVATION_SOFT_LIMIT_PCT) / 100;
+        if lines_of_code > soft(self.max_loc) {
+            found.push((ObservationAnomalyKind::TooLarge, lines_of_code));
+        } else if u64::from(files_processed) > soft(u64::from(self.max_files)) {
+            found.push((ObservationAnomalyKind::TooLarge, u64::from(files_processed)));
+        }
+
+        if files_processed > 0 {
+            let loc_per_file = lines_of_code / u64::from(files_processed);
+            if loc_per_file > MAX_PLAUSIBLE_LOC_PER_FILE {
+                found.push((ObservationAnomalyKind::BadRatio, loc_per_file));
+            }
+        }
+
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, new_authority, new_config, new_metrics};
+
+    /// `Config` as laid out at schema v1.
+    #[derive(AnchorSerialize)]
+    struct ConfigV1 {
+        admin: Pubkey,
+        fee_bps: u16,
+        max_modules_per_repo: u32,
+        schema_version: u8,
+        is_active: bool,
+        created_at: i64,
+        updated_at: i64,
+        policy_ref: [u8; 32],
+        bump: u8,
+        reserved: [u8; 63],
+    }
+
+    fn v1_config_bytes() -> (ConfigV1, Vec<u8>) {
+        let v1 = ConfigV1 {
+            admin: Pubkey::new_unique(),
+            fee_bps: 250,
+            max_modules_per_repo: 128,
+            schema_version: 1,
+            is_active: true,
+            created_at: 100,
+            updated_at: 200,
+            policy_ref: [7u8; 32],
+            bump: 254,
+            reserved: [0u8; 63],
+        };
+        let mut data = Config::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut data).unwrap();
+        (v1, data)
+    }
+
+    #[test]
+    fn v1_account_reads_unchanged_once_grown() {
+        let (v1, mut data) = v1_config_bytes();
+        assert_eq!(data.len(), Config::V1_LEN);
+        data.resize(Config::LEN, 0);
+
+        let mut config = Config::try_deserialize(&mut &data[..]).unwrap();
+        config.migrate_from_v1();
+
+        assert_eq!(config.admin, v1.admin);
+        assert_eq!(config.fee_bps, 250);
+        assert_eq!(config.max_modules_per_repo, 128);
+        assert!(config.is_active);
+        assert_eq!(config.created_at, 100);
+        assert_eq!(config.updated_at, 200);
+        assert_eq!(config.policy_ref, [7u8; 32]);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.deactivation_effective_at, 0);
+        assert_eq!(config.treasury, Pubkey::default());
+        assert!(!config.paused && !config.global_kill);
+        assert_eq!(config.default_visibility, Visibility::Public.as_u8());
+        assert_eq!(config.admin_threshold, 1);
+        assert!(config.observation_caps().check(MAX_LOC_PER_OBSERVATION, 1).is_ok());
+        assert!(config.default_category.is_empty());
+    }
+
+    #[test]
+    fn stored_admin_reads_either_layout() {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 1_000_000u64;
+
+        let (v1, mut data) = v1_config_bytes();
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(Config::stored_admin(&info).unwrap(), v1.admin);
+
+        let admin = Pubkey::new_unique();
+        let mut data = Vec::new();
+        new_config(admin, &clock_at(100)).try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(Config::stored_admin(&info).unwrap(), admin);
+
+        let foreign = Pubkey::new_unique();
+        let info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &foreign, false, 0);
+        assert!(Config::stored_admin(&info).is_err());
+    }
+
+    fn restrict(config: &mut Config, clock: &Clock) {
+        config
+            .apply_update(
+                ConfigUpdate {
+                    restrict_repo_creation: Some(true),
+                    ..ConfigUpdate::default()
+                },
+                clock,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn open_by_default_for_anyone() {
+        let clock = clock_at(1_000);
+        let config = new_config(Pubkey::new_unique(), &clock);
+
+        assert!(!config.restrict_repo_creation);
+        assert!(config
+            .assert_can_create_repo(&Pubkey::new_unique(), None, 1_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn restricted_rejects_non_creator() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        restrict(&mut config, &clock);
+
+        let signer = Pubkey::new_unique();
+        assert!(config.assert_can_create_repo(&signer, None, 1_000).is_err());
+
+        let mut observer = new_authority(signer, true, Pubkey::default(), &clock);
+        observer.add_role(ROLE_LABEL_OBSERVER, 0, &clock).unwrap();
+        assert!(config
+            .assert_can_create_repo(&signer, Some(&observer), 1_000)
+            .is_err());
+    }
+
+    #[test]
+    fn restricted_accepts_creator() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        restrict(&mut config, &clock);
+
+        let signer = Pubkey::new_unique();
+        let mut creator = new_authority(signer, true, Pubkey::default(), &clock);
+        creator.add_role(ROLE_LABEL_CREATOR, 0, &clock).unwrap();
+
+        assert!(config
+            .assert_can_create_repo(&signer, Some(&creator), 1_000)
+            .is_ok());
+
+        // Someone else's registry entry does not count.
+        assert!(config
+            .assert_can_create_repo(&Pubkey::new_unique(), Some(&creator), 1_000)
+            .is_err());
+    }
+
+    fn restrict_forks(config: &mut Config, clock: &Clock) {
+        config
+            .apply_update(
+                ConfigUpdate {
+                    restrict_fork_creation: Some(true),
+                    ..ConfigUpdate::default()
+                },
+                clock,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn anyone_forks_while_unrestricted() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        assert!(!config.restrict_fork_creation);
+
+        // The repo allowlist does not gate forks.
+        restrict(&mut config, &clock);
+        assert!(config
+            .assert_can_create_fork(&Pubkey::new_unique(), None, 1_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn restricted_forks_reject_non_forker() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        restrict_forks(&mut config, &clock);
+
+        let owner = Pubkey::new_unique();
+        assert!(config.assert_can_create_fork(&owner, None, 1_000).is_err());
+
+        let mut creator = new_authority(owner, true, Pubkey::default(), &clock);
+        creator.add_role(ROLE_LABEL_CREATOR, 0, &clock).unwrap();
+        assert!(config
+            .assert_can_create_fork(&owner, Some(&creator), 1_000)
+            .is_err());
+    }
+
+    #[test]
+    fn restricted_forks_accept_forker() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        restrict_forks(&mut config, &clock);
+
+        let owner = Pubkey::new_unique();
+        let mut forker = new_authority(owner, true, Pubkey::default(), &clock);
+        forker.add_role(ROLE_LABEL_FORKER, 0, &clock).unwrap();
+
+        assert!(config
+            .assert_can_create_fork(&owner, Some(&forker), 1_000)
+            .is_ok());
+        assert!(config
+            .assert_can_create_fork(&Pubkey::new_unique(), Some(&forker), 1_000)
+            .is_err());
+    }
+
+    fn enforce_content_addressing(config: &mut Config, clock: &Clock) {
+        config
+            .apply_update(
+                ConfigUpdate {
+                    enforce_content_addressed_modules: Some(true),
+                    ..ConfigUpdate::default()
+                },
+                clock,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn any_module_key_is_allowed_by_default() {
+        let clock = clock_at(1_000);
+        let config = new_config(Pubkey::new_unique(), &clock);
+
+        assert!(!config.enforce_content_addressed_modules);
+        assert!(config
+            .assert_module_key_allowed(&Pubkey::new_unique(), &[0u8; 32], &Pubkey::new_unique())
+            .is_ok());
+    }
+
+    #[test]
+    fn content_addressing_rejects_mismatched_module_key() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        enforce_content_addressing(&mut config, &clock);
+
+        let repo = Pubkey::new_unique();
+        let checksum = [7u8; 32];
+        assert!(config
+            .assert_module_key_allowed(&Pubkey::new_unique(), &checksum, &repo)
+            .is_err());
+
+        // A key derived for another repo or another checksum does not count,
+        // and a missing checksum never matches.
+        let other_repo = Module::content_addressed_key(&checksum, &Pubkey::new_unique());
+        assert!(config
+            .assert_module_key_allowed(&other_repo, &checksum, &repo)
+            .is_err());
+        let other_checksum = Module::content_addressed_key(&[8u8; 32], &repo);
+        assert!(config
+            .assert_module_key_allowed(&other_checksum, &checksum, &repo)
+            .is_err());
+        let unchecked = Module::content_addressed_key(&[0u8; 32], &repo);
+        assert!(config
+            .assert_module_key_allowed(&unchecked, &[0u8; 32], &repo)
+            .is_err());
+    }
+
+    #[test]
+    fn content_addressing_accepts_derived_module_key() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        enforce_content_addressing(&mut config, &clock);
+
+        let repo = Pubkey::new_unique();
+        let checksum = [7u8; 32];
+        let module_key = Module::content_addressed_key(&checksum, &repo);
+
+        assert_eq!(module_key, Module::content_addressed_key(&checksum, &repo));
+        assert!(config
+            .assert_module_key_allowed(&module_key, &checksum, &repo)
+            .is_ok());
+    }
+
+    #[test]
+    fn fees_fall_back_to_vault_without_treasury() {
+        let clock = clock_at(1_000);
+        let config = new_config(Pubkey::new_unique(), &clock);
+        let program_id = Pubkey::new_unique();
+
+        assert!(!config.has_treasury());
+        assert_eq!(
+            config.fee_destination(&program_id),
+            fee_vault_pda(&program_id).0
+        );
+    }
+
+    #[test]
+    fn treasury_redirects_fees_until_cleared() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        let program_id = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    treasury: Some(treasury),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+        assert_eq!(config.fee_destination(&program_id), treasury);
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    treasury: Some(Pubkey::default()),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+        assert_eq!(
+            config.fee_destination(&program_id),
+            fee_vault_pda(&program_id).0
+        );
+    }
+
+    fn deactivate(config: &mut Config, grace: Option<u32>, clock: &Clock) -> Result<()> {
+        config.apply_update(
+            ConfigUpdate {
+                is_active: Some(false),
+                deactivation_grace_seconds: grace,
+                ..ConfigUpdate::default()
+            },
+            clock,
+        )
+    }
+
+    #[test]
+    fn deactivation_waits_for_grace_period() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        deactivate(&mut config, Some(300), &clock_at(2_000)).unwrap();
+
+        assert!(!config.is_active);
+        assert_eq!(config.deactivation_effective_at, 2_300);
+        assert!(config.assert_active(2_000).is_ok());
+        assert!(config.assert_active(2_299).is_ok());
+        assert!(config.assert_active(2_300).is_err());
+        assert!(config.assert_active(9_999).is_err());
+    }
+
+    #[test]
+    fn default_grace_applies_and_reactivation_clears_it() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        deactivate(&mut config, None, &clock_at(2_000)).unwrap();
+        let effective_at = 2_000 + i64::from(DEFAULT_DEACTIVATION_GRACE_SECONDS);
+        assert_eq!(config.deactivation_effective_at, effective_at);
+
+        // Repeating the deactivation must not extend the window.
+        deactivate(&mut config, Some(MAX_DEACTIVATION_GRACE_SECONDS), &clock_at(2_100))
+            .unwrap();
+        assert_eq!(config.deactivation_effective_at, effective_at);
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    is_active: Some(true),
+                    ..ConfigUpdate::default()
+                },
+                &clock_at(2_200),
+            )
+            .unwrap();
+        assert_eq!(config.deactivation_effective_at, 0);
+        assert!(config.assert_active(i64::MAX).is_ok());
+    }
+
+    #[test]
+    fn zero_grace_deactivates_immediately_and_excess_is_rejected() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        let too_long = Some(MAX_DEACTIVATION_GRACE_SECONDS + 1);
+        assert!(deactivate(&mut config, too_long, &clock_at(2_000)).is_err());
+        assert!(config.is_active);
+
+        deactivate(&mut config, Some(0), &clock_at(2_000)).unwrap();
+        assert!(config.assert_active(2_000).is_err());
+    }
+
+    #[test]
+    fn invalid_field_rolls_back_the_whole_update() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        let before = (config.fee_bps, config.max_modules_per_repo, config.updated_at);
+
+        assert!(config
+            .apply_update(
+                ConfigUpdate {
+                    fee_bps: Some(400),
+                    max_modules_per_repo: Some(0),
+                    is_active: Some(false),
+                    ..ConfigUpdate::default()
+                },
+                &clock_at(2_000),
+            )
+            .is_err());
+
+        assert_eq!(
+            (config.fee_bps, config.max_modules_per_repo, config.updated_at),
+            before
+        );
+        assert!(config.is_active);
+        assert_eq!(config.deactivation_effective_at, 0);
+    }
+
+    #[test]
+    fn kill_switch_blocks_non_admin_paths_until_disengaged() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        assert!(config.assert_not_killed().is_ok());
+
+        config.engage_kill_switch(&clock_at(2_000)).unwrap();
+        assert!(config.assert_not_killed().is_err());
+        assert!(config.engage_kill_switch(&clock_at(2_500)).is_err());
+        assert_eq!(config.killed_at, 2_000);
+
+        // Admin recovery still goes through while killed.
+        config
+            .apply_update(
+                ConfigUpdate {
+                    fee_bps: Some(100),
+                    ..ConfigUpdate::default()
+                },
+                &clock_at(3_000),
+            )
+            .unwrap();
+        config.pause("incident".to_string(), 0, &clock_at(3_000)).unwrap();
+        assert_eq!(config.fee_bps, 100);
+
+        assert_eq!(config.disengage_kill_switch(&clock_at(4_000)).unwrap(), 2_000);
+        assert!(config.assert_not_killed().is_ok());
+        assert_eq!(config.killed_at, 0);
+        assert!(config.disengage_kill_switch(&clock_at(5_000)).is_err());
+        // The pause is independent of the kill switch.
+        assert!(config.is_paused(5_000));
+    }
+
+    #[test]
+    fn admin_quorum_counts_distinct_signers() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        let admin = config.admin;
+        let (second, third) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        // A fresh config needs the admin alone.
+        assert!(config.assert_admin_quorum(admin, &[]).is_ok());
+
+        config.set_admin_threshold(3, &clock).unwrap();
+        assert!(config.assert_admin_quorum(admin, &[second, third]).is_ok());
+        // Repeating a co-signer, or the admin, adds nothing.
+        assert!(config.assert_admin_quorum(admin, &[second, second]).is_err());
+        assert!(config.assert_admin_quorum(admin, &[admin, second]).is_err());
+        // Co-signers cannot act without the admin.
+        assert!(config.assert_admin_quorum(second, &[admin, third]).is_err());
+    }
+
+    #[test]
+    fn admin_threshold_and_rotation_are_validated() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        let old_admin = config.admin;
+
+        assert!(config.set_admin_threshold(0, &clock).is_err());
+        assert!(config
+            .set_admin_threshold(MAX_ADMIN_THRESHOLD + 1, &clock)
+            .is_err());
+        assert_eq!(config.admin_threshold, 1);
+
+        assert!(config.rotate_admin(Pubkey::default(), &clock).is_err());
+        let new_admin = Pubkey::new_unique();
+        assert_eq!(config.rotate_admin(new_admin, &clock_at(2_000)).unwrap(), old_admin);
+        assert_eq!((config.admin, config.updated_at), (new_admin, 2_000));
+    }
+
+    #[test]
+    fn fork_fee_defaults_to_zero_and_updates_independently() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        assert_eq!(config.fork_fee_lamports, 0);
+
+        let fee_bps = config.fee_bps;
+        config
+            .apply_update(
+                ConfigUpdate {
+                    fork_fee_lamports: Some(5_000),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+        assert_eq!(config.fork_fee_lamports, 5_000);
+        assert_eq!(config.fee_bps, fee_bps);
+
+        restrict(&mut config, &clock);
+        assert_eq!(config.fork_fee_lamports, 5_000);
+    }
+
+    fn set_module_defaults(
+        config: &mut Config,
+        category: Option<String>,
+        visibility: Option<u8>,
+        clock: &Clock,
+    ) -> Result<()> {
+        config.apply_update(
+            ConfigUpdate {
+                default_category: category,
+                default_visibility: visibility,
+                ..ConfigUpdate::default()
+            },
+            clock,
+        )
+    }
+
+    #[test]
+    fn omitted_category_and_visibility_use_configured_defaults() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        assert_eq!(config.module_category_or_default(String::new()), "");
+        assert_eq!(
+            config.module_visibility_or_default(None).unwrap(),
+            Visibility::Public
+        );
+
+        set_module_defaults(
+            &mut config,
+            Some("library".to_string()),
+            Some(Visibility::Unlisted.as_u8()),
+            &clock,
+        )
+        .unwrap();
+        assert_eq!(config.module_category_or_default(String::new()), "library");
+        assert_eq!(
+            config.module_visibility_or_default(None).unwrap(),
+            Visibility::Unlisted
+        );
+    }
+
+    #[test]
+    fn provided_category_and_visibility_override_defaults() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        set_module_defaults(
+            &mut config,
+            Some("library".to_string()),
+            Some(Visibility::Unlisted.as_u8()),
+            &clock,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.module_category_or_default("indexer".to_string()),
+            "indexer"
+        );
+        assert_eq!(
+            config
+                .module_visibility_or_default(Some(Visibility::Private.as_u8()))
+                .unwrap(),
+            Visibility::Private
+        );
+    }
+
+    #[test]
+    fn invalid_module_defaults_are_rejected() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        let too_long = "c".repeat(MAX_MODULE_CATEGORY_LEN + 1);
+
+        assert!(set_module_defaults(&mut config, Some(too_long), None, &clock).is_err());
+        assert!(set_module_defaults(&mut config, None, Some(u8::MAX), &clock).is_err());
+        assert_eq!(config.default_category, "");
+        assert_eq!(config.default_visibility, Visibility::Public.as_u8());
+    }
+
+    #[test]
+    fn scheduled_pause_lifts_by_itself() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        config
+            .pause("rpc incident".to_string(), 5_000, &clock_at(2_000))
+            .unwrap();
+
+        assert_eq!(config.pause_reason, "rpc incident");
+        assert!(config.assert_active(2_000).is_err());
+        assert!(config.assert_not_paused(4_999).is_err());
+        assert!(config.assert_not_paused(5_000).is_ok());
+        assert!(config.assert_active(5_000).is_ok());
+    }
+
+    #[test]
+    fn unscheduled_pause_lasts_until_unpaused() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        config
+            .pause("migration".to_string(), 0, &clock_at(2_000))
+            .unwrap();
+        assert!(config.is_paused(i64::MAX));
+
+        config.unpause(&clock_at(3_000));
+        assert!(!config.is_paused(3_000));
+        assert_eq!(config.pause_reason, "");
+        assert_eq!(config.auto_unpause_at, 0);
+    }
+
+    #[test]
+    fn invalid_pauses_are_rejected() {
+        let mut config = new_config(Pubkey::new_unique(), &clock_at(1_000));
+        let too_long = "r".repeat(MAX_PAUSE_REASON_LEN + 1);
+
+        assert!(config.pause(too_long, 0, &clock_at(2_000)).is_err());
+        assert!(config
+            .pause("late".to_string(), 2_000, &clock_at(2_000))
+            .is_err());
+        assert!(!config.paused);
+    }
+
+    #[test]
+    fn frozen_metadata_blocks_edits_but_not_observations() {
+        let clock = clock_at(1_000);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        assert!(config.assert_metadata_editable().is_ok());
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    metadata_frozen: Some(true),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+
+        assert!(config.assert_metadata_editable().is_err());
+        assert!(config.assert_active(clock.unix_timestamp).is_ok());
+        assert!(config.observation_caps().check(1_000, 10).is_ok());
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    metadata_frozen: Some(false),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+        assert!(config.assert_metadata_editable().is_ok());
+    }
+
+    #[test]
+    fn anomalies_flag_soft_thresholds_only() {
+        let caps = ObservationCaps::default();
+        let soft_loc = MAX_LOC_PER_OBSERVATION * OBSERVATION_SOFT_LIMIT_PCT / 100;
+
+        assert!(caps.anomalies(soft_loc, 1_000, None).is_empty());
+        assert_eq!(
+            caps.anomalies(soft_loc + 1, 1_000, Some(3_600)),
+            vec![(ObservationAnomalyKind::TooLarge, soft_loc + 1)]
+        );
+        assert_eq!(
+            caps.anomalies(1_000, 10, Some(5)),
+            vec![(ObservationAnomalyKind::TooFrequent, 5)]
+        );
+        assert_eq!(
+            caps.anomalies(50_000, 1, None),
+            vec![(ObservationAnomalyKind::BadRatio, 50_000)]
+        );
+    }
+
+    #[test]
+    fn creations_stop_at_the_global_cap() {
+        let clock = clock_at(100);
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+        let mut metrics = new_metrics(&clock);
+        config.max_total_forks = 3;
+
+        for _ in 0..3 {
+            let total = metrics.total(EntityKind::Fork);
+            config.assert_below_max_total(EntityKind::Fork, total).unwrap();
+            metrics.increment_forks().unwrap();
+        }
+        let total = metrics.total(EntityKind::Fork);
+        assert!(config.assert_below_max_total(EntityKind::Fork, total).is_err());
+
+        // Other kinds keep their own cap, and 0 disables one.
+        assert!(config.assert_below_max_total(EntityKind::Repo, u64::MAX).is_ok());
+        config.max_total_forks = 0;
+        assert!(config.assert_below_max_total(EntityKind::Fork, total).is_ok());
+    }
+}
@@ -17,12 +17,81 @@
 //! - `Fork` account structure
 //! - size constants for rent-exempt allocation
 //! - helpers for ownership checks, activation, lineage, and validation
+//! - `fork_module_diff` for comparing module sets between forks
 //! ===========================================================================
 
+use std::fmt;
+
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::Module;
+
+/// Readiness status of a fork.
+///
+/// Encoded as a `u8` in the `Fork` account. Forks move forward through
+/// `Draft -> Testing -> Released -> Retired`; `Testing` may fall back to
+/// `Draft` for rework, and any non-retired fork may be retired early.
+/// `Retired` is terminal.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForkStatus {
+    /// Being assembled; not ready for use.
+    Draft = 0,
+    /// Under evaluation; usable in test flows only.
+    Testing = 1,
+    /// Released for general use. The only status in which a fork is active.
+    Released = 2,
+    /// Permanently retired.
+    Retired = 3,
+}
+
+impl ForkStatus {
+    /// Convert from raw `u8` to `ForkStatus`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ForkStatus::Draft),
+            1 => Some(ForkStatus::Testing),
+            2 => Some(ForkStatus::Released),
+            3 => Some(ForkStatus::Retired),
+            _ => None,
+        }
+    }
+
+    /// Convert from raw `u8`, failing with `InvalidForkStatus` on unknown values.
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        Self::from_u8(value).ok_or_else(|| error!(Unit09Error::InvalidForkStatus))
+    }
+
+    /// Convert `ForkStatus` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns true if a fork in this status is considered active.
+    pub fn is_active(self) -> bool {
+        matches!(self, ForkStatus::Released)
+    }
+
+    /// Returns true if moving from `self` to `next` is allowed.
+    ///
+    /// Staying in the same status is always allowed (no-op).
+    pub fn can_transition_to(self, next: ForkStatus) -> bool {
+        use ForkStatus::*;
+
+        self == next
+            || matches!(
+                (self, next),
+                (Draft, Testing)
+                    | (Draft, Retired)
+                    | (Testing, Draft)
+                    | (Testing, Released)
+                    | (Testing, Retired)
+                    | (Released, Retired)
+            )
+    }
+}
 
 /// Fork account tracked by Unit09.
 ///
@@ -74,9 +143,14 @@ pub struct Fork {
 
     /// Whether this fork is currently active.
     ///
-    /// Inactive forks should not be used in new flows by default.
+    /// Derived from `status`: a fork is active exactly when it is
+    /// `ForkStatus::Released`. Inactive forks should not be used in new
+    /// flows by default.
     pub is_active: bool,
 
+    /// Readiness status, encoded as a `ForkStatus` discriminant.
+    pub status: u8,
+
     /// Whether this fork is considered “root-level” or derived.
     ///
     /// This field is purely informational and is not strictly required
@@ -111,8 +185,33 @@ pub struct Fork {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// `ForkStatus` of the parent fork when this fork was created, or
+    /// `Fork::NO_PARENT_STATUS` when the parent is not a fork.
+    pub parent_status_at_fork: u8,
+
+    /// Number of live forks created with this fork as their parent.
+    ///
+    /// Incremented by `create_fork` and decremented when a child is closed.
+    pub child_count: u32,
+
+    /// Unix timestamp after which anyone may retire this fork via
+    /// `expire_fork`, or 0 if it never expires.
+    ///
+    /// Meant for short-lived forks such as CI experiments.
+    pub expires_at: i64,
+
+    /// First fork of the lineage this fork belongs to: the parent fork's
+    /// `root`, or this fork's own PDA when it has no parent fork.
+    ///
+    /// Lets clients group a lineage without walking the parent chain.
+    pub root: Pubkey,
+
+    /// Position of this fork among the forks created by its owner, from the
+    /// owner's `OwnerForkCounter` (0 for the owner's first fork).
+    pub owner_fork_index: u32,
+
     /// Reserved bytes for future upgrades.
-    pub reserved: [u8; 62],
+    pub reserved: [u8; 12],
 }
 
 impl Fork {
@@ -128,6 +227,9 @@ impl Fork {
     /// Maximum length in bytes (UTF-8) for the `tags` field.
     pub const MAX_TAGS_LEN: usize = MAX_TAGS_LEN;
 
+    /// `parent_status_at_fork` value for forks without a parent fork.
+    pub const NO_PARENT_STATUS: u8 = u8::MAX;
+
     /// Total serialized length of the `Fork` account.
     ///
     /// Strings are encoded as:
@@ -140,6 +242,7 @@ impl Fork {
         + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri: String
         + 4 + Self::MAX_TAGS_LEN // tags: String
         + 1 // is_active: bool
+        + 1 // status: u8
         + 1 // is_root: bool
         + 2 // depth: u16
         + 8 // usage_count: u64
@@ -148,7 +251,12 @@ impl Fork {
         + 8 // updated_at: i64
         + 1 // schema_version: u8
         + 1 // bump: u8
-        + 62; // reserved: [u8; 62]
+        + 1 // parent_status_at_fork: u8
+        + 4 // child_count: u32
+        + 8 // expires_at: i64
+        + 32 // root: Pubkey
+        + 4 // owner_fork_index: u32
+        + 12; // reserved: [u8; 12]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -167,6 +275,7 @@ impl Fork {
         tags: String,
         is_root: bool,
         depth: u16,
+        parent_status: Option<ForkStatus>,
         bump: u8,
         clock: &Clock,
     ) -> Result<()> {
@@ -181,7 +290,8 @@ impl Fork {
         self.label = label;
         self.metadata_uri = metadata_uri;
         self.tags = tags;
-        self.is_active = true;
+        self.status = ForkStatus::Draft.as_u8();
+        self.is_active = ForkStatus::Draft.is_active();
         self.is_root = is_root;
         self.depth = depth;
         self.usage_count = 0;
@@ -190,7 +300,13 @@ impl Fork {
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 62];
+        self.parent_status_at_fork =
+            parent_status.map_or(Self::NO_PARENT_STATUS, ForkStatus::as_u8);
+        self.child_count = 0;
+        self.expires_at = 0;
+        self.root = Pubkey::default();
+        self.owner_fork_index = 0;
+        self.reserved = [0u8; 12];
 
         Ok(())
     }
@@ -208,7 +324,7 @@ impl Fork {
         maybe_label: Option<String>,
         maybe_metadata_uri: Option<String>,
         maybe_tags: Option<String>,
-        maybe_is_active: Option<bool>,
+        maybe_status: Option<ForkStatus>,
         clock: &Clock,
     ) -> Result<()> {
         if let Some(label) = maybe_label {
@@ -226,14 +342,168 @@ impl Fork {
             self.tags = tags;
         }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
+        if let Some(status) = maybe_status {
+            self.transition_to(status)?;
         }
 
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
+    /// Move this fork to `next`, enforcing `ForkStatus::can_transition_to`
+    /// and keeping `is_active` in sync.
+    pub fn transition_to(&mut self, next: ForkStatus) -> Result<()> {
+        let current = self.status()?;
+        if !current.can_transition_to(next) {
+            return err!(Unit09Error::InvalidForkStatusTransition);
+        }
+
+        self.status = next.as_u8();
+        self.is_active = next.is_active();
+        Ok(())
+    }
+
+    /// Decoded readiness status of this fork.
+    pub fn status(&self) -> Result<ForkStatus> {
+        ForkStatus::try_from_u8(self.status)
+    }
+
+    /// Ensure this fork can serve as the parent of a new fork and return its
+    /// status, to be recorded on the child.
+    ///
+    /// Retired forks are refused unless `allow_retired` is set, which is
+    /// meant for deliberately branching off an archived variant.
+    pub fn status_as_parent(&self, allow_retired: bool) -> Result<ForkStatus> {
+        let status = self.status()?;
+        if status == ForkStatus::Retired && !allow_retired {
+            return err!(Unit09Error::ParentForkRetired);
+        }
+        Ok(status)
+    }
+
+    /// `ForkStatus` of the parent at creation time, if the parent is a fork.
+    pub fn parent_status_at_fork(&self) -> Option<ForkStatus> {
+        ForkStatus::from_u8(self.parent_status_at_fork)
+    }
+
+    /// Lineage root to record on a new fork at `fork_key`, given its
+    /// parent fork and that parent's address, if any.
+    ///
+    /// Parent forks created before `root` was tracked have none recorded;
+    /// they start a lineage of their own.
+    pub fn lineage_root(fork_key: Pubkey, parent: Option<(Pubkey, &Fork)>) -> Pubkey {
+        match parent {
+            Some((parent_key, parent)) if parent.root == Pubkey::default() => parent_key,
+            Some((_, parent)) => parent.root,
+            None => fork_key,
+        }
+    }
+
+    /// Whether this fork, at `fork_key`, was created under a parent fork,
+    /// whose `child_count` then includes it.
+    ///
+    /// Read from `root`, which names the fork itself when it has no parent
+    /// fork. Forks created before `root` was tracked read as having none.
+    pub fn has_parent_fork(&self, fork_key: &Pubkey) -> bool {
+        self.root != Pubkey::default() && self.root != *fork_key
+    }
+
+    /// Record a new child fork created under this one.
+    pub fn add_child(&mut self) -> Result<()> {
+        self.child_count = self
+            .child_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Record that one of this fork's children was closed.
+    pub fn remove_child(&mut self) -> Result<()> {
+        self.child_count = self
+            .child_count
+            .checked_sub(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Check a move to `next` against this fork's children.
+    ///
+    /// A fork with children that goes from active to inactive leaves them
+    /// under an inactive parent. When `block_if_children` is set that is
+    /// refused with `ForkHasChildren`; otherwise this returns `true` so the
+    /// caller can warn about it. Any other move returns `false`.
+    pub fn check_deactivation(&self, next: ForkStatus, block_if_children: bool) -> Result<bool> {
+        let deactivates = self.is_active && !next.is_active();
+        if !deactivates || self.child_count == 0 {
+            return Ok(false);
+        }
+        if block_if_children {
+            return err!(Unit09Error::ForkHasChildren);
+        }
+        Ok(true)
+    }
+
+    /// Check a move to `next` against the modules the fork is made of.
+    ///
+    /// When `require_verified` is set, activating the fork needs at least
+    /// one module (`MissingRequiredAccount`) and every module in `modules`
+    /// verified (`ForkModuleUnverified`). Any other move passes.
+    pub fn check_activation(
+        &self,
+        next: ForkStatus,
+        require_verified: bool,
+        modules: &[Module],
+    ) -> Result<()> {
+        let activates = !self.is_active && next.is_active();
+        if !require_verified || !activates {
+            return Ok(());
+        }
+        if modules.is_empty() {
+            return err!(Unit09Error::MissingRequiredAccount);
+        }
+        if modules.iter().any(|module| !module.is_verified) {
+            return err!(Unit09Error::ForkModuleUnverified);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Expiry
+    // -----------------------------------------------------------------------
+
+    /// Set when this fork expires; 0 means never.
+    ///
+    /// A non-zero expiry must lie in the future.
+    pub fn set_expiry(&mut self, expires_at: i64, clock: &Clock) -> Result<()> {
+        if expires_at != 0 && expires_at <= clock.unix_timestamp {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.expires_at = expires_at;
+        Ok(())
+    }
+
+    /// Whether this fork has an expiry and it has passed at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+
+    /// Retire an expired fork.
+    ///
+    /// Fails with `ForkNotExpired` before the expiry (or when there is
+    /// none) and with `ForkInactive` if the fork is already retired.
+    pub fn expire(&mut self, clock: &Clock) -> Result<()> {
+        if !self.is_expired(clock.unix_timestamp) {
+            return err!(Unit09Error::ForkNotExpired);
+        }
+        if self.status()? == ForkStatus::Retired {
+            return err!(Unit09Error::ForkInactive);
+        }
+
+        self.transition_to(ForkStatus::Retired)?;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
     /// Optionally update the parent and depth for this fork in a controlled
     /// way, if your protocol allows reparenting.
     pub fn reparent(
@@ -340,3 +610,348 @@ impl Fork {
         Ok(())
     }
 }
+
+/// One-line summary for logs and tooling, e.g.
+/// `fork <fork_key> "unit09-lab-alpha" released depth=1 children=2 usage=5`.
+///
+/// Meant for tests and off-chain tools; no handler formats a fork, so it
+/// adds nothing to on-chain logs.
+impl fmt::Display for Fork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match ForkStatus::from_u8(self.status) {
+            Some(ForkStatus::Draft) => "draft",
+            Some(ForkStatus::Testing) => "testing",
+            Some(ForkStatus::Released) => "released",
+            Some(ForkStatus::Retired) => "retired",
+            None => "unknown",
+        };
+        write!(
+            f,
+            "fork {} \"{}\" {} depth={} children={} usage={}",
+            self.fork_key, self.label, status, self.depth, self.child_count, self.usage_count,
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Module Set Diff
+// ---------------------------------------------------------------------------
+
+/// Compare the module sets of two forks.
+///
+/// Returns `(added, removed)`: modules in `b` but not in `a`, and modules in
+/// `a` but not in `b`. Together they form the symmetric difference. Order
+/// follows the input slices and duplicates are reported once.
+///
+/// Pure and allocation-bounded by the inputs, so it can run both off-chain
+/// and inside `compute_fork_diff`.
+pub fn fork_module_diff(a: &[Pubkey], b: &[Pubkey]) -> (Vec<Pubkey>, Vec<Pubkey>) {
+    fn only_in(side: &[Pubkey], other: &[Pubkey]) -> Vec<Pubkey> {
+        let mut out: Vec<Pubkey> = Vec::new();
+        for key in side {
+            if !other.contains(key) && !out.contains(key) {
+                out.push(*key);
+            }
+        }
+        out
+    }
+
+    (only_in(b, a), only_in(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ForkStatus::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    const ALL: [ForkStatus; 4] = [Draft, Testing, Released, Retired];
+
+    fn fork_in(status: ForkStatus) -> Fork {
+        let mut fork: Fork = zeroed(Fork::LEN);
+        fork.init(
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            "unit09-lab-alpha".to_string(),
+            "https://unit09.org/forks/alpha.json".to_string(),
+            String::new(),
+            true,
+            0,
+            None,
+            255,
+            &Clock::default(),
+        )
+        .unwrap();
+        fork.status = status.as_u8();
+        fork.is_active = status.is_active();
+        fork
+    }
+
+    #[test]
+    fn new_forks_start_as_inactive_drafts() {
+        let fork = fork_in(Draft);
+        assert_eq!(fork.status().unwrap(), Draft);
+        assert!(!fork.is_active);
+    }
+
+    #[test]
+    fn legal_transitions_succeed_and_derive_active() {
+        let legal = [
+            (Draft, Testing),
+            (Draft, Retired),
+            (Testing, Draft),
+            (Testing, Released),
+            (Testing, Retired),
+            (Released, Retired),
+        ];
+
+        for (from, to) in legal {
+            let mut fork = fork_in(from);
+            fork.transition_to(to).unwrap();
+            assert_eq!(fork.status().unwrap(), to);
+            assert_eq!(fork.is_active, to == Released);
+        }
+    }
+
+    #[test]
+    fn illegal_transitions_are_rejected() {
+        let illegal = [
+            (Draft, Released),
+            (Released, Draft),
+            (Released, Testing),
+            (Retired, Draft),
+            (Retired, Testing),
+            (Retired, Released),
+        ];
+
+        for (from, to) in illegal {
+            let mut fork = fork_in(from);
+            assert!(fork.transition_to(to).is_err(), "{from:?} -> {to:?}");
+            assert_eq!(fork.status().unwrap(), from);
+        }
+    }
+
+    #[test]
+    fn staying_in_place_is_a_no_op() {
+        for status in ALL {
+            let mut fork = fork_in(status);
+            fork.transition_to(status).unwrap();
+            assert_eq!(fork.status().unwrap(), status);
+        }
+    }
+
+    #[test]
+    fn status_round_trips_through_u8() {
+        for status in ALL {
+            assert_eq!(ForkStatus::from_u8(status.as_u8()), Some(status));
+        }
+        assert_eq!(ForkStatus::from_u8(4), None);
+    }
+
+    #[test]
+    fn diff_of_overlapping_sets() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let parent = [keys[0], keys[1], keys[2]];
+        let child = [keys[1], keys[2], keys[3]];
+
+        let (added, removed) = fork_module_diff(&parent, &child);
+        assert_eq!(added, vec![keys[3]]);
+        assert_eq!(removed, vec![keys[0]]);
+    }
+
+    #[test]
+    fn diff_of_disjoint_sets() {
+        let parent = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let child = [Pubkey::new_unique()];
+
+        let (added, removed) = fork_module_diff(&parent, &child);
+        assert_eq!(added, child.to_vec());
+        assert_eq!(removed, parent.to_vec());
+    }
+
+    #[test]
+    fn diff_of_identical_sets_is_empty() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        let (added, removed) = fork_module_diff(&[a, b], &[b, a, a]);
+        assert!(added.is_empty());
+        assert!(removed.is_empty());
+    }
+
+    fn child_of(parent: &Fork, allow_retired: bool) -> Result<Fork> {
+        let status = parent.status_as_parent(allow_retired)?;
+        let mut child: Fork = zeroed(Fork::LEN);
+        child.init(
+            Pubkey::new_unique(),
+            parent.fork_key,
+            Pubkey::new_unique(),
+            "unit09-lab-beta".to_string(),
+            "https://unit09.org/forks/beta.json".to_string(),
+            String::new(),
+            false,
+            1,
+            Some(status),
+            255,
+            &Clock::default(),
+        )?;
+        Ok(child)
+    }
+
+    #[test]
+    fn child_of_active_parent_records_parent_status() {
+        let parent = fork_in(Released);
+        let child = child_of(&parent, false).unwrap();
+        assert_eq!(child.parent_status_at_fork(), Some(Released));
+
+        let root = fork_in(Draft);
+        assert_eq!(root.parent_status_at_fork(), None);
+        assert_eq!(root.parent_status_at_fork, Fork::NO_PARENT_STATUS);
+    }
+
+    #[test]
+    fn retired_parent_is_rejected_without_override() {
+        let parent = fork_in(Retired);
+        assert!(child_of(&parent, false).is_err());
+    }
+
+    #[test]
+    fn retired_parent_is_allowed_with_override() {
+        let parent = fork_in(Retired);
+        let child = child_of(&parent, true).unwrap();
+        assert_eq!(child.parent_status_at_fork(), Some(Retired));
+    }
+
+    #[test]
+    fn child_count_tracks_creation_and_close() {
+        let mut parent = fork_in(Released);
+        assert_eq!(parent.child_count, 0);
+
+        parent.add_child().unwrap();
+        parent.add_child().unwrap();
+        assert_eq!(parent.child_count, 2);
+
+        parent.remove_child().unwrap();
+        parent.remove_child().unwrap();
+        assert_eq!(parent.child_count, 0);
+        assert!(parent.remove_child().is_err());
+    }
+
+    #[test]
+    fn deactivating_a_parent_warns_or_blocks() {
+        let mut parent = fork_in(Released);
+        parent.add_child().unwrap();
+
+        assert!(parent.check_deactivation(Retired, false).unwrap());
+        assert!(parent.check_deactivation(Retired, true).is_err());
+
+        // Staying released is not a deactivation.
+        assert!(!parent.check_deactivation(Released, true).unwrap());
+    }
+
+    fn module_verified(is_verified: bool) -> Module {
+        let mut module: Module = zeroed(Module::LEN);
+        module.is_verified = is_verified;
+        module
+    }
+
+    #[test]
+    fn release_waits_for_verified_modules_under_the_policy() {
+        let fork = fork_in(Testing);
+        let mut modules = [module_verified(true), module_verified(false)];
+
+        assert!(fork.check_activation(Released, true, &modules).is_err());
+        assert!(fork.check_activation(Released, true, &[]).is_err());
+        // Without the policy, or for moves that do not activate, anything goes.
+        assert!(fork.check_activation(Released, false, &modules).is_ok());
+        assert!(fork.check_activation(Retired, true, &modules).is_ok());
+
+        modules[1].is_verified = true;
+        assert!(fork.check_activation(Released, true, &modules).is_ok());
+    }
+
+    #[test]
+    fn expired_fork_retires_only_after_expiry() {
+        let mut fork = fork_in(Released);
+        fork.set_expiry(1_000, &clock_at(0)).unwrap();
+
+        assert!(fork.expire(&clock_at(999)).is_err());
+        assert_eq!(fork.status().unwrap(), Released);
+
+        fork.expire(&clock_at(1_000)).unwrap();
+        assert_eq!(fork.status().unwrap(), Retired);
+        assert!(!fork.is_active);
+        assert_eq!(fork.updated_at, 1_000);
+
+        // A retired fork cannot be expired again.
+        assert!(fork.expire(&clock_at(2_000)).is_err());
+    }
+
+    #[test]
+    fn forks_without_expiry_never_expire() {
+        let mut fork = fork_in(Testing);
+        assert_eq!(fork.expires_at, 0);
+        assert!(fork.expire(&clock_at(i64::MAX)).is_err());
+
+        // Past expiries are refused up front.
+        assert!(fork.set_expiry(500, &clock_at(500)).is_err());
+        assert_eq!(fork.expires_at, 0);
+    }
+
+    #[test]
+    fn childless_or_inactive_forks_deactivate_freely() {
+        let childless = fork_in(Released);
+        assert!(!childless.check_deactivation(Retired, true).unwrap());
+
+        // Retiring a fork that was never active deactivates nothing.
+        let mut testing = fork_in(Testing);
+        testing.add_child().unwrap();
+        assert!(!testing.check_deactivation(Retired, true).unwrap());
+    }
+
+    #[test]
+    fn fork_chain_shares_one_root() {
+        let root_key = Pubkey::new_unique();
+        let mut root = fork_in(Draft);
+        root.root = Fork::lineage_root(root_key, None);
+
+        let child_key = Pubkey::new_unique();
+        let mut child = fork_in(Draft);
+        child.root = Fork::lineage_root(child_key, Some((root_key, &root)));
+
+        let mut grandchild = fork_in(Draft);
+        grandchild.root = Fork::lineage_root(Pubkey::new_unique(), Some((child_key, &child)));
+
+        assert_eq!(root.root, root_key);
+        assert_eq!(child.root, root_key);
+        assert_eq!(grandchild.root, root_key);
+    }
+
+    #[test]
+    fn standalone_fork_is_its_own_root() {
+        let fork_key = Pubkey::new_unique();
+        assert_eq!(Fork::lineage_root(fork_key, None), fork_key);
+
+        // A parent from before roots were tracked starts its own lineage.
+        let legacy_key = Pubkey::new_unique();
+        let legacy = fork_in(Released);
+        assert_eq!(legacy.root, Pubkey::default());
+        assert_eq!(Fork::lineage_root(fork_key, Some((legacy_key, &legacy))), legacy_key);
+    }
+
+    #[test]
+    fn summary_names_the_fork_and_its_status() {
+        let mut fork = fork_in(Released);
+        fork.child_count = 2;
+
+        let summary = fork.to_string();
+        assert!(summary.starts_with(&format!("fork {} ", fork.fork_key)));
+        assert!(summary.contains("\"unit09-lab-alpha\" released"));
+        assert!(summary.contains("children=2"));
+
+        fork.status = 9;
+        assert!(fork.to_string().contains(" unknown "));
+    }
+}
@@ -0,0 +1,441 @@
+//! ===========================================================================
+//! Unit09 – Global Metadata State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/global_metadata.rs
+//!
+//! `GlobalMetadata` stores human-facing information about a Unit09
+//! deployment: description, tags and a handful of canonical links used by
+//! websites, explorers and documentation portals.
+//!
+//! The account is created lazily by `set_metadata` and is only writable by
+//! the admin stored in `Config`. All updates are partial: a field that is not
+//! supplied keeps its current value.
+//!
+//! Tags are stored as individual entries, normalized and capped exactly like
+//! `Module::tags_vec`; the comma-separated form is still accepted as input
+//! and used for event previews.
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::Module;
+
+/// Global, human-facing metadata for a Unit09 deployment.
+#[account]
+pub struct GlobalMetadata {
+    /// High-level description of the deployment.
+    pub description: String,
+
+    /// Tags used by explorers and dashboards.
+    ///
+    /// Bounded by `MAX_TAGS` entries of at most `MAX_TAG_LEN` bytes each.
+    pub tags_vec: Vec<String>,
+
+    /// Canonical website URL.
+    pub website_url: String,
+
+    /// Documentation URL.
+    pub docs_url: String,
+
+    /// Dashboard URL (metrics, explorers, etc.).
+    pub dashboard_url: String,
+
+    /// Icon or logo URI.
+    pub icon_uri: String,
+
+    /// Unstructured JSON payload interpreted by off-chain tooling.
+    pub extra_json: String,
+
+    /// Creation timestamp (Unix seconds).
+    pub created_at: i64,
+
+    /// Last update timestamp (Unix seconds).
+    pub updated_at: i64,
+
+    /// Schema version for this metadata layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 64],
+}
+
+/// Which fields were changed by a call to `GlobalMetadata::apply_update`.
+///
+/// Used by `set_metadata` to emit only the previews that actually changed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GlobalMetadataChanges {
+    pub description: bool,
+    pub tags: bool,
+}
+
+impl GlobalMetadata {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Maximum length in bytes (UTF-8) for the `description` field.
+    pub const MAX_DESCRIPTION_LEN: usize = MAX_DESCRIPTION_LEN;
+
+    /// Maximum length in bytes (UTF-8) for a legacy comma-separated tags
+    /// string accepted by `set_metadata`.
+    pub const MAX_TAGS_LEN: usize = MAX_TAGS_LEN;
+
+    /// Maximum number of entries in `tags_vec`, as for modules.
+    pub const MAX_TAGS: usize = Module::MAX_TAGS;
+
+    /// Maximum length in bytes (UTF-8) for a single tag, as for modules.
+    pub const MAX_TAG_LEN: usize = Module::MAX_TAG_LEN;
+
+    /// Maximum length in bytes (UTF-8) for each URL field.
+    pub const MAX_URL_LEN: usize = MAX_URL_LEN;
+
+    /// Maximum length in bytes (UTF-8) for the `icon_uri` field.
+    pub const MAX_ICON_URI_LEN: usize = MAX_METADATA_URI_LEN;
+
+    /// Maximum length in bytes (UTF-8) for the `extra_json` field.
+    pub const MAX_EXTRA_JSON_LEN: usize = MAX_EXTRA_JSON_LEN;
+
+    /// Total serialized length of the `GlobalMetadata` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 4 + Self::MAX_DESCRIPTION_LEN // description: String
+        + 4 + Self::MAX_TAGS * (4 + Self::MAX_TAG_LEN) // tags_vec: Vec<String>
+        + 4 + Self::MAX_URL_LEN // website_url: String
+        + 4 + Self::MAX_URL_LEN // docs_url: String
+        + 4 + Self::MAX_URL_LEN // dashboard_url: String
+        + 4 + Self::MAX_ICON_URI_LEN // icon_uri: String
+        + 4 + Self::MAX_EXTRA_JSON_LEN // extra_json: String
+        + 8 // created_at: i64
+        + 8 // updated_at: i64
+        + 1 // schema_version: u8
+        + 1 // bump: u8
+        + 64; // reserved: [u8; 64]
+
+    // -----------------------------------------------------------------------
+    // Initialization
+    // -----------------------------------------------------------------------
+
+    /// Initialize the metadata account on its first write.
+    ///
+    /// Field-level validation is performed by the `set_metadata` handler
+    /// before this is called.
+    pub fn init(
+        &mut self,
+        description: String,
+        tags: String,
+        website_url: String,
+        docs_url: String,
+        dashboard_url: String,
+        icon_uri: String,
+        extra_json: String,
+        bump: u8,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::validate_lengths(&description, &tags)?;
+        let tags_vec = Module::parse_legacy_tags(&tags)?;
+
+        self.description = description;
+        self.tags_vec = tags_vec;
+        self.website_url = website_url;
+        self.docs_url = docs_url;
+        self.dashboard_url = dashboard_url;
+        self.icon_uri = icon_uri;
+        self.extra_json = extra_json;
+        self.created_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 64];
+
+        Ok(())
+    }
+
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.created_at != 0
+    }
+
+    // -----------------------------------------------------------------------
+    // Partial Updates
+    // -----------------------------------------------------------------------
+
+    /// Apply a partial update; `None` leaves the field untouched.
+    ///
+    /// Returns which of the previewed fields (description, tags) actually
+    /// changed value, so callers can keep event payloads minimal.
+    pub fn apply_update(
+        &mut self,
+        maybe_description: Option<String>,
+        maybe_tags: Option<String>,
+        maybe_website_url: Option<String>,
+        maybe_docs_url: Option<String>,
+        maybe_dashboard_url: Option<String>,
+        maybe_icon_uri: Option<String>,
+        maybe_extra_json: Option<String>,
+        clock: &Clock,
+    ) -> Result<GlobalMetadataChanges> {
+        let mut changes = GlobalMetadataChanges::default();
+
+        if let Some(description) = maybe_description {
+            Self::validate_lengths(&description, "")?;
+            changes.description = description != self.description;
+            self.description = description;
+        }
+
+        if let Some(tags) = maybe_tags {
+            Self::validate_lengths("", &tags)?;
+            let tags_vec = Module::parse_legacy_tags(&tags)?;
+            changes.tags = tags_vec != self.tags_vec;
+            self.tags_vec = tags_vec;
+        }
+
+        if let Some(website_url) = maybe_website_url {
+            self.website_url = website_url;
+        }
+
+        if let Some(docs_url) = maybe_docs_url {
+            self.docs_url = docs_url;
+        }
+
+        if let Some(dashboard_url) = maybe_dashboard_url {
+            self.dashboard_url = dashboard_url;
+        }
+
+        if let Some(icon_uri) = maybe_icon_uri {
+            self.icon_uri = icon_uri;
+        }
+
+        if let Some(extra_json) = maybe_extra_json {
+            self.extra_json = extra_json;
+        }
+
+        self.updated_at = clock.unix_timestamp;
+        Ok(changes)
+    }
+
+    // -----------------------------------------------------------------------
+    // Tags
+    // -----------------------------------------------------------------------
+
+    /// Add a single tag. Adding a tag that is already present is a no-op.
+    pub fn add_tag(&mut self, tag: &str, clock: &Clock) -> Result<()> {
+        Module::validate_tag(tag)?;
+
+        if self.has_tag(tag) {
+            return Ok(());
+        }
+        if self.tags_vec.len() >= Self::MAX_TAGS {
+            return err!(Unit09Error::TooManyTags);
+        }
+
+        self.tags_vec.push(tag.to_string());
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Remove a single tag, preserving the order of the remaining tags.
+    pub fn remove_tag(&mut self, tag: &str, clock: &Clock) -> Result<()> {
+        let index = self
+            .tags_vec
+            .iter()
+            .position(|t| t == tag)
+            .ok_or(Unit09Error::TagNotFound)?;
+
+        self.tags_vec.remove(index);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether the deployment carries the given tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags_vec.iter().any(|t| t == tag)
+    }
+
+    /// Tags rendered in the legacy comma-separated form.
+    pub fn legacy_tags(&self) -> String {
+        self.tags_vec.join(",")
+    }
+
+    // -----------------------------------------------------------------------
+    // Previews
+    // -----------------------------------------------------------------------
+
+    /// Truncated copy of `description` suitable for event payloads.
+    pub fn description_preview(&self) -> String {
+        preview(&self.description)
+    }
+
+    /// Truncated copy of the comma-separated tags suitable for event
+    /// payloads.
+    pub fn tags_preview(&self) -> String {
+        preview(&self.legacy_tags())
+    }
+
+    // -----------------------------------------------------------------------
+    // Validation Helpers
+    // -----------------------------------------------------------------------
+
+    fn validate_lengths(description: &str, tags: &str) -> Result<()> {
+        if description.len() > Self::MAX_DESCRIPTION_LEN || tags.len() > Self::MAX_TAGS_LEN {
+            return err!(Unit09Error::MetadataTooLong);
+        }
+        Ok(())
+    }
+}
+
+/// Truncate `value` to at most `METADATA_PREVIEW_LEN` bytes without
+/// splitting a UTF-8 character.
+fn preview(value: &str) -> String {
+    if value.len() <= METADATA_PREVIEW_LEN {
+        return value.to_string();
+    }
+
+    let mut end = METADATA_PREVIEW_LEN;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn new_metadata(clock: &Clock) -> GlobalMetadata {
+        let mut metadata: GlobalMetadata = zeroed(GlobalMetadata::LEN);
+        metadata
+            .init(
+                "Unit09 consumes Solana code".to_string(),
+                "solana,ai".to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                255,
+                clock,
+            )
+            .unwrap();
+        metadata
+    }
+
+    #[test]
+    fn updating_only_tags_preserves_description() {
+        let mut metadata = new_metadata(&clock_at(100));
+
+        let changes = metadata
+            .apply_update(
+                None,
+                Some("solana,ai,modules".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                &clock_at(200),
+            )
+            .unwrap();
+
+        assert_eq!(metadata.description, "Unit09 consumes Solana code");
+        assert_eq!(metadata.tags_vec, vec!["solana", "ai", "modules"]);
+        assert_eq!(metadata.updated_at, 200);
+        assert_eq!(
+            changes,
+            GlobalMetadataChanges {
+                description: false,
+                tags: true,
+            }
+        );
+    }
+
+    #[test]
+    fn resupplying_same_description_is_not_a_change() {
+        let mut metadata = new_metadata(&clock_at(100));
+
+        let changes = metadata
+            .apply_update(
+                Some("Unit09 consumes Solana code".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &clock_at(200),
+            )
+            .unwrap();
+
+        assert!(!changes.description);
+        assert_eq!(metadata.legacy_tags(), "solana,ai");
+    }
+
+    #[test]
+    fn legacy_tags_are_normalized_like_module_tags() {
+        let mut metadata = new_metadata(&clock_at(100));
+
+        let changes = metadata
+            .apply_update(
+                None,
+                Some(" ai, solana,,ai ".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                &clock_at(200),
+            )
+            .unwrap();
+
+        assert_eq!(metadata.tags_vec, vec!["ai", "solana"]);
+        assert!(changes.tags);
+        assert_eq!(metadata.tags_preview(), "ai,solana");
+    }
+
+    #[test]
+    fn add_and_remove_individual_tags() {
+        let clock = clock_at(200);
+        let mut metadata = new_metadata(&clock_at(100));
+
+        metadata.add_tag("modules", &clock).unwrap();
+        metadata.add_tag("ai", &clock).unwrap();
+        assert_eq!(metadata.tags_vec, vec!["solana", "ai", "modules"]);
+
+        metadata.remove_tag("ai", &clock).unwrap();
+        assert_eq!(metadata.legacy_tags(), "solana,modules");
+        assert_eq!(metadata.updated_at, 200);
+
+        assert!(metadata.remove_tag("ai", &clock).is_err());
+        assert!(metadata.add_tag("a,b", &clock).is_err());
+    }
+
+    #[test]
+    fn tag_count_limit_is_enforced() {
+        let clock = clock_at(200);
+        let mut metadata = new_metadata(&clock_at(100));
+
+        for i in metadata.tags_vec.len()..GlobalMetadata::MAX_TAGS {
+            metadata.add_tag(&format!("tag{i}"), &clock).unwrap();
+        }
+        assert_eq!(metadata.tags_vec.len(), GlobalMetadata::MAX_TAGS);
+        assert!(metadata.add_tag("one-too-many", &clock).is_err());
+
+        let too_many = (0..=GlobalMetadata::MAX_TAGS)
+            .map(|i| format!("t{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        assert!(metadata
+            .apply_update(None, Some(too_many), None, None, None, None, None, &clock)
+            .is_err());
+    }
+
+    #[test]
+    fn preview_truncates_on_char_boundary() {
+        let long = "é".repeat(METADATA_PREVIEW_LEN);
+        let truncated = preview(&long);
+        assert!(truncated.len() <= METADATA_PREVIEW_LEN);
+        assert!(long.starts_with(&truncated));
+    }
+}
@@ -58,6 +58,11 @@ impl LifecyclePhase {
         }
     }
 
+    /// Convert from raw `u8`, failing with `InvalidLifecycleState`.
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        Self::from_u8(value).ok_or_else(|| error!(Unit09Error::InvalidLifecycleState))
+    }
+
     /// Convert `LifecyclePhase` to raw `u8`.
     pub fn as_u8(self) -> u8 {
         self as u8
@@ -148,11 +153,20 @@ impl Lifecycle {
     // Initialization
     // -----------------------------------------------------------------------
 
-    /// Initialize a new lifecycle account in the `Bootstrapping` phase.
-    pub fn init(&mut self, bump: u8, clock: &Clock, note_ref: [u8; 32]) -> Result<()> {
+    /// Initialize a new lifecycle account in `initial_phase`.
+    ///
+    /// Deployments normally start in `Bootstrapping`; staging deployments may
+    /// start `Frozen` so nothing can be written until they are opened up.
+    pub fn init(
+        &mut self,
+        initial_phase: LifecyclePhase,
+        bump: u8,
+        clock: &Clock,
+        note_ref: [u8; 32],
+    ) -> Result<()> {
         let now = clock.unix_timestamp;
 
-        self.phase = LifecyclePhase::Bootstrapping.as_u8();
+        self.phase = initial_phase.as_u8();
         self.global_freeze = false;
         self.migration_required = false;
         self.migration_in_progress = false;
@@ -168,6 +182,11 @@ impl Lifecycle {
         Ok(())
     }
 
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.created_at != 0
+    }
+
     // -----------------------------------------------------------------------
     // Phase and State Updates
     // -----------------------------------------------------------------------
@@ -293,3 +312,45 @@ impl Lifecycle {
         Ok(self.global_freeze || phase.is_read_only())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn new_lifecycle(initial_phase: LifecyclePhase) -> Lifecycle {
+        let mut lifecycle: Lifecycle = zeroed(Lifecycle::LEN);
+        lifecycle
+            .init(initial_phase, 255, &clock_at(100), [0u8; 32])
+            .unwrap();
+        lifecycle
+    }
+
+    #[test]
+    fn bootstrapping_start_allows_writes() {
+        let lifecycle = new_lifecycle(LifecyclePhase::Bootstrapping);
+        assert_eq!(lifecycle.phase, LifecyclePhase::Bootstrapping.as_u8());
+        assert!(lifecycle.assert_writes_allowed().is_ok());
+    }
+
+    #[test]
+    fn frozen_start_blocks_writes_until_unfrozen() {
+        let mut lifecycle = new_lifecycle(LifecyclePhase::Frozen);
+        assert_eq!(lifecycle.phase, LifecyclePhase::Frozen.as_u8());
+        assert_eq!(lifecycle.phase_changed_at, 100);
+        assert!(lifecycle.assert_writes_allowed().is_err());
+        assert!(lifecycle.is_effectively_read_only().unwrap());
+
+        lifecycle
+            .set_phase(LifecyclePhase::Operational, &clock_at(200))
+            .unwrap();
+        assert!(lifecycle.assert_writes_allowed().is_ok());
+        assert_eq!(lifecycle.phase_changed_at, 200);
+    }
+
+    #[test]
+    fn unknown_phase_is_rejected() {
+        assert!(LifecyclePhase::try_from_u8(5).is_ok());
+        assert!(LifecyclePhase::try_from_u8(6).is_err());
+    }
+}
@@ -10,6 +10,10 @@
 //! - how many modules and forks exist
 //! - how many observation runs have occurred
 //! - approximate aggregate lines of code and files processed
+//! - lines of code observed per module category (`category_loc`)
+//! - how many modules have 1, 2–5, 6–20 or 21+ versions
+//!   (`modules_by_version_count`)
+//! - how many forks were created at depth 0, 1, 2 and 3+ (`forks_by_depth`)
 //!
 //! This account is intentionally simple and numeric to keep read costs low
 //! and make it easy for dashboards, explorers, and monitoring systems to
@@ -19,14 +23,57 @@
 //! - Increment counters in instruction handlers when:
 //!     * a new repo/module/fork is created
 //!     * an observation is recorded
+//! - Call `on_entity_removed` from every close / delete path so that the
+//!   matching counter is decremented exactly once.
 //! - Use `adjust_*` methods only when reconciling counts with off-chain data.
 //!
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::{
+    histogram_add, CategoryTally, Config, Fork, Module, ObservationCaps, Repo,
+};
+use crate::utils::time::day_index;
+
+/// Kind of counted entity, used to route removals to the right counter.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntityKind {
+    Repo,
+    Module,
+    Fork,
+}
+
+impl EntityKind {
+    /// Identify a counted entity from the first eight bytes of its data.
+    ///
+    /// Returns `None` for accounts that `Metrics` does not count.
+    pub fn from_discriminator(data: &[u8]) -> Option<Self> {
+        let discriminator = data.get(..8)?;
+        if discriminator == Repo::DISCRIMINATOR {
+            Some(Self::Repo)
+        } else if discriminator == Module::DISCRIMINATOR {
+            Some(Self::Module)
+        } else if discriminator == Fork::DISCRIMINATOR {
+            Some(Self::Fork)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the `Metrics` counter for this kind, as reported in
+    /// `MetricsLimitReached::limit_key`.
+    pub fn total_key(self) -> &'static str {
+        match self {
+            Self::Repo => "total_repos",
+            Self::Module => "total_modules",
+            Self::Fork => "total_forks",
+        }
+    }
+}
 
 /// Global aggregate metrics for a Unit09 deployment.
 ///
@@ -62,6 +109,40 @@ pub struct Metrics {
     /// Unix timestamp when this metrics account was last updated.
     pub updated_at: i64,
 
+    /// Day index (Unix seconds / `SECONDS_PER_DAY`) of the current daily bucket.
+    pub current_day: i64,
+
+    /// Observations recorded during `current_day`.
+    pub daily_observations: u64,
+
+    /// Modules registered during `current_day`.
+    pub daily_modules: u64,
+
+    /// Highest number of observations recorded in any single day.
+    pub peak_daily_observations: u64,
+
+    /// Highest number of modules registered in any single day.
+    pub peak_daily_modules: u64,
+
+    /// Lowest slot at which an observation has been recorded (0 = none yet).
+    pub min_observed_slot: u64,
+
+    /// Highest slot at which an observation has been recorded (0 = none yet).
+    pub max_observed_slot: u64,
+
+    /// Number of modules per version-count bucket.
+    ///
+    /// Bucket `i` counts modules whose `Module::version_count` is at least
+    /// `VERSION_COUNT_BUCKET_BOUNDS[i]` and below the next bound.
+    pub modules_by_version_count: [u64; 4],
+
+    /// Number of forks created per fork-tree depth.
+    ///
+    /// Bucket `i` counts forks created with `Fork::depth == i`; the last
+    /// bucket also counts every deeper fork. Closing a fork does not remove
+    /// it, so the buckets can sum to more than `total_forks`.
+    pub forks_by_depth: [u64; FORK_DEPTH_BUCKETS],
+
     /// Schema version for this metrics layout.
     pub schema_version: u8,
 
@@ -69,7 +150,16 @@ pub struct Metrics {
     pub bump: u8,
 
     /// Reserved bytes for future upgrades.
-    pub reserved: [u8; 78],
+    pub reserved: [u8; 22],
+
+    /// Observed lines of code per module category.
+    ///
+    /// Each observation's full `lines_of_code` is attributed to the observed
+    /// repository's dominant category (`Repo::dominant_module_category`):
+    /// the category with the most registered modules, ties going to the
+    /// earliest. Observations of repositories with no modules are not
+    /// attributed, so the buckets can sum to less than `total_lines_of_code`.
+    pub category_loc: Vec<CategoryTally>,
 }
 
 impl Metrics {
@@ -87,9 +177,19 @@ impl Metrics {
         + 8  // last_observation_at: i64
         + 8  // created_at: i64
         + 8  // updated_at: i64
+        + 8  // current_day: i64
+        + 8  // daily_observations: u64
+        + 8  // daily_modules: u64
+        + 8  // peak_daily_observations: u64
+        + 8  // peak_daily_modules: u64
+        + 8  // min_observed_slot: u64
+        + 8  // max_observed_slot: u64
+        + 32 // modules_by_version_count: [u64; 4]
+        + 8 * FORK_DEPTH_BUCKETS // forks_by_depth: [u64; FORK_DEPTH_BUCKETS]
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 78; // reserved: [u8; 78]
+        + 22 // reserved: [u8; 22]
+        + CategoryTally::HISTOGRAM_LEN; // category_loc: Vec<CategoryTally>
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -110,13 +210,28 @@ impl Metrics {
         self.last_observation_at = 0;
         self.created_at = now;
         self.updated_at = now;
+        self.current_day = day_index(now);
+        self.daily_observations = 0;
+        self.daily_modules = 0;
+        self.peak_daily_observations = 0;
+        self.peak_daily_modules = 0;
+        self.min_observed_slot = 0;
+        self.max_observed_slot = 0;
+        self.modules_by_version_count = [0u64; 4];
+        self.forks_by_depth = [0u64; FORK_DEPTH_BUCKETS];
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 78];
+        self.reserved = [0u8; 22];
+        self.category_loc = Vec::new();
 
         Ok(())
     }
 
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.created_at != 0
+    }
+
     // -----------------------------------------------------------------------
     // Creation Counters
     // -----------------------------------------------------------------------
@@ -130,9 +245,8 @@ impl Metrics {
         Ok(())
     }
 
-    /// Decrement total repositories counter, if you ever add repository
-    /// deletion or archival.
-    pub fn decrement_repos(&mut self) -> Result<()> {
+    /// Decrement total repositories counter.
+    fn decrement_repos(&mut self) -> Result<()> {
         self.total_repos = self
             .total_repos
             .checked_sub(1)
@@ -149,8 +263,26 @@ impl Metrics {
         Ok(())
     }
 
+    /// Increment total modules counter and account for the registration in
+    /// the current daily bucket.
+    ///
+    /// This should be preferred over `increment_modules` by instructions that
+    /// create modules, so that `peak_daily_modules` stays accurate.
+    pub fn record_module_registration(&mut self, clock: &Clock) -> Result<()> {
+        self.increment_modules()?;
+        self.roll_daily_bucket(clock);
+
+        self.daily_modules = self
+            .daily_modules
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.peak_daily_modules = self.peak_daily_modules.max(self.daily_modules);
+
+        Ok(())
+    }
+
     /// Decrement total modules counter.
-    pub fn decrement_modules(&mut self) -> Result<()> {
+    fn decrement_modules(&mut self) -> Result<()> {
         self.total_modules = self
             .total_modules
             .checked_sub(1)
@@ -168,7 +300,7 @@ impl Metrics {
     }
 
     /// Decrement total forks counter.
-    pub fn decrement_forks(&mut self) -> Result<()> {
+    fn decrement_forks(&mut self) -> Result<()> {
         self.total_forks = self
             .total_forks
             .checked_sub(1)
@@ -176,6 +308,31 @@ impl Metrics {
         Ok(())
     }
 
+    /// Current value of the creation counter for `kind`.
+    pub fn total(&self, kind: EntityKind) -> u64 {
+        match kind {
+            EntityKind::Repo => self.total_repos,
+            EntityKind::Module => self.total_modules,
+            EntityKind::Fork => self.total_forks,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Removal
+    // -----------------------------------------------------------------------
+
+    /// Account for a closed or deleted entity.
+    ///
+    /// This is the only way to decrement the creation counters; every close
+    /// instruction must call it once for the entity it removes.
+    pub fn on_entity_removed(&mut self, kind: EntityKind) -> Result<()> {
+        match kind {
+            EntityKind::Repo => self.decrement_repos(),
+            EntityKind::Module => self.decrement_modules(),
+            EntityKind::Fork => self.decrement_forks(),
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Observation Aggregation
     // -----------------------------------------------------------------------
@@ -188,40 +345,176 @@ impl Metrics {
         &mut self,
         lines_of_code: u64,
         files_processed: u32,
+        config: Option<&Config>,
         clock: &Clock,
     ) -> Result<()> {
-        // Bounds check using constants.
-        if lines_of_code > MAX_LOC_PER_OBSERVATION {
-            return err!(Unit09Error::ObservationDataTooLarge);
-        }
-        if files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-            return err!(Unit09Error::ObservationDataTooLarge);
-        }
+        self.add_observation_totals(lines_of_code, files_processed, config)?;
 
-        // Increment observation count.
-        self.total_observations = self
-            .total_observations
+        // Update last observation timestamp and the observed slot range.
+        self.last_observation_at = clock.unix_timestamp;
+        self.widen_observed_slot_range(clock.slot);
+
+        // Account for the observation in the current daily bucket.
+        self.roll_daily_bucket(clock);
+        self.daily_observations = self
+            .daily_observations
             .checked_add(1)
             .ok_or(Unit09Error::CounterOverflow)?;
+        self.peak_daily_observations = self
+            .peak_daily_observations
+            .max(self.daily_observations);
+
+        Ok(())
+    }
+
+    /// Record a backfilled historical observation.
+    ///
+    /// Only the cumulative totals grow; the daily bucket, its peak, the
+    /// observed slot range and `last_observation_at` describe live activity
+    /// and are left unchanged.
+    pub fn record_backfill(
+        &mut self,
+        lines_of_code: u64,
+        files_processed: u32,
+        config: Option<&Config>,
+    ) -> Result<()> {
+        self.add_observation_totals(lines_of_code, files_processed, config)
+    }
+
+    /// Check an observation against the caps and add it to the cumulative
+    /// totals, updating either all of them or none.
+    fn add_observation_totals(
+        &mut self,
+        lines_of_code: u64,
+        files_processed: u32,
+        config: Option<&Config>,
+    ) -> Result<()> {
+        // Bounds check against the deployment's caps (or the compile-time
+        // defaults when no config is supplied).
+        ObservationCaps::from_config(config).check(lines_of_code, files_processed)?;
 
-        // Aggregate lines of code.
-        self.total_lines_of_code = self
+        let total_observations = self
+            .total_observations
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let total_lines_of_code = self
             .total_lines_of_code
             .checked_add(lines_of_code)
             .ok_or(Unit09Error::CounterOverflow)?;
-
-        // Aggregate files processed.
-        self.total_files_processed = self
+        let total_files_processed = self
             .total_files_processed
             .checked_add(files_processed as u64)
             .ok_or(Unit09Error::CounterOverflow)?;
 
-        // Update last observation timestamp.
-        self.last_observation_at = clock.unix_timestamp;
+        self.total_observations = total_observations;
+        self.total_lines_of_code = total_lines_of_code;
+        self.total_files_processed = total_files_processed;
+        Ok(())
+    }
+
+    /// Attribute an observation's `lines_of_code` to `category`.
+    ///
+    /// `category` is the observed repository's dominant module category;
+    /// `None` (a repository without modules) records nothing. See
+    /// `category_loc` for the attribution rule.
+    pub fn record_category_loc(
+        &mut self,
+        category: Option<&str>,
+        lines_of_code: u64,
+    ) -> Result<()> {
+        match category {
+            Some(category) => histogram_add(&mut self.category_loc, category, lines_of_code),
+            None => Ok(()),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Version Count Histogram
+    // -----------------------------------------------------------------------
+
+    /// Move a module between `modules_by_version_count` buckets after its
+    /// `Module::version_count` went from `previous` to `current`.
+    ///
+    /// A `current` of 0 removes the module, as when it is closed. Modules
+    /// published before the histogram existed were never counted, so the
+    /// bucket they leave is not decremented below zero.
+    pub fn record_module_version_count(&mut self, previous: u16, current: u16) -> Result<()> {
+        let from = version_count_bucket(previous);
+        let to = version_count_bucket(current);
+        if from == to {
+            return Ok(());
+        }
+
+        let mut buckets = self.modules_by_version_count;
+        if let Some(from) = from {
+            buckets[from] = buckets[from].saturating_sub(1);
+        }
+        if let Some(to) = to {
+            buckets[to] = buckets[to]
+                .checked_add(1)
+                .ok_or(Unit09Error::CounterOverflow)?;
+        }
+        self.modules_by_version_count = buckets;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Fork Depth Histogram
+    // -----------------------------------------------------------------------
 
+    /// Count a fork created at `depth` in `forks_by_depth`.
+    pub fn record_fork_depth(&mut self, depth: u16) -> Result<()> {
+        let bucket = usize::from(depth).min(FORK_DEPTH_BUCKETS - 1);
+        self.forks_by_depth[bucket] = self.forks_by_depth[bucket]
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Slot Coverage
+    // -----------------------------------------------------------------------
+
+    /// Extend `[min_observed_slot, max_observed_slot]` to include `slot`.
+    fn widen_observed_slot_range(&mut self, slot: u64) {
+        if self.min_observed_slot == 0 || slot < self.min_observed_slot {
+            self.min_observed_slot = slot;
+        }
+        if slot > self.max_observed_slot {
+            self.max_observed_slot = slot;
+        }
+    }
+
+    /// Number of slots covered by recorded observations, inclusive.
+    ///
+    /// Monitoring can divide `total_observations` by this to estimate
+    /// coverage density. Returns 0 before the first observation.
+    pub fn observed_slot_span(&self) -> u64 {
+        if self.max_observed_slot == 0 {
+            return 0;
+        }
+        self.max_observed_slot
+            .saturating_sub(self.min_observed_slot)
+            .saturating_add(1)
+    }
+
+    // -----------------------------------------------------------------------
+    // Daily Buckets
+    // -----------------------------------------------------------------------
+
+    /// Move to a fresh daily bucket if the clock has crossed into a new day.
+    ///
+    /// Peaks are never reset; they only grow when a day's bucket exceeds the
+    /// previously recorded maximum.
+    fn roll_daily_bucket(&mut self, clock: &Clock) {
+        let today = day_index(clock.unix_timestamp);
+        if today != self.current_day {
+            self.current_day = today;
+            self.daily_observations = 0;
+            self.daily_modules = 0;
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Bulk Adjustment (Reconciliation)
     // -----------------------------------------------------------------------
@@ -264,6 +557,55 @@ impl Metrics {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Consistency Checks
+    // -----------------------------------------------------------------------
+
+    /// Check internal invariants and return a short code for each one that
+    /// does not hold. An empty list means the account looks consistent.
+    ///
+    /// Intended for detecting corruption after `adjust_aggregate` or other
+    /// manual interventions; it never mutates state.
+    pub fn consistency_violations(&self, now: i64) -> Vec<&'static str> {
+        let mut violations = Vec::new();
+
+        if self.total_observations == 0 {
+            if self.total_files_processed > 0 {
+                violations.push("files_without_observations");
+            }
+            if self.total_lines_of_code > 0 {
+                violations.push("loc_without_observations");
+            }
+        } else if self.last_observation_at == 0 {
+            violations.push("observations_without_timestamp");
+        }
+
+        if self.last_observation_at > now {
+            violations.push("last_observation_in_future");
+        }
+        if self.updated_at > now {
+            violations.push("updated_in_future");
+        }
+        if self.created_at > self.updated_at {
+            violations.push("created_after_updated");
+        }
+
+        if self.daily_observations > self.peak_daily_observations {
+            violations.push("daily_observations_above_peak");
+        }
+        if self.daily_modules > self.peak_daily_modules {
+            violations.push("daily_modules_above_peak");
+        }
+        if self.peak_daily_observations > self.total_observations {
+            violations.push("peak_observations_above_total");
+        }
+        if self.min_observed_slot > self.max_observed_slot {
+            violations.push("min_slot_above_max");
+        }
+
+        violations
+    }
+
     // -----------------------------------------------------------------------
     // Utility Helpers
     // -----------------------------------------------------------------------
@@ -281,10 +623,22 @@ impl Metrics {
             total_lines_of_code: self.total_lines_of_code,
             total_files_processed: self.total_files_processed,
             last_observation_at: self.last_observation_at,
+            peak_daily_observations: self.peak_daily_observations,
+            peak_daily_modules: self.peak_daily_modules,
+            min_observed_slot: self.min_observed_slot,
+            max_observed_slot: self.max_observed_slot,
         }
     }
 }
 
+/// Index of the `modules_by_version_count` bucket for `version_count`, or
+/// `None` for a module without versions.
+fn version_count_bucket(version_count: u16) -> Option<usize> {
+    VERSION_COUNT_BUCKET_BOUNDS
+        .iter()
+        .rposition(|bound| version_count >= *bound)
+}
+
 /// Lightweight metrics snapshot for off-chain tools.
 ///
 /// This is not stored on-chain; it is purely a helper structure returned by
@@ -298,4 +652,369 @@ pub struct MetricsSummary {
     pub total_lines_of_code: u64,
     pub total_files_processed: u64,
     pub last_observation_at: i64,
+    pub peak_daily_observations: u64,
+    pub peak_daily_modules: u64,
+    pub min_observed_slot: u64,
+    pub max_observed_slot: u64,
+}
+
+impl MetricsSummary {
+    /// Number of columns in a summary row.
+    pub const COLUMNS: usize = 11;
+
+    /// Column names, in the order `to_row` writes the values.
+    pub fn header() -> [&'static str; Self::COLUMNS] {
+        [
+            "total_repos",
+            "total_modules",
+            "total_forks",
+            "total_observations",
+            "total_lines_of_code",
+            "total_files_processed",
+            "last_observation_at",
+            "peak_daily_observations",
+            "peak_daily_modules",
+            "min_observed_slot",
+            "max_observed_slot",
+        ]
+    }
+
+    /// Fixed-column row of every counter and timestamp, matching `header`,
+    /// for CSV exports of metrics snapshots.
+    pub fn to_row(&self) -> [String; Self::COLUMNS] {
+        [
+            self.total_repos.to_string(),
+            self.total_modules.to_string(),
+            self.total_forks.to_string(),
+            self.total_observations.to_string(),
+            self.total_lines_of_code.to_string(),
+            self.total_files_processed.to_string(),
+            self.last_observation_at.to_string(),
+            self.peak_daily_observations.to_string(),
+            self.peak_daily_modules.to_string(),
+            self.min_observed_slot.to_string(),
+            self.max_observed_slot.to_string(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{histogram_value, ConfigUpdate};
+    use crate::utils::test_utils::{self, clock_at, new_config, new_metrics, new_module};
+
+    #[test]
+    fn summary_row_lines_up_with_the_header() {
+        let clock = Clock {
+            slot: 42,
+            ..clock_at(10 * SECONDS_PER_DAY + 60)
+        };
+        let mut metrics = new_metrics(&clock);
+        metrics.record_observation(1_200, 30, None, &clock).unwrap();
+        metrics.record_module_registration(&clock).unwrap();
+
+        let header = MetricsSummary::header();
+        let row = metrics.summary().to_row();
+        assert_eq!(header.len(), row.len());
+
+        let column = |name: &str| {
+            let index = header.iter().position(|column| *column == name).unwrap();
+            row[index].as_str()
+        };
+        assert_eq!(column("total_observations"), "1");
+        assert_eq!(column("total_modules"), "1");
+        assert_eq!(column("total_lines_of_code"), "1200");
+        assert_eq!(column("total_files_processed"), "30");
+        assert_eq!(column("last_observation_at"), clock.unix_timestamp.to_string());
+        assert_eq!(column("max_observed_slot"), "42");
+    }
+
+    #[test]
+    fn backfill_skips_the_daily_bucket() {
+        let today = clock_at(10 * SECONDS_PER_DAY + 60);
+        let mut metrics = new_metrics(&today);
+        metrics.record_observation(100, 1, None, &today).unwrap();
+
+        metrics.record_backfill(5_000, 40, None).unwrap();
+
+        assert_eq!(metrics.total_observations, 2);
+        assert_eq!(metrics.total_lines_of_code, 5_100);
+        assert_eq!(metrics.total_files_processed, 41);
+        assert_eq!(metrics.daily_observations, 1);
+        assert_eq!(metrics.peak_daily_observations, 1);
+        assert_eq!(metrics.last_observation_at, today.unix_timestamp);
+    }
+
+    #[test]
+    fn peak_daily_counts_stick_after_quieter_day() {
+        let busy_day = clock_at(10 * SECONDS_PER_DAY + 60);
+        let mut metrics = new_metrics(&busy_day);
+
+        for _ in 0..5 {
+            metrics.record_observation(100, 1, None, &busy_day).unwrap();
+        }
+        for _ in 0..3 {
+            metrics.record_module_registration(&busy_day).unwrap();
+        }
+
+        assert_eq!(metrics.peak_daily_observations, 5);
+        assert_eq!(metrics.peak_daily_modules, 3);
+
+        let quiet_day = clock_at(11 * SECONDS_PER_DAY + 60);
+        metrics.record_observation(100, 1, None, &quiet_day).unwrap();
+        metrics.record_module_registration(&quiet_day).unwrap();
+
+        assert_eq!(metrics.daily_observations, 1);
+        assert_eq!(metrics.daily_modules, 1);
+        assert_eq!(metrics.peak_daily_observations, 5);
+        assert_eq!(metrics.peak_daily_modules, 3);
+        assert_eq!(metrics.total_observations, 6);
+        assert_eq!(metrics.total_modules, 4);
+    }
+
+    #[test]
+    fn busier_later_day_raises_peak() {
+        let first_day = clock_at(SECONDS_PER_DAY);
+        let mut metrics = new_metrics(&first_day);
+        metrics.record_observation(10, 1, None, &first_day).unwrap();
+
+        let second_day = clock_at(2 * SECONDS_PER_DAY);
+        metrics.record_observation(10, 1, None, &second_day).unwrap();
+        metrics.record_observation(10, 1, None, &second_day).unwrap();
+
+        assert_eq!(metrics.peak_daily_observations, 2);
+    }
+
+    #[test]
+    fn fresh_metrics_are_consistent() {
+        let clock = clock_at(1_000);
+        let mut metrics = new_metrics(&clock);
+        assert!(metrics.consistency_violations(1_000).is_empty());
+
+        metrics.record_observation(100, 4, None, &clock).unwrap();
+        assert!(metrics.consistency_violations(1_000).is_empty());
+    }
+
+    #[test]
+    fn manual_adjustment_inconsistencies_are_reported() {
+        let clock = clock_at(1_000);
+        let mut metrics = new_metrics(&clock);
+
+        metrics
+            .adjust_aggregate(None, None, None, None, Some(500), Some(12), &clock)
+            .unwrap();
+        metrics.last_observation_at = 5_000;
+
+        let violations = metrics.consistency_violations(1_000);
+        assert_eq!(
+            violations,
+            vec![
+                "files_without_observations",
+                "loc_without_observations",
+                "last_observation_in_future",
+            ]
+        );
+    }
+
+    #[test]
+    fn observations_without_timestamp_are_reported() {
+        let clock = clock_at(1_000);
+        let mut metrics = new_metrics(&clock);
+
+        metrics
+            .adjust_aggregate(None, None, None, Some(3), None, None, &clock)
+            .unwrap();
+
+        assert_eq!(
+            metrics.consistency_violations(1_000),
+            vec!["observations_without_timestamp"]
+        );
+    }
+
+    #[test]
+    fn observed_slot_range_tracks_extremes() {
+        let mut metrics = new_metrics(&clock_at(1_000));
+        assert_eq!(metrics.observed_slot_span(), 0);
+
+        let at_slot = |slot: u64| Clock {
+            slot,
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+
+        metrics.record_observation(10, 1, None, &at_slot(100)).unwrap();
+        metrics.record_observation(10, 1, None, &at_slot(500)).unwrap();
+        assert_eq!(metrics.min_observed_slot, 100);
+        assert_eq!(metrics.max_observed_slot, 500);
+
+        metrics.record_observation(10, 1, None, &at_slot(300)).unwrap();
+        assert_eq!(metrics.min_observed_slot, 100);
+        assert_eq!(metrics.max_observed_slot, 500);
+        assert_eq!(metrics.observed_slot_span(), 401);
+    }
+
+    #[test]
+    fn raised_config_cap_admits_large_observation() {
+        let clock = clock_at(1_000);
+        let mut metrics = new_metrics(&clock);
+
+        let mut config = new_config(Pubkey::new_unique(), &clock);
+
+        let huge = MAX_LOC_PER_OBSERVATION + 1;
+        assert!(metrics
+            .record_observation(huge, 1, Some(&config), &clock)
+            .is_err());
+        assert!(metrics.record_observation(huge, 1, None, &clock).is_err());
+
+        config
+            .apply_update(
+                ConfigUpdate {
+                    max_loc_per_observation: Some(huge),
+                    ..ConfigUpdate::default()
+                },
+                &clock,
+            )
+            .unwrap();
+        metrics
+            .record_observation(huge, 1, Some(&config), &clock)
+            .unwrap();
+        assert_eq!(metrics.total_lines_of_code, huge);
+    }
+
+    fn new_repo(categories: &[&str]) -> Repo {
+        let mut repo = test_utils::new_repo(Pubkey::new_unique(), &clock_at(0));
+        for category in categories {
+            repo.add_module_category(category).unwrap();
+        }
+        repo
+    }
+
+    #[test]
+    fn observations_are_attributed_to_dominant_category() {
+        let clock = clock_at(1_000);
+        let mut metrics = new_metrics(&clock);
+        let program_heavy = new_repo(&["sdk", "program", "program", "program"]);
+        let empty = new_repo(&[]);
+
+        let observed = [(&program_heavy, 1_200), (&empty, 300), (&program_heavy, 800)];
+        for (repo, lines_of_code) in observed {
+            metrics
+                .record_observation(lines_of_code, 1, None, &clock)
+                .unwrap();
+            metrics
+                .record_category_loc(repo.dominant_module_category(), lines_of_code)
+                .unwrap();
+        }
+
+        assert_eq!(histogram_value(&metrics.category_loc, "program"), 2_000);
+        assert_eq!(histogram_value(&metrics.category_loc, "sdk"), 0);
+        assert_eq!(metrics.total_lines_of_code, 2_300);
+    }
+
+    #[test]
+    fn removal_decrements_only_the_matching_counter() {
+        let mut metrics = new_metrics(&clock_at(0));
+        metrics.increment_repos().unwrap();
+        metrics.increment_modules().unwrap();
+        metrics.increment_forks().unwrap();
+
+        metrics.on_entity_removed(EntityKind::Repo).unwrap();
+        assert_eq!(
+            (metrics.total_repos, metrics.total_modules, metrics.total_forks),
+            (0, 1, 1)
+        );
+
+        metrics.on_entity_removed(EntityKind::Module).unwrap();
+        assert_eq!(
+            (metrics.total_repos, metrics.total_modules, metrics.total_forks),
+            (0, 0, 1)
+        );
+
+        metrics.on_entity_removed(EntityKind::Fork).unwrap();
+        assert_eq!(
+            (metrics.total_repos, metrics.total_modules, metrics.total_forks),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn removal_below_zero_is_rejected() {
+        let mut metrics = new_metrics(&clock_at(0));
+        assert!(metrics.on_entity_removed(EntityKind::Fork).is_err());
+        assert_eq!(metrics.total_forks, 0);
+    }
+
+    #[test]
+    fn entity_kind_is_read_from_discriminator() {
+        assert_eq!(
+            EntityKind::from_discriminator(&Repo::DISCRIMINATOR),
+            Some(EntityKind::Repo)
+        );
+        assert_eq!(
+            EntityKind::from_discriminator(&Fork::DISCRIMINATOR),
+            Some(EntityKind::Fork)
+        );
+        assert_eq!(EntityKind::from_discriminator(&Metrics::DISCRIMINATOR), None);
+        assert_eq!(EntityKind::from_discriminator(&[0u8; 4]), None);
+    }
+
+    #[test]
+    fn version_count_histogram_follows_bucket_boundaries() {
+        let mut metrics = new_metrics(&clock_at(100));
+
+        metrics.record_module_version_count(0, 1).unwrap();
+        assert_eq!(metrics.modules_by_version_count, [1, 0, 0, 0]);
+
+        metrics.record_module_version_count(1, 2).unwrap();
+        assert_eq!(metrics.modules_by_version_count, [0, 1, 0, 0]);
+
+        // Staying inside the 2–5 bucket leaves the histogram untouched.
+        metrics.record_module_version_count(2, 3).unwrap();
+        assert_eq!(metrics.modules_by_version_count, [0, 1, 0, 0]);
+
+        metrics.record_module_version_count(5, 6).unwrap();
+        assert_eq!(metrics.modules_by_version_count, [0, 0, 1, 0]);
+
+        metrics.record_module_version_count(6, 0).unwrap();
+        assert_eq!(metrics.modules_by_version_count, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn forks_are_counted_by_depth() {
+        let mut metrics = new_metrics(&clock_at(100));
+
+        for depth in [0, 1, 1, 2] {
+            metrics.record_fork_depth(depth).unwrap();
+        }
+        assert_eq!(metrics.forks_by_depth, [1, 2, 1, 0]);
+
+        // Anything deeper than the last bucket lands in it.
+        metrics.record_fork_depth(3).unwrap();
+        metrics.record_fork_depth(40).unwrap();
+        assert_eq!(metrics.forks_by_depth, [1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn versions_created_on_module_move_it_between_buckets() {
+        let clock = clock_at(100);
+        let mut metrics = new_metrics(&clock);
+        let mut module =
+            new_module(Pubkey::new_unique(), Pubkey::new_unique(), "unit09-router", &clock);
+
+        let publish = |module: &mut Module, metrics: &mut Metrics| {
+            let previous = module.version_count;
+            module.record_version_created(false, &clock);
+            metrics
+                .record_module_version_count(previous, module.version_count)
+                .unwrap();
+        };
+
+        publish(&mut module, &mut metrics);
+        assert_eq!(metrics.modules_by_version_count, [1, 0, 0, 0]);
+
+        publish(&mut module, &mut metrics);
+        assert_eq!(module.version_count, 2);
+        assert_eq!(metrics.modules_by_version_count, [0, 1, 0, 0]);
+    }
 }
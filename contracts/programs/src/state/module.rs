@@ -18,14 +18,88 @@
 //! This file defines:
 //! - `Module` account structure
 //! - size constants for rent-exempt allocation
+//! - `ExternalRef` cross-references into package registries
 //! - helper methods for authority checks, activation checks,
-//!   usage tracking, and metadata validation
+//!   usage tracking, maturity scoring, and metadata validation
 //! ===========================================================================
 
+use std::fmt;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::Visibility;
+use crate::utils::monotonic_updated_at;
+
+/// Package registry a module may also be published to.
+///
+/// Encoded as a `u8` inside `ExternalRef`. The numeric mapping is part of the
+/// public interface and must stay stable.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExternalRegistry {
+    /// crates.io (Rust).
+    CratesIo = 0,
+    /// npm (JavaScript / TypeScript).
+    Npm = 1,
+    /// OCI container registry; the ID is a full image reference.
+    Oci = 2,
+    /// Any other registry; the ID should be self-describing (e.g. a URL).
+    Other = 3,
+}
+
+impl ExternalRegistry {
+    /// Convert from raw `u8` to `ExternalRegistry`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ExternalRegistry::CratesIo),
+            1 => Some(ExternalRegistry::Npm),
+            2 => Some(ExternalRegistry::Oci),
+            3 => Some(ExternalRegistry::Other),
+            _ => None,
+        }
+    }
+
+    /// Convert from raw `u8`, failing with `InvalidExternalRegistry`.
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        Self::from_u8(value).ok_or_else(|| error!(Unit09Error::InvalidExternalRegistry))
+    }
+
+    /// Convert `ExternalRegistry` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Identifier of a module in an external package registry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ExternalRef {
+    /// Registry, encoded as an `ExternalRegistry` discriminant.
+    pub registry: u8,
+
+    /// Registry-side identifier (crate name, npm package, image reference).
+    pub id: String,
+}
+
+impl ExternalRef {
+    /// Serialized size of a single entry at maximum ID length.
+    pub const LEN: usize = 1 + 4 + MAX_EXTERNAL_REF_ID_LEN;
+
+    /// Build a reference for `registry`.
+    pub fn new(registry: ExternalRegistry, id: impl Into<String>) -> Self {
+        Self {
+            registry: registry.as_u8(),
+            id: id.into(),
+        }
+    }
+
+    /// Decoded registry of this reference.
+    pub fn registry(&self) -> Result<ExternalRegistry> {
+        ExternalRegistry::try_from_u8(self.registry)
+    }
+}
 
 /// Module account tracked by Unit09.
 ///
@@ -54,6 +128,13 @@ pub struct Module {
     /// - perform module-specific administrative actions
     pub authority: Pubkey,
 
+    /// Lowercased first `NAME_PREFIX_LEN` bytes of `name`, zero-padded.
+    ///
+    /// Kept ahead of every variable-length field so it sits at the fixed
+    /// offset `NAME_PREFIX_OFFSET`, where indexers can run `memcmp` prefix
+    /// filters for autocomplete. Derived by `name_prefix`; never set directly.
+    pub name_prefix: [u8; 16],
+
     /// Human-readable name for the module.
     ///
     /// Example: "unit09-router", "metrics-indexer"
@@ -76,10 +157,24 @@ pub struct Module {
     /// - "worker"
     pub category: String,
 
-    /// Tags for search and discovery.
+    /// Individual tags for search and discovery.
     ///
-    /// Example: "solana,anchor,token,module"
-    pub tags: String,
+    /// Bounded by `MAX_MODULE_TAGS` entries of at most `MAX_MODULE_TAG_LEN`
+    /// bytes each. Instruction arguments still accept the legacy
+    /// comma-separated form; see `parse_legacy_tags` and `legacy_tags`.
+    ///
+    /// Example: ["solana", "anchor", "token", "module"]
+    pub tags_vec: Vec<String>,
+
+    /// SPDX license identifier of the module source (e.g. "MIT").
+    ///
+    /// Empty when the license is unknown; see `license_compatible_with`.
+    pub license_spdx: String,
+
+    /// Cross-references to the same module in external package registries.
+    ///
+    /// At most `MAX_MODULE_EXTERNAL_REFS` entries, one per `(registry, id)`.
+    pub external_refs: Vec<ExternalRef>,
 
     /// Whether this module is currently active.
     ///
@@ -92,6 +187,16 @@ pub struct Module {
     /// should not be used in new designs.
     pub is_deprecated: bool,
 
+    /// Module recommended in place of this one, set when it is deprecated.
+    ///
+    /// `Pubkey::default()` when no replacement was named.
+    pub replaced_by: Pubkey,
+
+    /// Discovery visibility, encoded as a `Visibility` discriminant.
+    ///
+    /// Metadata only; off-chain indexers are responsible for honoring it.
+    pub visibility: u8,
+
     /// Semantic version: major component.
     ///
     /// Increment for breaking changes.
@@ -107,6 +212,20 @@ pub struct Module {
     /// Increment for backwards-compatible bug fixes.
     pub patch_version: u16,
 
+    /// PDA of the highest `ModuleVersion` promoted to stable.
+    ///
+    /// `Pubkey::default()` until a version is promoted.
+    pub latest_stable_version: Pubkey,
+
+    /// Semantic version of `latest_stable_version`: major component.
+    pub latest_stable_major: u16,
+
+    /// Semantic version of `latest_stable_version`: minor component.
+    pub latest_stable_minor: u16,
+
+    /// Semantic version of `latest_stable_version`: patch component.
+    pub latest_stable_patch: u16,
+
     /// How many times this module has been used or referenced by other
     /// on-chain entities (forks, compositions, deployments).
     pub usage_count: u64,
@@ -114,6 +233,53 @@ pub struct Module {
     /// Last time this module was used in a tracked way.
     pub last_used_at: i64,
 
+    /// Lines of code that observations of the parent repository attributed
+    /// to this module, summed over all observations.
+    pub observed_lines_of_code: u64,
+
+    /// Number of versions that are, or were promoted to, stable.
+    pub stable_version_count: u16,
+
+    /// Number of `ModuleVersion` snapshots published for this module.
+    pub version_count: u16,
+
+    /// When the most recent version was published. Starts at `created_at`,
+    /// which is when the module's first version is declared.
+    pub last_version_at: i64,
+
+    /// Maturity signal in `0..=100`, see `compute_maturity`.
+    ///
+    /// Recomputed only when a version is created or promoted, so it reflects
+    /// the module as of its latest release activity.
+    pub maturity_score: u8,
+
+    /// Whether the Unit09 team reviewed this module.
+    ///
+    /// Set only by the config admin through `verify_module` and
+    /// `unverify_module`; the module authority cannot change it.
+    pub is_verified: bool,
+
+    /// Unix timestamp when the badge was granted, or 0 when not verified.
+    pub verified_at: i64,
+
+    /// Module this one was derived from, or `Pubkey::default()` for a root
+    /// module. Set at registration and never changed.
+    pub parent_module: Pubkey,
+
+    /// Whether this module is a derivative (`parent_module` is set).
+    pub is_derivative: bool,
+
+    /// Number of modules registered as derivatives of this one.
+    pub derivative_count: u32,
+
+    /// Launch time (Unix seconds) before which the module cannot be used or
+    /// linked; 0 makes it usable immediately.
+    pub active_from: i64,
+
+    /// When the module was last renamed, or 0 if it never was. Renames are
+    /// spaced by `Config::rename_cooldown_secs`.
+    pub last_renamed_at: i64,
+
     /// Creation timestamp (Unix seconds).
     pub created_at: i64,
 
@@ -129,6 +295,40 @@ pub struct Module {
     /// Reserved space for future upgrades.
     ///
     /// This allows adding new fields later without breaking the account size.
+    pub reserved: [u8; 2],
+
+    /// SHA-256 digest of the document at `metadata_uri`, or zeroed when
+    /// unknown. `apply_update` keeps it in step with `metadata_uri`.
+    pub metadata_checksum: [u8; 32],
+}
+
+/// `Module` as laid out at schema v1, without the discriminator.
+///
+/// Unlike `Repo` and `Config`, schema v2 does not keep this as a prefix:
+/// `name_prefix` has to sit ahead of every variable-length field for
+/// `memcmp` filters, and the comma-separated `tags` became `tags_vec`.
+/// `set_schema_version` therefore reads a v1 module through this type and
+/// rewrites it in the current layout (see `Module::migrate_from_v1`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ModuleV1 {
+    pub module_key: Pubkey,
+    pub repo: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub metadata_uri: String,
+    pub category: String,
+    pub tags: String,
+    pub is_active: bool,
+    pub is_deprecated: bool,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub patch_version: u16,
+    pub usage_count: u64,
+    pub last_used_at: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub schema_version: u8,
+    pub bump: u8,
     pub reserved: [u8; 54],
 }
 
@@ -145,9 +345,51 @@ impl Module {
     /// Maximum length in bytes (UTF-8) for the `category` field.
     pub const MAX_CATEGORY_LEN: usize = MAX_MODULE_CATEGORY_LEN;
 
-    /// Maximum length in bytes (UTF-8) for the `tags` field.
+    /// Maximum length in bytes (UTF-8) for a legacy comma-separated tags
+    /// string accepted by instructions.
     pub const MAX_TAGS_LEN: usize = MAX_TAGS_LEN;
 
+    /// Maximum length in bytes (UTF-8) for the `license_spdx` field.
+    pub const MAX_LICENSE_SPDX_LEN: usize = MAX_LICENSE_SPDX_LEN;
+
+    /// Maximum number of entries in `tags_vec`.
+    pub const MAX_TAGS: usize = MAX_MODULE_TAGS;
+
+    /// Maximum length in bytes (UTF-8) for a single tag.
+    pub const MAX_TAG_LEN: usize = MAX_MODULE_TAG_LEN;
+
+    /// Maximum number of entries in `external_refs`.
+    pub const MAX_EXTERNAL_REFS: usize = MAX_MODULE_EXTERNAL_REFS;
+
+    /// Length of the `name_prefix` search field.
+    pub const NAME_PREFIX_LEN: usize = 16;
+
+    /// Byte offset of `name_prefix` within the account data, for `memcmp`
+    /// filters.
+    pub const NAME_PREFIX_OFFSET: usize = Self::DISCRIMINATOR_LEN + 32 + 32 + 32;
+
+    /// Serialized length of a schema v1 `Module` (`ModuleV1`).
+    pub const V1_LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // module_key: Pubkey
+        + 32 // repo: Pubkey
+        + 32 // authority: Pubkey
+        + 4 + Self::MAX_NAME_LEN // name: String
+        + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri: String
+        + 4 + Self::MAX_CATEGORY_LEN // category: String
+        + 4 + Self::MAX_TAGS_LEN // tags: String
+        + 1 // is_active: bool
+        + 1 // is_deprecated: bool
+        + 2 // major_version: u16
+        + 2 // minor_version: u16
+        + 2 // patch_version: u16
+        + 8 // usage_count: u64
+        + 8 // last_used_at: i64
+        + 8 // created_at: i64
+        + 8 // updated_at: i64
+        + 1 // schema_version: u8
+        + 1 // bump: u8
+        + 54; // reserved: [u8; 54]
+
     /// Total serialized length of the `Module` account.
     ///
     /// Strings are encoded as:
@@ -156,22 +398,54 @@ impl Module {
         + 32 // module_key: Pubkey
         + 32 // repo: Pubkey
         + 32 // authority: Pubkey
+        + Self::NAME_PREFIX_LEN // name_prefix: [u8; 16]
         + 4 + Self::MAX_NAME_LEN // name: String
         + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri: String
         + 4 + Self::MAX_CATEGORY_LEN // category: String
-        + 4 + Self::MAX_TAGS_LEN // tags: String
+        + 4 + Self::MAX_TAGS * (4 + Self::MAX_TAG_LEN) // tags_vec: Vec<String>
+        + 4 + Self::MAX_LICENSE_SPDX_LEN // license_spdx: String
+        + 4 + Self::MAX_EXTERNAL_REFS * ExternalRef::LEN // external_refs: Vec<ExternalRef>
         + 1 // is_active: bool
         + 1 // is_deprecated: bool
+        + 32 // replaced_by: Pubkey
+        + 1 // visibility: u8
         + 2 // major_version: u16
         + 2 // minor_version: u16
         + 2 // patch_version: u16
+        + 32 // latest_stable_version: Pubkey
+        + 2 // latest_stable_major: u16
+        + 2 // latest_stable_minor: u16
+        + 2 // latest_stable_patch: u16
         + 8 // usage_count: u64
         + 8 // last_used_at: i64
+        + 8 // observed_lines_of_code: u64
+        + 2 // stable_version_count: u16
+        + 2 // version_count: u16
+        + 8 // last_version_at: i64
+        + 1 // maturity_score: u8
+        + 1 // is_verified: bool
+        + 8 // verified_at: i64
+        + 32 // parent_module: Pubkey
+        + 1 // is_derivative: bool
+        + 4 // derivative_count: u32
+        + 8 // active_from: i64
+        + 8 // last_renamed_at: i64
         + 8 // created_at: i64
         + 8 // updated_at: i64
         + 1 // schema_version: u8
         + 1 // bump: u8
-        + 54; // reserved: [u8; 54]
+        + 2 // reserved: [u8; 2]
+        + 32; // metadata_checksum: [u8; 32]
+
+    /// Content-addressed `module_key` for a module whose metadata document
+    /// hashes to `metadata_checksum`, registered under the `repo` PDA.
+    ///
+    /// The key is the SHA-256 of the checksum followed by the repo address,
+    /// so anyone holding the metadata can recompute the module PDA. Required
+    /// when `Config::enforce_content_addressed_modules` is set.
+    pub fn content_addressed_key(metadata_checksum: &[u8; 32], repo: &Pubkey) -> Pubkey {
+        Pubkey::new_from_array(hashv(&[metadata_checksum, repo.as_ref()]).to_bytes())
+    }
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -189,14 +463,17 @@ impl Module {
         metadata_uri: String,
         category: String,
         tags: String,
+        license_spdx: String,
         version: (u16, u16, u16),
+        visibility: Visibility,
         bump: u8,
         clock: &Clock,
     ) -> Result<()> {
         Self::validate_name(&name)?;
         Self::validate_metadata_uri(&metadata_uri)?;
         Self::validate_category(&category)?;
-        Self::validate_tags(&tags)?;
+        let tags_vec = Self::parse_legacy_tags(&tags)?;
+        Self::validate_license_spdx(&license_spdx)?;
         Self::validate_version(version)?;
 
         let (major, minor, patch) = version;
@@ -204,54 +481,186 @@ impl Module {
         self.module_key = module_key;
         self.repo = repo;
         self.authority = authority;
+        self.name_prefix = Self::name_prefix(&name);
         self.name = name;
         self.metadata_uri = metadata_uri;
         self.category = category;
-        self.tags = tags;
+        self.tags_vec = tags_vec;
+        self.license_spdx = license_spdx;
+        self.external_refs = Vec::new();
         self.is_active = true;
         self.is_deprecated = false;
+        self.replaced_by = Pubkey::default();
+        self.visibility = visibility.as_u8();
         self.major_version = major;
         self.minor_version = minor;
         self.patch_version = patch;
+        self.latest_stable_version = Pubkey::default();
+        self.latest_stable_major = 0;
+        self.latest_stable_minor = 0;
+        self.latest_stable_patch = 0;
         self.usage_count = 0;
         self.last_used_at = 0;
+        self.observed_lines_of_code = 0;
+        self.stable_version_count = 0;
+        self.version_count = 0;
+        self.last_version_at = clock.unix_timestamp;
+        self.maturity_score = 0;
+        self.is_verified = false;
+        self.verified_at = 0;
+        self.parent_module = Pubkey::default();
+        self.is_derivative = false;
+        self.active_from = 0;
+        self.derivative_count = 0;
+        self.last_renamed_at = 0;
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 54];
+        self.reserved = [0u8; 2];
+        self.metadata_checksum = [0u8; 32];
+        self.refresh_maturity(clock.unix_timestamp);
 
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Migration
+    // -----------------------------------------------------------------------
+
+    /// Fill a zeroed account with the schema v1 module `v1`, giving the
+    /// fields v1 lacks the values `init` would have set.
+    ///
+    /// `schema_version` is copied as is; `set_schema_version` writes the new
+    /// value afterwards. The legacy tags go through `salvage_legacy_tags`,
+    /// so a v1 account can always be migrated.
+    pub fn migrate_from_v1(&mut self, v1: ModuleV1) {
+        self.module_key = v1.module_key;
+        self.repo = v1.repo;
+        self.authority = v1.authority;
+        self.name_prefix = Self::name_prefix(&v1.name);
+        self.name = v1.name;
+        self.metadata_uri = v1.metadata_uri;
+        self.category = v1.category;
+        self.tags_vec = Self::salvage_legacy_tags(&v1.tags);
+        self.is_active = v1.is_active;
+        self.is_deprecated = v1.is_deprecated;
+        self.visibility = Visibility::Public.as_u8();
+        self.major_version = v1.major_version;
+        self.minor_version = v1.minor_version;
+        self.patch_version = v1.patch_version;
+        self.usage_count = v1.usage_count;
+        self.last_used_at = v1.last_used_at;
+        self.last_version_at = v1.created_at;
+        self.created_at = v1.created_at;
+        self.updated_at = v1.updated_at;
+        self.schema_version = v1.schema_version;
+        self.bump = v1.bump;
+        self.refresh_maturity(v1.updated_at);
+    }
+
+    /// Read `repo` straight from the data of a `Module` account.
+    ///
+    /// `module_key` and `repo` lead both the v1 and the current layout, so
+    /// this works on accounts that do not deserialize as `Module`. `None`
+    /// when `data` is too short to hold it.
+    pub fn stored_repo(data: &[u8]) -> Option<Pubkey> {
+        let start = Self::DISCRIMINATOR_LEN + 32;
+        let bytes: [u8; 32] = data.get(start..start + 32)?.try_into().ok()?;
+        Some(Pubkey::new_from_array(bytes))
+    }
+
     // -----------------------------------------------------------------------
     // Metadata / Version Updates
     // -----------------------------------------------------------------------
 
+    /// Stamp `updated_at` from `clock`, never moving it before `created_at`.
+    fn touch(&mut self, clock: &Clock) {
+        self.updated_at = monotonic_updated_at(self.created_at, clock.unix_timestamp);
+        debug_assert!(self.updated_at >= self.created_at);
+    }
+
+    /// Grant or revoke the verified badge.
+    ///
+    /// `signer` must be `admin`, the config admin; the module authority
+    /// has no say. Returns whether the badge changed; setting it to its
+    /// current value leaves `verified_at` untouched.
+    pub fn set_verified(
+        &mut self,
+        signer: &Pubkey,
+        admin: &Pubkey,
+        verified: bool,
+        clock: &Clock,
+    ) -> Result<bool> {
+        if signer != admin {
+            return err!(Unit09Error::InvalidAdmin);
+        }
+        if self.is_verified == verified {
+            return Ok(false);
+        }
+
+        self.is_verified = verified;
+        self.verified_at = if verified { clock.unix_timestamp } else { 0 };
+        self.touch(clock);
+        Ok(true)
+    }
+
+    /// Hand the module to `new_authority`.
+    ///
+    /// Callers are responsible for authorizing the change; see
+    /// `ModuleClaim::resolve`.
+    pub fn set_authority(&mut self, new_authority: Pubkey, clock: &Clock) {
+        self.authority = new_authority;
+        self.touch(clock);
+    }
+
     /// Apply updates to the module metadata and status.
     ///
     /// Used by `update_module` or similar instructions to mutate fields
     /// without reconstructing the full struct.
+    ///
+    /// Changing the name is refused within `rename_cooldown_secs` of the
+    /// previous rename (see `assert_rename_allowed`); passing the current
+    /// name again is not a rename.
+    ///
+    /// A new `metadata_checksum` is stored as given. Without one, moving to a
+    /// different `metadata_uri` clears the stored checksum, which described
+    /// the old document.
     pub fn apply_update(
         &mut self,
         maybe_name: Option<String>,
         maybe_metadata_uri: Option<String>,
+        maybe_metadata_checksum: Option<[u8; 32]>,
         maybe_category: Option<String>,
         maybe_tags: Option<String>,
         maybe_is_active: Option<bool>,
         maybe_is_deprecated: Option<bool>,
         maybe_version: Option<(u16, u16, u16)>,
+        maybe_visibility: Option<Visibility>,
+        maybe_license_spdx: Option<String>,
+        rename_cooldown_secs: u32,
         clock: &Clock,
     ) -> Result<()> {
         if let Some(name) = maybe_name {
             Self::validate_name(&name)?;
+            if name != self.name {
+                self.assert_rename_allowed(rename_cooldown_secs, clock.unix_timestamp)?;
+                self.last_renamed_at = clock.unix_timestamp;
+            }
+            self.name_prefix = Self::name_prefix(&name);
             self.name = name;
         }
 
         if let Some(metadata_uri) = maybe_metadata_uri {
             Self::validate_metadata_uri(&metadata_uri)?;
+            if metadata_uri != self.metadata_uri {
+                self.metadata_checksum = [0u8; 32];
+            }
             self.metadata_uri = metadata_uri;
         }
+        if let Some(metadata_checksum) = maybe_metadata_checksum {
+            self.metadata_checksum = metadata_checksum;
+        }
 
         if let Some(category) = maybe_category {
             Self::validate_category(&category)?;
@@ -259,8 +668,7 @@ impl Module {
         }
 
         if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
+            self.tags_vec = Self::parse_legacy_tags(&tags)?;
         }
 
         if let Some(is_active) = maybe_is_active {
@@ -269,6 +677,9 @@ impl Module {
 
         if let Some(is_deprecated) = maybe_is_deprecated {
             self.is_deprecated = is_deprecated;
+            if !is_deprecated {
+                self.replaced_by = Pubkey::default();
+            }
         }
 
         if let Some(version) = maybe_version {
@@ -279,10 +690,178 @@ impl Module {
             self.patch_version = patch;
         }
 
-        self.updated_at = clock.unix_timestamp;
+        if let Some(visibility) = maybe_visibility {
+            self.visibility = visibility.as_u8();
+        }
+
+        if let Some(license_spdx) = maybe_license_spdx {
+            Self::validate_license_spdx(&license_spdx)?;
+            self.license_spdx = license_spdx;
+        }
+
+        self.touch(clock);
         Ok(())
     }
 
+    /// Ensure the module may be renamed at `now`.
+    ///
+    /// Fails with `ModuleRenameCooldown` until `cooldown_secs` have passed
+    /// since the last rename. A cooldown of 0, or a module that was never
+    /// renamed, always allows it.
+    pub fn assert_rename_allowed(&self, cooldown_secs: u32, now: i64) -> Result<()> {
+        if cooldown_secs == 0 || self.last_renamed_at == 0 {
+            return Ok(());
+        }
+        let allowed_at = self
+            .last_renamed_at
+            .saturating_add(i64::from(cooldown_secs));
+        if now < allowed_at {
+            return err!(Unit09Error::ModuleRenameCooldown);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Tags
+    // -----------------------------------------------------------------------
+
+    /// Add a single tag. Adding a tag that is already present is a no-op.
+    pub fn add_tag(&mut self, tag: &str, clock: &Clock) -> Result<()> {
+        Self::validate_tag(tag)?;
+
+        if self.has_tag(tag) {
+            return Ok(());
+        }
+        if self.tags_vec.len() >= Self::MAX_TAGS {
+            return err!(Unit09Error::TooManyTags);
+        }
+
+        self.tags_vec.push(tag.to_string());
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Remove a single tag, preserving the order of the remaining tags.
+    pub fn remove_tag(&mut self, tag: &str, clock: &Clock) -> Result<()> {
+        let index = self
+            .tags_vec
+            .iter()
+            .position(|t| t == tag)
+            .ok_or(Unit09Error::TagNotFound)?;
+
+        self.tags_vec.remove(index);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Whether the module carries the given tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags_vec.iter().any(|t| t == tag)
+    }
+
+    // -----------------------------------------------------------------------
+    // External Registry References
+    // -----------------------------------------------------------------------
+
+    /// Add an external registry reference. Adding an existing reference is a
+    /// no-op.
+    pub fn add_external_ref(&mut self, external_ref: ExternalRef, clock: &Clock) -> Result<()> {
+        Self::validate_external_ref(&external_ref)?;
+
+        if self.external_refs.contains(&external_ref) {
+            return Ok(());
+        }
+        if self.external_refs.len() >= Self::MAX_EXTERNAL_REFS {
+            return err!(Unit09Error::TooManyExternalRefs);
+        }
+
+        self.external_refs.push(external_ref);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Remove an external registry reference.
+    pub fn remove_external_ref(&mut self, external_ref: &ExternalRef, clock: &Clock) -> Result<()> {
+        let index = self
+            .external_refs
+            .iter()
+            .position(|r| r == external_ref)
+            .ok_or(Unit09Error::ExternalRefNotFound)?;
+
+        self.external_refs.remove(index);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Registry-side ID of this module in `registry`, if recorded.
+    pub fn external_id(&self, registry: ExternalRegistry) -> Option<&str> {
+        self.external_refs
+            .iter()
+            .find(|r| r.registry == registry.as_u8())
+            .map(|r| r.id.as_str())
+    }
+
+    /// Tags rendered in the legacy comma-separated form.
+    pub fn legacy_tags(&self) -> String {
+        self.tags_vec.join(",")
+    }
+
+    /// Split a legacy comma-separated tags string into individual tags.
+    ///
+    /// Whitespace around each tag is trimmed, empty segments and duplicates
+    /// are dropped, and the count and per-tag length limits are enforced.
+    pub fn parse_legacy_tags(tags: &str) -> Result<Vec<String>> {
+        if tags.len() > Self::MAX_TAGS_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+
+        let mut parsed: Vec<String> = Vec::new();
+        for tag in tags.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            Self::validate_tag(tag)?;
+            if parsed.iter().any(|t| t == tag) {
+                continue;
+            }
+            if parsed.len() >= Self::MAX_TAGS {
+                return err!(Unit09Error::TooManyTags);
+            }
+            parsed.push(tag.to_string());
+        }
+
+        Ok(parsed)
+    }
+
+    /// Like `parse_legacy_tags`, but never fails: tags the per-tag rules
+    /// reject are skipped and those past `MAX_TAGS` are dropped.
+    ///
+    /// Used when migrating schema v1 accounts, whose tags string was never
+    /// checked against those limits.
+    pub fn salvage_legacy_tags(tags: &str) -> Vec<String> {
+        let mut salvaged: Vec<String> = Vec::new();
+        for tag in tags.split(',').map(str::trim) {
+            if salvaged.len() >= Self::MAX_TAGS {
+                break;
+            }
+            if Self::validate_tag(tag).is_ok() && !salvaged.iter().any(|t| t == tag) {
+                salvaged.push(tag.to_string());
+            }
+        }
+        salvaged
+    }
+
+    // -----------------------------------------------------------------------
+    // Licensing
+    // -----------------------------------------------------------------------
+
+    /// Whether this module's license can be combined with `other`'s in a
+    /// single composed work (for example, a fork that includes both).
+    ///
+    /// This is a deliberately small matrix over common SPDX identifiers, not
+    /// legal advice. Unknown or empty licenses are only compatible with an
+    /// identical, non-empty identifier.
+    pub fn license_compatible_with(&self, other: &Module) -> bool {
+        licenses_compatible(&self.license_spdx, &other.license_spdx)
+    }
+
     // -----------------------------------------------------------------------
     // Authority and Activation Guards
     // -----------------------------------------------------------------------
@@ -295,14 +874,37 @@ impl Module {
         Ok(())
     }
 
-    /// Ensure that the module is currently active.
-    pub fn assert_active(&self) -> Result<()> {
+    /// Ensure that the module is currently active and past its embargo.
+    pub fn assert_active(&self, now: i64) -> Result<()> {
         if !self.is_active {
             return err!(Unit09Error::ModuleInactive);
         }
+        self.assert_not_embargoed(now)
+    }
+
+    /// Ensure that `now` is at or after the module's `active_from`.
+    pub fn assert_not_embargoed(&self, now: i64) -> Result<()> {
+        if now < self.active_from {
+            return err!(Unit09Error::ModuleEmbargoed);
+        }
+        Ok(())
+    }
+
+    /// Set the launch time before which the module cannot be used or
+    /// linked. 0 lifts the embargo; negative values are rejected.
+    pub fn set_active_from(&mut self, active_from: i64) -> Result<()> {
+        if active_from < 0 {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.active_from = active_from;
         Ok(())
     }
 
+    /// Decoded discovery visibility of this module.
+    pub fn visibility(&self) -> Result<Visibility> {
+        Visibility::try_from_u8(self.visibility)
+    }
+
     /// Ensure that the module is not deprecated.
     pub fn assert_not_deprecated(&self) -> Result<()> {
         if self.is_deprecated {
@@ -311,6 +913,126 @@ impl Module {
         Ok(())
     }
 
+    /// Compare-and-swap guard for updates.
+    ///
+    /// When the caller passes the `updated_at` it last read, the module must
+    /// not have changed since; `None` skips the check.
+    pub fn assert_unchanged_since(&self, expected_updated_at: Option<i64>) -> Result<()> {
+        match expected_updated_at {
+            Some(expected) if expected != self.updated_at => {
+                err!(Unit09Error::ConcurrentModification)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Stable Version Pointer
+    // -----------------------------------------------------------------------
+
+    /// Semantic version of the latest stable pointer, if one is set.
+    pub fn latest_stable(&self) -> Option<(u16, u16, u16)> {
+        if self.latest_stable_version == Pubkey::default() {
+            return None;
+        }
+        Some((
+            self.latest_stable_major,
+            self.latest_stable_minor,
+            self.latest_stable_patch,
+        ))
+    }
+
+    /// Record that `version_key` (at `version`) was promoted to stable.
+    ///
+    /// Every promotion counts towards the maturity score, but the pointer
+    /// only moves forward: promoting an older version leaves it untouched.
+    /// Returns whether the pointer changed.
+    pub fn record_stable_version(
+        &mut self,
+        version_key: Pubkey,
+        version: (u16, u16, u16),
+        clock: &Clock,
+    ) -> bool {
+        self.stable_version_count = self.stable_version_count.saturating_add(1);
+        self.refresh_maturity(clock.unix_timestamp);
+
+        if matches!(self.latest_stable(), Some(current) if current >= version) {
+            return false;
+        }
+
+        let (major, minor, patch) = version;
+        self.latest_stable_version = version_key;
+        self.latest_stable_major = major;
+        self.latest_stable_minor = minor;
+        self.latest_stable_patch = patch;
+        self.touch(clock);
+        true
+    }
+
+    // -----------------------------------------------------------------------
+    // Maturity
+    // -----------------------------------------------------------------------
+
+    /// Record that a new `ModuleVersion` was published for this module.
+    ///
+    /// `is_stable` counts versions that are created stable; versions promoted
+    /// later are counted by `record_stable_version`.
+    pub fn record_version_created(&mut self, is_stable: bool, clock: &Clock) {
+        self.version_count = self.version_count.saturating_add(1);
+        if is_stable {
+            self.stable_version_count = self.stable_version_count.saturating_add(1);
+        }
+        self.last_version_at = clock.unix_timestamp.max(self.last_version_at);
+        self.refresh_maturity(clock.unix_timestamp);
+        self.touch(clock);
+    }
+
+    /// Maturity score for a version history, in `0..=100`.
+    ///
+    /// The sum of three parts:
+    /// - stability: 8 points per stable version, capped at 40 (five versions)
+    /// - age: up to 30 points, growing linearly over the first 365 days since
+    ///   the first version
+    /// - cadence: once at least one version followed the first, 30 points if
+    ///   the latest is at most 30 days old, 20 within 90 days, 10 within 180
+    ///   days, 0 after that
+    ///
+    /// Timestamps in the future count as zero elapsed time.
+    pub fn compute_maturity(
+        stable_versions: u16,
+        first_version_at: i64,
+        last_version_at: i64,
+        now: i64,
+    ) -> u8 {
+        let stability = u64::from(stable_versions.min(5)) * 8;
+
+        let age_days = (now.saturating_sub(first_version_at) / SECONDS_PER_DAY).clamp(0, 365);
+        let age = age_days as u64 * 30 / 365;
+
+        let cadence = if last_version_at > first_version_at {
+            match now.saturating_sub(last_version_at) / SECONDS_PER_DAY {
+                days if days <= 30 => 30,
+                days if days <= 90 => 20,
+                days if days <= 180 => 10,
+                _ => 0,
+            }
+        } else {
+            0
+        };
+
+        (stability + age + cadence) as u8
+    }
+
+    /// Recompute `maturity_score` as of `now`.
+    fn refresh_maturity(&mut self, now: i64) {
+        self.maturity_score = Self::compute_maturity(
+            self.stable_version_count,
+            self.created_at,
+            self.last_version_at,
+            now,
+        );
+    }
+
     // -----------------------------------------------------------------------
     // Usage Tracking
     // -----------------------------------------------------------------------
@@ -319,8 +1041,10 @@ impl Module {
     ///
     /// This is expected to be called by instructions or off-chain actors
     /// whenever the module is used in a meaningful way (for example when
-    /// building or executing a composed system).
+    /// building or executing a composed system). Usage is rejected while the
+    /// module is inactive or embargoed.
     pub fn record_usage(&mut self, clock: &Clock) -> Result<()> {
+        self.assert_active(clock.unix_timestamp)?;
         self.usage_count = self
             .usage_count
             .checked_add(1)
@@ -329,10 +1053,94 @@ impl Module {
         Ok(())
     }
 
+    /// Mark this freshly registered module, at `self_key`, as a derivative
+    /// of `parent`, at `parent_key`, and count it on the parent.
+    ///
+    /// Fails with `InvalidModuleParent` for a default or self parent key.
+    pub fn derive_from(
+        &mut self,
+        self_key: &Pubkey,
+        parent_key: Pubkey,
+        parent: &mut Module,
+    ) -> Result<()> {
+        if parent_key == Pubkey::default() || parent_key == *self_key {
+            return err!(Unit09Error::InvalidModuleParent);
+        }
+        let derivative_count = parent
+            .derivative_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+
+        self.parent_module = parent_key;
+        self.is_derivative = true;
+        parent.derivative_count = derivative_count;
+        Ok(())
+    }
+
+    /// Uncount a derivative of this module that is being closed.
+    pub fn remove_derivative(&mut self) -> Result<()> {
+        self.derivative_count = self
+            .derivative_count
+            .checked_sub(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Point this deprecated module, at `self_key`, at `replacement`, at
+    /// `replacement_key`, as the module to use instead.
+    ///
+    /// Fails with `InvalidModuleReplacement` unless this module is
+    /// deprecated and the replacement is another module that is not.
+    pub fn set_replaced_by(
+        &mut self,
+        self_key: &Pubkey,
+        replacement_key: Pubkey,
+        replacement: &Module,
+    ) -> Result<()> {
+        if !self.is_deprecated
+            || replacement_key == Pubkey::default()
+            || replacement_key == *self_key
+            || replacement.is_deprecated
+        {
+            return err!(Unit09Error::InvalidModuleReplacement);
+        }
+        self.replaced_by = replacement_key;
+        Ok(())
+    }
+
+    /// Add `lines_of_code` attributed to this module by an observation of
+    /// `repo`.
+    ///
+    /// Fails with `ModuleRepoMismatch` when the module belongs to another
+    /// repository.
+    pub fn add_observed_loc(&mut self, repo: &Pubkey, lines_of_code: u64) -> Result<()> {
+        if self.repo != *repo {
+            return err!(Unit09Error::ModuleRepoMismatch);
+        }
+        self.observed_lines_of_code = self
+            .observed_lines_of_code
+            .checked_add(lines_of_code)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Validation Helpers
     // -----------------------------------------------------------------------
 
+    /// Search prefix for `name`: ASCII-lowercased, truncated to
+    /// `NAME_PREFIX_LEN` bytes and zero-padded.
+    ///
+    /// Truncation is byte-wise, so a multi-byte UTF-8 character may be cut;
+    /// indexers should build their query prefix with the same rule.
+    pub fn name_prefix(name: &str) -> [u8; 16] {
+        let mut prefix = [0u8; Self::NAME_PREFIX_LEN];
+        for (slot, byte) in prefix.iter_mut().zip(name.bytes()) {
+            *slot = byte.to_ascii_lowercase();
+        }
+        prefix
+    }
+
     /// Validate the module name.
     fn validate_name(name: &str) -> Result<()> {
         if name.is_empty() {
@@ -379,9 +1187,35 @@ impl Module {
         Ok(())
     }
 
-    /// Validate the tags string.
-    fn validate_tags(tags: &str) -> Result<()> {
-        if tags.len() > Self::MAX_TAGS_LEN {
+    /// Validate a single tag.
+    pub(crate) fn validate_tag(tag: &str) -> Result<()> {
+        if tag.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if tag.len() > Self::MAX_TAG_LEN {
+            return err!(Unit09Error::TagTooLong);
+        }
+        if tag.contains(',') {
+            return err!(Unit09Error::MetadataInvalid);
+        }
+        Ok(())
+    }
+
+    /// Validate a single external registry reference.
+    fn validate_external_ref(external_ref: &ExternalRef) -> Result<()> {
+        external_ref.registry()?;
+        if external_ref.id.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if external_ref.id.len() > MAX_EXTERNAL_REF_ID_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate the SPDX license identifier (may be empty).
+    fn validate_license_spdx(license_spdx: &str) -> Result<()> {
+        if license_spdx.len() > Self::MAX_LICENSE_SPDX_LEN {
             return err!(Unit09Error::StringTooLong);
         }
         Ok(())
@@ -401,3 +1235,781 @@ impl Module {
         Ok(())
     }
 }
+
+/// One-line summary for logs and tooling, e.g.
+/// `module <module_key> "unit09-router" v1.2.0 active verified versions=4 usage=17`.
+///
+/// Handlers never format modules; the summary is for tests and off-chain
+/// tools, where it costs no compute.
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.is_deprecated {
+            "deprecated"
+        } else if self.is_active {
+            "active"
+        } else {
+            "inactive"
+        };
+        write!(
+            f,
+            "module {} \"{}\" v{}.{}.{} {}",
+            self.module_key,
+            self.name,
+            self.major_version,
+            self.minor_version,
+            self.patch_version,
+            status,
+        )?;
+        if self.is_verified {
+            write!(f, " verified")?;
+        }
+        write!(f, " versions={} usage={}", self.version_count, self.usage_count)
+    }
+}
+
+/// Coarse license families used by `Module::license_compatible_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LicenseClass {
+    Permissive,
+    WeakCopyleft,
+    Gpl2Only,
+    Gpl3,
+    Unknown,
+}
+
+fn license_class(spdx: &str) -> LicenseClass {
+    match spdx {
+        "MIT" | "Apache-2.0" | "BSD-2-Clause" | "BSD-3-Clause" | "ISC" | "0BSD" | "Unlicense"
+        | "CC0-1.0" => LicenseClass::Permissive,
+        "MPL-2.0" | "LGPL-2.1" | "LGPL-2.1-only" | "LGPL-2.1-or-later" | "LGPL-3.0"
+        | "LGPL-3.0-only" | "LGPL-3.0-or-later" => LicenseClass::WeakCopyleft,
+        "GPL-2.0" | "GPL-2.0-only" => LicenseClass::Gpl2Only,
+        "GPL-2.0-or-later" | "GPL-3.0" | "GPL-3.0-only" | "GPL-3.0-or-later" | "AGPL-3.0"
+        | "AGPL-3.0-only" | "AGPL-3.0-or-later" => LicenseClass::Gpl3,
+        _ => LicenseClass::Unknown,
+    }
+}
+
+fn licenses_compatible(a: &str, b: &str) -> bool {
+    use LicenseClass::*;
+
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+    if a == b {
+        return true;
+    }
+
+    // Licenses that cannot be combined with GPL-2.0-only code.
+    let gpl2_incompatible = |spdx: &str| spdx == "Apache-2.0" || spdx.starts_with("LGPL-3.0");
+
+    match (license_class(a), license_class(b)) {
+        (Unknown, _) | (_, Unknown) => false,
+        (Gpl2Only, Gpl3) | (Gpl3, Gpl2Only) => false,
+        (Gpl2Only, _) => !gpl2_incompatible(b),
+        (_, Gpl2Only) => !gpl2_incompatible(a),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+    use crate::utils::test_utils::{self, clock_at};
+
+    fn new_module(visibility: Visibility) -> Module {
+        let mut module = test_utils::new_module(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "unit09-router",
+            &clock_at(100),
+        );
+        module.visibility = visibility.as_u8();
+        module
+    }
+
+    #[test]
+    fn visibility_can_be_set_to_each_variant() {
+        let mut module = new_module(Visibility::Unlisted);
+        assert_eq!(module.visibility().unwrap(), Visibility::Unlisted);
+
+        for visibility in [Visibility::Private, Visibility::Public, Visibility::Unlisted] {
+            module
+                .apply_update(
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(visibility),
+                    None,
+                    0,
+                    &clock_at(200),
+                )
+                .unwrap();
+            assert_eq!(module.visibility().unwrap(), visibility);
+        }
+    }
+
+    #[test]
+    fn only_the_admin_sets_the_verified_badge() {
+        let admin = Pubkey::new_unique();
+        let mut module = new_module(Visibility::Public);
+        let author = module.authority;
+
+        assert!(module
+            .set_verified(&author, &admin, true, &clock_at(200))
+            .is_err());
+        assert!(!module.is_verified);
+
+        assert!(module.set_verified(&admin, &admin, true, &clock_at(300)).unwrap());
+        assert_eq!((module.is_verified, module.verified_at), (true, 300));
+        assert!(!module.set_verified(&admin, &admin, true, &clock_at(400)).unwrap());
+        assert_eq!(module.verified_at, 300);
+
+        assert!(module
+            .set_verified(&author, &admin, false, &clock_at(500))
+            .is_err());
+        assert!(module.set_verified(&admin, &admin, false, &clock_at(600)).unwrap());
+        assert_eq!((module.is_verified, module.verified_at), (false, 0));
+    }
+
+    #[test]
+    fn author_updates_leave_the_badge_alone() {
+        let admin = Pubkey::new_unique();
+        let mut module = new_module(Visibility::Public);
+        module.set_verified(&admin, &admin, true, &clock_at(200)).unwrap();
+
+        module
+            .apply_update(
+                Some("unit09-router-v2".to_string()),
+                Some("https://unit09.org/metadata/router-v2.json".to_string()),
+                None,
+                Some("library".to_string()),
+                Some("solana,anchor".to_string()),
+                Some(false),
+                Some(true),
+                Some((2, 0, 0)),
+                Some(Visibility::Unlisted),
+                Some("Apache-2.0".to_string()),
+                0,
+                &clock_at(300),
+            )
+            .unwrap();
+
+        assert_eq!((module.is_verified, module.verified_at), (true, 200));
+    }
+
+    fn update_metadata(module: &mut Module, uri: Option<&str>, checksum: Option<[u8; 32]>) {
+        module
+            .apply_update(
+                None,
+                uri.map(str::to_string),
+                checksum,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                0,
+                &clock_at(300),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn metadata_checksum_follows_metadata_uri() {
+        let mut module = new_module(Visibility::Public);
+        let current_uri = module.metadata_uri.clone();
+        module.metadata_checksum = [1u8; 32];
+
+        update_metadata(&mut module, Some(&current_uri), None);
+        assert_eq!(module.metadata_checksum, [1u8; 32]);
+
+        update_metadata(&mut module, None, Some([2u8; 32]));
+        assert_eq!(module.metadata_checksum, [2u8; 32]);
+
+        let moved_uri = "https://unit09.org/metadata/router-v2.json";
+        update_metadata(&mut module, Some(moved_uri), Some([3u8; 32]));
+        assert_eq!(
+            (module.metadata_uri.as_str(), module.metadata_checksum),
+            (moved_uri, [3u8; 32])
+        );
+
+        update_metadata(&mut module, Some("https://unit09.org/metadata/router-v3.json"), None);
+        assert_eq!(module.metadata_checksum, [0u8; 32]);
+    }
+
+    #[test]
+    fn legacy_tags_round_trip() {
+        let module = new_module(Visibility::Public);
+        assert_eq!(module.tags_vec, vec!["solana".to_string()]);
+
+        let parsed = Module::parse_legacy_tags(" solana, anchor,,token ,anchor").unwrap();
+        assert_eq!(parsed, vec!["solana", "anchor", "token"]);
+
+        let mut module = module;
+        module.tags_vec = parsed;
+        assert_eq!(module.legacy_tags(), "solana,anchor,token");
+    }
+
+    #[test]
+    fn add_and_remove_individual_tags() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+
+        module.add_tag("anchor", &clock).unwrap();
+        module.add_tag("token", &clock).unwrap();
+        module.add_tag("anchor", &clock).unwrap();
+        assert_eq!(module.tags_vec, vec!["solana", "anchor", "token"]);
+
+        module.remove_tag("anchor", &clock).unwrap();
+        assert_eq!(module.tags_vec, vec!["solana", "token"]);
+        assert!(!module.has_tag("anchor"));
+        assert_eq!(module.updated_at, 200);
+
+        assert!(module.remove_tag("anchor", &clock).is_err());
+    }
+
+    #[test]
+    fn tag_count_limit_is_enforced() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+
+        for i in 1..Module::MAX_TAGS {
+            module.add_tag(&format!("tag{i}"), &clock).unwrap();
+        }
+        assert_eq!(module.tags_vec.len(), Module::MAX_TAGS);
+        assert!(module.add_tag("one-too-many", &clock).is_err());
+
+        let too_many = (0..=Module::MAX_TAGS)
+            .map(|i| format!("t{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        assert!(Module::parse_legacy_tags(&too_many).is_err());
+    }
+
+    #[test]
+    fn tag_length_limit_is_enforced() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+
+        let max = "x".repeat(Module::MAX_TAG_LEN);
+        let long = "x".repeat(Module::MAX_TAG_LEN + 1);
+
+        module.add_tag(&max, &clock).unwrap();
+        assert!(module.add_tag(&long, &clock).is_err());
+        assert!(module.add_tag("", &clock).is_err());
+        assert!(Module::parse_legacy_tags(&format!("solana,{long}")).is_err());
+    }
+
+    fn v1_module(tags: &str) -> ModuleV1 {
+        ModuleV1 {
+            module_key: Pubkey::new_unique(),
+            repo: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            name: "Unit09-Router".to_string(),
+            metadata_uri: "ipfs://unit09-router".to_string(),
+            category: "program".to_string(),
+            tags: tags.to_string(),
+            is_active: true,
+            is_deprecated: false,
+            major_version: 1,
+            minor_version: 2,
+            patch_version: 3,
+            usage_count: 9,
+            last_used_at: 150,
+            created_at: 100,
+            updated_at: 200,
+            schema_version: 1,
+            bump: 253,
+            reserved: [0u8; 54],
+        }
+    }
+
+    #[test]
+    fn v1_len_matches_largest_v1_module() {
+        let mut v1 = v1_module(&"t".repeat(Module::MAX_TAGS_LEN));
+        v1.name = "n".repeat(Module::MAX_NAME_LEN);
+        v1.metadata_uri = "u".repeat(Module::MAX_METADATA_URI_LEN);
+        v1.category = "c".repeat(Module::MAX_CATEGORY_LEN);
+
+        let mut data = Module::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Module::V1_LEN);
+    }
+
+    #[test]
+    fn v1_module_migrates_to_current_layout() {
+        let v1 = v1_module(" solana, anchor,,solana");
+        let mut module: Module = test_utils::zeroed(Module::LEN);
+        module.migrate_from_v1(v1.clone());
+
+        assert_eq!(module.module_key, v1.module_key);
+        assert_eq!(module.repo, v1.repo);
+        assert_eq!(module.authority, v1.authority);
+        assert_eq!(module.name, v1.name);
+        assert_eq!(&module.name_prefix[..13], b"unit09-router");
+        assert_eq!(module.tags_vec, vec!["solana", "anchor"]);
+        assert_eq!(module.legacy_tags(), "solana,anchor");
+        assert_eq!(module.latest_stable(), None);
+        assert_eq!((module.major_version, module.minor_version), (1, 2));
+        assert_eq!(module.usage_count, 9);
+        assert_eq!(module.last_version_at, 100);
+        assert_eq!(module.updated_at, 200);
+        assert_eq!(module.bump, 253);
+        assert_eq!(module.visibility().unwrap(), Visibility::Public);
+
+        let mut data = Vec::new();
+        module.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= Module::LEN);
+        let offset = Module::NAME_PREFIX_OFFSET;
+        assert_eq!(data[offset..offset + Module::NAME_PREFIX_LEN], module.name_prefix);
+    }
+
+    #[test]
+    fn salvaged_legacy_tags_drop_what_the_limits_reject() {
+        let long = "x".repeat(Module::MAX_TAG_LEN + 1);
+        let many = (0..Module::MAX_TAGS + 2)
+            .map(|i| format!("t{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let salvaged = Module::salvage_legacy_tags(&format!("solana,{long},anchor"));
+        assert_eq!(salvaged, vec!["solana", "anchor"]);
+        assert_eq!(Module::salvage_legacy_tags(&many).len(), Module::MAX_TAGS);
+        assert!(Module::salvage_legacy_tags(" , ,").is_empty());
+    }
+
+    fn with_license(license: &str) -> Module {
+        let mut module = new_module(Visibility::Public);
+        module.license_spdx = license.to_string();
+        module
+    }
+
+    #[test]
+    fn permissive_licenses_compose() {
+        let mit = with_license("MIT");
+        let apache = with_license("Apache-2.0");
+        let gpl3 = with_license("GPL-3.0-only");
+
+        assert!(mit.license_compatible_with(&apache));
+        assert!(apache.license_compatible_with(&mit));
+        assert!(apache.license_compatible_with(&gpl3));
+    }
+
+    #[test]
+    fn incompatible_licenses_are_rejected() {
+        let apache = with_license("Apache-2.0");
+        let gpl2 = with_license("GPL-2.0-only");
+        let gpl3 = with_license("GPL-3.0-only");
+        let unknown = with_license("");
+
+        assert!(!apache.license_compatible_with(&gpl2));
+        assert!(!gpl2.license_compatible_with(&gpl3));
+        assert!(!unknown.license_compatible_with(&unknown));
+        assert!(!with_license("Proprietary").license_compatible_with(&apache));
+    }
+
+    #[test]
+    fn stable_pointer_only_moves_forward() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(module.latest_stable(), None);
+
+        let v1_1 = Pubkey::new_unique();
+        let v1_0 = Pubkey::new_unique();
+        let v2_0 = Pubkey::new_unique();
+
+        assert!(module.record_stable_version(v1_1, (1, 1, 0), &clock));
+        assert_eq!(module.latest_stable(), Some((1, 1, 0)));
+
+        assert!(!module.record_stable_version(v1_0, (1, 0, 0), &clock));
+        assert_eq!(module.latest_stable_version, v1_1);
+
+        assert!(module.record_stable_version(v2_0, (2, 0, 0), &clock));
+        assert_eq!(module.latest_stable_version, v2_0);
+        assert_eq!(module.latest_stable(), Some((2, 0, 0)));
+    }
+
+    #[test]
+    fn external_refs_cover_crates_and_images() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+
+        module
+            .add_external_ref(ExternalRef::new(ExternalRegistry::CratesIo, "unit09-router"), &clock)
+            .unwrap();
+        module
+            .add_external_ref(
+                ExternalRef::new(ExternalRegistry::Oci, "ghcr.io/unit09-labs/router:1.2.0"),
+                &clock,
+            )
+            .unwrap();
+        // Re-adding is idempotent.
+        module
+            .add_external_ref(ExternalRef::new(ExternalRegistry::CratesIo, "unit09-router"), &clock)
+            .unwrap();
+
+        assert_eq!(module.external_refs.len(), 2);
+        assert_eq!(module.external_id(ExternalRegistry::CratesIo), Some("unit09-router"));
+        assert_eq!(
+            module.external_id(ExternalRegistry::Oci),
+            Some("ghcr.io/unit09-labs/router:1.2.0")
+        );
+        assert_eq!(module.external_id(ExternalRegistry::Npm), None);
+
+        let crate_ref = ExternalRef::new(ExternalRegistry::CratesIo, "unit09-router");
+        module.remove_external_ref(&crate_ref, &clock).unwrap();
+        assert!(module.remove_external_ref(&crate_ref, &clock).is_err());
+        assert_eq!(module.external_id(ExternalRegistry::CratesIo), None);
+    }
+
+    #[test]
+    fn external_refs_are_bounded_and_validated() {
+        let clock = clock_at(200);
+        let mut module = new_module(Visibility::Public);
+
+        for i in 0..Module::MAX_EXTERNAL_REFS {
+            let id = format!("@unit09/module-{i}");
+            module
+                .add_external_ref(ExternalRef::new(ExternalRegistry::Npm, id), &clock)
+                .unwrap();
+        }
+        let overflow = ExternalRef::new(ExternalRegistry::Npm, "@unit09/one-too-many");
+        assert!(module.add_external_ref(overflow, &clock).is_err());
+
+        let mut module = new_module(Visibility::Public);
+        let too_long = "a".repeat(MAX_EXTERNAL_REF_ID_LEN + 1);
+        let bad_registry = ExternalRef {
+            registry: 42,
+            id: "unit09".to_string(),
+        };
+        assert!(module
+            .add_external_ref(ExternalRef::new(ExternalRegistry::Other, too_long), &clock)
+            .is_err());
+        assert!(module
+            .add_external_ref(ExternalRef::new(ExternalRegistry::Npm, ""), &clock)
+            .is_err());
+        assert!(module.add_external_ref(bad_registry, &clock).is_err());
+        assert!(module.external_refs.is_empty());
+    }
+
+    #[test]
+    fn regressed_clock_never_moves_updated_at_before_created_at() {
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(module.created_at, 100);
+
+        module
+            .apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(true),
+                None,
+                None,
+                None,
+                0,
+                &clock_at(40),
+            )
+            .unwrap();
+        assert!(module.is_deprecated);
+        assert_eq!(module.updated_at, module.created_at);
+
+        module.add_tag("router", &clock_at(250)).unwrap();
+        assert_eq!(module.updated_at, 250);
+    }
+
+    #[test]
+    fn name_prefix_is_lowercased_and_padded() {
+        let prefix = Module::name_prefix("Unit09-Router");
+        assert_eq!(&prefix[..13], b"unit09-router");
+        assert_eq!(&prefix[13..], &[0u8; 3]);
+        assert_eq!(Module::name_prefix(""), [0u8; 16]);
+    }
+
+    #[test]
+    fn name_prefix_is_truncated_to_fixed_width() {
+        let prefix = Module::name_prefix("Unit09-Metrics-Indexer-Worker");
+        assert_eq!(&prefix, b"unit09-metrics-i");
+    }
+
+    fn rename(module: &mut Module, name: &str, cooldown_secs: u32, now: i64) -> Result<()> {
+        module.apply_update(
+            Some(name.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            cooldown_secs,
+            &clock_at(now),
+        )
+    }
+
+    #[test]
+    fn renames_are_spaced_by_the_cooldown() {
+        let mut module = new_module(Visibility::Public);
+
+        rename(&mut module, "router-v2", 1_000, 200).unwrap();
+        assert_eq!((module.name.as_str(), module.last_renamed_at), ("router-v2", 200));
+
+        assert!(rename(&mut module, "router-v3", 1_000, 300).is_err());
+        assert_eq!(module.name, "router-v2");
+        // Resubmitting the current name is not a rename.
+        rename(&mut module, "router-v2", 1_000, 300).unwrap();
+        assert_eq!(module.last_renamed_at, 200);
+
+        rename(&mut module, "router-v3", 1_000, 1_200).unwrap();
+        assert_eq!((module.name.as_str(), module.last_renamed_at), ("router-v3", 1_200));
+
+        // A zero cooldown disables the check.
+        rename(&mut module, "router-v4", 0, 1_201).unwrap();
+        assert_eq!(module.name, "router-v4");
+    }
+
+    #[test]
+    fn name_prefix_follows_name_updates() {
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(&module.name_prefix[..13], b"unit09-router");
+
+        module
+            .apply_update(
+                Some("Metrics".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                0,
+                &clock_at(200),
+            )
+            .unwrap();
+        assert_eq!(module.name_prefix, Module::name_prefix("metrics"));
+
+        let mut data = Vec::new();
+        module.try_serialize(&mut data).unwrap();
+        let offset = Module::NAME_PREFIX_OFFSET;
+        assert_eq!(&data[offset..offset + Module::NAME_PREFIX_LEN], &module.name_prefix);
+    }
+
+    const DAY: i64 = SECONDS_PER_DAY;
+
+    #[test]
+    fn maturity_scores_for_pinned_histories() {
+        // Brand-new module: nothing stable, no age, no follow-up release.
+        assert_eq!(Module::compute_maturity(0, 0, 0, 0), 0);
+
+        // Six months in, two stable versions, last release 10 days ago.
+        assert_eq!(Module::compute_maturity(2, 0, 172 * DAY, 182 * DAY), 16 + 14 + 30);
+
+        // Two years in, seven stable versions, last release 100 days ago.
+        assert_eq!(Module::compute_maturity(7, 0, 630 * DAY, 730 * DAY), 40 + 30 + 10);
+
+        // Abandoned: one stable version, nothing new for over a year.
+        assert_eq!(Module::compute_maturity(1, 0, 30 * DAY, 400 * DAY), 8 + 30);
+
+        // Fully mature and actively maintained.
+        assert_eq!(Module::compute_maturity(u16::MAX, 0, 365 * DAY, 365 * DAY), 100);
+    }
+
+    #[test]
+    fn maturity_ignores_clock_skew() {
+        assert_eq!(Module::compute_maturity(0, 1_000, 1_000, 0), 0);
+        assert_eq!(Module::compute_maturity(1, 0, 50 * DAY, 40 * DAY), 8 + 3 + 30);
+    }
+
+    #[test]
+    fn maturity_is_recomputed_on_version_activity() {
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(module.maturity_score, 0);
+
+        let created = module.created_at;
+        module.record_version_created(false, &clock_at(created + 100 * DAY));
+        assert_eq!(module.stable_version_count, 0);
+        assert_eq!(module.maturity_score, 8 + 30);
+
+        let later = clock_at(created + 120 * DAY);
+        module.record_stable_version(Pubkey::new_unique(), (1, 1, 0), &later);
+        assert_eq!(module.stable_version_count, 1);
+        assert_eq!(module.maturity_score, 8 + 9 + 30);
+
+        // Promoting an older version still counts, even though the pointer stays.
+        assert!(!module.record_stable_version(Pubkey::new_unique(), (1, 0, 0), &later));
+        assert_eq!(module.maturity_score, 16 + 9 + 30);
+    }
+
+    #[test]
+    fn expected_updated_at_must_match() {
+        let mut module = new_module(Visibility::Public);
+        let read_at = module.updated_at;
+
+        assert!(module.assert_unchanged_since(None).is_ok());
+        assert!(module.assert_unchanged_since(Some(read_at)).is_ok());
+
+        module.add_tag("anchor", &clock_at(read_at + 60)).unwrap();
+        assert!(module.assert_unchanged_since(Some(read_at)).is_err());
+        assert!(module.assert_unchanged_since(Some(read_at + 60)).is_ok());
+    }
+
+    #[test]
+    fn derivative_module_is_flagged_and_counted_on_parent() {
+        let mut parent = new_module(Visibility::Public);
+        let parent_key = Pubkey::new_unique();
+        let mut derivative = new_module(Visibility::Public);
+        let derivative_key = Pubkey::new_unique();
+
+        derivative
+            .derive_from(&derivative_key, parent_key, &mut parent)
+            .unwrap();
+
+        assert!(derivative.is_derivative);
+        assert_eq!(derivative.parent_module, parent_key);
+        assert_eq!(parent.derivative_count, 1);
+        assert!(!parent.is_derivative);
+
+        parent.remove_derivative().unwrap();
+        assert_eq!(parent.derivative_count, 0);
+        assert!(parent.remove_derivative().is_err());
+    }
+
+    #[test]
+    fn root_module_is_not_a_derivative() {
+        let module = new_module(Visibility::Public);
+        assert!(!module.is_derivative);
+        assert_eq!(module.parent_module, Pubkey::default());
+        assert_eq!(module.derivative_count, 0);
+    }
+
+    #[test]
+    fn default_or_self_parent_is_rejected() {
+        let mut parent = new_module(Visibility::Public);
+        let mut module = new_module(Visibility::Public);
+        let module_key = Pubkey::new_unique();
+
+        assert!(module
+            .derive_from(&module_key, Pubkey::default(), &mut parent)
+            .is_err());
+        assert!(module
+            .derive_from(&module_key, module_key, &mut parent)
+            .is_err());
+        assert!(!module.is_derivative);
+        assert_eq!(parent.derivative_count, 0);
+    }
+
+    #[test]
+    fn embargoed_module_rejects_usage_until_active_from() {
+        let mut module = new_module(Visibility::Public);
+        module.set_active_from(1_000).unwrap();
+
+        assert!(module.assert_active(999).is_err());
+        assert!(module.record_usage(&clock_at(999)).is_err());
+        assert_eq!(module.usage_count, 0);
+
+        assert!(module.assert_active(1_000).is_ok());
+        module.record_usage(&clock_at(1_000)).unwrap();
+        assert_eq!((module.usage_count, module.last_used_at), (1, 1_000));
+    }
+
+    #[test]
+    fn zero_active_from_means_no_embargo() {
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(module.active_from, 0);
+        assert!(module.assert_active(0).is_ok());
+        assert!(module.set_active_from(-1).is_err());
+    }
+
+    #[test]
+    fn summary_names_the_module_version_and_status() {
+        let mut module = new_module(Visibility::Public);
+        let summary = module.to_string();
+        assert!(summary.starts_with(&format!("module {} ", module.module_key)));
+        assert!(summary.contains("\"unit09-router\" v1.0.0 active"));
+        assert!(!summary.contains("verified"));
+
+        module.is_deprecated = true;
+        module.is_verified = true;
+        module.usage_count = 17;
+        let summary = module.to_string();
+        assert!(summary.contains(" deprecated verified "));
+        assert!(summary.ends_with("usage=17"));
+    }
+
+    fn set_deprecated(module: &mut Module, is_deprecated: bool, at: i64) {
+        let flag = Some(is_deprecated);
+        module
+            .apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                flag,
+                None,
+                None,
+                None,
+                0,
+                &clock_at(at),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn deprecating_with_a_replacement_stores_it() {
+        let module_key = Pubkey::new_unique();
+        let replacement_key = Pubkey::new_unique();
+        let replacement = new_module(Visibility::Public);
+        let mut module = new_module(Visibility::Public);
+        assert_eq!(module.replaced_by, Pubkey::default());
+
+        // Only a deprecated module names a replacement.
+        assert!(module
+            .set_replaced_by(&module_key, replacement_key, &replacement)
+            .is_err());
+
+        set_deprecated(&mut module, true, 200);
+        module
+            .set_replaced_by(&module_key, replacement_key, &replacement)
+            .unwrap();
+        assert_eq!(module.replaced_by, replacement_key);
+
+        // Undeprecating drops the pointer.
+        set_deprecated(&mut module, false, 300);
+        assert_eq!(module.replaced_by, Pubkey::default());
+    }
+
+    #[test]
+    fn deprecated_or_self_replacements_are_rejected() {
+        let module_key = Pubkey::new_unique();
+        let mut module = new_module(Visibility::Public);
+        let mut replacement = new_module(Visibility::Public);
+        set_deprecated(&mut module, true, 200);
+        set_deprecated(&mut replacement, true, 200);
+
+        assert!(module
+            .set_replaced_by(&module_key, Pubkey::new_unique(), &replacement)
+            .is_err());
+        let live = new_module(Visibility::Public);
+        assert!(module.set_replaced_by(&module_key, module_key, &live).is_err());
+        assert_eq!(module.replaced_by, Pubkey::default());
+    }
+}
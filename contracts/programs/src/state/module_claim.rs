@@ -0,0 +1,351 @@
+//! ===========================================================================
+//! Unit09 – Module Claim State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/module_claim.rs
+//!
+//! A `ModuleClaim` records that someone claims ownership of a `Module`, for
+//! example the team behind a well-known name that another key registered
+//! first. The claimant backs the claim with a domain proof: an `https://`
+//! URL on the domain the module's name refers to, serving content that
+//! names the claimant's key. The proof is checked off-chain by the admin.
+//!
+//! Claims are filed with `claim_module_name` and settled by the config
+//! admin with `resolve_claim`, which either hands the module to the
+//! claimant or rejects the claim. Each claimant files into their own PDA,
+//! so one claimant cannot block another by filing first; a claimant has at
+//! most one pending claim per module, and a settled one can be replaced by
+//! a new one.
+//!
+//! This file defines:
+//! - `ModuleClaim` account structure and `ClaimStatus`
+//! - length constants for rent-exempt allocation
+//! - helpers for filing and resolving claims
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::Module;
+
+/// State of a `ModuleClaim`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ClaimStatus {
+    /// Filed and awaiting the admin's decision.
+    Pending = 0,
+    /// Approved; the module was transferred to the claimant.
+    Approved = 1,
+    /// Rejected; the module kept its authority.
+    Rejected = 2,
+}
+
+impl ClaimStatus {
+    /// Convert a raw discriminant into a status, if valid.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ClaimStatus::Pending),
+            1 => Some(ClaimStatus::Approved),
+            2 => Some(ClaimStatus::Rejected),
+            _ => None,
+        }
+    }
+
+    /// Raw discriminant stored on-chain.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Ownership claim on a module, reviewed by the config admin.
+#[account]
+pub struct ModuleClaim {
+    /// PDA of the claimed module.
+    pub module: Pubkey,
+
+    /// Key asking to become the module authority.
+    pub claimant: Pubkey,
+
+    /// Module authority when the claim was filed.
+    pub previous_authority: Pubkey,
+
+    /// `https://` URL of the domain proof.
+    pub proof_uri: String,
+
+    /// `ClaimStatus` discriminant.
+    pub status: u8,
+
+    /// Unix timestamp when the claim was filed.
+    pub filed_at: i64,
+
+    /// Unix timestamp when the claim was resolved (0 while pending).
+    pub resolved_at: i64,
+
+    /// Admin that resolved the claim (`Pubkey::default()` while pending).
+    pub resolved_by: Pubkey,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 32],
+}
+
+impl ModuleClaim {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Maximum length of `proof_uri` in bytes (UTF-8).
+    pub const MAX_PROOF_URI_LEN: usize = MAX_CLAIM_PROOF_URI_LEN;
+
+    /// Total serialized length of the `ModuleClaim` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // module: Pubkey
+        + 32 // claimant: Pubkey
+        + 32 // previous_authority: Pubkey
+        + 4 + Self::MAX_PROOF_URI_LEN // proof_uri: String
+        + 1  // status: u8
+        + 8  // filed_at: i64
+        + 8  // resolved_at: i64
+        + 32 // resolved_by: Pubkey
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    /// Whether a claim has ever been filed in this account.
+    pub fn is_initialized(&self) -> bool {
+        self.module != Pubkey::default()
+    }
+
+    /// Current status, if the stored discriminant is valid.
+    pub fn status(&self) -> Option<ClaimStatus> {
+        ClaimStatus::from_u8(self.status)
+    }
+
+    /// Whether the claim awaits the admin's decision.
+    pub fn is_pending(&self) -> bool {
+        self.is_initialized() && self.status == ClaimStatus::Pending.as_u8()
+    }
+
+    /// File a claim by `claimant` on `module`, replacing any settled claim
+    /// held in this account.
+    ///
+    /// Fails with `ModuleClaimPending` while this claimant's previous claim
+    /// is pending, and with `InvalidAuthority` when the claimant already
+    /// owns the module.
+    pub fn file(
+        &mut self,
+        module_key: Pubkey,
+        module: &Module,
+        claimant: Pubkey,
+        proof_uri: String,
+        bump: u8,
+        clock: &Clock,
+    ) -> Result<()> {
+        if self.is_pending() {
+            return err!(Unit09Error::ModuleClaimPending);
+        }
+        if claimant == module.authority {
+            return err!(Unit09Error::InvalidAuthority);
+        }
+        Self::validate_proof_uri(&proof_uri)?;
+
+        self.module = module_key;
+        self.claimant = claimant;
+        self.previous_authority = module.authority;
+        self.proof_uri = proof_uri;
+        self.status = ClaimStatus::Pending.as_u8();
+        self.filed_at = clock.unix_timestamp;
+        self.resolved_at = 0;
+        self.resolved_by = Pubkey::default();
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+
+        Ok(())
+    }
+
+    /// Settle a pending claim on `module`.
+    ///
+    /// `signer` must be `admin`, the config admin. Approving makes the
+    /// claimant the module authority; rejecting leaves the module as it
+    /// is. Fails with `ModuleClaimNotPending` when there is nothing to
+    /// settle, and approval fails with `ModuleClaimStale` once the module
+    /// has changed hands since the claim was filed (for example through
+    /// another claimant's approved claim).
+    pub fn resolve(
+        &mut self,
+        module: &mut Module,
+        signer: &Pubkey,
+        admin: &Pubkey,
+        approve: bool,
+        clock: &Clock,
+    ) -> Result<()> {
+        if signer != admin {
+            return err!(Unit09Error::InvalidAdmin);
+        }
+        if !self.is_pending() {
+            return err!(Unit09Error::ModuleClaimNotPending);
+        }
+        if approve && module.authority != self.previous_authority {
+            return err!(Unit09Error::ModuleClaimStale);
+        }
+
+        let status = if approve {
+            module.set_authority(self.claimant, clock);
+            ClaimStatus::Approved
+        } else {
+            ClaimStatus::Rejected
+        };
+
+        self.status = status.as_u8();
+        self.resolved_at = clock.unix_timestamp;
+        self.resolved_by = *signer;
+        Ok(())
+    }
+
+    /// Validate a domain proof URL: non-empty, bounded and `https://`.
+    fn validate_proof_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if uri.len() > Self::MAX_PROOF_URI_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        if !uri.starts_with("https://") {
+            return err!(Unit09Error::MetadataInvalid);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{self, clock_at, zeroed};
+
+    const PROOF: &str = "https://unit09.org/.well-known/unit09-claim.txt";
+
+    fn new_module(authority: Pubkey) -> Module {
+        test_utils::new_module(
+            Pubkey::new_unique(),
+            authority,
+            "unit09-router",
+            &clock_at(100),
+        )
+    }
+
+    fn blank_claim() -> ModuleClaim {
+        zeroed(ModuleClaim::LEN)
+    }
+
+    #[test]
+    fn claim_is_filed_pending() {
+        let squatter = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let module = new_module(squatter);
+        let module_key = Pubkey::new_unique();
+        let mut claim = blank_claim();
+
+        claim
+            .file(module_key, &module, claimant, PROOF.to_string(), 254, &clock_at(200))
+            .unwrap();
+
+        assert!(claim.is_pending());
+        assert_eq!((claim.module, claim.claimant), (module_key, claimant));
+        assert_eq!(claim.previous_authority, squatter);
+        assert_eq!(claim.filed_at, 200);
+
+        // A second claim waits for the first to be resolved, proofs must
+        // point at a domain, and owners cannot claim their own module.
+        let refile = |claim: &mut ModuleClaim, claimant, proof: &str| {
+            claim.file(module_key, &module, claimant, proof.to_string(), 254, &clock_at(300))
+        };
+        let other = Pubkey::new_unique();
+        assert!(refile(&mut claim, other, PROOF).is_err());
+        assert!(refile(&mut blank_claim(), other, "ipfs://QmProof").is_err());
+        assert!(refile(&mut blank_claim(), squatter, PROOF).is_err());
+        assert!(refile(&mut blank_claim(), other, PROOF).is_ok());
+    }
+
+    #[test]
+    fn approved_claim_transfers_the_module() {
+        let admin = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let mut module = new_module(Pubkey::new_unique());
+        let mut claim = blank_claim();
+        claim
+            .file(Pubkey::new_unique(), &module, claimant, PROOF.to_string(), 254, &clock_at(200))
+            .unwrap();
+
+        // Only the admin decides.
+        assert!(claim
+            .resolve(&mut module, &claimant, &admin, true, &clock_at(300))
+            .is_err());
+
+        claim
+            .resolve(&mut module, &admin, &admin, true, &clock_at(300))
+            .unwrap();
+
+        assert_eq!(module.authority, claimant);
+        assert_eq!(claim.status().unwrap(), ClaimStatus::Approved);
+        assert_eq!((claim.resolved_at, claim.resolved_by), (300, admin));
+        assert!(claim
+            .resolve(&mut module, &admin, &admin, false, &clock_at(400))
+            .is_err());
+    }
+
+    #[test]
+    fn rejected_claim_leaves_ownership_unchanged() {
+        let admin = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let claimant = Pubkey::new_unique();
+        let mut module = new_module(owner);
+        let mut claim = blank_claim();
+        claim
+            .file(Pubkey::new_unique(), &module, claimant, PROOF.to_string(), 254, &clock_at(200))
+            .unwrap();
+
+        claim
+            .resolve(&mut module, &admin, &admin, false, &clock_at(300))
+            .unwrap();
+
+        assert_eq!(module.authority, owner);
+        assert_eq!(claim.status().unwrap(), ClaimStatus::Rejected);
+        assert!(!claim.is_pending());
+    }
+
+    #[test]
+    fn competing_claim_goes_stale_once_another_is_approved() {
+        let admin = Pubkey::new_unique();
+        let mut module = new_module(Pubkey::new_unique());
+        let module_key = Pubkey::new_unique();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut first_claim = blank_claim();
+        let mut second_claim = blank_claim();
+        first_claim
+            .file(module_key, &module, first, PROOF.to_string(), 254, &clock_at(200))
+            .unwrap();
+        second_claim
+            .file(module_key, &module, second, PROOF.to_string(), 253, &clock_at(210))
+            .unwrap();
+
+        first_claim
+            .resolve(&mut module, &admin, &admin, true, &clock_at(300))
+            .unwrap();
+        assert_eq!(module.authority, first);
+
+        // The second claim named the old owner; it can only be rejected.
+        assert!(second_claim
+            .resolve(&mut module, &admin, &admin, true, &clock_at(310))
+            .is_err());
+        assert_eq!(module.authority, first);
+        second_claim
+            .resolve(&mut module, &admin, &admin, false, &clock_at(320))
+            .unwrap();
+        assert_eq!(second_claim.status().unwrap(), ClaimStatus::Rejected);
+    }
+}
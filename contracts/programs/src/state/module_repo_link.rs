@@ -0,0 +1,332 @@
+//! ===========================================================================
+//! Unit09 – Module Repo Link State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/module_repo_link.rs
+//!
+//! A `ModuleRepoLink` records that a `Module` is used by (or originates from)
+//! a `Repo` other than the one it was registered under. One link exists per
+//! `(module, repo)` pair.
+//!
+//! Consent:
+//! - by default a link is active as soon as either authority creates it
+//! - when the repo sets `Repo::require_mutual_consent` and the two
+//!   authorities differ, the link starts pending and only becomes active
+//!   once the other authority confirms it via `confirm_module_link`
+//!
+//! Expiry:
+//! - a link may carry an `expires_at` for temporary associations (such as
+//!   "used in this audit"); readers treat it as inactive from then on (see
+//!   `is_in_effect`) and anyone may close it with `expire_link`
+//!
+//! This file defines:
+//! - `ModuleRepoLink` account structure
+//! - length constants for rent-exempt allocation
+//! - helpers for creation, refresh, confirmation and expiry
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Association between a module and a repository.
+#[account]
+pub struct ModuleRepoLink {
+    /// PDA of the linked module.
+    pub module: Pubkey,
+
+    /// PDA of the repository the module is linked to.
+    pub repo: Pubkey,
+
+    /// Authority that last created or refreshed the link.
+    pub linked_by: Pubkey,
+
+    /// Whether this link is the module's primary ("home") association.
+    pub is_primary: bool,
+
+    /// Whether the link is in effect. False while awaiting confirmation.
+    pub is_active: bool,
+
+    /// Authority whose confirmation is still required.
+    ///
+    /// `Pubkey::default()` when nothing is pending.
+    pub pending_confirmation_from: Pubkey,
+
+    /// Free-form notes for off-chain indexers or dashboards.
+    pub notes: String,
+
+    /// Unix timestamp when the link was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when the link was last updated.
+    pub updated_at: i64,
+
+    /// Unix timestamp when the link became active (0 while pending).
+    pub confirmed_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Unix timestamp from which the link no longer applies; 0 for a
+    /// permanent link.
+    pub expires_at: i64,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 55],
+}
+
+impl ModuleRepoLink {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Maximum length of the `notes` field in bytes (UTF-8).
+    pub const MAX_NOTES_LEN: usize = MAX_LINK_NOTES_LEN;
+
+    /// Total serialized length of the `ModuleRepoLink` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // module: Pubkey
+        + 32 // repo: Pubkey
+        + 32 // linked_by: Pubkey
+        + 1  // is_primary: bool
+        + 1  // is_active: bool
+        + 32 // pending_confirmation_from: Pubkey
+        + 4 + Self::MAX_NOTES_LEN // notes: String
+        + 8  // created_at: i64
+        + 8  // updated_at: i64
+        + 8  // confirmed_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 8  // expires_at: i64
+        + 55; // reserved: [u8; 55]
+
+    // -----------------------------------------------------------------------
+    // Initialization
+    // -----------------------------------------------------------------------
+
+    /// Initialize a new link.
+    ///
+    /// `pending_from` names the authority that still has to confirm; pass
+    /// `None` when no confirmation is needed and the link is active at once.
+    pub fn init(
+        &mut self,
+        module: Pubkey,
+        repo: Pubkey,
+        linked_by: Pubkey,
+        is_primary: bool,
+        notes: String,
+        pending_from: Option<Pubkey>,
+        bump: u8,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::validate_notes(&notes)?;
+
+        let now = clock.unix_timestamp;
+
+        self.module = module;
+        self.repo = repo;
+        self.linked_by = linked_by;
+        self.is_primary = is_primary;
+        self.is_active = pending_from.is_none();
+        self.pending_confirmation_from = pending_from.unwrap_or_default();
+        self.notes = notes;
+        self.created_at = now;
+        self.updated_at = now;
+        self.confirmed_at = if self.is_active { now } else { 0 };
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.expires_at = 0;
+        self.reserved = [0u8; 55];
+
+        Ok(())
+    }
+
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.module != Pubkey::default() && self.repo != Pubkey::default()
+    }
+
+    /// Refresh metadata on an existing link.
+    ///
+    /// Activation is left untouched: a pending link stays pending until it
+    /// is explicitly confirmed.
+    pub fn refresh(
+        &mut self,
+        linked_by: Pubkey,
+        is_primary: bool,
+        notes: String,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::validate_notes(&notes)?;
+
+        self.linked_by = linked_by;
+        self.is_primary = is_primary;
+        self.notes = notes;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Confirmation
+    // -----------------------------------------------------------------------
+
+    /// Whether the link is waiting on the other authority.
+    pub fn is_pending(&self) -> bool {
+        self.pending_confirmation_from != Pubkey::default()
+    }
+
+    /// Confirm a pending link, activating it.
+    ///
+    /// Only the authority named in `pending_confirmation_from` may confirm.
+    pub fn confirm(&mut self, signer: &Pubkey, clock: &Clock) -> Result<()> {
+        if !self.is_pending() {
+            return err!(Unit09Error::LinkNotPending);
+        }
+        if *signer != self.pending_confirmation_from {
+            return err!(Unit09Error::InvalidAuthority);
+        }
+
+        let now = clock.unix_timestamp;
+        self.pending_confirmation_from = Pubkey::default();
+        self.is_active = true;
+        self.confirmed_at = now;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Expiry
+    // -----------------------------------------------------------------------
+
+    /// Set when the link stops applying; 0 makes it permanent.
+    ///
+    /// A non-zero `expires_at` must lie in the future.
+    pub fn set_expiry(&mut self, expires_at: i64, clock: &Clock) -> Result<()> {
+        if expires_at != 0 && expires_at <= clock.unix_timestamp {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.expires_at = expires_at;
+        Ok(())
+    }
+
+    /// Whether the link has an expiry that has passed at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+
+    /// Whether the link applies at `now`: active and not expired.
+    pub fn is_in_effect(&self, now: i64) -> bool {
+        self.is_active && !self.is_expired(now)
+    }
+
+    /// Ensure the link may be closed by `expire_link` at `now`.
+    ///
+    /// Fails with `LinkNotExpired` before the expiry, or when there is none.
+    pub fn assert_expired(&self, now: i64) -> Result<()> {
+        if !self.is_expired(now) {
+            return err!(Unit09Error::LinkNotExpired);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Validation Helpers
+    // -----------------------------------------------------------------------
+
+    /// Validate the notes string.
+    fn validate_notes(notes: &str) -> Result<()> {
+        if notes.len() > Self::MAX_NOTES_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn new_link(pending_from: Option<Pubkey>) -> ModuleRepoLink {
+        let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+        link.init(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            false,
+            "reused for indexing".to_string(),
+            pending_from,
+            255,
+            &clock_at(100),
+        )
+        .unwrap();
+        link
+    }
+
+    #[test]
+    fn link_without_consent_is_active_immediately() {
+        let link = new_link(None);
+        assert!(link.is_active);
+        assert!(!link.is_pending());
+        assert_eq!(link.confirmed_at, 100);
+    }
+
+    #[test]
+    fn pending_link_is_inactive_until_confirmed() {
+        let counterparty = Pubkey::new_unique();
+        let mut link = new_link(Some(counterparty));
+        assert!(!link.is_active);
+        assert!(link.is_pending());
+        assert_eq!(link.confirmed_at, 0);
+
+        link.refresh(link.linked_by, true, String::new(), &clock_at(150))
+            .unwrap();
+        assert!(!link.is_active);
+
+        link.confirm(&counterparty, &clock_at(200)).unwrap();
+        assert!(link.is_active);
+        assert!(!link.is_pending());
+        assert_eq!(link.confirmed_at, 200);
+    }
+
+    #[test]
+    fn only_counterparty_can_confirm_once() {
+        let counterparty = Pubkey::new_unique();
+        let mut link = new_link(Some(counterparty));
+        let initiator = link.linked_by;
+
+        assert!(link.confirm(&initiator, &clock_at(200)).is_err());
+        assert!(link.confirm(&Pubkey::new_unique(), &clock_at(200)).is_err());
+        assert!(!link.is_active);
+
+        link.confirm(&counterparty, &clock_at(200)).unwrap();
+        assert!(link.confirm(&counterparty, &clock_at(300)).is_err());
+    }
+
+    #[test]
+    fn expired_link_is_closeable_only_after_expiry() {
+        let mut link = new_link(None);
+        assert_eq!(link.expires_at, 0);
+        // Permanent links never expire.
+        assert!(link.assert_expired(i64::MAX).is_err());
+
+        link.set_expiry(1_000, &clock_at(100)).unwrap();
+        assert!(link.is_in_effect(999));
+        assert!(link.assert_expired(999).is_err());
+
+        assert!(!link.is_in_effect(1_000));
+        assert!(link.assert_expired(1_000).is_ok());
+    }
+
+    #[test]
+    fn past_expiries_are_refused_and_zero_clears() {
+        let mut link = new_link(None);
+        assert!(link.set_expiry(100, &clock_at(100)).is_err());
+        assert_eq!(link.expires_at, 0);
+
+        link.set_expiry(500, &clock_at(100)).unwrap();
+        link.set_expiry(0, &clock_at(200)).unwrap();
+        assert!(!link.is_expired(i64::MAX));
+    }
+}
@@ -20,13 +20,19 @@
 //! - `ModuleVersion` account structure
 //! - length constants for rent-exempt allocation
 //! - helpers for initialization and validation
+//! - release attestation (signed releases)
+//! - dependency lock (exact dependency versions for reproducible builds)
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
 
+/// A dependency module pinned at `(module, major, minor, patch)`.
+pub type DependencyPin = (Pubkey, u16, u16, u16);
+
 /// Immutable version snapshot for a `Module`.
 ///
 /// Every time a module version is published, a new `ModuleVersion` account
@@ -91,6 +97,23 @@ pub struct ModuleVersion {
     /// a delegated maintainer is allowed to publish versions.
     pub created_by: Pubkey,
 
+    /// SHA-256 digest of the build artifact published for this version.
+    ///
+    /// All zeros means no artifact was recorded.
+    pub artifact_checksum: [u8; 32],
+
+    /// Size of the build artifact in bytes.
+    pub artifact_size: u64,
+
+    /// Reference to the release attestation (signature bundle, provenance
+    /// statement, ...). Empty means none was provided.
+    pub attestation_uri: String,
+
+    /// Key that signed the release.
+    ///
+    /// `Pubkey::default()` means the version has not been attested.
+    pub attested_by: Pubkey,
+
     /// Schema version for this account layout.
     pub schema_version: u8,
 
@@ -98,7 +121,14 @@ pub struct ModuleVersion {
     pub bump: u8,
 
     /// Reserved space for future upgrades.
-    pub reserved: [u8; 63],
+    pub reserved: [u8; 23],
+
+    /// Exact versions of the dependency modules this version was built
+    /// against, captured at snapshot time.
+    ///
+    /// Bounded by `MAX_DEPENDENCY_LOCK_ENTRIES`; each module appears at most
+    /// once. Like the rest of the snapshot, it is never changed after `init`.
+    pub dependency_lock: Vec<DependencyPin>,
 }
 
 impl ModuleVersion {
@@ -114,6 +144,15 @@ impl ModuleVersion {
     /// Maximum length of the label string.
     pub const MAX_LABEL_LEN: usize = MAX_NAME_LEN;
 
+    /// Maximum length of the attestation URI.
+    pub const MAX_ATTESTATION_URI_LEN: usize = MAX_ATTESTATION_URI_LEN;
+
+    /// Maximum number of entries in the dependency lock.
+    pub const MAX_DEPENDENCY_LOCK_ENTRIES: usize = MAX_DEPENDENCY_LOCK_ENTRIES;
+
+    /// Serialized length of one `DependencyPin`.
+    pub const DEPENDENCY_PIN_LEN: usize = 32 + 2 + 2 + 2;
+
     /// Total serialized length of the `ModuleVersion` account.
     ///
     /// Strings are encoded as:
@@ -131,9 +170,14 @@ impl ModuleVersion {
         + 8  // created_at: i64
         + 8  // deprecated_at: i64
         + 32 // created_by: Pubkey
+        + 32 // artifact_checksum: [u8; 32]
+        + 8  // artifact_size: u64
+        + 4 + Self::MAX_ATTESTATION_URI_LEN // attestation_uri: String
+        + 32 // attested_by: Pubkey
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 63; // reserved: [u8; 63]
+        + 23 // reserved: [u8; 23]
+        + 4 + Self::MAX_DEPENDENCY_LOCK_ENTRIES * Self::DEPENDENCY_PIN_LEN; // dependency_lock
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -152,13 +196,23 @@ impl ModuleVersion {
         changelog_uri: String,
         label: String,
         is_stable: bool,
+        artifact_checksum: [u8; 32],
+        artifact_size: u64,
+        attestation_uri: String,
+        attested_by: Option<Pubkey>,
+        dependency_lock: Vec<DependencyPin>,
         bump: u8,
         clock: &Clock,
     ) -> Result<()> {
         Self::validate_version(version)?;
+        Self::validate_dependency_lock(&module, &dependency_lock)?;
         Self::validate_metadata_uri(&metadata_uri)?;
         Self::validate_changelog_uri(&changelog_uri)?;
         Self::validate_label(&label)?;
+        Self::validate_attestation_uri(&attestation_uri)?;
+        if attested_by.is_some() && attestation_uri.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
 
         let (major, minor, patch) = version;
 
@@ -174,9 +228,14 @@ impl ModuleVersion {
         self.created_at = clock.unix_timestamp;
         self.deprecated_at = 0;
         self.created_by = created_by;
+        self.artifact_checksum = artifact_checksum;
+        self.artifact_size = artifact_size;
+        self.attestation_uri = attestation_uri;
+        self.attested_by = attested_by.unwrap_or_default();
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 63];
+        self.reserved = [0u8; 23];
+        self.dependency_lock = dependency_lock;
 
         Ok(())
     }
@@ -201,6 +260,106 @@ impl ModuleVersion {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Stability Promotion
+    // -----------------------------------------------------------------------
+
+    /// Semantic version of this snapshot as `(major, minor, patch)`.
+    pub fn version(&self) -> (u16, u16, u16) {
+        (self.major_version, self.minor_version, self.patch_version)
+    }
+
+    /// Promote a beta version to stable.
+    ///
+    /// This is the only field that may change after creation besides
+    /// deprecation, and it only ever moves from false to true.
+    pub fn promote_to_stable(&mut self) -> Result<()> {
+        if self.is_deprecated {
+            return err!(Unit09Error::ModuleVersionDeprecated);
+        }
+        if self.is_stable {
+            return err!(Unit09Error::ModuleVersionAlreadyStable);
+        }
+
+        self.is_stable = true;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Artifact Provenance
+    // -----------------------------------------------------------------------
+
+    /// Returns true if an artifact checksum was recorded for this version.
+    pub fn has_artifact(&self) -> bool {
+        self.artifact_checksum != [0u8; 32]
+    }
+
+    /// Verify that `bytes` is the build artifact recorded for this version.
+    ///
+    /// Both the byte length and the SHA-256 digest must match.
+    pub fn verify_artifact(&self, bytes: &[u8]) -> Result<()> {
+        if !self.has_artifact() {
+            return err!(Unit09Error::ArtifactNotRecorded);
+        }
+        if bytes.len() as u64 != self.artifact_size {
+            return err!(Unit09Error::ArtifactMismatch);
+        }
+        if hash(bytes).to_bytes() != self.artifact_checksum {
+            return err!(Unit09Error::ArtifactMismatch);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Release Attestation
+    // -----------------------------------------------------------------------
+
+    /// Returns true if a signer has been recorded for this version.
+    pub fn is_attested(&self) -> bool {
+        self.attested_by != Pubkey::default()
+    }
+
+    /// Record `attested_by` as the signer of this release.
+    ///
+    /// `maybe_attestation_uri` replaces the stored reference when given; the
+    /// version must end up with a non-empty reference. Attestation is
+    /// one-way: an attested version cannot be re-attested.
+    pub fn mark_attested(
+        &mut self,
+        attested_by: Pubkey,
+        maybe_attestation_uri: Option<String>,
+    ) -> Result<()> {
+        if self.is_attested() {
+            return err!(Unit09Error::ModuleVersionAlreadyAttested);
+        }
+        if attested_by == Pubkey::default() {
+            return err!(Unit09Error::InvalidAuthority);
+        }
+
+        if let Some(attestation_uri) = maybe_attestation_uri {
+            Self::validate_attestation_uri(&attestation_uri)?;
+            self.attestation_uri = attestation_uri;
+        }
+        if self.attestation_uri.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+
+        self.attested_by = attested_by;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Dependency Lock
+    // -----------------------------------------------------------------------
+
+    /// Version of `dependency` pinned by this snapshot, if it is locked.
+    pub fn pinned_version(&self, dependency: &Pubkey) -> Option<(u16, u16, u16)> {
+        self.dependency_lock
+            .iter()
+            .find(|(module, ..)| module == dependency)
+            .map(|&(_, major, minor, patch)| (major, minor, patch))
+    }
+
     // -----------------------------------------------------------------------
     // Helpers and Validation
     // -----------------------------------------------------------------------
@@ -217,6 +376,25 @@ impl ModuleVersion {
         Ok(())
     }
 
+    /// Check the dependency lock's size and that every pin names a distinct
+    /// module other than `module` at a non-zero version.
+    fn validate_dependency_lock(module: &Pubkey, dependency_lock: &[DependencyPin]) -> Result<()> {
+        if dependency_lock.len() > Self::MAX_DEPENDENCY_LOCK_ENTRIES {
+            return err!(Unit09Error::DependencyLockTooLarge);
+        }
+
+        for (index, &(dependency, major, minor, patch)) in dependency_lock.iter().enumerate() {
+            let duplicate = dependency_lock[..index]
+                .iter()
+                .any(|(earlier, ..)| *earlier == dependency);
+            if dependency == *module || duplicate || (major, minor, patch) == (0, 0, 0) {
+                return err!(Unit09Error::InvalidDependencyLock);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate metadata URI for this version.
     fn validate_metadata_uri(uri: &str) -> Result<()> {
         if uri.is_empty() {
@@ -263,6 +441,30 @@ impl ModuleVersion {
         Ok(())
     }
 
+    /// Validate attestation URI.
+    ///
+    /// Empty is allowed (no attestation yet); otherwise the same schemes as
+    /// the other version URIs are accepted.
+    fn validate_attestation_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() {
+            return Ok(());
+        }
+        if uri.len() > Self::MAX_ATTESTATION_URI_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+
+        let has_known_prefix = uri.starts_with("http://")
+            || uri.starts_with("https://")
+            || uri.starts_with("ipfs://")
+            || uri.starts_with("ar://");
+
+        if !has_known_prefix {
+            return err!(Unit09Error::MetadataInvalid);
+        }
+
+        Ok(())
+    }
+
     /// Validate version label.
     fn validate_label(label: &str) -> Result<()> {
         if label.len() > Self::MAX_LABEL_LEN {
@@ -271,3 +473,203 @@ impl ModuleVersion {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::zeroed;
+
+    const ARTIFACT: &[u8] = b"unit09-router.so build output";
+
+    fn new_version(artifact_checksum: [u8; 32], artifact_size: u64) -> ModuleVersion {
+        let mut version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        version
+            .init(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                (1, 2, 3),
+                "https://unit09.org/metadata/router/1.2.3.json".to_string(),
+                String::new(),
+                String::new(),
+                true,
+                artifact_checksum,
+                artifact_size,
+                String::new(),
+                None,
+                Vec::new(),
+                255,
+                &Clock::default(),
+            )
+            .unwrap();
+        version
+    }
+
+    #[test]
+    fn stores_artifact_fields_at_creation() {
+        let checksum = hash(ARTIFACT).to_bytes();
+        let version = new_version(checksum, ARTIFACT.len() as u64);
+
+        assert!(version.has_artifact());
+        assert_eq!(version.artifact_checksum, checksum);
+        assert_eq!(version.artifact_size, ARTIFACT.len() as u64);
+    }
+
+    #[test]
+    fn verifies_matching_artifact() {
+        let version = new_version(hash(ARTIFACT).to_bytes(), ARTIFACT.len() as u64);
+        assert!(version.verify_artifact(ARTIFACT).is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatching_artifact() {
+        let version = new_version(hash(ARTIFACT).to_bytes(), ARTIFACT.len() as u64);
+
+        // Same length, different content.
+        let mut tampered = ARTIFACT.to_vec();
+        tampered[0] ^= 0xff;
+        assert!(version.verify_artifact(&tampered).is_err());
+
+        // Different length.
+        assert!(version.verify_artifact(&ARTIFACT[1..]).is_err());
+    }
+
+    #[test]
+    fn rejects_verification_without_recorded_artifact() {
+        let version = new_version([0u8; 32], 0);
+        assert!(!version.has_artifact());
+        assert!(version.verify_artifact(&[]).is_err());
+    }
+
+    #[test]
+    fn promotion_is_one_way() {
+        let mut version = new_version([0u8; 32], 0);
+        version.is_stable = false;
+
+        version.promote_to_stable().unwrap();
+        assert!(version.is_stable);
+        assert!(version.promote_to_stable().is_err());
+    }
+
+    #[test]
+    fn deprecated_version_cannot_be_promoted() {
+        let mut version = new_version([0u8; 32], 0);
+        version.is_stable = false;
+        version.deprecate(&Clock::default()).unwrap();
+
+        assert!(version.promote_to_stable().is_err());
+        assert!(!version.is_stable);
+    }
+
+    const ATTESTATION: &str = "https://unit09.org/attestations/router/1.2.3.sigstore.json";
+
+    #[test]
+    fn stores_attestation_at_creation() {
+        let mut version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        let signer = Pubkey::new_unique();
+        version
+            .init(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                (1, 2, 3),
+                "https://unit09.org/metadata/router/1.2.3.json".to_string(),
+                String::new(),
+                String::new(),
+                true,
+                [0u8; 32],
+                0,
+                ATTESTATION.to_string(),
+                Some(signer),
+                Vec::new(),
+                255,
+                &Clock::default(),
+            )
+            .unwrap();
+
+        assert!(version.is_attested());
+        assert_eq!(version.attested_by, signer);
+        assert_eq!(version.attestation_uri, ATTESTATION);
+        assert!(version.mark_attested(Pubkey::new_unique(), None).is_err());
+    }
+
+    #[test]
+    fn existing_version_can_be_attested_later() {
+        let mut version = new_version([0u8; 32], 0);
+        assert!(!version.is_attested());
+
+        // A signer without any attestation reference is refused.
+        assert!(version.mark_attested(Pubkey::new_unique(), None).is_err());
+        assert!(version
+            .mark_attested(Pubkey::default(), Some(ATTESTATION.to_string()))
+            .is_err());
+
+        let signer = Pubkey::new_unique();
+        version
+            .mark_attested(signer, Some(ATTESTATION.to_string()))
+            .unwrap();
+        assert!(version.is_attested());
+        assert_eq!(version.attested_by, signer);
+        assert_eq!(version.attestation_uri, ATTESTATION);
+    }
+
+    fn version_with_lock(
+        module: Pubkey,
+        dependency_lock: Vec<DependencyPin>,
+    ) -> Result<ModuleVersion> {
+        let mut version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        version.init(
+            module,
+            Pubkey::new_unique(),
+            (2, 0, 0),
+            "https://unit09.org/metadata/router/2.0.0.json".to_string(),
+            String::new(),
+            String::new(),
+            true,
+            [0u8; 32],
+            0,
+            String::new(),
+            None,
+            dependency_lock,
+            255,
+            &Clock::default(),
+        )?;
+        Ok(version)
+    }
+
+    #[test]
+    fn stores_pinned_dependencies() {
+        let (indexer, worker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lock = vec![(indexer, 1, 4, 2), (worker, 0, 9, 0)];
+        let mut version = version_with_lock(Pubkey::new_unique(), lock.clone()).unwrap();
+
+        assert_eq!(version.dependency_lock, lock);
+        assert_eq!(version.pinned_version(&indexer), Some((1, 4, 2)));
+        assert_eq!(version.pinned_version(&worker), Some((0, 9, 0)));
+        assert_eq!(version.pinned_version(&Pubkey::new_unique()), None);
+
+        // Later changes to the snapshot leave the lock untouched.
+        version.is_stable = false;
+        version.promote_to_stable().unwrap();
+        version
+            .mark_attested(Pubkey::new_unique(), Some(ATTESTATION.to_string()))
+            .unwrap();
+        version.deprecate(&Clock::default()).unwrap();
+        assert_eq!(version.dependency_lock, lock);
+    }
+
+    #[test]
+    fn rejects_invalid_dependency_locks() {
+        let module = Pubkey::new_unique();
+        let dependency = Pubkey::new_unique();
+
+        assert!(version_with_lock(module, vec![(module, 1, 0, 0)]).is_err());
+        assert!(version_with_lock(module, vec![(dependency, 0, 0, 0)]).is_err());
+        assert!(
+            version_with_lock(module, vec![(dependency, 1, 0, 0), (dependency, 1, 1, 0)]).is_err()
+        );
+
+        let oversized = (0..=MAX_DEPENDENCY_LOCK_ENTRIES)
+            .map(|_| (Pubkey::new_unique(), 1, 0, 0))
+            .collect();
+        assert!(version_with_lock(module, oversized).is_err());
+    }
+}
@@ -0,0 +1,342 @@
+//! ===========================================================================
+//! Unit09 – Observer Stats State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/observer_stats.rs
+//!
+//! An `ObserverStats` account tallies one observer's contributions to one
+//! repository: how many observations they recorded and how many lines of
+//! code those observations processed. One account exists per
+//! `(repo, observer)` pair, created on that observer's first
+//! `record_observation` against the repo.
+//!
+//! Indexers can rank a repository's observers by these tallies to build
+//! contributor leaderboards without replaying every `ObservationRecorded`.
+//!
+//! When `Config::observation_reward_lamports` is set, eligible observations
+//! also accrue that reward here, at most once per
+//! `OBSERVATION_REWARD_INTERVAL_SECONDS`; the observer withdraws it from the
+//! rewards vault with `claim_observer_reward`. See `record_observation` for
+//! which observations are eligible.
+//!
+//! This file defines:
+//! - `ObserverStats` account structure
+//! - length constants for rent-exempt allocation
+//! - helpers for creation, per-observation updates, rewards and key
+//!   rotation
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Per-observer observation tallies for a repository.
+#[account]
+pub struct ObserverStats {
+    /// PDA of the observed repository.
+    pub repo: Pubkey,
+
+    /// Signer that recorded the observations.
+    pub observer: Pubkey,
+
+    /// Number of observations this observer recorded on the repo.
+    pub observation_count: u64,
+
+    /// Sum of `lines_of_code` over those observations.
+    pub total_lines_of_code: u64,
+
+    /// Unix timestamp of this observer's first observation on the repo.
+    pub first_observed_at: i64,
+
+    /// Unix timestamp of this observer's latest observation on the repo.
+    pub last_observed_at: i64,
+
+    /// Observation rewards, in lamports, accrued and not yet claimed.
+    pub accrued_reward: u64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Unix timestamp of the latest observation that accrued a reward; 0
+    /// before the first.
+    pub last_rewarded_at: i64,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 16],
+}
+
+impl ObserverStats {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length of the `ObserverStats` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // repo: Pubkey
+        + 32 // observer: Pubkey
+        + 8  // observation_count: u64
+        + 8  // total_lines_of_code: u64
+        + 8  // first_observed_at: i64
+        + 8  // last_observed_at: i64
+        + 8  // accrued_reward: u64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 8  // last_rewarded_at: i64
+        + 16; // reserved: [u8; 16]
+
+    /// Initialize empty tallies for `observer` on `repo`.
+    pub fn init(&mut self, repo: Pubkey, observer: Pubkey, bump: u8, clock: &Clock) {
+        self.repo = repo;
+        self.observer = observer;
+        self.observation_count = 0;
+        self.total_lines_of_code = 0;
+        self.first_observed_at = clock.unix_timestamp;
+        self.last_observed_at = clock.unix_timestamp;
+        self.accrued_reward = 0;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.last_rewarded_at = 0;
+        self.reserved = [0u8; 16];
+    }
+
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.repo != Pubkey::default() && self.observer != Pubkey::default()
+    }
+
+    /// Count one observation of `lines_of_code` lines.
+    pub fn record_observation(&mut self, lines_of_code: u64, clock: &Clock) -> Result<()> {
+        let observation_count = self
+            .observation_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let total_lines_of_code = self
+            .total_lines_of_code
+            .checked_add(lines_of_code)
+            .ok_or(Unit09Error::CounterOverflow)?;
+
+        self.observation_count = observation_count;
+        self.total_lines_of_code = total_lines_of_code;
+        self.last_observed_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Add `reward` lamports to the unclaimed balance for an observation at
+    /// `now`, returning whether it was added.
+    ///
+    /// Nothing is added when `reward` is zero or within
+    /// `OBSERVATION_REWARD_INTERVAL_SECONDS` of the previous reward.
+    pub fn accrue_reward(&mut self, reward: u64, now: i64) -> Result<bool> {
+        let rewarded_recently = self.last_rewarded_at != 0
+            && now.saturating_sub(self.last_rewarded_at) < OBSERVATION_REWARD_INTERVAL_SECONDS;
+        if reward == 0 || rewarded_recently {
+            return Ok(false);
+        }
+
+        self.accrued_reward = self
+            .accrued_reward
+            .checked_add(reward)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.last_rewarded_at = now;
+        Ok(true)
+    }
+
+    /// Settle the unclaimed balance against a vault holding
+    /// `vault_available` spendable lamports.
+    ///
+    /// Returns the amount to pay out and clears the balance. Fails with
+    /// `NoRewardAccrued` when there is nothing to claim, and with
+    /// `RewardsVaultInsufficient` when the vault cannot pay all of it.
+    pub fn take_reward(&mut self, vault_available: u64) -> Result<u64> {
+        let amount = self.accrued_reward;
+        if amount == 0 {
+            return err!(Unit09Error::NoRewardAccrued);
+        }
+        if amount > vault_available {
+            return err!(Unit09Error::RewardsVaultInsufficient);
+        }
+
+        self.accrued_reward = 0;
+        Ok(amount)
+    }
+
+    /// Take over the tallies of `previous`, the stats of the same repo under
+    /// a rotated-out observer key.
+    ///
+    /// Counts and unclaimed rewards are added, so rotating onto a key that
+    /// already observed the repo merges both histories. The later
+    /// `last_rewarded_at` is kept, so a rotation does not restart the reward
+    /// interval. Either every tally moves or none does.
+    pub fn absorb(&mut self, previous: &ObserverStats) -> Result<()> {
+        if previous.repo != self.repo || previous.observer == self.observer {
+            return err!(Unit09Error::InvalidObserverRotation);
+        }
+        let observation_count = self
+            .observation_count
+            .checked_add(previous.observation_count)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let total_lines_of_code = self
+            .total_lines_of_code
+            .checked_add(previous.total_lines_of_code)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let accrued_reward = self
+            .accrued_reward
+            .checked_add(previous.accrued_reward)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let (first_observed_at, last_observed_at) = if self.observation_count == 0 {
+            (previous.first_observed_at, previous.last_observed_at)
+        } else {
+            (
+                self.first_observed_at.min(previous.first_observed_at),
+                self.last_observed_at.max(previous.last_observed_at),
+            )
+        };
+
+        self.observation_count = observation_count;
+        self.total_lines_of_code = total_lines_of_code;
+        self.accrued_reward = accrued_reward;
+        self.first_observed_at = first_observed_at;
+        self.last_observed_at = last_observed_at;
+        self.last_rewarded_at = self.last_rewarded_at.max(previous.last_rewarded_at);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn new_stats(repo: Pubkey, observer: Pubkey) -> ObserverStats {
+        let mut stats: ObserverStats = zeroed(ObserverStats::LEN);
+        assert!(!stats.is_initialized());
+        stats.init(repo, observer, 255, &clock_at(100));
+        stats
+    }
+
+    #[test]
+    fn observers_accumulate_independently() {
+        let repo = Pubkey::new_unique();
+        let mut alice = new_stats(repo, Pubkey::new_unique());
+        let mut bob = new_stats(repo, Pubkey::new_unique());
+
+        alice.record_observation(1_000, &clock_at(200)).unwrap();
+        bob.record_observation(250, &clock_at(300)).unwrap();
+        alice.record_observation(500, &clock_at(400)).unwrap();
+
+        assert_eq!((alice.observation_count, alice.total_lines_of_code), (2, 1_500));
+        assert_eq!((bob.observation_count, bob.total_lines_of_code), (1, 250));
+        assert_eq!(alice.first_observed_at, 100);
+        assert_eq!(alice.last_observed_at, 400);
+        assert_eq!(bob.last_observed_at, 300);
+    }
+
+    #[test]
+    fn overflowing_loc_is_rejected() {
+        let mut stats = new_stats(Pubkey::new_unique(), Pubkey::new_unique());
+        stats.record_observation(u64::MAX, &clock_at(200)).unwrap();
+
+        assert!(stats.record_observation(1, &clock_at(300)).is_err());
+        assert_eq!(stats.observation_count, 1);
+    }
+
+    #[test]
+    fn rotation_carries_tallies_to_the_new_key() {
+        let repo = Pubkey::new_unique();
+        let mut old = new_stats(repo, Pubkey::new_unique());
+        old.record_observation(1_000, &clock_at(200)).unwrap();
+        old.record_observation(500, &clock_at(300)).unwrap();
+        assert!(old.accrue_reward(5_000, 300).unwrap());
+
+        let mut rotated = new_stats(repo, Pubkey::new_unique());
+        rotated.absorb(&old).unwrap();
+
+        assert_eq!((rotated.observation_count, rotated.total_lines_of_code), (2, 1_500));
+        assert_eq!(rotated.first_observed_at, old.first_observed_at);
+        assert_eq!(rotated.last_observed_at, 300);
+        assert_eq!(rotated.accrued_reward, 5_000);
+        assert_eq!(rotated.last_rewarded_at, 300);
+
+        // The new key is still inside the old key's reward interval.
+        assert!(!rotated.accrue_reward(5_000, 301).unwrap());
+    }
+
+    #[test]
+    fn rotation_onto_an_existing_observer_merges_tallies() {
+        let repo = Pubkey::new_unique();
+        let mut old = new_stats(repo, Pubkey::new_unique());
+        old.record_observation(1_000, &clock_at(200)).unwrap();
+        let mut existing = new_stats(repo, Pubkey::new_unique());
+        existing.record_observation(40, &clock_at(400)).unwrap();
+
+        existing.absorb(&old).unwrap();
+
+        assert_eq!((existing.observation_count, existing.total_lines_of_code), (2, 1_040));
+        assert_eq!(existing.last_observed_at, 400);
+    }
+
+    #[test]
+    fn rotation_requires_a_new_key_on_the_same_repo() {
+        let observer = Pubkey::new_unique();
+        let old = new_stats(Pubkey::new_unique(), observer);
+
+        let mut same_key = new_stats(old.repo, observer);
+        assert!(same_key.absorb(&old).is_err());
+
+        let mut other_repo = new_stats(Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(other_repo.absorb(&old).is_err());
+        assert_eq!(other_repo.observation_count, 0);
+    }
+
+    #[test]
+    fn observations_accrue_the_configured_reward() {
+        let mut stats = new_stats(Pubkey::new_unique(), Pubkey::new_unique());
+        let reward = 5_000;
+        for at in [200, 4_000, 8_000] {
+            stats.record_observation(1_000, &clock_at(at)).unwrap();
+            assert!(stats.accrue_reward(reward, at).unwrap());
+        }
+
+        assert_eq!(stats.accrued_reward, 15_000);
+        assert_eq!(stats.last_rewarded_at, 8_000);
+    }
+
+    #[test]
+    fn rewards_are_rate_limited_per_observer() {
+        let mut stats = new_stats(Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(stats.accrue_reward(5_000, 200).unwrap());
+
+        // Rapid-fire observations earn nothing more until the interval ends.
+        let next = 200 + OBSERVATION_REWARD_INTERVAL_SECONDS;
+        assert!(!stats.accrue_reward(5_000, 201).unwrap());
+        assert!(!stats.accrue_reward(5_000, next - 1).unwrap());
+        assert_eq!(stats.accrued_reward, 5_000);
+
+        assert!(stats.accrue_reward(5_000, next).unwrap());
+        assert_eq!(stats.accrued_reward, 10_000);
+
+        // A disabled reward does not restart the interval.
+        assert!(!stats.accrue_reward(0, next * 2).unwrap());
+        assert_eq!(stats.last_rewarded_at, next);
+    }
+
+    #[test]
+    fn claiming_pays_the_accrued_reward_out_of_the_vault() {
+        let mut stats = new_stats(Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(stats.take_reward(1_000_000).is_err());
+
+        stats.accrue_reward(15_000, 200).unwrap();
+        assert!(stats.take_reward(14_999).is_err());
+        assert_eq!(stats.accrued_reward, 15_000);
+
+        let mut vault_available = 1_000_000;
+        let paid = stats.take_reward(vault_available).unwrap();
+        vault_available -= paid;
+
+        assert_eq!(paid, 15_000);
+        assert_eq!(vault_available, 985_000);
+        assert_eq!(stats.accrued_reward, 0);
+        assert!(stats.take_reward(vault_available).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! ===========================================================================
+//! Unit09 – Owner Fork Counter State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/owner_fork_counter.rs
+//!
+//! An `OwnerForkCounter` numbers the forks created by one owner. One
+//! account exists per owner, created by that owner's first `create_fork`.
+//! Each new fork takes the next index as `Fork::owner_fork_index`, so
+//! indexers can list a wallet's forks page by page without scanning every
+//! fork.
+//!
+//! The counter never goes down: closing a fork leaves a gap rather than
+//! letting a later fork reuse its index.
+//!
+//! This file defines:
+//! - `OwnerForkCounter` account structure
+//! - length constants for rent-exempt allocation
+//! - helpers for creation and index assignment
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Number of forks created by an owner.
+#[account]
+pub struct OwnerForkCounter {
+    /// Owner whose forks are counted; also the PDA seed.
+    pub owner: Pubkey,
+
+    /// Forks created by `owner` so far; the next fork's index.
+    pub fork_count: u32,
+
+    /// Unix timestamp of the owner's first fork.
+    pub created_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 32],
+}
+
+impl OwnerForkCounter {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length of the `OwnerForkCounter` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // owner: Pubkey
+        + 4  // fork_count: u32
+        + 8  // created_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    /// Initialize an empty counter for `owner`.
+    pub fn init(&mut self, owner: Pubkey, bump: u8, clock: &Clock) {
+        self.owner = owner;
+        self.fork_count = 0;
+        self.created_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+    }
+
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.owner != Pubkey::default()
+    }
+
+    /// Take the index for the owner's next fork and advance the counter.
+    pub fn next_index(&mut self) -> Result<u32> {
+        let index = self.fork_count;
+        self.fork_count = index.checked_add(1).ok_or(Unit09Error::CounterOverflow)?;
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::zeroed;
+
+    fn counter_for(owner: Pubkey) -> OwnerForkCounter {
+        let mut counter: OwnerForkCounter = zeroed(OwnerForkCounter::LEN);
+        counter.init(owner, 255, &Clock::default());
+        counter
+    }
+
+    #[test]
+    fn forks_are_numbered_per_owner() {
+        let mut alice = counter_for(Pubkey::new_unique());
+        let mut bob = counter_for(Pubkey::new_unique());
+
+        assert_eq!(alice.next_index().unwrap(), 0);
+        assert_eq!(alice.next_index().unwrap(), 1);
+        assert_eq!(bob.next_index().unwrap(), 0);
+        assert_eq!((alice.fork_count, bob.fork_count), (2, 1));
+    }
+
+    #[test]
+    fn exhausted_counter_is_rejected() {
+        let mut counter = counter_for(Pubkey::new_unique());
+        counter.fork_count = u32::MAX;
+
+        assert!(counter.next_index().is_err());
+        assert_eq!(counter.fork_count, u32::MAX);
+    }
+}
@@ -16,14 +16,23 @@
 //! - `Repo` account structure
 //! - size constants for rent-exempt allocation
 //! - helper methods for authority checks, activation checks,
-//!   observation recording, and module counters.
+//!   observation recording, module counters, and pinned modules.
+//! - manifest checksum verification (`Repo::verify_manifest`)
 //!
 //! ===========================================================================
 
+use std::fmt;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::{
+    histogram_add, histogram_dominant, histogram_remove, CategoryTally, Config, ObservationCaps,
+    Visibility,
+};
+use crate::utils::{day_index, monotonic_updated_at};
 
 /// Repository account tracked by Unit09.
 ///
@@ -91,8 +100,100 @@ pub struct Repo {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Discovery visibility, encoded as a `Visibility` discriminant.
+    ///
+    /// Metadata only; off-chain indexers are responsible for honoring it.
+    pub visibility: u8,
+
+    /// Current size of the codebase in lines of code, as last reported.
+    ///
+    /// Unlike `total_lines_of_code`, which only ever grows, this value can
+    /// shrink when workers report deletions via a negative delta.
+    pub current_lines_of_code: u64,
+
+    /// Proposed new authority awaiting acceptance.
+    ///
+    /// `Pubkey::default()` means no transfer is pending. Set by
+    /// `initiate_repo_transfer` and consumed by `accept_repo_transfer`.
+    pub pending_authority: Pubkey,
+
+    /// Whether `record_observation` emits a per-run `ObservationRecorded`.
+    ///
+    /// High-frequency repositories can turn this off to spare indexers; the
+    /// counters are still updated and remain visible via metrics snapshots.
+    pub emit_observation_events: bool,
+
+    /// Whether module links between this repository and a module owned by
+    /// a different authority need confirmation from both sides.
+    pub require_mutual_consent: bool,
+
+    /// Unix timestamp of the most recent observation (0 if never observed).
+    pub last_observation_at: i64,
+
+    /// Consecutive days, up to the latest observation, with at least one
+    /// observation each (0 if never observed).
+    pub current_streak_days: u32,
+
+    /// Longest observation streak this repository has reached.
+    pub longest_streak_days: u32,
+
+    /// Whether every observation must carry an Ed25519 signature, over
+    /// exactly its counts and a recent slot, by a worker key holding the
+    /// observer role for this repository (see `record_observation`).
+    pub require_signed_observations: bool,
+
+    /// `activity_tier` as of the latest observation; one of the
+    /// `ACTIVITY_TIER_*` constants.
+    pub activity_tier: u8,
+
     /// Reserved space for future fields.
-    pub reserved: [u8; 62],
+    ///
+    /// Everything up to here keeps the schema v1 layout (`Repo::V1_LEN`
+    /// bytes): the fields after `bump` were carved out of v1's
+    /// `reserved: [u8; 62]`. The fields below were appended in schema v2 and
+    /// exist once `set_schema_version` has migrated the account.
+    pub reserved: [u8; 1],
+
+    /// Optional HTTPS endpoint where off-chain relays push events for this
+    /// repository. Empty when unset; never called on-chain.
+    pub notify_uri: String,
+
+    /// Module PDAs the authority highlights as entrypoints, in pin order.
+    ///
+    /// Bounded by `MAX_REPO_PINNED_MODULES`; each entry is unique and was
+    /// registered under this repository when pinned.
+    pub pinned_modules: Vec<Pubkey>,
+
+    /// Number of registered modules per module category.
+    ///
+    /// Maintained by `register_module`, `update_module` and `close_module`;
+    /// see `category_histogram` for the bucket bounds.
+    pub module_categories: Vec<CategoryTally>,
+
+    /// SHA-256 checksum of the manifest at `manifest_uri`; zeroed when no
+    /// manifest is attached.
+    pub manifest_checksum: [u8; 32],
+
+    /// Optional pointer to an off-chain manifest describing the repository
+    /// (name, tags, languages, ...). Empty when unset.
+    ///
+    /// Only the pointer and `manifest_checksum` live on-chain; readers
+    /// fetch the manifest and check it with `verify_manifest`.
+    pub manifest_uri: String,
+
+    /// Content hash reported by the latest observation that carried one;
+    /// zeroed when none did.
+    pub last_content_hash: [u8; 32],
+
+    /// Aggregated commits reported by observations that carried a count.
+    pub total_commits_observed: u64,
+
+    /// Signing slot of the latest accepted worker-signed observation; 0
+    /// when none was accepted yet.
+    ///
+    /// A signed payload is only accepted for a later slot, so each worker
+    /// signature can be used once.
+    pub last_signed_observation_slot: u64,
 }
 
 impl Repo {
@@ -105,14 +206,23 @@ impl Repo {
     /// Maximum length of the `url` field in bytes (UTF-8).
     pub const MAX_URL_LEN: usize = MAX_URL_LEN;
 
+    /// Maximum length of the `notify_uri` field in bytes (UTF-8).
+    pub const MAX_NOTIFY_URI_LEN: usize = MAX_NOTIFY_URI_LEN;
+
     /// Maximum length of the `tags` field in bytes (UTF-8).
     pub const MAX_TAGS_LEN: usize = MAX_REPO_TAGS_LEN;
 
-    /// Total serialized length of the `Repo` account.
+    /// Maximum length of the `manifest_uri` field in bytes (UTF-8).
+    pub const MAX_MANIFEST_URI_LEN: usize = MAX_METADATA_URI_LEN;
+
+    /// Maximum number of entries in `pinned_modules`.
+    pub const MAX_PINNED_MODULES: usize = MAX_REPO_PINNED_MODULES;
+
+    /// Serialized length of a schema v1 `Repo`, which ends at `reserved`.
     ///
     /// String fields are stored as a 4-byte length prefix followed by bytes.
     /// We allocate the maximum size to keep the layout stable.
-    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+    pub const V1_LEN: usize = Self::DISCRIMINATOR_LEN
         + 32  // repo_key: Pubkey
         + 32  // authority: Pubkey
         + 4 + Self::MAX_NAME_LEN // name: String
@@ -128,7 +238,28 @@ impl Repo {
         + 8  // updated_at: i64
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 62; // reserved: [u8; 62]
+        + 1  // visibility: u8
+        + 8  // current_lines_of_code: u64
+        + 32 // pending_authority: Pubkey
+        + 1  // emit_observation_events: bool
+        + 1  // require_mutual_consent: bool
+        + 8  // last_observation_at: i64
+        + 4  // current_streak_days: u32
+        + 4  // longest_streak_days: u32
+        + 1  // require_signed_observations: bool
+        + 1  // activity_tier: u8
+        + 1; // reserved: [u8; 1]
+
+    /// Total serialized length of the `Repo` account.
+    pub const LEN: usize = Self::V1_LEN
+        + 4 + Self::MAX_NOTIFY_URI_LEN // notify_uri: String
+        + 4 + Self::MAX_PINNED_MODULES * 32 // pinned_modules: Vec<Pubkey>
+        + CategoryTally::HISTOGRAM_LEN // module_categories: Vec<CategoryTally>
+        + 32 // manifest_checksum: [u8; 32]
+        + 4 + Self::MAX_MANIFEST_URI_LEN // manifest_uri: String
+        + 32 // last_content_hash: [u8; 32]
+        + 8 // total_commits_observed: u64
+        + 8; // last_signed_observation_slot: u64
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -145,6 +276,7 @@ impl Repo {
         url: String,
         tags: String,
         allow_observation: bool,
+        visibility: Visibility,
         bump: u8,
         clock: &Clock,
     ) -> Result<()> {
@@ -167,15 +299,93 @@ impl Repo {
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 62];
+        self.visibility = visibility.as_u8();
+        self.current_lines_of_code = 0;
+        self.pending_authority = Pubkey::default();
+        self.emit_observation_events = true;
+        self.require_mutual_consent = false;
+        self.last_observation_at = 0;
+        self.current_streak_days = 0;
+        self.longest_streak_days = 0;
+        self.require_signed_observations = false;
+        self.activity_tier = ACTIVITY_TIER_DORMANT;
+        self.reserved = [0u8; 1];
+        self.notify_uri = String::new();
+        self.pinned_modules = Vec::new();
+        self.module_categories = Vec::new();
+        self.manifest_checksum = [0u8; 32];
+        self.manifest_uri = String::new();
+        self.last_content_hash = [0u8; 32];
+        self.total_commits_observed = 0;
+        self.last_signed_observation_slot = 0;
 
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Migration
+    // -----------------------------------------------------------------------
+
+    /// Give a schema v1 account that was just grown to `LEN` the values
+    /// `init` would have set.
+    ///
+    /// Every field v1 lacks reads as zero after the realloc, which already
+    /// matches `init` for all of them but `emit_observation_events`.
+    pub fn migrate_from_v1(&mut self) {
+        self.emit_observation_events = true;
+    }
+
+    // -----------------------------------------------------------------------
+    // Manifest
+    // -----------------------------------------------------------------------
+
+    /// Point the repository at an off-chain manifest with the given
+    /// SHA-256 `checksum`.
+    ///
+    /// The URI must look like a URL and the checksum must not be zeroed.
+    pub fn attach_manifest(&mut self, uri: String, checksum: [u8; 32]) -> Result<()> {
+        Self::validate_url(&uri)?;
+        if uri.len() > Self::MAX_MANIFEST_URI_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        if checksum == [0u8; 32] {
+            return err!(Unit09Error::MetadataInvalid);
+        }
+
+        self.manifest_uri = uri;
+        self.manifest_checksum = checksum;
+        Ok(())
+    }
+
+    /// Whether a manifest is attached.
+    pub fn has_manifest(&self) -> bool {
+        !self.manifest_uri.is_empty()
+    }
+
+    /// Check fetched manifest `bytes` against `manifest_checksum`.
+    ///
+    /// Fails with `MetadataMissing` when no manifest is attached and with
+    /// `ManifestChecksumMismatch` when the bytes hash differently.
+    pub fn verify_manifest(&self, bytes: &[u8]) -> Result<()> {
+        if !self.has_manifest() {
+            return err!(Unit09Error::MetadataMissing);
+        }
+        if hash(bytes).to_bytes() != self.manifest_checksum {
+            return err!(Unit09Error::ManifestChecksumMismatch);
+        }
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Metadata Updates
     // -----------------------------------------------------------------------
 
+    /// Stamp `updated_at` from `clock`, never moving it before `created_at`.
+    pub fn touch(&mut self, clock: &Clock) {
+        self.updated_at = monotonic_updated_at(self.created_at, clock.unix_timestamp);
+        debug_assert!(self.updated_at >= self.created_at);
+    }
+
     /// Update the repository metadata fields that are provided as `Some`.
     ///
     /// This can be used by `update_repo` and similar instructions.
@@ -183,9 +393,14 @@ impl Repo {
         &mut self,
         maybe_name: Option<String>,
         maybe_url: Option<String>,
+        maybe_notify_uri: Option<String>,
         maybe_tags: Option<String>,
         maybe_is_active: Option<bool>,
         maybe_allow_observation: Option<bool>,
+        maybe_visibility: Option<Visibility>,
+        maybe_emit_observation_events: Option<bool>,
+        maybe_require_mutual_consent: Option<bool>,
+        maybe_require_signed_observations: Option<bool>,
         clock: &Clock,
     ) -> Result<()> {
         if let Some(name) = maybe_name {
@@ -198,6 +413,11 @@ impl Repo {
             self.url = url;
         }
 
+        if let Some(notify_uri) = maybe_notify_uri {
+            Self::validate_notify_uri(&notify_uri)?;
+            self.notify_uri = notify_uri;
+        }
+
         if let Some(tags) = maybe_tags {
             Self::validate_tags(&tags)?;
             self.tags = tags;
@@ -211,10 +431,78 @@ impl Repo {
             self.allow_observation = allow_obs;
         }
 
-        self.updated_at = clock.unix_timestamp;
+        if let Some(visibility) = maybe_visibility {
+            self.visibility = visibility.as_u8();
+        }
+
+        if let Some(emit_events) = maybe_emit_observation_events {
+            self.emit_observation_events = emit_events;
+        }
+
+        if let Some(require_consent) = maybe_require_mutual_consent {
+            self.require_mutual_consent = require_consent;
+        }
+
+        if let Some(require_signed) = maybe_require_signed_observations {
+            self.require_signed_observations = require_signed;
+        }
+
+        self.touch(clock);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Authority Transfer (two-step)
+    // -----------------------------------------------------------------------
+
+    /// Whether an authority transfer is awaiting acceptance.
+    pub fn has_pending_transfer(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// Propose `new_authority` as the next repository authority.
+    ///
+    /// Overwrites any transfer that is already pending.
+    pub fn initiate_transfer(&mut self, new_authority: Pubkey, clock: &Clock) -> Result<()> {
+        if new_authority == Pubkey::default() || new_authority == self.authority {
+            return err!(Unit09Error::InvalidPendingAuthority);
+        }
+
+        self.pending_authority = new_authority;
+        self.touch(clock);
         Ok(())
     }
 
+    /// Finalize a pending transfer. Only the pending authority may accept.
+    ///
+    /// Returns the previous authority.
+    pub fn accept_transfer(&mut self, signer: &Pubkey, clock: &Clock) -> Result<Pubkey> {
+        if !self.has_pending_transfer() {
+            return err!(Unit09Error::NoPendingTransfer);
+        }
+        if *signer != self.pending_authority {
+            return err!(Unit09Error::InvalidPendingAuthority);
+        }
+
+        let previous = self.authority;
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        self.touch(clock);
+        Ok(previous)
+    }
+
+    /// Drop a pending transfer. Returns the authority that had been proposed.
+    pub fn cancel_transfer(&mut self, clock: &Clock) -> Result<Pubkey> {
+        if !self.has_pending_transfer() {
+            return err!(Unit09Error::NoPendingTransfer);
+        }
+
+        let cancelled = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        self.touch(clock);
+        Ok(cancelled)
+    }
+
     // -----------------------------------------------------------------------
     // Authority and Activation Guards
     // -----------------------------------------------------------------------
@@ -235,6 +523,24 @@ impl Repo {
         Ok(())
     }
 
+    /// Compare-and-swap guard for updates.
+    ///
+    /// When the caller passes the `updated_at` it last read, the repository must
+    /// not have changed since; `None` skips the check.
+    pub fn assert_unchanged_since(&self, expected_updated_at: Option<i64>) -> Result<()> {
+        match expected_updated_at {
+            Some(expected) if expected != self.updated_at => {
+                err!(Unit09Error::ConcurrentModification)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Decoded discovery visibility of this repository.
+    pub fn visibility(&self) -> Result<Visibility> {
+        Visibility::try_from_u8(self.visibility)
+    }
+
     /// Ensure that the repository is allowed to be observed.
     pub fn assert_observation_allowed(&self) -> Result<()> {
         if !self.allow_observation {
@@ -243,6 +549,25 @@ impl Repo {
         Ok(())
     }
 
+    /// Authority whose confirmation a new link between this repository and
+    /// a module owned by `module_authority` still needs, if any.
+    ///
+    /// `signer` is whichever of the two authorities created the link.
+    pub fn link_confirmation_required_from(
+        &self,
+        module_authority: &Pubkey,
+        signer: &Pubkey,
+    ) -> Option<Pubkey> {
+        if !self.require_mutual_consent || *module_authority == self.authority {
+            return None;
+        }
+        if *signer == self.authority {
+            Some(*module_authority)
+        } else {
+            Some(self.authority)
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Module Counters
     // -----------------------------------------------------------------------
@@ -274,6 +599,78 @@ impl Repo {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Module Categories
+    // -----------------------------------------------------------------------
+
+    /// Count a module of `category` in `module_categories`.
+    pub fn add_module_category(&mut self, category: &str) -> Result<()> {
+        histogram_add(&mut self.module_categories, category, 1)
+    }
+
+    /// Stop counting a module of `category` in `module_categories`.
+    pub fn remove_module_category(&mut self, category: &str) {
+        histogram_remove(&mut self.module_categories, category, 1);
+    }
+
+    /// Category with the most modules, used to attribute observed lines of
+    /// code in `Metrics::category_loc`.
+    ///
+    /// Ties go to the category registered first. Returns `None` while no
+    /// module is counted.
+    pub fn dominant_module_category(&self) -> Option<&str> {
+        histogram_dominant(&self.module_categories)
+    }
+
+    // -----------------------------------------------------------------------
+    // Pinned Modules
+    // -----------------------------------------------------------------------
+
+    /// Pin `module` as an entrypoint of the repository at `repo`.
+    ///
+    /// `module_repo` is the repository recorded on the module account and
+    /// must equal `repo`. Pinning an already pinned module is a no-op.
+    pub fn pin_module(
+        &mut self,
+        repo: &Pubkey,
+        module: Pubkey,
+        module_repo: &Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        if module_repo != repo {
+            return err!(Unit09Error::ModuleRepoMismatch);
+        }
+
+        if self.is_pinned(&module) {
+            return Ok(());
+        }
+        if self.pinned_modules.len() >= Self::MAX_PINNED_MODULES {
+            return err!(Unit09Error::RepoPinLimitReached);
+        }
+
+        self.pinned_modules.push(module);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Unpin `module`, preserving the order of the remaining pins.
+    pub fn unpin_module(&mut self, module: &Pubkey, clock: &Clock) -> Result<()> {
+        let index = self
+            .pinned_modules
+            .iter()
+            .position(|pinned| pinned == module)
+            .ok_or(Unit09Error::ModuleNotPinned)?;
+
+        self.pinned_modules.remove(index);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Whether `module` is pinned on this repository.
+    pub fn is_pinned(&self, module: &Pubkey) -> bool {
+        self.pinned_modules.contains(module)
+    }
+
     // -----------------------------------------------------------------------
     // Observation Aggregation
     // -----------------------------------------------------------------------
@@ -285,14 +682,11 @@ impl Repo {
         &mut self,
         lines_of_code: u64,
         files_processed: u32,
+        config: Option<&Config>,
     ) -> Result<()> {
-        // Basic bounds checking using constants
-        if lines_of_code > MAX_LOC_PER_OBSERVATION {
-            return err!(Unit09Error::ObservationDataTooLarge);
-        }
-        if files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-            return err!(Unit09Error::ObservationDataTooLarge);
-        }
+        // Bounds checking against the deployment's caps, falling back to
+        // the compile-time constants.
+        ObservationCaps::from_config(config).check(lines_of_code, files_processed)?;
 
         // Increment observation count
         self.observation_count = self
@@ -318,6 +712,172 @@ impl Repo {
         Ok(())
     }
 
+    /// Add the commits reported by an observation to
+    /// `total_commits_observed`.
+    pub fn add_commits(&mut self, commits: u64) -> Result<()> {
+        self.total_commits_observed = self
+            .total_commits_observed
+            .checked_add(commits)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Accept a worker-signed observation signed at `slot`.
+    ///
+    /// `slot` must be later than that of the previously accepted one, so a
+    /// signed payload cannot be replayed (`ObservationSignatureReplayed`).
+    pub fn accept_signed_observation_slot(&mut self, slot: u64) -> Result<()> {
+        if slot <= self.last_signed_observation_slot {
+            return err!(Unit09Error::ObservationSignatureReplayed);
+        }
+        self.last_signed_observation_slot = slot;
+        Ok(())
+    }
+
+    /// Whether `content_hash` is the one reported by the latest observation,
+    /// i.e. the code has not changed since.
+    pub fn is_unchanged_content(&self, content_hash: &[u8; 32]) -> bool {
+        self.last_content_hash != [0u8; 32] && self.last_content_hash == *content_hash
+    }
+
+    /// Seconds between `now` and the previous observation, if any.
+    pub fn seconds_since_last_observation(&self, now: i64) -> Option<i64> {
+        if self.observation_count == 0 {
+            return None;
+        }
+        Some(now.saturating_sub(self.last_observation_at))
+    }
+
+    /// Stamp an observation at `now` and advance the daily streak.
+    ///
+    /// Days are `day_index` buckets. An observation on the day after the
+    /// previous one extends the streak, one on the same day leaves it as is,
+    /// and one after a gap of a day or more starts a new streak of 1. A clock
+    /// that moved back to an earlier day leaves the streak untouched.
+    ///
+    /// Returns the streak length when it has just reached one of
+    /// `OBSERVATION_STREAK_MILESTONES`.
+    pub fn record_observation_day(&mut self, now: i64) -> Result<Option<u32>> {
+        let today = day_index(now);
+        let current_streak_days = if self.current_streak_days == 0 {
+            1
+        } else {
+            let last_day = day_index(self.last_observation_at);
+            if today <= last_day {
+                self.current_streak_days
+            } else if today == last_day + 1 {
+                self.current_streak_days
+                    .checked_add(1)
+                    .ok_or(Unit09Error::CounterOverflow)?
+            } else {
+                1
+            }
+        };
+
+        let extended = current_streak_days != self.current_streak_days;
+        self.current_streak_days = current_streak_days;
+        self.longest_streak_days = self.longest_streak_days.max(current_streak_days);
+        self.last_observation_at = now;
+
+        let milestone = extended && OBSERVATION_STREAK_MILESTONES.contains(&current_streak_days);
+        Ok(milestone.then_some(current_streak_days))
+    }
+
+    /// Activity tier of the repository at `now`, for dashboards.
+    ///
+    /// - `ACTIVITY_TIER_DORMANT`: never observed, or not observed for more
+    ///   than `ACTIVITY_DORMANT_AFTER_SECONDS`
+    /// - `ACTIVITY_TIER_HOT`: observed within `ACTIVITY_HOT_WITHIN_SECONDS`
+    ///   and, on average since creation, at least
+    ///   `ACTIVITY_HOT_MIN_OBSERVATIONS_PER_DAY` times a day; repositories
+    ///   younger than a day are averaged over one day
+    /// - `ACTIVITY_TIER_ACTIVE`: anything in between
+    pub fn activity_tier(&self, now: i64) -> u8 {
+        let Some(since_last) = self.seconds_since_last_observation(now) else {
+            return ACTIVITY_TIER_DORMANT;
+        };
+        if since_last > ACTIVITY_DORMANT_AFTER_SECONDS {
+            return ACTIVITY_TIER_DORMANT;
+        }
+
+        let age_days = (now.saturating_sub(self.created_at) / SECONDS_PER_DAY).max(1) as u64;
+        let per_day = self.observation_count / age_days;
+        if since_last <= ACTIVITY_HOT_WITHIN_SECONDS
+            && per_day >= ACTIVITY_HOT_MIN_OBSERVATIONS_PER_DAY
+        {
+            ACTIVITY_TIER_HOT
+        } else {
+            ACTIVITY_TIER_ACTIVE
+        }
+    }
+
+    /// Recompute the cached `activity_tier` at `now`.
+    ///
+    /// Returns the previous tier when it changed.
+    pub fn refresh_activity_tier(&mut self, now: i64) -> Option<u8> {
+        let tier = self.activity_tier(now);
+        let previous = std::mem::replace(&mut self.activity_tier, tier);
+        (previous != tier).then_some(previous)
+    }
+
+    /// Health score of the repository at `now`, from 0 to 100.
+    ///
+    /// The sum of:
+    /// - recency (0–40): 40 when observed within a day, 30 within a week,
+    ///   15 within 30 days, 0 after that or when never observed
+    /// - streak (0–30): one point per day of `current_streak_days`, while
+    ///   the repository is not dormant
+    /// - modules (0–20): two points per registered module, up to 10
+    /// - status (0–10): 10 while active and accepting observations
+    ///
+    /// Not stored; `record_observation` reports it in `ObservationRecorded`.
+    pub fn compute_health(&self, now: i64) -> u8 {
+        let recency = match self.seconds_since_last_observation(now) {
+            Some(since) if since <= SECONDS_PER_DAY => 40,
+            Some(since) if since <= 7 * SECONDS_PER_DAY => 30,
+            Some(since) if since <= ACTIVITY_DORMANT_AFTER_SECONDS => 15,
+            _ => 0,
+        };
+        let streak = if recency > 0 {
+            self.current_streak_days.min(30)
+        } else {
+            0
+        };
+        let modules = self.module_count.min(10) * 2;
+        let status = if self.is_active && self.allow_observation {
+            10
+        } else {
+            0
+        };
+
+        (recency + streak + modules + status) as u8
+    }
+
+    /// Update `current_lines_of_code` after an observation.
+    ///
+    /// - `None`: absolute mode, the observed `lines_of_code` becomes the
+    ///   current size.
+    /// - `Some(delta)`: delta mode, the signed delta is applied to the current
+    ///   size. Growth uses checked arithmetic; shrinkage is clamped at zero so
+    ///   that a stale baseline can never underflow.
+    pub fn track_current_loc(
+        &mut self,
+        lines_of_code: u64,
+        maybe_delta: Option<i64>,
+    ) -> Result<()> {
+        self.current_lines_of_code = match maybe_delta {
+            None => lines_of_code,
+            Some(delta) if delta >= 0 => self
+                .current_lines_of_code
+                .checked_add(delta.unsigned_abs())
+                .ok_or(Unit09Error::CounterOverflow)?,
+            Some(delta) => self
+                .current_lines_of_code
+                .saturating_sub(delta.unsigned_abs()),
+        };
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Validation Helpers
     // -----------------------------------------------------------------------
@@ -348,6 +908,21 @@ impl Repo {
         Ok(())
     }
 
+    /// Validate the notification endpoint. Empty clears it; anything else
+    /// must be an HTTPS URL so relays never push over plaintext.
+    fn validate_notify_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() {
+            return Ok(());
+        }
+        if uri.len() > Self::MAX_NOTIFY_URI_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        match uri.strip_prefix("https://") {
+            Some(rest) if rest.contains('.') && !rest.starts_with('.') => Ok(()),
+            _ => err!(Unit09Error::InvalidUrl),
+        }
+    }
+
     /// Validate the tags string.
     fn validate_tags(tags: &str) -> Result<()> {
         if tags.len() > Self::MAX_TAGS_LEN {
@@ -356,361 +931,567 @@ impl Repo {
         Ok(())
     }
 }
-//! ===========================================================================
-//! Unit09 – Repository State
-//! Path: contracts/unit09-program/programs/unit09_program/src/state/repo.rs
-//!
-//! A repository represents a real-world codebase that Unit09 observes and
-//! modularizes into runnable units. It is the primary anchor for:
-//! - tracking code sources
-//! - counting observations
-//! - aggregating module statistics
-//!
-//! Each `Repo` is a PDA derived from:
-//!     seed: REPO_SEED
-//!     key:  repo_key (arbitrary Pubkey chosen by the caller)
-//!
-//! This module defines:
-//! - `Repo` account structure
-//! - size constants for rent-exempt allocation
-//! - helper methods for authority checks, activation checks,
-//!   observation recording, and module counters.
-//!
-//! ===========================================================================
 
-use anchor_lang::prelude::*;
+/// One-line summary for logs and tooling, e.g.
+/// `repo <repo_key> "unit09-core" active modules=3 observations=12 loc=48000`.
+///
+/// No handler formats a `Repo`; logging one on-chain spends compute and log
+/// space, so keep it to tests and off-chain tools.
+impl fmt::Display for Repo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "repo {} \"{}\" {} modules={} observations={} loc={}",
+            self.repo_key,
+            self.name,
+            if self.is_active { "active" } else { "inactive" },
+            self.module_count,
+            self.observation_count,
+            self.current_lines_of_code,
+        )
+    }
+}
 
-use crate::constants::*;
-use crate::errors::Unit09Error;
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
 
-/// Repository account tracked by Unit09.
-///
-/// A repository may represent:
-/// - a GitHub / GitLab repository
-/// - a monorepo with multiple on-chain programs
-/// - any logical grouping of code that Unit09 parses
-#[account]
-pub struct Repo {
-    /// Arbitrary key chosen to identify this repository at PDA derivation time.
-    ///
-    /// In most cases this will be derived from an off-chain identifier
-    /// (for example, the hash of a repository URL or a content identifier).
-    pub repo_key: Pubkey,
+    use super::*;
+    use crate::utils::test_utils::{self, clock_at};
 
-    /// Authority that controls metadata and activation state for this repository.
-    pub authority: Pubkey,
+    fn new_repo(visibility: Visibility) -> Repo {
+        let mut repo = test_utils::new_repo(Pubkey::new_unique(), &clock_at(100));
+        repo.visibility = visibility.as_u8();
+        repo
+    }
 
-    /// Human-readable name for the repository.
-    ///
-    /// Example: "unit09-solana-core"
-    pub name: String,
+    /// `Repo` as laid out at schema v1.
+    #[derive(AnchorSerialize)]
+    struct RepoV1 {
+        repo_key: Pubkey,
+        authority: Pubkey,
+        name: String,
+        url: String,
+        tags: String,
+        is_active: bool,
+        allow_observation: bool,
+        module_count: u32,
+        observation_count: u64,
+        total_lines_of_code: u64,
+        total_files_processed: u64,
+        created_at: i64,
+        updated_at: i64,
+        schema_version: u8,
+        bump: u8,
+        reserved: [u8; 62],
+    }
 
-    /// URL pointing to the codebase.
-    ///
-    /// Example: "https://github.com/unit09-labs/unit09"
-    pub url: String,
+    #[test]
+    fn v1_account_reads_unchanged_once_grown() {
+        let v1 = RepoV1 {
+            repo_key: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            name: "unit09-core".to_string(),
+            url: "https://github.com/unit09-labs/unit09".to_string(),
+            tags: "solana".to_string(),
+            is_active: true,
+            allow_observation: true,
+            module_count: 3,
+            observation_count: 7,
+            total_lines_of_code: 1_200,
+            total_files_processed: 40,
+            created_at: 100,
+            updated_at: 200,
+            schema_version: 1,
+            bump: 254,
+            reserved: [0u8; 62],
+        };
+        let max_strings = Repo::MAX_NAME_LEN + Repo::MAX_URL_LEN + Repo::MAX_TAGS_LEN;
+        assert_eq!(Repo::V1_LEN, 8 + 32 + 32 + 12 + max_strings + 2 + 4 + 5 * 8 + 2 + 62);
+
+        let mut data = Repo::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut data).unwrap();
+        assert!(data.len() <= Repo::V1_LEN);
+        data.resize(Repo::LEN, 0);
+
+        let mut repo = Repo::try_deserialize(&mut &data[..]).unwrap();
+        repo.migrate_from_v1();
+
+        assert_eq!(repo.repo_key, v1.repo_key);
+        assert_eq!(repo.authority, v1.authority);
+        assert_eq!(repo.name, v1.name);
+        assert_eq!(repo.module_count, 3);
+        assert_eq!(repo.observation_count, 7);
+        assert_eq!(repo.total_files_processed, 40);
+        assert_eq!(repo.updated_at, 200);
+        assert_eq!(repo.bump, 254);
+        assert_eq!(repo.pending_authority, Pubkey::default());
+        assert!(repo.emit_observation_events);
+        assert_eq!(repo.visibility, Visibility::Public.as_u8());
+        assert!(repo.pinned_modules.is_empty());
+        assert!(repo.notify_uri.is_empty());
+        assert_eq!(repo.total_commits_observed, 0);
+        assert_eq!(repo.last_signed_observation_slot, 0);
+    }
 
-    /// Optional tags describing the repository.
-    ///
-    /// Example: "solana,anchor,protocol"
-    pub tags: String,
+    #[test]
+    fn signed_observation_slot_only_moves_forward() {
+        let mut repo = new_repo(Visibility::Public);
 
-    /// Whether this repository is active.
-    ///
-    /// Inactive repositories should not be observed or used for new
-    /// module registrations.
-    pub is_active: bool,
+        repo.accept_signed_observation_slot(500).unwrap();
+        assert_eq!(repo.last_signed_observation_slot, 500);
 
-    /// Whether Unit09’s external workers are allowed to perform
-    /// automated observation runs for this repository.
-    pub allow_observation: bool,
+        // The same signed payload cannot be submitted again, nor an older one.
+        assert!(repo.accept_signed_observation_slot(500).is_err());
+        assert!(repo.accept_signed_observation_slot(499).is_err());
+        assert_eq!(repo.last_signed_observation_slot, 500);
 
-    /// Total number of modules registered for this repository.
-    pub module_count: u32,
+        repo.accept_signed_observation_slot(501).unwrap();
+        assert_eq!(repo.last_signed_observation_slot, 501);
+    }
 
-    /// Total number of observation runs recorded for this repository.
-    pub observation_count: u64,
+    #[test]
+    fn manifest_checksum_verifies_sample_bytes() {
+        let manifest = br#"{"name":"unit09-core","tags":["solana"],"language":"rust"}"#;
+        let mut repo = new_repo(Visibility::Public);
+        assert!(!repo.has_manifest());
+        assert!(repo.verify_manifest(manifest).is_err());
+
+        repo.attach_manifest(
+            "https://unit09.org/manifests/unit09-core.json".to_string(),
+            hash(manifest).to_bytes(),
+        )
+        .unwrap();
+
+        assert!(repo.has_manifest());
+        assert!(repo.verify_manifest(manifest).is_ok());
+        assert!(repo.verify_manifest(b"{}").is_err());
+    }
 
-    /// Aggregated lines of code processed across all observations.
-    pub total_lines_of_code: u64,
+    #[test]
+    fn invalid_manifest_pointers_are_rejected() {
+        let mut repo = new_repo(Visibility::Public);
+        let checksum = hash(b"manifest").to_bytes();
 
-    /// Aggregated files processed across all observations.
-    pub total_files_processed: u64,
+        assert!(repo.attach_manifest("not a url".to_string(), checksum).is_err());
+        assert!(repo
+            .attach_manifest("https://unit09.org/m.json".to_string(), [0u8; 32])
+            .is_err());
+        assert!(!repo.has_manifest());
+    }
 
-    /// Unix timestamp when this repository entry was created.
-    pub created_at: i64,
+    #[test]
+    fn negative_delta_shrinks_current_loc() {
+        let mut repo = new_repo(Visibility::Public);
+        repo.track_current_loc(10_000, None).unwrap();
 
-    /// Unix timestamp when this repository entry was last updated.
-    pub updated_at: i64,
+        repo.track_current_loc(500, Some(-2_500)).unwrap();
+        assert_eq!(repo.current_lines_of_code, 7_500);
 
-    /// Schema version for this repository layout.
-    pub schema_version: u8,
+        repo.track_current_loc(500, Some(1_000)).unwrap();
+        assert_eq!(repo.current_lines_of_code, 8_500);
+    }
 
-    /// Bump used for PDA derivation.
-    pub bump: u8,
+    #[test]
+    fn negative_delta_clamps_at_zero() {
+        let mut repo = new_repo(Visibility::Public);
+        repo.track_current_loc(100, None).unwrap();
 
-    /// Reserved space for future fields.
-    pub reserved: [u8; 62],
-}
+        repo.track_current_loc(100, Some(-1_000)).unwrap();
+        assert_eq!(repo.current_lines_of_code, 0);
 
-impl Repo {
-    /// Discriminator length used by Anchor.
-    pub const DISCRIMINATOR_LEN: usize = 8;
+        repo.track_current_loc(100, Some(i64::MIN)).unwrap();
+        assert_eq!(repo.current_lines_of_code, 0);
+    }
 
-    /// Maximum length of the `name` field in bytes (UTF-8).
-    pub const MAX_NAME_LEN: usize = MAX_NAME_LEN;
+    #[test]
+    fn visibility_can_be_set_to_each_variant() {
+        let mut repo = new_repo(Visibility::Private);
+        assert_eq!(repo.visibility().unwrap(), Visibility::Private);
+
+        for visibility in [Visibility::Unlisted, Visibility::Public, Visibility::Private] {
+            repo.apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(visibility),
+                None,
+                None,
+                None,
+                &clock_at(200),
+            )
+            .unwrap();
+            assert_eq!(repo.visibility().unwrap(), visibility);
+        }
+    }
 
-    /// Maximum length of the `url` field in bytes (UTF-8).
-    pub const MAX_URL_LEN: usize = MAX_URL_LEN;
+    #[test]
+    fn transfer_completes_when_pending_authority_accepts() {
+        let mut repo = new_repo(Visibility::Public);
+        let original = repo.authority;
+        let next = Pubkey::new_unique();
+
+        repo.initiate_transfer(next, &clock_at(200)).unwrap();
+        assert!(repo.has_pending_transfer());
+        assert_eq!(repo.authority, original);
+
+        let previous = repo.accept_transfer(&next, &clock_at(300)).unwrap();
+        assert_eq!(previous, original);
+        assert_eq!(repo.authority, next);
+        assert!(!repo.has_pending_transfer());
+        assert_eq!(repo.updated_at, 300);
+    }
 
-    /// Maximum length of the `tags` field in bytes (UTF-8).
-    pub const MAX_TAGS_LEN: usize = MAX_REPO_TAGS_LEN;
+    #[test]
+    fn only_pending_authority_can_accept() {
+        let mut repo = new_repo(Visibility::Public);
+        let original = repo.authority;
+        let next = Pubkey::new_unique();
+
+        assert!(repo.accept_transfer(&next, &clock_at(200)).is_err());
+
+        repo.initiate_transfer(next, &clock_at(200)).unwrap();
+        assert!(repo.accept_transfer(&original, &clock_at(300)).is_err());
+        assert!(repo
+            .accept_transfer(&Pubkey::new_unique(), &clock_at(300))
+            .is_err());
+        assert_eq!(repo.authority, original);
+        assert_eq!(repo.pending_authority, next);
+    }
 
-    /// Total serialized length of the `Repo` account.
-    ///
-    /// String fields are stored as a 4-byte length prefix followed by bytes.
-    /// We allocate the maximum size to keep the layout stable.
-    pub const LEN: usize = Self::DISCRIMINATOR_LEN
-        + 32  // repo_key: Pubkey
-        + 32  // authority: Pubkey
-        + 4 + Self::MAX_NAME_LEN // name: String
-        + 4 + Self::MAX_URL_LEN  // url: String
-        + 4 + Self::MAX_TAGS_LEN // tags: String
-        + 1  // is_active: bool
-        + 1  // allow_observation: bool
-        + 4  // module_count: u32
-        + 8  // observation_count: u64
-        + 8  // total_lines_of_code: u64
-        + 8  // total_files_processed: u64
-        + 8  // created_at: i64
-        + 8  // updated_at: i64
-        + 1  // schema_version: u8
-        + 1  // bump: u8
-        + 62; // reserved: [u8; 62]
+    #[test]
+    fn cancelled_transfer_cannot_be_accepted() {
+        let mut repo = new_repo(Visibility::Public);
+        let original = repo.authority;
+        let next = Pubkey::new_unique();
 
-    // -----------------------------------------------------------------------
-    // Initialization
-    // -----------------------------------------------------------------------
+        repo.initiate_transfer(next, &clock_at(200)).unwrap();
+        assert_eq!(repo.cancel_transfer(&clock_at(250)).unwrap(), next);
+        assert!(!repo.has_pending_transfer());
 
-    /// Initialize a new repository with the given parameters.
-    ///
-    /// This is typically called from the `register_repo` instruction.
-    pub fn init(
-        &mut self,
-        repo_key: Pubkey,
-        authority: Pubkey,
-        name: String,
-        url: String,
-        tags: String,
-        allow_observation: bool,
-        bump: u8,
-        clock: &Clock,
-    ) -> Result<()> {
-        Self::validate_name(&name)?;
-        Self::validate_url(&url)?;
-        Self::validate_tags(&tags)?;
+        assert!(repo.accept_transfer(&next, &clock_at(300)).is_err());
+        assert!(repo.cancel_transfer(&clock_at(300)).is_err());
+        assert_eq!(repo.authority, original);
+    }
 
-        self.repo_key = repo_key;
-        self.authority = authority;
-        self.name = name;
-        self.url = url;
-        self.tags = tags;
-        self.is_active = true;
-        self.allow_observation = allow_observation;
-        self.module_count = 0;
-        self.observation_count = 0;
-        self.total_lines_of_code = 0;
-        self.total_files_processed = 0;
-        self.created_at = clock.unix_timestamp;
-        self.updated_at = clock.unix_timestamp;
-        self.schema_version = CURRENT_SCHEMA_VERSION;
-        self.bump = bump;
-        self.reserved = [0u8; 62];
+    #[test]
+    fn transfer_to_self_or_default_is_rejected() {
+        let mut repo = new_repo(Visibility::Public);
+        let current = repo.authority;
 
-        Ok(())
+        assert!(repo.initiate_transfer(current, &clock_at(200)).is_err());
+        assert!(repo
+            .initiate_transfer(Pubkey::default(), &clock_at(200))
+            .is_err());
     }
 
-    // -----------------------------------------------------------------------
-    // Metadata Updates
-    // -----------------------------------------------------------------------
+    #[test]
+    fn observation_totals_bracket_reported_loc() {
+        let mut repo = new_repo(Visibility::Public);
+        repo.record_observation(1_200, 10, None).unwrap();
 
-    /// Update the repository metadata fields that are provided as `Some`.
-    ///
-    /// This can be used by `update_repo` and similar instructions.
-    pub fn apply_update(
-        &mut self,
-        maybe_name: Option<String>,
-        maybe_url: Option<String>,
-        maybe_tags: Option<String>,
-        maybe_is_active: Option<bool>,
-        maybe_allow_observation: Option<bool>,
-        clock: &Clock,
-    ) -> Result<()> {
-        if let Some(name) = maybe_name {
-            Self::validate_name(&name)?;
-            self.name = name;
-        }
+        let prev_total_loc = repo.total_lines_of_code;
+        repo.record_observation(345, 3, None).unwrap();
+        let new_total_loc = repo.total_lines_of_code;
 
-        if let Some(url) = maybe_url {
-            Self::validate_url(&url)?;
-            self.url = url;
-        }
+        assert_eq!(prev_total_loc, 1_200);
+        assert_eq!(new_total_loc - prev_total_loc, 345);
+    }
 
-        if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
-        }
+    fn set_notify_uri(repo: &mut Repo, uri: &str) -> Result<()> {
+        repo.apply_update(
+            None,
+            None,
+            Some(uri.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &clock_at(200),
+        )
+    }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
-        }
+    #[test]
+    fn notify_uri_can_be_set_and_cleared() {
+        let mut repo = new_repo(Visibility::Public);
+        assert!(repo.notify_uri.is_empty());
 
-        if let Some(allow_obs) = maybe_allow_observation {
-            self.allow_observation = allow_obs;
-        }
+        set_notify_uri(&mut repo, "https://relay.unit09.org/hooks/core").unwrap();
+        assert_eq!(repo.notify_uri, "https://relay.unit09.org/hooks/core");
 
-        self.updated_at = clock.unix_timestamp;
-        Ok(())
+        set_notify_uri(&mut repo, "").unwrap();
+        assert!(repo.notify_uri.is_empty());
     }
 
-    // -----------------------------------------------------------------------
-    // Authority and Activation Guards
-    // -----------------------------------------------------------------------
-
-    /// Ensure that the given signer is the authority for this repository.
-    pub fn assert_authority(&self, signer: &Signer) -> Result<()> {
-        if signer.key() != self.authority {
-            return err!(Unit09Error::InvalidAuthority);
+    #[test]
+    fn notify_uri_must_be_https() {
+        let mut repo = new_repo(Visibility::Public);
+        set_notify_uri(&mut repo, "https://relay.unit09.org/hooks/core").unwrap();
+
+        for bad in [
+            "http://relay.unit09.org/hooks/core",
+            "wss://relay.unit09.org",
+            "https://localhost",
+            "relay.unit09.org",
+        ] {
+            assert!(set_notify_uri(&mut repo, bad).is_err(), "{bad}");
         }
-        Ok(())
+        assert_eq!(repo.notify_uri, "https://relay.unit09.org/hooks/core");
+
+        let too_long = format!("https://relay.unit09.org/{}", "a".repeat(Repo::MAX_NOTIFY_URI_LEN));
+        assert!(set_notify_uri(&mut repo, &too_long).is_err());
     }
 
-    /// Ensure that the repository is currently active.
-    pub fn assert_active(&self) -> Result<()> {
-        if !self.is_active {
-            return err!(Unit09Error::RepoInactive);
-        }
-        Ok(())
+    #[test]
+    fn mutual_consent_names_the_other_authority() {
+        let mut repo = new_repo(Visibility::Public);
+        let module_authority = Pubkey::new_unique();
+
+        assert_eq!(
+            repo.link_confirmation_required_from(&module_authority, &module_authority),
+            None
+        );
+
+        repo.require_mutual_consent = true;
+        assert_eq!(
+            repo.link_confirmation_required_from(&module_authority, &module_authority),
+            Some(repo.authority)
+        );
+        assert_eq!(
+            repo.link_confirmation_required_from(&module_authority, &repo.authority),
+            Some(module_authority)
+        );
+        assert_eq!(
+            repo.link_confirmation_required_from(&repo.authority, &repo.authority),
+            None
+        );
     }
 
-    /// Ensure that the repository is allowed to be observed.
-    pub fn assert_observation_allowed(&self) -> Result<()> {
-        if !self.allow_observation {
-            return err!(Unit09Error::ObservationNotAllowed);
-        }
-        Ok(())
+    #[test]
+    fn regressed_clock_never_moves_updated_at_before_created_at() {
+        let mut repo = new_repo(Visibility::Public);
+        assert_eq!(repo.created_at, 100);
+
+        repo.apply_update(
+            None,
+            None,
+            None,
+            Some("rust".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &clock_at(40),
+        )
+        .unwrap();
+        assert_eq!(repo.tags, "rust");
+        assert_eq!(repo.updated_at, repo.created_at);
+
+        repo.initiate_transfer(Pubkey::new_unique(), &clock_at(250)).unwrap();
+        assert_eq!(repo.updated_at, 250);
     }
 
-    // -----------------------------------------------------------------------
-    // Module Counters
-    // -----------------------------------------------------------------------
+    #[test]
+    fn pins_modules_up_to_the_cap() {
+        let mut repo = new_repo(Visibility::Public);
+        let repo_pda = Pubkey::new_unique();
+        let clock = clock_at(200);
+
+        let modules: Vec<Pubkey> = (0..Repo::MAX_PINNED_MODULES)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for module in &modules {
+            repo.pin_module(&repo_pda, *module, &repo_pda, &clock).unwrap();
+        }
+        assert_eq!(repo.pinned_modules, modules);
 
-    /// Increment the module count for this repository.
-    ///
-    /// This should be called when a new module is successfully registered.
-    pub fn increment_module_count(&mut self) -> Result<()> {
-        let new_value = self
-            .module_count
-            .checked_add(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
+        // Re-pinning is a no-op even when full.
+        repo.pin_module(&repo_pda, modules[0], &repo_pda, &clock).unwrap();
+        assert_eq!(repo.pinned_modules.len(), Repo::MAX_PINNED_MODULES);
 
-        if new_value > DEFAULT_MAX_MODULES_PER_REPO {
-            return err!(Unit09Error::RepoModuleLimitReached);
-        }
+        let extra = Pubkey::new_unique();
+        assert!(repo.pin_module(&repo_pda, extra, &repo_pda, &clock).is_err());
+        assert!(!repo.is_pinned(&extra));
+    }
 
-        self.module_count = new_value;
-        Ok(())
+    #[test]
+    fn rejects_pinning_a_foreign_module() {
+        let mut repo = new_repo(Visibility::Public);
+        let repo_pda = Pubkey::new_unique();
+        let other_repo = Pubkey::new_unique();
+        let module = Pubkey::new_unique();
+
+        assert!(repo
+            .pin_module(&repo_pda, module, &other_repo, &clock_at(200))
+            .is_err());
+        assert!(repo.pinned_modules.is_empty());
     }
 
-    /// Decrement the module count for this repository, used if you ever add
-    /// soft-deletion or archival of modules.
-    pub fn decrement_module_count(&mut self) -> Result<()> {
-        self.module_count = self
-            .module_count
-            .checked_sub(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
-        Ok(())
+    #[test]
+    fn unpin_keeps_remaining_order() {
+        let mut repo = new_repo(Visibility::Public);
+        let repo_pda = Pubkey::new_unique();
+        let clock = clock_at(200);
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        for module in [a, b, c] {
+            repo.pin_module(&repo_pda, module, &repo_pda, &clock).unwrap();
+        }
+
+        repo.unpin_module(&b, &clock_at(300)).unwrap();
+        assert_eq!(repo.pinned_modules, vec![a, c]);
+        assert_eq!(repo.updated_at, 300);
+
+        assert!(repo.unpin_module(&b, &clock).is_err());
     }
 
-    // -----------------------------------------------------------------------
-    // Observation Aggregation
-    // -----------------------------------------------------------------------
+    #[test]
+    fn expected_updated_at_must_match() {
+        let mut repo = new_repo(Visibility::Public);
+        let read_at = repo.updated_at;
 
-    /// Record a single observation result on this repository.
-    ///
-    /// This is used by `record_observation` instruction handlers.
-    pub fn record_observation(
-        &mut self,
-        lines_of_code: u64,
-        files_processed: u32,
-    ) -> Result<()> {
-        // Basic bounds checking using constants
-        if lines_of_code > MAX_LOC_PER_OBSERVATION {
-            return err!(Unit09Error::ObservationDataTooLarge);
-        }
-        if files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-            return err!(Unit09Error::ObservationDataTooLarge);
+        assert!(repo.assert_unchanged_since(None).is_ok());
+        assert!(repo.assert_unchanged_since(Some(read_at)).is_ok());
+
+        let module = Pubkey::new_unique();
+        let repo_pda = Pubkey::new_unique();
+        repo.pin_module(&repo_pda, module, &repo_pda, &clock_at(read_at + 60)).unwrap();
+        assert!(repo.assert_unchanged_since(Some(read_at)).is_err());
+        assert!(repo.assert_unchanged_since(Some(read_at + 60)).is_ok());
+    }
+
+    #[test]
+    fn consecutive_days_grow_the_streak() {
+        let mut repo = new_repo(Visibility::Public);
+        let mut milestones = Vec::new();
+        for day in 0..7 {
+            let now = 10 * SECONDS_PER_DAY + day * SECONDS_PER_DAY + 3_600;
+            milestones.push(repo.record_observation_day(now).unwrap());
+            // A second observation on the same day does not count twice.
+            assert_eq!(repo.record_observation_day(now + 60).unwrap(), None);
         }
 
-        // Increment observation count
-        self.observation_count = self
-            .observation_count
-            .checked_add(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
+        assert_eq!(repo.current_streak_days, 7);
+        assert_eq!(repo.longest_streak_days, 7);
+        assert_eq!(milestones[..6], [None; 6]);
+        assert_eq!(milestones[6], Some(7));
+    }
 
-        if self.observation_count > SOFT_MAX_OBSERVATIONS_PER_REPO {
-            return err!(Unit09Error::RepoObservationLimitReached);
+    #[test]
+    fn a_gap_resets_the_streak_but_keeps_the_longest() {
+        let mut repo = new_repo(Visibility::Public);
+        for day in 0..3 {
+            repo.record_observation_day(day * SECONDS_PER_DAY).unwrap();
         }
+        assert_eq!(repo.current_streak_days, 3);
 
-        // Aggregate lines of code and files
-        self.total_lines_of_code = self
-            .total_lines_of_code
-            .checked_add(lines_of_code)
-            .ok_or(Unit09Error::CounterOverflow)?;
+        // Day 3 is skipped.
+        repo.record_observation_day(4 * SECONDS_PER_DAY).unwrap();
+        assert_eq!((repo.current_streak_days, repo.longest_streak_days), (1, 3));
 
-        self.total_files_processed = self
-            .total_files_processed
-            .checked_add(files_processed as u64)
-            .ok_or(Unit09Error::CounterOverflow)?;
+        repo.record_observation_day(5 * SECONDS_PER_DAY).unwrap();
+        assert_eq!((repo.current_streak_days, repo.longest_streak_days), (2, 3));
+        assert_eq!(repo.last_observation_at, 5 * SECONDS_PER_DAY);
+    }
 
-        Ok(())
+    #[test]
+    fn activity_tiers_follow_recency_and_frequency() {
+        let day = SECONDS_PER_DAY;
+        let tier_at = |observation_count: u64, last_observation_at: i64, now: i64| {
+            let mut repo = new_repo(Visibility::Public);
+            repo.observation_count = observation_count;
+            repo.last_observation_at = last_observation_at;
+            repo.activity_tier(now)
+        };
+
+        // Created at 100, sixty days ago.
+        let now = 100 + 60 * day;
+        let cases = [
+            (0, 0, ACTIVITY_TIER_DORMANT),
+            (500, now - 31 * day, ACTIVITY_TIER_DORMANT),
+            (500, now - 30 * day, ACTIVITY_TIER_ACTIVE),
+            (500, now - 2 * day, ACTIVITY_TIER_ACTIVE),
+            (239, now - 3_600, ACTIVITY_TIER_ACTIVE),
+            (240, now - 3_600, ACTIVITY_TIER_HOT),
+            (500, now - 3_600, ACTIVITY_TIER_HOT),
+        ];
+        for (observation_count, last_observation_at, tier) in cases {
+            assert_eq!(tier_at(observation_count, last_observation_at, now), tier);
+        }
+
+        // A repository younger than a day is averaged over one day.
+        let now = 100 + 3_600;
+        assert_eq!(tier_at(4, now - 60, now), ACTIVITY_TIER_HOT);
+        assert_eq!(tier_at(3, now - 60, now), ACTIVITY_TIER_ACTIVE);
     }
 
-    // -----------------------------------------------------------------------
-    // Validation Helpers
-    // -----------------------------------------------------------------------
+    #[test]
+    fn refreshing_the_tier_reports_transitions() {
+        let mut repo = new_repo(Visibility::Public);
+        assert_eq!(repo.activity_tier, ACTIVITY_TIER_DORMANT);
+        assert_eq!(repo.refresh_activity_tier(200), None);
 
-    /// Validate the repository name.
-    fn validate_name(name: &str) -> Result<()> {
-        if name.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if name.len() > Self::MAX_NAME_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        Ok(())
+        repo.observation_count = 1;
+        repo.record_observation_day(200).unwrap();
+        assert_eq!(repo.refresh_activity_tier(200), Some(ACTIVITY_TIER_DORMANT));
+        assert_eq!(repo.activity_tier, ACTIVITY_TIER_ACTIVE);
+
+        repo.observation_count = 4;
+        assert_eq!(repo.refresh_activity_tier(300), Some(ACTIVITY_TIER_ACTIVE));
+        assert_eq!(repo.activity_tier, ACTIVITY_TIER_HOT);
+        assert_eq!(repo.refresh_activity_tier(300), None);
+
+        assert_eq!(repo.refresh_activity_tier(200 + 31 * SECONDS_PER_DAY), Some(ACTIVITY_TIER_HOT));
+        assert_eq!(repo.activity_tier, ACTIVITY_TIER_DORMANT);
     }
 
-    /// Validate the repository URL with basic checks.
-    fn validate_url(url: &str) -> Result<()> {
-        if url.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if url.len() > Self::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        // Very basic structural check: must contain at least one dot and "://"
-        if !url.contains("://") || !url.contains('.') {
-            return err!(Unit09Error::InvalidUrl);
-        }
-        Ok(())
+    #[test]
+    fn health_scores_for_pinned_states() {
+        let now = 100 * SECONDS_PER_DAY;
+        let mut repo = new_repo(Visibility::Public);
+        // Never observed: only the status counts.
+        assert_eq!(repo.compute_health(now), 10);
+
+        repo.observation_count = 40;
+        repo.last_observation_at = now - 60;
+        repo.current_streak_days = 12;
+        repo.module_count = 3;
+        assert_eq!(repo.compute_health(now), 40 + 12 + 6 + 10);
+
+        // A long streak and many modules are capped.
+        repo.current_streak_days = 90;
+        repo.module_count = 25;
+        assert_eq!(repo.compute_health(now), 100);
+
+        // Staleness drops recency, then the streak with it.
+        assert_eq!(repo.compute_health(now + 3 * SECONDS_PER_DAY), 30 + 30 + 20 + 10);
+        assert_eq!(repo.compute_health(now + 20 * SECONDS_PER_DAY), 15 + 30 + 20 + 10);
+        assert_eq!(repo.compute_health(now + 31 * SECONDS_PER_DAY), 20 + 10);
+
+        repo.is_active = false;
+        assert_eq!(repo.compute_health(now + 31 * SECONDS_PER_DAY), 20);
     }
 
-    /// Validate the tags string.
-    fn validate_tags(tags: &str) -> Result<()> {
-        if tags.len() > Self::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        Ok(())
+    #[test]
+    fn summary_names_the_repo_and_its_counts() {
+        let mut repo = new_repo(Visibility::Public);
+        repo.module_count = 3;
+        repo.observation_count = 12;
+        repo.current_lines_of_code = 48_000;
+
+        assert_eq!(
+            repo.to_string(),
+            format!(
+                "repo {} \"unit09-core\" active modules=3 observations=12 loc=48000",
+                repo.repo_key
+            )
+        );
     }
 }
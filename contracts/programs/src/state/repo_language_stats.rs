@@ -0,0 +1,238 @@
+//! ===========================================================================
+//! Unit09 – Repo Language Stats State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/repo_language_stats.rs
+//!
+//! A `RepoLanguageStats` account keeps the cumulative lines of code per
+//! language for one repository, summed over every observation that carried
+//! a `language_breakdown`. One account exists per repository, created on
+//! the first such observation that passes it.
+//!
+//! The detail lives outside `Repo` so the account touched by every
+//! observation stays small; clients fetch it only when they need the
+//! breakdown.
+//!
+//! At most `MAX_REPO_LANGUAGES` languages are tracked by name. Lines of
+//! code for any further language are folded into the `"other"` entry.
+//!
+//! This file defines:
+//! - `RepoLanguageStats` account structure
+//! - `LanguageTally` entries
+//! - length constants for rent-exempt allocation
+//! - helpers for creation and per-observation updates
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Entry that collects languages once `MAX_REPO_LANGUAGES` are tracked.
+pub const OTHER_LANGUAGE: &str = "other";
+
+/// Cumulative lines of code for one language.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LanguageTally {
+    /// Language name as reported by observers, e.g. "rust".
+    pub language: String,
+
+    /// Lines of code observed in this language across all runs.
+    pub lines_of_code: u64,
+}
+
+impl LanguageTally {
+    /// Serialized length of one entry.
+    pub const LEN: usize = 4 + MAX_LANGUAGE_NAME_LEN // language: String
+        + 8; // lines_of_code: u64
+}
+
+/// Per-language lines of code for a repository.
+#[account]
+pub struct RepoLanguageStats {
+    /// PDA of the repository these stats belong to.
+    pub repo: Pubkey,
+
+    /// Number of observations whose breakdown was added here.
+    pub observation_count: u64,
+
+    /// Unix timestamp of the latest breakdown added.
+    pub updated_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 32],
+
+    /// Cumulative lines of code per language, in first-seen order.
+    ///
+    /// Bounded by `MAX_REPO_LANGUAGES` named entries plus `"other"`.
+    pub languages: Vec<LanguageTally>,
+}
+
+impl RepoLanguageStats {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Maximum number of entries, including the `"other"` entry.
+    pub const MAX_ENTRIES: usize = MAX_REPO_LANGUAGES + 1;
+
+    /// Total serialized length of the `RepoLanguageStats` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // repo: Pubkey
+        + 8  // observation_count: u64
+        + 8  // updated_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32 // reserved: [u8; 32]
+        + 4 + Self::MAX_ENTRIES * LanguageTally::LEN; // languages: Vec<LanguageTally>
+
+    /// Initialize empty stats for `repo`.
+    pub fn init(&mut self, repo: Pubkey, bump: u8, clock: &Clock) {
+        self.repo = repo;
+        self.observation_count = 0;
+        self.updated_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+        self.languages = Vec::new();
+    }
+
+    /// Whether this account has been initialized by `init`.
+    pub fn is_initialized(&self) -> bool {
+        self.repo != Pubkey::default()
+    }
+
+    /// Add one observation's `(language, lines_of_code)` breakdown.
+    ///
+    /// Either every entry is added or, on overflow, none is.
+    pub fn record_breakdown<'a>(
+        &mut self,
+        breakdown: impl IntoIterator<Item = (&'a str, u64)>,
+        clock: &Clock,
+    ) -> Result<()> {
+        let mut languages = self.languages.clone();
+        for (language, lines_of_code) in breakdown {
+            add_language_loc(&mut languages, language, lines_of_code)?;
+        }
+        let observation_count = self
+            .observation_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+
+        self.languages = languages;
+        self.observation_count = observation_count;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Cumulative lines of code recorded for `language` (0 if absent).
+    pub fn lines_of_code(&self, language: &str) -> u64 {
+        self.languages
+            .iter()
+            .find(|entry| entry.language == language)
+            .map(|entry| entry.lines_of_code)
+            .unwrap_or(0)
+    }
+}
+
+/// Add `lines_of_code` to `language`, or to `"other"` once
+/// `MAX_REPO_LANGUAGES` named languages are tracked.
+fn add_language_loc(
+    languages: &mut Vec<LanguageTally>,
+    language: &str,
+    lines_of_code: u64,
+) -> Result<()> {
+    let named = languages
+        .iter()
+        .filter(|entry| entry.language != OTHER_LANGUAGE)
+        .count();
+    let tracked = languages.iter().any(|entry| entry.language == language);
+    let key = if tracked || named < MAX_REPO_LANGUAGES {
+        language
+    } else {
+        OTHER_LANGUAGE
+    };
+
+    let index = match languages.iter().position(|entry| entry.language == key) {
+        Some(index) => index,
+        None => {
+            languages.push(LanguageTally {
+                language: key.to_string(),
+                lines_of_code: 0,
+            });
+            languages.len() - 1
+        }
+    };
+
+    let entry = &mut languages[index];
+    entry.lines_of_code = entry
+        .lines_of_code
+        .checked_add(lines_of_code)
+        .ok_or(Unit09Error::CounterOverflow)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{clock_at, zeroed};
+
+    fn new_stats() -> RepoLanguageStats {
+        let mut stats: RepoLanguageStats = zeroed(RepoLanguageStats::LEN);
+        assert!(!stats.is_initialized());
+        stats.init(Pubkey::new_unique(), 255, &clock_at(100));
+        stats
+    }
+
+    #[test]
+    fn breakdowns_accumulate_across_runs() {
+        let mut stats = new_stats();
+        stats
+            .record_breakdown([("rust", 1_000), ("typescript", 400)], &clock_at(200))
+            .unwrap();
+        stats
+            .record_breakdown([("rust", 250), ("python", 80)], &clock_at(300))
+            .unwrap();
+
+        assert_eq!(stats.lines_of_code("rust"), 1_250);
+        assert_eq!(stats.lines_of_code("typescript"), 400);
+        assert_eq!(stats.lines_of_code("python"), 80);
+        assert_eq!(stats.lines_of_code("go"), 0);
+        assert_eq!(stats.observation_count, 2);
+        assert_eq!(stats.updated_at, 300);
+    }
+
+    #[test]
+    fn extra_languages_fold_into_other() {
+        let mut stats = new_stats();
+        let names: Vec<String> = (0..MAX_REPO_LANGUAGES).map(|i| format!("lang-{i}")).collect();
+        stats
+            .record_breakdown(names.iter().map(|name| (name.as_str(), 1)), &clock_at(200))
+            .unwrap();
+        stats
+            .record_breakdown([("zig", 5), ("lang-0", 2), ("nim", 3)], &clock_at(300))
+            .unwrap();
+
+        assert_eq!(stats.languages.len(), RepoLanguageStats::MAX_ENTRIES);
+        assert_eq!(stats.lines_of_code(OTHER_LANGUAGE), 8);
+        assert_eq!(stats.lines_of_code("lang-0"), 3);
+        assert_eq!(stats.lines_of_code("zig"), 0);
+    }
+
+    #[test]
+    fn overflowing_breakdown_is_not_applied() {
+        let mut stats = new_stats();
+        stats
+            .record_breakdown([("rust", u64::MAX)], &clock_at(200))
+            .unwrap();
+
+        assert!(stats
+            .record_breakdown([("typescript", 10), ("rust", 1)], &clock_at(300))
+            .is_err());
+        assert_eq!(stats.lines_of_code("typescript"), 0);
+        assert_eq!(stats.observation_count, 1);
+    }
+}
@@ -0,0 +1,129 @@
+//! ===========================================================================
+//! Unit09 – Repo URL Index State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/repo_url_index.rs
+//!
+//! A `RepoUrlIndex` claims a repository URL for a single `Repo`. Its PDA is
+//! seeded by the SHA-256 hash of the URL, so at most one index, and hence
+//! one repository, can exist per URL.
+//!
+//! Indexes are written by `register_repo`. When `Config::enforce_unique_url`
+//! is set every registration must claim one, and registering a URL that is
+//! already claimed fails with `RepoUrlTaken`.
+//!
+//! URLs are hashed byte-for-byte: no normalization (case, trailing slash,
+//! `.git` suffix) is applied. URL changes made through `update_repo` are not
+//! re-indexed.
+//!
+//! This file defines:
+//! - `RepoUrlIndex` account structure
+//! - length constants for rent-exempt allocation
+//! - `RepoUrlIndex::url_hash` and the claim helper
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Claim of a repository URL by a `Repo`.
+#[account]
+pub struct RepoUrlIndex {
+    /// SHA-256 hash of the claimed URL; also the PDA seed.
+    pub url_hash: [u8; 32],
+
+    /// PDA of the repository holding the URL.
+    pub repo: Pubkey,
+
+    /// Unix timestamp when the URL was claimed.
+    pub created_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved space for future fields.
+    pub reserved: [u8; 32],
+}
+
+impl RepoUrlIndex {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length of the `RepoUrlIndex` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // url_hash: [u8; 32]
+        + 32 // repo: Pubkey
+        + 8  // created_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    /// Hash of `url` used to seed its index PDA.
+    pub fn url_hash(url: &str) -> [u8; 32] {
+        hash(url.as_bytes()).to_bytes()
+    }
+
+    /// Whether this index already names a repository.
+    pub fn is_claimed(&self) -> bool {
+        self.repo != Pubkey::default()
+    }
+
+    /// Claim `url` for `repo`.
+    ///
+    /// Fails with `RepoUrlTaken` if the index is already claimed.
+    pub fn claim(&mut self, url: &str, repo: Pubkey, bump: u8, clock: &Clock) -> Result<()> {
+        if self.is_claimed() {
+            return err!(Unit09Error::RepoUrlTaken);
+        }
+
+        self.url_hash = Self::url_hash(url);
+        self.repo = repo;
+        self.created_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::zeroed;
+
+    const URL: &str = "https://github.com/unit09-labs/unit09";
+
+    fn empty_index() -> RepoUrlIndex {
+        zeroed(RepoUrlIndex::LEN)
+    }
+
+    #[test]
+    fn duplicate_url_is_rejected() {
+        let mut index = empty_index();
+        let first = Pubkey::new_unique();
+        index.claim(URL, first, 255, &Clock::default()).unwrap();
+        assert!(index.is_claimed());
+        assert_eq!(index.url_hash, RepoUrlIndex::url_hash(URL));
+
+        assert!(index
+            .claim(URL, Pubkey::new_unique(), 255, &Clock::default())
+            .is_err());
+        assert_eq!(index.repo, first);
+    }
+
+    #[test]
+    fn distinct_urls_get_distinct_indexes() {
+        let other = "https://github.com/unit09-labs/unit09-fork";
+        assert_ne!(RepoUrlIndex::url_hash(URL), RepoUrlIndex::url_hash(other));
+
+        let mut index = empty_index();
+        index
+            .claim(other, Pubkey::new_unique(), 255, &Clock::default())
+            .unwrap();
+        assert_eq!(index.url_hash, RepoUrlIndex::url_hash(other));
+    }
+}
@@ -0,0 +1,82 @@
+//! ===========================================================================
+//! Unit09 – Visibility
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/visibility.rs
+//!
+//! Canonical discovery visibility shared by `Repo` and `Module`.
+//!
+//! Visibility is metadata only: the program stores and emits it, while
+//! explorers, indexers and dashboards are expected to honor it when building
+//! public listings. Nothing on-chain is hidden by a non-public value.
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::errors::Unit09Error;
+
+/// Discovery visibility for repositories and modules.
+///
+/// Encoded as a `u8` on accounts and in events. The numeric mapping is part
+/// of the public interface and must stay stable.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// Listed in public discovery surfaces.
+    #[default]
+    Public = 0,
+    /// Reachable by direct link but excluded from listings and search.
+    Unlisted = 1,
+    /// Hidden from all public surfaces; shown only to the authority.
+    Private = 2,
+}
+
+impl Visibility {
+    /// Convert from raw `u8` to `Visibility`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Visibility::Public),
+            1 => Some(Visibility::Unlisted),
+            2 => Some(Visibility::Private),
+            _ => None,
+        }
+    }
+
+    /// Convert from raw `u8`, failing with `InvalidVisibility` on unknown values.
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        Self::from_u8(value).ok_or_else(|| error!(Unit09Error::InvalidVisibility))
+    }
+
+    /// Convert `Visibility` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns true if this value should appear in public listings.
+    pub fn is_listed(self) -> bool {
+        matches!(self, Visibility::Public)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_variant() {
+        for visibility in [Visibility::Public, Visibility::Unlisted, Visibility::Private] {
+            assert_eq!(Visibility::from_u8(visibility.as_u8()), Some(visibility));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_values() {
+        assert_eq!(Visibility::from_u8(3), None);
+        assert!(Visibility::try_from_u8(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn only_public_is_listed() {
+        assert!(Visibility::Public.is_listed());
+        assert!(!Visibility::Unlisted.is_listed());
+        assert!(!Visibility::Private.is_listed());
+    }
+}
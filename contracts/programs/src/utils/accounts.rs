@@ -0,0 +1,92 @@
+//! ===========================================================================
+//! Unit09 – Account Creation Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/accounts.rs
+//!
+//! Helpers for creating program-owned PDAs by hand, for instructions that
+//! create a variable number of accounts from `remaining_accounts` and so
+//! cannot use Anchor's `init` constraint.
+//!
+//! Anyone can send lamports to a PDA address before it is created. A plain
+//! `system_program::create_account` then fails with "account already in
+//! use", which would let a griefer block the account for a single lamport.
+//! `create_pda_account` handles that case the way `init` does.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::errors::Unit09Error;
+
+/// Create the PDA `target` with `space` bytes, owned by `owner` and funded
+/// by `payer` to rent exemption.
+///
+/// `signer_seeds` are the PDA's seeds including its bump. When `target`
+/// already holds lamports it must still be a system account; like Anchor's
+/// `init`, the rent shortfall is topped up and the account is allocated and
+/// assigned instead of created.
+pub fn create_pda_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    owner: &Pubkey,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    if target.owner != &system_program::ID {
+        return err!(Unit09Error::InvalidAccountOwner);
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
@@ -0,0 +1,140 @@
+//! ===========================================================================
+//! Unit09 – Ed25519 Instruction Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/ed25519.rs
+//!
+//! Helpers for reading signatures checked by the native Ed25519 program.
+//!
+//! Verifying an Ed25519 signature inside the program would cost far more
+//! compute than an instruction can spend. Instead the client places an
+//! Ed25519 program instruction right before ours, and the runtime fails the
+//! whole transaction if that signature does not verify. These helpers read
+//! the instruction back through the instructions sysvar and return the
+//! public key and message it verified; the caller then checks that they
+//! are the ones it expects.
+//!
+//! Only instructions carrying a single signature, with every offset
+//! pointing into their own data, are accepted.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::Unit09Error;
+
+/// Signature count and padding byte at the start of the instruction data.
+const HEADER_LEN: usize = 2;
+
+/// Size of one `Ed25519SignatureOffsets` entry: seven `u16` fields.
+const OFFSETS_LEN: usize = 14;
+
+/// Instruction index that refers to the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Size of an Ed25519 signature.
+const SIGNATURE_LEN: usize = 64;
+
+/// Public key and message verified by the Ed25519 program instruction
+/// immediately before the one currently executing.
+pub fn preceding_ed25519_message(instructions_sysvar: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let previous = current
+        .checked_sub(1)
+        .ok_or(Unit09Error::InvalidEd25519Instruction)?;
+    let instruction = load_instruction_at_checked(usize::from(previous), instructions_sysvar)?;
+    if instruction.program_id != ed25519_program::ID {
+        return err!(Unit09Error::InvalidEd25519Instruction);
+    }
+
+    let (signer, message) = parse_ed25519_instruction(&instruction.data)?;
+    Ok((signer, message.to_vec()))
+}
+
+/// Public key and message of single-signature Ed25519 program instruction
+/// data.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return err!(Unit09Error::InvalidEd25519Instruction);
+    }
+
+    let offsets = &data[HEADER_LEN..HEADER_LEN + OFFSETS_LEN];
+    let field = |index: usize| u16::from_le_bytes([offsets[2 * index], offsets[2 * index + 1]]);
+    let (signature_offset, signature_instruction) = (field(0), field(1));
+    let (pubkey_offset, pubkey_instruction) = (field(2), field(3));
+    let (message_offset, message_len, message_instruction) = (field(4), field(5), field(6));
+
+    // Offsets into other instructions would let the signed data differ from
+    // what this instruction carries.
+    let instructions = [signature_instruction, pubkey_instruction, message_instruction];
+    if instructions.iter().any(|&index| index != CURRENT_INSTRUCTION) {
+        return err!(Unit09Error::InvalidEd25519Instruction);
+    }
+
+    let slice = |offset: u16, len: usize| {
+        let start = usize::from(offset);
+        data.get(start..start + len)
+            .ok_or_else(|| error!(Unit09Error::InvalidEd25519Instruction))
+    };
+    slice(signature_offset, SIGNATURE_LEN)?;
+    let signer = Pubkey::try_from(slice(pubkey_offset, 32)?)
+        .map_err(|_| error!(Unit09Error::InvalidEd25519Instruction))?;
+    let message = slice(message_offset, usize::from(message_len))?;
+
+    Ok((signer, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Instruction data laid out as the Ed25519 program's client builds it:
+    /// header, offsets, public key, signature, message.
+    fn instruction_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let pubkey_offset = (HEADER_LEN + OFFSETS_LEN) as u16;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + SIGNATURE_LEN as u16;
+
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            instruction_index,
+            pubkey_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7u8; SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn signer_and_message_are_read_back() {
+        let signer = Pubkey::new_unique();
+        let data = instruction_data(&signer, b"observed", CURRENT_INSTRUCTION);
+
+        let (parsed_signer, message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(parsed_signer, signer);
+        assert_eq!(message, b"observed");
+    }
+
+    #[test]
+    fn foreign_offsets_and_truncated_data_are_rejected() {
+        let signer = Pubkey::new_unique();
+        assert!(parse_ed25519_instruction(&instruction_data(&signer, b"observed", 0)).is_err());
+
+        let data = instruction_data(&signer, b"observed", CURRENT_INSTRUCTION);
+        assert!(parse_ed25519_instruction(&data[..data.len() - 1]).is_err());
+
+        let mut two_signatures = data;
+        two_signatures[0] = 2;
+        assert!(parse_ed25519_instruction(&two_signatures).is_err());
+    }
+}
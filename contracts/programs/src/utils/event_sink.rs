@@ -0,0 +1,126 @@
+//! ===========================================================================
+//! Unit09 – Event Routing
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/event_sink.rs
+//!
+//! Core events can reach indexers in two ways:
+//! - program logs (`emit!`): cheap, but RPC nodes truncate long log output,
+//!   so events in busy transactions can be lost
+//! - self-CPI (`emit_cpi!`): the event becomes the data of an inner
+//!   instruction to this program, signed by the event-authority PDA, and is
+//!   read back from the transaction's inner instructions
+//!
+//! `Config::use_cpi_events` picks the path. Instructions that emit core
+//! events carry the `#[event_cpi]` accounts, build an `EventSink` from them
+//! and emit through it. Both paths carry the same bytes, so decoders only
+//! need to know where to look.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::Event;
+
+use crate::state::Config;
+
+/// Where a handler's core events are emitted.
+pub struct EventSink<'a, 'info> {
+    /// Event-authority account and its bump, when routing through a CPI.
+    cpi: Option<CpiEventContext<'a, 'info>>,
+}
+
+/// The parts of an instruction `Context` that `emit_cpi!` reads.
+///
+/// `emit_cpi!` expects a `ctx` with `accounts.event_authority` and
+/// `bumps.event_authority`; handlers destructure their accounts, so the
+/// sink keeps its own copy of the two.
+struct CpiEventContext<'a, 'info> {
+    accounts: CpiEventAccounts<'a, 'info>,
+    bumps: CpiEventBumps,
+}
+
+struct CpiEventAccounts<'a, 'info> {
+    event_authority: &'a AccountInfo<'info>,
+}
+
+struct CpiEventBumps {
+    event_authority: u8,
+}
+
+impl<'a, 'info> EventSink<'a, 'info> {
+    /// Sink for the deployment described by `config`.
+    ///
+    /// `event_authority` and `bump` come from the instruction's
+    /// `#[event_cpi]` accounts and are only used when
+    /// `config.use_cpi_events` is set.
+    pub fn new(config: &Config, event_authority: &'a AccountInfo<'info>, bump: u8) -> Self {
+        Self {
+            cpi: config.use_cpi_events.then_some(CpiEventContext {
+                accounts: CpiEventAccounts { event_authority },
+                bumps: CpiEventBumps {
+                    event_authority: bump,
+                },
+            }),
+        }
+    }
+
+    /// Whether events go through the self-CPI path.
+    pub fn uses_cpi(&self) -> bool {
+        self.cpi.is_some()
+    }
+
+    /// Emit `event` through the configured path.
+    pub fn emit<E: Event>(&self, event: E) -> Result<()> {
+        let Some(ctx) = &self.cpi else {
+            emit!(event);
+            return Ok(());
+        };
+
+        emit_cpi!(event);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::RepoRegistered;
+    use crate::utils::test_utils;
+
+    fn new_config(use_cpi_events: bool) -> Config {
+        let mut config = test_utils::new_config(Pubkey::new_unique(), &Clock::default());
+        config.use_cpi_events = use_cpi_events;
+        config
+    }
+
+    fn event() -> RepoRegistered {
+        RepoRegistered {
+            repo: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            url: "https://github.com/unit09-labs/unit09".to_string(),
+            visibility: 1,
+        }
+    }
+
+    #[test]
+    fn flag_selects_the_cpi_path() {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0u64;
+        let mut data = [0u8; 0];
+        let event_authority =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        assert!(!EventSink::new(&new_config(false), &event_authority, 255).uses_cpi());
+        assert!(EventSink::new(&new_config(true), &event_authority, 255).uses_cpi());
+    }
+
+    #[test]
+    fn log_path_needs_no_event_authority() {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0u64;
+        let mut data = [0u8; 0];
+        let event_authority =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        let sink = EventSink::new(&new_config(false), &event_authority, 255);
+        assert!(sink.emit(event()).is_ok());
+    }
+}
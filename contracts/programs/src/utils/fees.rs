@@ -0,0 +1,61 @@
+//! ===========================================================================
+//! Unit09 – Fee Collection Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/fees.rs
+//!
+//! Helpers for moving fees from a payer to wherever the deployment wants them.
+//!
+//! Routing:
+//! - `Config::treasury` when the admin has configured one
+//! - otherwise the lamport-only fee vault PDA (`FEE_VAULT_SEED`)
+//!
+//! Instructions that charge a fee should pass the destination account in
+//! their context and call `collect_fee`; the helper checks that the account
+//! is the expected destination before transferring.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::Unit09Error;
+use crate::state::Config;
+
+/// Ensure `destination` is where `config` routes fees and is system-owned.
+pub fn assert_fee_destination(config: &Config, destination: &AccountInfo) -> Result<()> {
+    if destination.key() != config.fee_destination(&crate::ID) {
+        return err!(Unit09Error::InvalidFeeDestination);
+    }
+    if destination.owner != &system_program::ID {
+        return err!(Unit09Error::InvalidFeeDestination);
+    }
+    Ok(())
+}
+
+/// Transfer `amount` lamports from `payer` to the configured fee destination.
+///
+/// A zero amount is a no-op and does not validate `destination`, so
+/// instructions with an optional fee can call this unconditionally.
+pub fn collect_fee<'info>(
+    config: &Config,
+    payer: &Signer<'info>,
+    destination: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    assert_fee_destination(config, destination)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: destination.clone(),
+            },
+        ),
+        amount,
+    )
+}
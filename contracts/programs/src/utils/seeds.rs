@@ -85,6 +85,22 @@ pub fn global_metadata_seeds<'a>(bump: u8) -> SeedSlice<'a> {
     &[GLOBAL_METADATA_SEED.as_bytes(), &[bump]]
 }
 
+/// Derive the PDA of the fee vault used when no treasury is configured.
+///
+/// Seeds:
+/// - `[FEE_VAULT_SEED.as_bytes()]`
+pub fn fee_vault_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED.as_bytes()], program_id)
+}
+
+/// Derive the PDA of the vault observation rewards are paid from.
+///
+/// Seeds:
+/// - `[REWARDS_VAULT_SEED.as_bytes()]`
+pub fn rewards_vault_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARDS_VAULT_SEED.as_bytes()], program_id)
+}
+
 // ---------------------------------------------------------------------------
 // Authority
 // ---------------------------------------------------------------------------
@@ -140,6 +156,20 @@ pub fn repo_seeds<'a>(repo_key: &Pubkey, bump: u8) -> SeedSlice<'a> {
     ]
 }
 
+/// Derive the PDA for the `RepoUrlIndex` claiming `url`.
+///
+/// Seeds:
+/// - `[REPO_URL_INDEX_SEED.as_bytes(), RepoUrlIndex::url_hash(url)]`
+pub fn repo_url_index_pda(program_id: &Pubkey, url: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REPO_URL_INDEX_SEED.as_bytes(),
+            &crate::state::RepoUrlIndex::url_hash(url),
+        ],
+        program_id,
+    )
+}
+
 // ---------------------------------------------------------------------------
 // Module
 // ---------------------------------------------------------------------------
@@ -194,6 +224,9 @@ pub fn module_seeds<'a>(
 /// - `major.to_le_bytes()`
 /// - `minor.to_le_bytes()`
 /// - `patch.to_le_bytes()`
+///
+/// Each component is encoded as exactly two little-endian bytes, so distinct
+/// versions never produce the same seeds.
 pub fn module_version_pda(
     program_id: &Pubkey,
     module_pubkey: &Pubkey,
@@ -217,24 +250,25 @@ pub fn module_version_pda(
     )
 }
 
-pub fn module_version_seeds<'a>(
+/// Signer seeds for a `ModuleVersion` PDA, bump included.
+///
+/// The version components are encoded here, so the seeds are returned owned:
+/// a `SeedSlice` borrowing the encoded bytes would outlive them. Borrow
+/// each entry as `&[u8]` to pass the seeds to `invoke_signed`.
+pub fn module_version_seeds(
     module_pubkey: &Pubkey,
     major: u16,
     minor: u16,
     patch: u16,
     bump: u8,
-) -> SeedSlice<'a> {
-    let major_bytes = major.to_le_bytes();
-    let minor_bytes = minor.to_le_bytes();
-    let patch_bytes = patch.to_le_bytes();
-
-    &[
-        MODULE_VERSION_SEED.as_bytes(),
-        module_pubkey.as_ref(),
-        &major_bytes,
-        &minor_bytes,
-        &patch_bytes,
-        &[bump],
+) -> Vec<Vec<u8>> {
+    vec![
+        MODULE_VERSION_SEED.as_bytes().to_vec(),
+        module_pubkey.as_ref().to_vec(),
+        major.to_le_bytes().to_vec(),
+        minor.to_le_bytes().to_vec(),
+        patch.to_le_bytes().to_vec(),
+        vec![bump],
     ]
 }
 
@@ -308,6 +342,31 @@ pub fn module_repo_link_seeds<'a>(
     ]
 }
 
+// ---------------------------------------------------------------------------
+// Module Claim
+// ---------------------------------------------------------------------------
+
+/// Derive the PDA for a `ModuleClaim` account.
+///
+/// Seeds:
+/// - `MODULE_CLAIM_SEED.as_bytes()`
+/// - `module_pubkey.as_ref()`
+/// - `claimant.as_ref()`
+pub fn module_claim_pda(
+    program_id: &Pubkey,
+    module_pubkey: &Pubkey,
+    claimant: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MODULE_CLAIM_SEED.as_bytes(),
+            module_pubkey.as_ref(),
+            claimant.as_ref(),
+        ],
+        program_id,
+    )
+}
+
 // ---------------------------------------------------------------------------
 // Convenience: Generic PDA Assertion
 // ---------------------------------------------------------------------------
@@ -326,3 +385,425 @@ pub fn assert_pda(
     require_keys_eq!(*account_key, expected, crate::errors::Unit09Error::InvalidPda);
     Ok(bump)
 }
+
+/// Batch form of `assert_pda` for instructions that validate many accounts,
+/// such as those passed in `remaining_accounts`.
+///
+/// Returns the bumps in input order, or fails with `InvalidPda` on the first
+/// account that does not match its seeds.
+pub fn assert_pdas(pairs: &[(&Pubkey, &[&[u8]])], program_id: &Pubkey) -> Result<Vec<u8>> {
+    pairs
+        .iter()
+        .map(|(account_key, seeds)| assert_pda(account_key, seeds, program_id))
+        .collect()
+}
+
+/// Round-trip checks between the `*_pda` helpers above and the seed lists
+/// written out in the instructions' `#[account(seeds = ...)]` constraints.
+///
+/// Each constraint is reproduced literally, reading the same account fields
+/// the constraint reads, so a change on either side without the other fails
+/// here instead of at runtime.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        Authority, Config, Fork, GlobalMetadata, Lifecycle, LifecyclePhase, Metrics, Module,
+        ModuleRepoLink, ModuleVersion, Repo, RepoUrlIndex, Visibility,
+    };
+    use crate::utils::test_utils::zeroed;
+
+    /// Assert that the helper-derived PDA matches the constraint seeds.
+    fn assert_round_trip(helper: (Pubkey, u8), constraint_seeds: &[&[u8]]) {
+        let (address, bump) = helper;
+        let derived = Pubkey::find_program_address(constraint_seeds, &crate::ID);
+        assert_eq!(derived, (address, bump));
+        assert_eq!(assert_pda(&address, constraint_seeds, &crate::ID).unwrap(), bump);
+    }
+
+    /// Assert that the bump an account persisted re-derives its address.
+    fn assert_persisted_bump(address: Pubkey, seeds: &[&[u8]], persisted_bump: u8) {
+        let bump = [persisted_bump];
+        let mut signer_seeds = seeds.to_vec();
+        signer_seeds.push(&bump);
+        assert_eq!(
+            Pubkey::create_program_address(&signer_seeds, &crate::ID).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn singleton_seeds_round_trip() {
+        assert_round_trip(config_pda(&crate::ID), &[CONFIG_SEED.as_bytes()]);
+        assert_round_trip(lifecycle_pda(&crate::ID), &[LIFECYCLE_SEED.as_bytes()]);
+        assert_round_trip(metrics_pda(&crate::ID), &[METRICS_SEED.as_bytes()]);
+    }
+
+    #[test]
+    fn repo_seeds_round_trip() {
+        let mut repo: Repo = zeroed(Repo::LEN);
+        repo.repo_key = Pubkey::new_unique();
+
+        assert_round_trip(
+            repo_pda(&crate::ID, &repo.repo_key),
+            &[REPO_SEED.as_bytes(), repo.repo_key.as_ref()],
+        );
+    }
+
+    #[test]
+    fn module_seeds_round_trip() {
+        let (repo_address, _) = repo_pda(&crate::ID, &Pubkey::new_unique());
+        let mut module: Module = zeroed(Module::LEN);
+        module.repo = repo_address;
+        module.module_key = Pubkey::new_unique();
+
+        let helper = module_pda(&crate::ID, &repo_address, &module.module_key);
+
+        // Instructions that carry the repo account (`repo.key()`).
+        assert_round_trip(
+            helper,
+            &[
+                MODULE_SEED.as_bytes(),
+                repo_address.as_ref(),
+                module.module_key.as_ref(),
+            ],
+        );
+
+        // Instructions that only carry the module (`module.repo`).
+        assert_round_trip(
+            helper,
+            &[
+                MODULE_SEED.as_bytes(),
+                module.repo.as_ref(),
+                module.module_key.as_ref(),
+            ],
+        );
+    }
+
+    #[test]
+    fn module_version_seeds_round_trip() {
+        let (module_address, _) =
+            module_pda(&crate::ID, &Pubkey::new_unique(), &Pubkey::new_unique());
+        let mut version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        version.module = module_address;
+        version.major_version = 1;
+        version.minor_version = 2;
+        version.patch_version = 0x0300;
+
+        assert_round_trip(
+            module_version_pda(&crate::ID, &module_address, 1, 2, 0x0300),
+            &[
+                MODULE_VERSION_SEED.as_bytes(),
+                module_address.as_ref(),
+                &version.major_version.to_le_bytes(),
+                &version.minor_version.to_le_bytes(),
+                &version.patch_version.to_le_bytes(),
+            ],
+        );
+    }
+
+    #[test]
+    fn module_version_signer_seeds_match_pda() {
+        let (module_address, _) =
+            module_pda(&crate::ID, &Pubkey::new_unique(), &Pubkey::new_unique());
+
+        for (major, minor, patch) in [(1, 2, 3), (3, 2, 1), (0, 0x0102, 0x0300)] {
+            let (address, bump) =
+                module_version_pda(&crate::ID, &module_address, major, minor, patch);
+            let seeds = module_version_seeds(&module_address, major, minor, patch, bump);
+            let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+
+            assert_eq!(
+                Pubkey::create_program_address(&seeds, &crate::ID).unwrap(),
+                address
+            );
+        }
+
+        // Each component is a fixed two bytes, so reordering the components
+        // cannot collide.
+        assert_ne!(
+            module_version_pda(&crate::ID, &module_address, 1, 2, 3),
+            module_version_pda(&crate::ID, &module_address, 3, 2, 1)
+        );
+    }
+
+    #[test]
+    fn batch_of_matching_pdas_returns_bumps() {
+        let repo_key = Pubkey::new_unique();
+        let fork_key = Pubkey::new_unique();
+        let (repo_address, repo_bump) = repo_pda(&crate::ID, &repo_key);
+        let (fork_address, fork_bump) = fork_pda(&crate::ID, &fork_key);
+
+        let bumps = assert_pdas(
+            &[
+                (&repo_address, &[REPO_SEED.as_bytes(), repo_key.as_ref()][..]),
+                (&fork_address, &[FORK_SEED.as_bytes(), fork_key.as_ref()][..]),
+            ],
+            &crate::ID,
+        )
+        .unwrap();
+        assert_eq!(bumps, vec![repo_bump, fork_bump]);
+        assert!(assert_pdas(&[], &crate::ID).unwrap().is_empty());
+    }
+
+    #[test]
+    fn batch_with_one_wrong_pda_is_rejected() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut addresses: Vec<Pubkey> = keys
+            .iter()
+            .map(|key| fork_pda(&crate::ID, key).0)
+            .collect();
+        addresses[1] = Pubkey::new_unique();
+
+        let seeds: Vec<[&[u8]; 2]> = keys
+            .iter()
+            .map(|key| [FORK_SEED.as_bytes(), key.as_ref()])
+            .collect();
+        let pairs: Vec<(&Pubkey, &[&[u8]])> = addresses
+            .iter()
+            .zip(&seeds)
+            .map(|(address, seeds)| (address, &seeds[..]))
+            .collect();
+
+        assert!(assert_pdas(&pairs, &crate::ID).is_err());
+        assert!(assert_pdas(&pairs[..1], &crate::ID).is_ok());
+    }
+
+    #[test]
+    fn fork_seeds_round_trip() {
+        let mut fork: Fork = zeroed(Fork::LEN);
+        fork.fork_key = Pubkey::new_unique();
+
+        assert_round_trip(
+            fork_pda(&crate::ID, &fork.fork_key),
+            &[FORK_SEED.as_bytes(), fork.fork_key.as_ref()],
+        );
+    }
+
+    #[test]
+    fn module_repo_link_seeds_round_trip() {
+        let (module_address, _) =
+            module_pda(&crate::ID, &Pubkey::new_unique(), &Pubkey::new_unique());
+        let (repo_address, _) = repo_pda(&crate::ID, &Pubkey::new_unique());
+        let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+        link.module = module_address;
+        link.repo = repo_address;
+
+        let helper = module_repo_link_pda(&crate::ID, &module_address, &repo_address);
+
+        // `link_module_to_repo` derives from the module and repo accounts.
+        assert_round_trip(
+            helper,
+            &[
+                MODULE_REPO_LINK_SEED.as_bytes(),
+                module_address.as_ref(),
+                repo_address.as_ref(),
+            ],
+        );
+
+        // `confirm_module_link` derives from the fields stored on the link.
+        assert_round_trip(
+            helper,
+            &[
+                MODULE_REPO_LINK_SEED.as_bytes(),
+                link.module.as_ref(),
+                link.repo.as_ref(),
+            ],
+        );
+    }
+
+    // Handlers pass `ctx.bumps.<account>` to `init`; Anchor derives it with
+    // `find_program_address` over the constraint seeds, which is what the
+    // `*_pda` helpers return. These tests persist that bump the same way.
+
+    #[test]
+    fn initialize_persists_helper_bumps() {
+        let clock = Clock::default();
+
+        let (config_address, config_bump) = config_pda(&crate::ID);
+        let mut config: Config = zeroed(Config::LEN);
+        config
+            .init(Pubkey::new_unique(), 250, 128, [0u8; 32], config_bump, &clock)
+            .unwrap();
+        assert_persisted_bump(config_address, &[CONFIG_SEED.as_bytes()], config.bump);
+
+        let (metrics_address, metrics_bump) = metrics_pda(&crate::ID);
+        let mut metrics: Metrics = zeroed(Metrics::LEN);
+        metrics.init(metrics_bump, &clock).unwrap();
+        assert_persisted_bump(metrics_address, &[METRICS_SEED.as_bytes()], metrics.bump);
+
+        let (lifecycle_address, lifecycle_bump) = lifecycle_pda(&crate::ID);
+        let mut lifecycle: Lifecycle = zeroed(Lifecycle::LEN);
+        lifecycle
+            .init(LifecyclePhase::Operational, lifecycle_bump, &clock, [0u8; 32])
+            .unwrap();
+        assert_persisted_bump(lifecycle_address, &[LIFECYCLE_SEED.as_bytes()], lifecycle.bump);
+
+        let (metadata_address, metadata_bump) = global_metadata_pda(&crate::ID);
+        let mut metadata: GlobalMetadata = zeroed(GlobalMetadata::LEN);
+        metadata
+            .init(
+                "Unit09 consumes Solana code".to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                metadata_bump,
+                &clock,
+            )
+            .unwrap();
+        assert_persisted_bump(
+            metadata_address,
+            &[GLOBAL_METADATA_SEED.as_bytes()],
+            metadata.bump,
+        );
+    }
+
+    #[test]
+    fn registration_persists_helper_bumps() {
+        let clock = Clock::default();
+
+        let repo_key = Pubkey::new_unique();
+        let url = "https://github.com/unit09-labs/unit09";
+        let (repo_address, repo_bump) = repo_pda(&crate::ID, &repo_key);
+        let mut repo: Repo = zeroed(Repo::LEN);
+        repo.init(
+            repo_key,
+            Pubkey::new_unique(),
+            "unit09-core".to_string(),
+            url.to_string(),
+            "solana".to_string(),
+            true,
+            Visibility::Public,
+            repo_bump,
+            &clock,
+        )
+        .unwrap();
+        assert_persisted_bump(repo_address, &[REPO_SEED.as_bytes(), repo_key.as_ref()], repo.bump);
+
+        let (index_address, index_bump) = repo_url_index_pda(&crate::ID, url);
+        let mut index: RepoUrlIndex = zeroed(RepoUrlIndex::LEN);
+        index.claim(url, repo_address, index_bump, &clock).unwrap();
+        assert_persisted_bump(
+            index_address,
+            &[REPO_URL_INDEX_SEED.as_bytes(), &RepoUrlIndex::url_hash(url)],
+            index.bump,
+        );
+
+        let module_key = Pubkey::new_unique();
+        let (module_address, module_bump) = module_pda(&crate::ID, &repo_address, &module_key);
+        let mut module: Module = zeroed(Module::LEN);
+        module
+            .init(
+                module_key,
+                repo_address,
+                repo.authority,
+                "unit09-router".to_string(),
+                "https://unit09.org/metadata/router.json".to_string(),
+                "program".to_string(),
+                "solana".to_string(),
+                "MIT".to_string(),
+                (1, 0, 0),
+                Visibility::Public,
+                module_bump,
+                &clock,
+            )
+            .unwrap();
+        assert_persisted_bump(
+            module_address,
+            &[MODULE_SEED.as_bytes(), repo_address.as_ref(), module_key.as_ref()],
+            module.bump,
+        );
+
+        let (version_address, version_bump) =
+            module_version_pda(&crate::ID, &module_address, 1, 0, 0);
+        let mut version: ModuleVersion = zeroed(ModuleVersion::LEN);
+        version
+            .init(
+                module_address,
+                repo.authority,
+                (1, 0, 0),
+                module.metadata_uri.clone(),
+                String::new(),
+                String::new(),
+                true,
+                [0u8; 32],
+                0,
+                String::new(),
+                None,
+                Vec::new(),
+                version_bump,
+                &clock,
+            )
+            .unwrap();
+        let seeds = module_version_seeds(&module_address, 1, 0, 0, version.bump);
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        assert_eq!(
+            Pubkey::create_program_address(&seeds, &crate::ID).unwrap(),
+            version_address
+        );
+    }
+
+    #[test]
+    fn forks_links_and_roles_persist_helper_bumps() {
+        let clock = Clock::default();
+
+        let fork_key = Pubkey::new_unique();
+        let (fork_address, fork_bump) = fork_pda(&crate::ID, &fork_key);
+        let mut fork: Fork = zeroed(Fork::LEN);
+        fork.init(
+            fork_key,
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            "unit09-lab-alpha".to_string(),
+            "https://unit09.org/forks/alpha.json".to_string(),
+            String::new(),
+            true,
+            0,
+            None,
+            fork_bump,
+            &clock,
+        )
+        .unwrap();
+        assert_persisted_bump(fork_address, &[FORK_SEED.as_bytes(), fork_key.as_ref()], fork.bump);
+
+        let (module_address, repo_address) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (link_address, link_bump) =
+            module_repo_link_pda(&crate::ID, &module_address, &repo_address);
+        let mut link: ModuleRepoLink = zeroed(ModuleRepoLink::LEN);
+        link.init(
+            module_address,
+            repo_address,
+            Pubkey::new_unique(),
+            true,
+            String::new(),
+            None,
+            link_bump,
+            &clock,
+        )
+        .unwrap();
+        assert_persisted_bump(
+            link_address,
+            &[
+                MODULE_REPO_LINK_SEED.as_bytes(),
+                module_address.as_ref(),
+                repo_address.as_ref(),
+            ],
+            link.bump,
+        );
+
+        let target = Pubkey::new_unique();
+        let (authority_address, authority_bump) = authority_pda(&crate::ID, &target);
+        let mut authority: Authority = zeroed(Authority::LEN);
+        authority
+            .init(target, true, Pubkey::default(), authority_bump, &clock)
+            .unwrap();
+        assert_persisted_bump(
+            authority_address,
+            &[AUTHORITY_SEED.as_bytes(), target.as_ref()],
+            authority.bump,
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! ===========================================================================
+//! Unit09 – Semantic Version Constraints
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/semver.rs
+//!
+//! Helpers for matching `(major, minor, patch)` versions, as stored on
+//! `ModuleVersion`, against a constraint, and for picking the newest
+//! version that satisfies one ("latest version matching ^1.2").
+//!
+//! Supported constraints follow Cargo's rules:
+//! - exact `=1.2.3`: only 1.2.3
+//! - caret `^1.2.3`: at least 1.2.3, without changing the left-most
+//!   non-zero component (`^0.2.3` stays on 0.2, `^0.0.3` is exact)
+//! - tilde `~1.2.3`: at least 1.2.3 within 1.2
+//!
+//! On-chain, a constraint is stored in `VersionConstraint::PACKED_LEN`
+//! bytes: the kind discriminant followed by the three components as
+//! little-endian `u16`s. The helpers are pure so off-chain tools can reuse
+//! them.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::errors::Unit09Error;
+
+/// How a `VersionConstraint` compares versions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ConstraintKind {
+    /// `=M.m.p`
+    Exact = 0,
+    /// `^M.m.p`
+    Caret = 1,
+    /// `~M.m.p`
+    Tilde = 2,
+}
+
+impl ConstraintKind {
+    /// Convert from raw `u8` to `ConstraintKind`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ConstraintKind::Exact),
+            1 => Some(ConstraintKind::Caret),
+            2 => Some(ConstraintKind::Tilde),
+            _ => None,
+        }
+    }
+
+    /// Convert `ConstraintKind` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A version requirement such as `^1.2.0`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionConstraint {
+    /// Comparison rule.
+    pub kind: ConstraintKind,
+    /// Base version as `(major, minor, patch)`.
+    pub version: (u16, u16, u16),
+}
+
+impl VersionConstraint {
+    /// Size of the packed representation: kind byte plus three `u16`s.
+    pub const PACKED_LEN: usize = 1 + 3 * 2;
+
+    /// Decode a packed constraint, failing with `InvalidVersionConstraint`
+    /// on an unknown kind.
+    pub fn unpack(bytes: &[u8; Self::PACKED_LEN]) -> Result<Self> {
+        let kind = ConstraintKind::from_u8(bytes[0])
+            .ok_or_else(|| error!(Unit09Error::InvalidVersionConstraint))?;
+        let component = |index: usize| {
+            u16::from_le_bytes([bytes[1 + 2 * index], bytes[2 + 2 * index]])
+        };
+        Ok(Self {
+            kind,
+            version: (component(0), component(1), component(2)),
+        })
+    }
+
+    /// Encode the constraint in its packed representation.
+    pub fn pack(&self) -> [u8; Self::PACKED_LEN] {
+        let (major, minor, patch) = self.version;
+        let mut bytes = [0u8; Self::PACKED_LEN];
+        bytes[0] = self.kind.as_u8();
+        for (index, component) in [major, minor, patch].into_iter().enumerate() {
+            bytes[1 + 2 * index..3 + 2 * index].copy_from_slice(&component.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Whether `version` satisfies `constraint`.
+pub fn version_satisfies(version: (u16, u16, u16), constraint: VersionConstraint) -> bool {
+    let base = constraint.version;
+    if version < base {
+        return false;
+    }
+
+    let (major, minor, _) = version;
+    match constraint.kind {
+        ConstraintKind::Exact => version == base,
+        ConstraintKind::Tilde => (major, minor) == (base.0, base.1),
+        ConstraintKind::Caret => match base {
+            (0, 0, _) => version == base,
+            (0, base_minor, _) => major == 0 && minor == base_minor,
+            (base_major, _, _) => major == base_major,
+        },
+    }
+}
+
+/// Newest of `versions` that satisfies `constraint`, if any.
+pub fn resolve_best(
+    versions: &[(u16, u16, u16)],
+    constraint: VersionConstraint,
+) -> Option<(u16, u16, u16)> {
+    versions
+        .iter()
+        .copied()
+        .filter(|&version| version_satisfies(version, constraint))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLISHED: [(u16, u16, u16); 7] = [
+        (0, 2, 3),
+        (0, 2, 9),
+        (0, 3, 0),
+        (1, 2, 0),
+        (1, 2, 7),
+        (1, 4, 1),
+        (2, 0, 0),
+    ];
+
+    fn constraint(kind: ConstraintKind, version: (u16, u16, u16)) -> VersionConstraint {
+        VersionConstraint { kind, version }
+    }
+
+    #[test]
+    fn caret_stays_on_the_leftmost_nonzero_component() {
+        let caret = |version| constraint(ConstraintKind::Caret, version);
+
+        assert_eq!(resolve_best(&PUBLISHED, caret((1, 2, 0))), Some((1, 4, 1)));
+        assert_eq!(resolve_best(&PUBLISHED, caret((0, 2, 3))), Some((0, 2, 9)));
+        assert_eq!(resolve_best(&PUBLISHED, caret((0, 0, 3))), None);
+        assert!(!version_satisfies((1, 1, 9), caret((1, 2, 0))));
+        assert!(!version_satisfies((2, 0, 0), caret((1, 2, 0))));
+    }
+
+    #[test]
+    fn tilde_stays_on_the_minor_version() {
+        let tilde = |version| constraint(ConstraintKind::Tilde, version);
+
+        assert_eq!(resolve_best(&PUBLISHED, tilde((1, 2, 0))), Some((1, 2, 7)));
+        assert_eq!(resolve_best(&PUBLISHED, tilde((0, 2, 5))), Some((0, 2, 9)));
+        assert!(!version_satisfies((1, 2, 6), tilde((1, 2, 7))));
+    }
+
+    #[test]
+    fn exact_matches_only_itself() {
+        let exact = |version| constraint(ConstraintKind::Exact, version);
+
+        assert_eq!(resolve_best(&PUBLISHED, exact((1, 2, 0))), Some((1, 2, 0)));
+        assert!(!version_satisfies((1, 2, 7), exact((1, 2, 0))));
+    }
+
+    #[test]
+    fn unmatched_constraints_resolve_to_none() {
+        assert_eq!(resolve_best(&PUBLISHED, constraint(ConstraintKind::Caret, (3, 0, 0))), None);
+        assert_eq!(resolve_best(&PUBLISHED, constraint(ConstraintKind::Tilde, (1, 3, 0))), None);
+        assert_eq!(resolve_best(&[], constraint(ConstraintKind::Exact, (1, 0, 0))), None);
+    }
+
+    #[test]
+    fn packed_constraints_round_trip() {
+        let caret = constraint(ConstraintKind::Caret, (1, 2, 300));
+        assert_eq!(caret.pack(), [1, 1, 0, 2, 0, 44, 1]);
+        assert_eq!(VersionConstraint::unpack(&caret.pack()).unwrap(), caret);
+
+        assert!(VersionConstraint::unpack(&[3, 1, 0, 0, 0, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! ===========================================================================
+//! Unit09 – Test Utilities
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/test_utils.rs
+//!
+//! Fixtures shared by the unit tests: clocks at a given time or slot, blank
+//! accounts, and initialized accounts with the usual fixture values.
+//!
+//! Compiled for tests only.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Authority, Config, Metrics, Module, Repo, Visibility};
+
+/// `Clock` at `unix_timestamp`, every other field default.
+pub fn clock_at(unix_timestamp: i64) -> Clock {
+    Clock {
+        unix_timestamp,
+        ..Clock::default()
+    }
+}
+
+/// `Clock` at `slot`, every other field default.
+pub fn clock_at_slot(slot: u64) -> Clock {
+    Clock {
+        slot,
+        ..Clock::default()
+    }
+}
+
+/// Account read from `len` zeroed bytes, as `init` finds a freshly
+/// allocated one.
+pub fn zeroed<T: AnchorDeserialize>(len: usize) -> T {
+    T::deserialize(&mut &vec![0u8; len][..]).unwrap()
+}
+
+/// Public, observable `Repo` "unit09-core" owned by `authority`,
+/// registered at `clock`.
+pub fn new_repo(authority: Pubkey, clock: &Clock) -> Repo {
+    let mut repo: Repo = zeroed(Repo::LEN);
+    repo.init(
+        Pubkey::new_unique(),
+        authority,
+        "unit09-core".to_string(),
+        "https://github.com/unit09-labs/unit09".to_string(),
+        "solana".to_string(),
+        true,
+        Visibility::Public,
+        255,
+        clock,
+    )
+    .unwrap();
+    repo
+}
+
+/// Public v1.0.0 `Module` named `name` in `repo`, owned by `authority`,
+/// registered at `clock`.
+pub fn new_module(repo: Pubkey, authority: Pubkey, name: &str, clock: &Clock) -> Module {
+    let mut module: Module = zeroed(Module::LEN);
+    module
+        .init(
+            Pubkey::new_unique(),
+            repo,
+            authority,
+            name.to_string(),
+            format!("https://unit09.org/metadata/{name}.json"),
+            "program".to_string(),
+            "solana".to_string(),
+            "MIT".to_string(),
+            (1, 0, 0),
+            Visibility::Public,
+            255,
+            clock,
+        )
+        .unwrap();
+    module
+}
+
+/// `Config` administered by `admin` (250 bps fee, 128 modules per repo),
+/// initialized at `clock`.
+pub fn new_config(admin: Pubkey, clock: &Clock) -> Config {
+    let mut config: Config = zeroed(Config::LEN);
+    config
+        .init(admin, 250, 128, [0u8; 32], 255, clock)
+        .unwrap();
+    config
+}
+
+/// `Metrics` initialized at `clock`.
+pub fn new_metrics(clock: &Clock) -> Metrics {
+    let mut metrics: Metrics = zeroed(Metrics::LEN);
+    metrics.init(255, clock).unwrap();
+    metrics
+}
+
+/// `Authority` entry for `key` without roles, global or scoped to
+/// `resource_scope`, created at `clock`.
+pub fn new_authority(
+    key: Pubkey,
+    is_global: bool,
+    resource_scope: Pubkey,
+    clock: &Clock,
+) -> Authority {
+    let mut authority: Authority = zeroed(Authority::LEN);
+    authority
+        .init(key, is_global, resource_scope, 255, clock)
+        .unwrap();
+    authority
+}
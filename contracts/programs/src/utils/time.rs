@@ -18,7 +18,12 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::SECONDS_PER_DAY;
 use crate::errors::Unit09Error;
+use crate::events::Unit09Log;
+
+/// Log category used when `monotonic_updated_at` clamps a regressed clock.
+pub const CLOCK_REGRESSION_LOG_CATEGORY: &str = "clock_regression";
 
 /// Return the current Unix timestamp from the provided `Clock` reference.
 ///
@@ -27,6 +32,14 @@ pub fn now(clock: &Clock) -> i64 {
     clock.unix_timestamp
 }
 
+/// Return the day index (days since the Unix epoch) for a timestamp.
+///
+/// Used to group activity into daily buckets. Negative timestamps are
+/// floored so that each bucket always spans exactly `SECONDS_PER_DAY`.
+pub fn day_index(ts: i64) -> i64 {
+    ts.div_euclid(SECONDS_PER_DAY)
+}
+
 /// Compute the age (in seconds) of a given timestamp relative to `clock`.
 ///
 /// - `created_at` is expected to be a Unix timestamp in seconds.
@@ -159,3 +172,22 @@ pub fn clamp_to_past(clock: &Clock, ts: i64) -> i64 {
 pub fn add_offset_saturating(ts: i64, offset_secs: i64) -> i64 {
     ts.saturating_add(offset_secs)
 }
+
+/// Return the `updated_at` value to store for an account created at
+/// `created_at` when the clock reads `now`.
+///
+/// The clock sysvar is not guaranteed to be monotonic. If it has regressed
+/// below `created_at`, the result is clamped to `created_at` (so that
+/// `updated_at >= created_at` always holds) and a `Unit09Log` is emitted.
+pub fn monotonic_updated_at(created_at: i64, now: i64) -> i64 {
+    if now >= created_at {
+        return now;
+    }
+
+    emit!(Unit09Log {
+        category: CLOCK_REGRESSION_LOG_CATEGORY.to_string(),
+        message: format!("clock {} behind created_at {}; clamped", now, created_at),
+        logged_at: now,
+    });
+    created_at
+}
@@ -19,6 +19,9 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::events::MetricsLimitReached;
+use crate::state::{Authority, Config, EntityKind, Metrics};
+use crate::utils::seeds::{authority_pda, config_pda, lifecycle_pda, metrics_pda};
 
 /// Validate that a string is not empty.
 pub fn assert_non_empty_str(value: &str) -> Result<()> {
@@ -179,3 +182,297 @@ pub fn assert_deployment_active(is_active: bool) -> Result<()> {
     require!(is_active, Unit09Error::DeploymentInactive);
     Ok(())
 }
+
+/// Ensure an instruction received at most `MAX_REMAINING_ACCOUNTS`
+/// remaining accounts.
+///
+/// Call this before iterating `remaining_accounts`, so an oversized request
+/// fails with `TooManyRemainingAccounts` rather than exhausting the compute
+/// budget mid-loop.
+pub fn assert_remaining_accounts_within_cap(count: usize) -> Result<()> {
+    require!(
+        count <= MAX_REMAINING_ACCOUNTS,
+        Unit09Error::TooManyRemainingAccounts
+    );
+    Ok(())
+}
+
+/// Ensure the deployment has room for one more `kind` entity under its
+/// `Config::max_total_*` cap.
+///
+/// A refusal emits `MetricsLimitReached` before failing with
+/// `GlobalLimitReached`, so operators can see which cap was hit.
+pub fn assert_global_limit(
+    config: &Config,
+    metrics: &Metrics,
+    kind: EntityKind,
+    now: i64,
+) -> Result<()> {
+    let current_value = metrics.total(kind);
+    if let Err(error) = config.assert_below_max_total(kind, current_value) {
+        emit!(MetricsLimitReached {
+            limit_key: kind.total_key().to_string(),
+            current_value,
+            observed_at: now,
+        });
+        return Err(error);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Admin Quorum
+// ---------------------------------------------------------------------------
+
+/// Collect the admin co-signers passed as `(signer, authority)` pairs in
+/// `remaining_accounts`, for `Config::assert_admin_quorum`.
+///
+/// Each pair is a key that signed the transaction followed by its
+/// `Authority` PDA, which must be owned by this program and hold the global
+/// admin role at `now`. A pair that does not check out fails the call
+/// rather than being skipped, so a missing approval is never silent.
+pub fn admin_co_signers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    now: i64,
+) -> Result<Vec<Pubkey>> {
+    if accounts.len() % 2 != 0 {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+
+    accounts
+        .chunks(2)
+        .map(|pair| {
+            let (signer, authority_info) = (&pair[0], &pair[1]);
+            require!(signer.is_signer, Unit09Error::ExpectedSigner);
+            require_keys_eq!(
+                *authority_info.key,
+                authority_pda(program_id, signer.key).0,
+                Unit09Error::InvalidPda
+            );
+            require!(authority_info.owner == program_id, Unit09Error::InvalidAccountOwner);
+
+            let authority =
+                Authority::try_deserialize(&mut &authority_info.try_borrow_data()?[..])?;
+            require!(authority.is_global_admin(now), Unit09Error::AuthorityRoleNotAllowed);
+            Ok(*signer.key)
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Admin Account Closing
+// ---------------------------------------------------------------------------
+
+/// Confirmation nonce an admin must supply to force-close `target`.
+///
+/// Derived from the first eight bytes of the target key (little-endian), so
+/// the operator has to compute it for the exact account they mean to close.
+pub fn admin_close_confirmation(target: &Pubkey) -> u64 {
+    let bytes = target.to_bytes();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(head)
+}
+
+/// Check that `target` may be force-closed by `admin_close_account`.
+///
+/// The account must be owned by `program_id`, must not be one of the
+/// deployment singletons, must match `expected_discriminator` when given,
+/// and `nonce` must equal `admin_close_confirmation(target)`.
+pub fn assert_admin_closable(
+    target: &Pubkey,
+    owner: &Pubkey,
+    data: &[u8],
+    expected_discriminator: Option<[u8; 8]>,
+    nonce: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    require!(owner == program_id, Unit09Error::InvalidAccountOwner);
+
+    let protected = [
+        config_pda(program_id).0,
+        lifecycle_pda(program_id).0,
+        metrics_pda(program_id).0,
+    ];
+    require!(!protected.contains(target), Unit09Error::ProtectedAccount);
+
+    if let Some(discriminator) = expected_discriminator {
+        require!(
+            data.len() >= 8 && data[..8] == discriminator,
+            Unit09Error::InvalidAccountDiscriminator
+        );
+    }
+
+    require!(
+        nonce == admin_close_confirmation(target),
+        Unit09Error::InvalidConfirmationNonce
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use crate::state::Repo;
+    use crate::utils::test_utils::{new_authority, new_config};
+
+    fn repo_data() -> Vec<u8> {
+        let mut data = vec![0u8; 64];
+        data[..8].copy_from_slice(&Repo::DISCRIMINATOR);
+        data
+    }
+
+    #[test]
+    fn program_owned_repo_can_be_closed() {
+        let program_id = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+
+        assert!(assert_admin_closable(
+            &target,
+            &program_id,
+            &repo_data(),
+            Some(Repo::DISCRIMINATOR),
+            admin_close_confirmation(&target),
+            &program_id,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn foreign_account_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+
+        assert!(assert_admin_closable(
+            &target,
+            &Pubkey::default(),
+            &repo_data(),
+            None,
+            admin_close_confirmation(&target),
+            &program_id,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn wrong_nonce_or_discriminator_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        let nonce = admin_close_confirmation(&target);
+
+        assert!(assert_admin_closable(
+            &target,
+            &program_id,
+            &repo_data(),
+            None,
+            nonce.wrapping_add(1),
+            &program_id,
+        )
+        .is_err());
+
+        assert!(assert_admin_closable(
+            &target,
+            &program_id,
+            &repo_data(),
+            Some([9u8; 8]),
+            nonce,
+            &program_id,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn singletons_are_protected() {
+        let program_id = Pubkey::new_unique();
+        let config = config_pda(&program_id).0;
+
+        assert!(assert_admin_closable(
+            &config,
+            &program_id,
+            &[],
+            None,
+            admin_close_confirmation(&config),
+            &program_id,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn remaining_accounts_above_cap_are_rejected() {
+        assert!(assert_remaining_accounts_within_cap(MAX_REMAINING_ACCOUNTS).is_ok());
+
+        let error = assert_remaining_accounts_within_cap(MAX_REMAINING_ACCOUNTS + 1).unwrap_err();
+        let code = match error {
+            Error::AnchorError(anchor_error) => anchor_error.error_code_number,
+            Error::ProgramError(_) => 0,
+        };
+        assert_eq!(code, u32::from(Unit09Error::TooManyRemainingAccounts));
+    }
+
+    /// Serialized `Authority` for `key`, holding the global admin role.
+    fn admin_authority_data(key: Pubkey) -> Vec<u8> {
+        let clock = Clock::default();
+        let mut authority = new_authority(key, true, Pubkey::default(), &clock);
+        authority.add_role(ROLE_LABEL_ADMIN, 0, &clock).unwrap();
+
+        let mut data = Vec::new();
+        authority.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn two_of_three_admins_meet_the_quorum() {
+        let program_id = Pubkey::new_unique();
+        let admins = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let clock = Clock::default();
+        let mut config = new_config(admins[0], &clock);
+        config.set_admin_threshold(2, &clock).unwrap();
+
+        // The second admin co-signs alongside its `Authority` PDA.
+        let authority_key = authority_pda(&program_id, &admins[1]).0;
+        let mut authority_data = admin_authority_data(admins[1]);
+        let (mut signer_lamports, mut authority_lamports) = (0, 0);
+        let mut signer_data: [u8; 0] = [];
+        let system_program = Pubkey::default();
+        let mut accounts = [
+            AccountInfo::new(
+                &admins[1],
+                true,
+                false,
+                &mut signer_lamports,
+                &mut signer_data,
+                &system_program,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &authority_key,
+                false,
+                false,
+                &mut authority_lamports,
+                &mut authority_data,
+                &program_id,
+                false,
+                0,
+            ),
+        ];
+
+        let co_signers = admin_co_signers(&program_id, &accounts, 0).unwrap();
+        assert_eq!(co_signers, vec![admins[1]]);
+        assert!(config.assert_admin_quorum(admins[0], &co_signers).is_ok());
+
+        // With the admin alone the quorum is not met.
+        let error = config.assert_admin_quorum(admins[0], &[]).unwrap_err();
+        let code = match error {
+            Error::AnchorError(anchor_error) => anchor_error.error_code_number,
+            Error::ProgramError(_) => 0,
+        };
+        assert_eq!(code, u32::from(Unit09Error::AdminQuorumNotMet));
+
+        // A co-signer that did not sign the transaction does not count.
+        accounts[0].is_signer = false;
+        assert!(admin_co_signers(&program_id, &accounts, 0).is_err());
+    }
+}
@@ -21,6 +21,7 @@
  * ============================================================================
  */
 
+import { createHash } from "crypto";
 import { PublicKey } from "@solana/web3.js";
 import type { Unit09ProgramClient } from "./provider";
 
@@ -52,6 +53,18 @@ export const SEED_LIFECYCLE = "lifecycle";
  */
 export const SEED_REPO = "repo";
 
+/**
+ * Seed prefix for RepoUrlIndex accounts.
+ * On-chain: `b"repo_url_index"`
+ */
+export const SEED_REPO_URL_INDEX = "repo_url_index";
+
+/**
+ * Seed prefix for per-(repo, observer) ObserverStats accounts.
+ * On-chain: `b"observer_stats"`
+ */
+export const SEED_OBSERVER_STATS = "observer_stats";
+
 /**
  * Seed prefix for Module accounts.
  * On-chain: `b"module"`
@@ -88,6 +101,18 @@ export const SEED_GLOBAL_METADATA = "global_metadata";
  */
 export const SEED_AUTHORITY = "authority";
 
+/**
+ * Seed prefix for the lamport-only fee vault.
+ * On-chain: `b"fee_vault"`
+ */
+export const SEED_FEE_VAULT = "fee_vault";
+
+/**
+ * Seed of the PDA that signs self-CPI events (`#[event_cpi]`).
+ * On-chain: `b"__event_authority"`
+ */
+export const SEED_EVENT_AUTHORITY = "__event_authority";
+
 // ============================================================================
 // Helper: version tuple to byte seeds
 // ============================================================================
@@ -173,6 +198,44 @@ export function getRepoPda(programId: PublicKey, repoKey: PublicKey): PublicKey
   return findRepoPda(programId, repoKey)[0];
 }
 
+/**
+ * Derive the RepoUrlIndex PDA claiming a repository URL.
+ * Seeds: `[b"repo_url_index", sha256(url)]`
+ */
+export function findRepoUrlIndexPda(programId: PublicKey, url: string): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_REPO_URL_INDEX), createHash("sha256").update(url).digest()],
+    programId
+  );
+}
+
+export function getRepoUrlIndexPda(programId: PublicKey, url: string): PublicKey {
+  return findRepoUrlIndexPda(programId, url)[0];
+}
+
+/**
+ * Derive the ObserverStats PDA of an observer on a repo.
+ * Seeds: `[b"observer_stats", repo_pda, observer]`
+ */
+export function findObserverStatsPda(
+  programId: PublicKey,
+  repo: PublicKey,
+  observer: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_OBSERVER_STATS), repo.toBuffer(), observer.toBuffer()],
+    programId
+  );
+}
+
+export function getObserverStatsPda(
+  programId: PublicKey,
+  repo: PublicKey,
+  observer: PublicKey
+): PublicKey {
+  return findObserverStatsPda(programId, repo, observer)[0];
+}
+
 /**
  * Derive the Module PDA.
  * Seeds: `[b"module", module_key]`
@@ -282,6 +345,30 @@ export function getGlobalMetadataPda(programId: PublicKey): PublicKey {
   return findGlobalMetadataPda(programId)[0];
 }
 
+/**
+ * Derive the fee vault PDA (default fee destination when no treasury is set).
+ * Seeds: `[b"fee_vault"]`
+ */
+export function findFeeVaultPda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from(SEED_FEE_VAULT)], programId);
+}
+
+export function getFeeVaultPda(programId: PublicKey): PublicKey {
+  return findFeeVaultPda(programId)[0];
+}
+
+/**
+ * Derive the event-authority PDA that signs self-CPI events.
+ * Seeds: `[b"__event_authority"]`
+ */
+export function findEventAuthorityPda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from(SEED_EVENT_AUTHORITY)], programId);
+}
+
+export function getEventAuthorityPda(programId: PublicKey): PublicKey {
+  return findEventAuthorityPda(programId)[0];
+}
+
 /**
  * Derive the Authority PDA.
  * Seeds: `[b"authority", authority_pubkey]`
@@ -332,6 +419,7 @@ export interface CorePdasOutput {
   metrics: PublicKey;
   lifecycle: PublicKey;
   globalMetadata: PublicKey;
+  feeVault: PublicKey;
   repo?: PublicKey;
   module?: PublicKey;
   moduleVersion?: PublicKey;
@@ -350,6 +438,7 @@ export function deriveAllCorePdas(input: CorePdasInput): CorePdasOutput {
   const metrics = getMetricsPda(programId);
   const lifecycle = getLifecyclePda(programId);
   const globalMetadata = getGlobalMetadataPda(programId);
+  const feeVault = getFeeVaultPda(programId);
 
   let repo: PublicKey | undefined;
   let module: PublicKey | undefined;
@@ -392,6 +481,7 @@ export function deriveAllCorePdas(input: CorePdasInput): CorePdasOutput {
     metrics,
     lifecycle,
     globalMetadata,
+    feeVault,
     repo,
     module,
     moduleVersion,
@@ -40,6 +40,7 @@ import type {
 import {
   buildInitializePdaAccounts,
   deriveAllCorePdasFromProgram,
+  getForkPda,
 } from "./accounts";
 
 // ============================================================================
@@ -163,12 +164,25 @@ export function buildTagLine(base?: string): string {
 // InitializeArgs builder
 // ============================================================================
 
+/**
+ * Numeric `LifecyclePhase` values as encoded on-chain.
+ */
+export const LIFECYCLE_PHASE = {
+  bootstrapping: 0,
+  operational: 1,
+  maintenance: 2,
+  frozen: 3,
+  migration: 4,
+  sunset: 5,
+} as const;
+
 export interface BuildInitializeArgsOptions {
   admin?: PublicKey;
   feeBps?: number;
   maxModulesPerRepo?: number;
   policyRef?: Uint8Array;
   lifecycleNoteRef?: Uint8Array;
+  initialLifecycleState?: number;
 }
 
 /**
@@ -182,6 +196,7 @@ export function buildInitializeArgs(opts: BuildInitializeArgsOptions = {}): Init
     maxModulesPerRepo: opts.maxModulesPerRepo ?? 128,
     policyRef: opts.policyRef ?? defaultBytes,
     lifecycleNoteRef: opts.lifecycleNoteRef ?? defaultBytes,
+    initialLifecycleState: opts.initialLifecycleState ?? LIFECYCLE_PHASE.bootstrapping,
   };
 }
 
@@ -195,6 +210,8 @@ export interface BuildRegisterRepoArgsOptions {
   url?: string;
   tags?: string;
   allowObservation?: boolean;
+  manifestUri?: string;
+  manifestChecksum?: Uint8Array;
 }
 
 /**
@@ -215,6 +232,8 @@ export function buildRegisterRepoArgs(
     url,
     tags,
     allowObservation,
+    manifestUri: opts.manifestUri ?? null,
+    manifestChecksum: opts.manifestChecksum ?? null,
   };
 }
 
@@ -224,6 +243,10 @@ export interface BuildUpdateRepoArgsOptions {
   tags?: string | null;
   isActive?: boolean | null;
   allowObservation?: boolean | null;
+  /** Require observations to carry a worker signature. */
+  requireSignedObservations?: boolean | null;
+  /** Last-read `repo.updatedAt`; the update fails if it has changed. */
+  expectedUpdatedAt?: bigint | null;
 }
 
 /**
@@ -240,6 +263,8 @@ export function buildUpdateRepoArgs(
     tags: opts.tags ?? null,
     isActive: opts.isActive ?? null,
     allowObservation: opts.allowObservation ?? null,
+    requireSignedObservations: opts.requireSignedObservations ?? null,
+    expectedUpdatedAt: opts.expectedUpdatedAt ?? null,
   };
 }
 
@@ -251,6 +276,7 @@ export interface BuildRegisterModuleArgsOptions {
   moduleKey?: PublicKey;
   name?: string;
   metadataUri?: string;
+  metadataChecksum?: Uint8Array | null;
   category?: string;
   tags?: string;
   version?: SemanticVersionTuple;
@@ -258,6 +284,8 @@ export interface BuildRegisterModuleArgsOptions {
   changelogUri?: string;
   isStable?: boolean;
   createInitialVersionSnapshot?: boolean;
+  parentModule?: PublicKey;
+  activeFrom?: bigint;
 }
 
 /**
@@ -280,6 +308,9 @@ export function buildRegisterModuleArgs(
     changelogUri: opts.changelogUri ?? buildChangelogUri(),
     isStable: opts.isStable ?? false,
     createInitialVersionSnapshot: opts.createInitialVersionSnapshot ?? true,
+    parentModule: (opts.parentModule ?? PublicKey.default).toBase58(),
+    activeFrom: opts.activeFrom ?? 0n,
+    metadataChecksum: opts.metadataChecksum ?? null,
   };
 }
 
@@ -294,6 +325,9 @@ export interface BuildUpdateModuleArgsOptions {
   versionLabel?: string | null;
   changelogUri?: string | null;
   isStable?: boolean | null;
+  /** Last-read `module.updatedAt`; the update fails if it has changed. */
+  expectedUpdatedAt?: bigint | null;
+  metadataChecksum?: Uint8Array | null;
 }
 
 /**
@@ -313,6 +347,8 @@ export function buildUpdateModuleArgs(
     versionLabel: opts.versionLabel ?? null,
     changelogUri: opts.changelogUri ?? null,
     isStable: opts.isStable ?? null,
+    expectedUpdatedAt: opts.expectedUpdatedAt ?? null,
+    metadataChecksum: opts.metadataChecksum ?? null,
   };
 }
 
@@ -328,6 +364,8 @@ export interface BuildCreateForkArgsOptions {
   tags?: string;
   isRoot?: boolean;
   depth?: number | null;
+  allowRetiredParent?: boolean;
+  expiresAt?: bigint | null;
 }
 
 /**
@@ -348,14 +386,28 @@ export function buildCreateForkArgs(
     tags: opts.tags ?? buildTagLine("unit09,fork,branch"),
     isRoot,
     depth,
+    allowRetiredParent: opts.allowRetiredParent ?? false,
+    expiresAt: opts.expiresAt ?? null,
   };
 }
 
+/**
+ * Numeric `ForkStatus` values as encoded on-chain.
+ *
+ * A fork is active exactly when its status is `released`.
+ */
+export const FORK_STATUS = {
+  draft: 0,
+  testing: 1,
+  released: 2,
+  retired: 3,
+} as const;
+
 export interface BuildUpdateForkStateArgsOptions {
   label?: string | null;
   metadataUri?: string | null;
   tags?: string | null;
-  isActive?: boolean | null;
+  status?: number | null;
 }
 
 /**
@@ -368,7 +420,7 @@ export function buildUpdateForkStateArgs(
     label: opts.label ?? null,
     metadataUri: opts.metadataUri ?? null,
     tags: opts.tags ?? null,
-    isActive: opts.isActive ?? null,
+    status: opts.status ?? null,
   };
 }
 
@@ -382,6 +434,10 @@ export interface BuildRecordObservationArgsOptions {
   modulesTouched?: number;
   revision?: string;
   note?: string;
+  moduleContributions?: [string, bigint][] | null;
+  backfill?: boolean;
+  /** Hash of the analyzed content; a repeat is recorded as unchanged. */
+  contentHash?: Uint8Array | null;
 }
 
 /**
@@ -396,6 +452,9 @@ export function buildRecordObservationArgs(
     modulesTouched: opts.modulesTouched ?? randInt(1, 12),
     revision: opts.revision ?? `rev-${randomSuffix(8)}`,
     note: opts.note ?? "Automated observation recorded during test.",
+    moduleContributions: opts.moduleContributions ?? null,
+    backfill: opts.backfill ?? false,
+    contentHash: opts.contentHash ?? null,
   };
 }
 
@@ -589,7 +648,10 @@ export async function createForkOnChain(
     .accounts({
       config: pda.config,
       lifecycle: pda.lifecycle,
+      metrics: pda.metrics,
       fork: pda.fork,
+      parentFork: opts.parent ? getForkPda(program.programId, opts.parent) : null,
+      feeDestination: pda.feeVault,
       owner,
       payer: ctx.wallet.publicKey,
       systemProgram: SystemProgram.programId,
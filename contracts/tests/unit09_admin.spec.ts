@@ -0,0 +1,209 @@
+/**
+ * ============================================================================
+ * Unit09 – Admin Integration Tests
+ * Path: contracts/unit09-program/tests/unit09_admin.spec.ts
+ *
+ * This file focuses on admin key management:
+ *   - Rotating `Config::admin` revokes the outgoing admin's admin role
+ *   - Granting and revoking the admin role require the admin quorum
+ *
+ * Every test leaves `Config::admin` as the test wallet and
+ * `Config::admin_threshold` at 1, so the other suites are unaffected.
+ *
+ * It relies on helpers from:
+ *   - tests/helpers/provider.ts
+ *   - tests/helpers/accounts.ts
+ *   - tests/helpers/builders.ts
+ *
+ * All content is written in English only.
+ * ============================================================================
+ */
+
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+import { createUnit09TestContext } from "./helpers/provider";
+import { deriveAllCorePdasFromProgram, getAuthorityPda } from "./helpers/accounts";
+import { initializeUnit09OnChain } from "./helpers/builders";
+
+// Increase timeout for CI or slow RPCs
+jest.setTimeout(120_000);
+
+// Shared test context
+const ctx = createUnit09TestContext();
+
+// On-chain `ROLE_LABEL_ADMIN`.
+const ROLE_ADMIN = "admin";
+
+type RemainingAccount = { pubkey: PublicKey; isWritable: boolean; isSigner: boolean };
+
+/**
+ * `(signer, authority)` remaining-account pair for an admin co-signer.
+ */
+function coSignerAccounts(coSigner: PublicKey): RemainingAccount[] {
+  return [
+    { pubkey: coSigner, isWritable: false, isSigner: true },
+    {
+      pubkey: getAuthorityPda(ctx.program.programId, coSigner),
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+}
+
+/**
+ * Grant the global admin role to `target`, signed by the test wallet.
+ */
+async function grantAdminRole(
+  target: PublicKey,
+  coSigners: Keypair[] = []
+): Promise<string> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program);
+
+  return program.methods
+    .grantRole({
+      role: ROLE_ADMIN,
+      expiresAt: 0,
+      isGlobal: true,
+      resourceScope: PublicKey.default,
+    } as any)
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+      lifecycle: pdas.lifecycle,
+      target,
+      authorityEntry: getAuthorityPda(program.programId, target),
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(coSigners.flatMap((signer) => coSignerAccounts(signer.publicKey)))
+    .signers(coSigners)
+    .rpc();
+}
+
+/**
+ * Revoke the admin role from `target`, signed by the test wallet.
+ */
+async function revokeAdminRole(target: PublicKey): Promise<string> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program);
+
+  return program.methods
+    .revokeRole({ role: ROLE_ADMIN })
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+      lifecycle: pdas.lifecycle,
+      authorityEntry: getAuthorityPda(program.programId, target),
+    })
+    .rpc();
+}
+
+/**
+ * Rotate `Config::admin` from `admin` to `newAdmin`.
+ */
+async function rotateAdmin(
+  admin: Keypair,
+  newAdmin: PublicKey,
+  adminThreshold: number | null = null,
+  coSigners: Keypair[] = []
+): Promise<string> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program);
+
+  return program.methods
+    .rotateAdmin({ newAdmin, adminThreshold })
+    .accounts({
+      admin: admin.publicKey,
+      config: pdas.config,
+      adminAuthority: getAuthorityPda(program.programId, admin.publicKey),
+    })
+    .remainingAccounts(coSigners.flatMap((signer) => coSignerAccounts(signer.publicKey)))
+    .signers([admin, ...coSigners])
+    .rpc();
+}
+
+/**
+ * Role labels currently stored on `key`'s `Authority` entry.
+ */
+async function fetchRoles(key: PublicKey): Promise<string[]> {
+  const program = ctx.program;
+  const authority = await program.account.authority.fetch(
+    getAuthorityPda(program.programId, key)
+  );
+  return (authority.roles as Array<{ role: string }>).map((entry) => entry.role);
+}
+
+describe("unit09_program – admin", () => {
+  beforeAll(async () => {
+    // Ensure payer is funded
+    await ctx.ensurePayerHasFunds(2 * 1_000_000_000); // 2 SOL
+
+    const program = ctx.program;
+    const { config } = deriveAllCorePdasFromProgram(program);
+
+    // Initialize program if needed
+    let needsInit = false;
+    try {
+      await program.account.config.fetch(config);
+    } catch {
+      needsInit = true;
+    }
+
+    if (needsInit) {
+      await initializeUnit09OnChain(ctx);
+    }
+  });
+
+  it("revokes the outgoing admin's admin role on rotation", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const nextAdmin = await ctx.createFundedKeypair(1);
+
+    await grantAdminRole(ctx.wallet.publicKey);
+    expect(await fetchRoles(ctx.wallet.publicKey)).toContain(ROLE_ADMIN);
+
+    await rotateAdmin(ctx.payer, nextAdmin.publicKey);
+
+    let config = await program.account.config.fetch(pdas.config);
+    expect(config.admin.toBase58()).toBe(nextAdmin.publicKey.toBase58());
+    expect(await fetchRoles(ctx.wallet.publicKey)).not.toContain(ROLE_ADMIN);
+
+    // `nextAdmin` was never granted a role, so it has no entry to revoke.
+    await rotateAdmin(nextAdmin, ctx.wallet.publicKey);
+
+    config = await program.account.config.fetch(pdas.config);
+    expect(config.admin.toBase58()).toBe(ctx.wallet.publicKey.toBase58());
+  });
+
+  it("keeps the admin role when rotating to the same key", async () => {
+    await grantAdminRole(ctx.wallet.publicKey);
+
+    await rotateAdmin(ctx.payer, ctx.wallet.publicKey);
+
+    expect(await fetchRoles(ctx.wallet.publicKey)).toContain(ROLE_ADMIN);
+  });
+
+  it("requires the admin quorum to grant or revoke the admin role", async () => {
+    const coSigner = await ctx.createFundedKeypair(1);
+    const candidate = Keypair.generate().publicKey;
+
+    await grantAdminRole(coSigner.publicKey);
+    await rotateAdmin(ctx.payer, ctx.wallet.publicKey, 2);
+
+    try {
+      await expect(grantAdminRole(candidate)).rejects.toThrow(/AdminQuorumNotMet/);
+      await expect(revokeAdminRole(coSigner.publicKey)).rejects.toThrow(
+        /AdminQuorumNotMet/
+      );
+
+      await grantAdminRole(candidate, [coSigner]);
+      expect(await fetchRoles(candidate)).toContain(ROLE_ADMIN);
+    } finally {
+      await rotateAdmin(ctx.payer, ctx.wallet.publicKey, 1, [coSigner]);
+    }
+
+    await revokeAdminRole(candidate);
+    await revokeAdminRole(coSigner.publicKey);
+    expect(await fetchRoles(coSigner.publicKey)).not.toContain(ROLE_ADMIN);
+  });
+});
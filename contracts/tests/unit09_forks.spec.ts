@@ -6,8 +6,10 @@
  * This file focuses on fork-related behavior:
  *   - Creating root and child forks
  *   - Preventing duplicate fork creation for the same fork key
- *   - Updating fork state (label, metadata, tags, active flag)
+ *   - Updating fork state (label, metadata, tags, readiness status)
  *   - Verifying lifecycle and metrics react to fork-level activity
+ *   - Charging and reporting the configured fork creation fee
+ *   - Delivering ForkCreated through a self-CPI when CPI events are enabled
  *
  * It relies on helpers from:
  *   - tests/helpers/provider.ts
@@ -20,15 +22,17 @@
  */
 
 import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
-import { BN } from "@coral-xyz/anchor";
+import { BN, BorshCoder, EventParser, utils } from "@coral-xyz/anchor";
 
 import { createUnit09TestContext } from "./helpers/provider";
 import {
   deriveAllCorePdasFromProgram,
+  getEventAuthorityPda,
   getForkPda,
 } from "./helpers/accounts";
 import {
   BuildInitializeArgsOptions,
+  FORK_STATUS,
   buildCreateForkArgs,
   buildUpdateForkStateArgs,
   createForkOnChain,
@@ -47,6 +51,118 @@ jest.setTimeout(120_000);
 // Shared test context
 const ctx = createUnit09TestContext();
 
+// Fork fee used by the fee tests. It must cover rent exemption for a
+// zero-data account so the first transfer into a fresh fee vault succeeds.
+const FORK_FEE_LAMPORTS = 1_000_000;
+
+/**
+ * Apply `fields` via `setConfig`, leaving every other field unchanged.
+ */
+async function setConfigFields(fields: Record<string, unknown>): Promise<void> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program);
+
+  await program.methods
+    .setConfig({
+      feeBps: null,
+      maxModulesPerRepo: null,
+      isActive: null,
+      deactivationGraceSeconds: null,
+      restrictRepoCreation: null,
+      policyRef: null,
+      treasury: null,
+      maxLocPerObservation: null,
+      maxFilesPerObservation: null,
+      forkFeeLamports: null,
+      defaultCategory: null,
+      defaultVisibility: null,
+      useCpiEvents: null,
+      blockParentForkDeactivation: null,
+      enforceUniqueUrl: null,
+      metadataFrozen: null,
+      ...fields,
+    } as any)
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+/**
+ * Set `Config::fork_fee_lamports`, leaving everything else unchanged.
+ */
+async function setForkFee(lamports: number): Promise<void> {
+  await setConfigFields({ forkFeeLamports: new BN(lamports) });
+}
+
+/**
+ * Set `Config::use_cpi_events`, leaving everything else unchanged.
+ */
+async function setCpiEvents(enabled: boolean): Promise<void> {
+  await setConfigFields({ useCpiEvents: enabled });
+}
+
+/**
+ * Return the decoded `ForkCreated` event emitted by transaction `tx`.
+ */
+async function fetchForkCreatedEvent(tx: string): Promise<any> {
+  const program = ctx.program;
+  const confirmed = await ctx.connection.getTransaction(tx, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const logs = confirmed?.meta?.logMessages ?? [];
+
+  const parser = new EventParser(program.programId, new BorshCoder(program.idl));
+  for (const event of parser.parseLogs(logs)) {
+    if (event.name === "ForkCreated" || event.name === "forkCreated") {
+      return event.data;
+    }
+  }
+  throw new Error(`ForkCreated event not found in ${tx}`);
+}
+
+/**
+ * Return the decoded `ForkCreated` event that transaction `tx` delivered
+ * through a self-CPI (inner instruction signed by the event authority).
+ */
+async function fetchForkCreatedCpiEvent(tx: string): Promise<any> {
+  const program = ctx.program;
+  const confirmed = await ctx.connection.getTransaction(tx, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  if (!confirmed?.meta) {
+    throw new Error(`Transaction ${tx} not found`);
+  }
+
+  const keys = confirmed.transaction.message.getAccountKeys({
+    accountKeysFromLookups: confirmed.meta.loadedAddresses,
+  });
+  const eventAuthority = getEventAuthorityPda(program.programId);
+
+  for (const inner of confirmed.meta.innerInstructions ?? []) {
+    for (const ix of inner.instructions) {
+      const programId = keys.get(ix.programIdIndex);
+      const signer = keys.get(ix.accounts[0]);
+      if (!programId?.equals(program.programId) || !signer?.equals(eventAuthority)) {
+        continue;
+      }
+
+      // Skip Anchor's 8-byte event instruction tag; the rest is the same
+      // discriminator + Borsh payload that `emit!` would have logged.
+      const data = Buffer.from(utils.bytes.bs58.decode(ix.data)).subarray(8);
+      const event = program.coder.events.decode(data.toString("base64"));
+      if (event && (event.name === "ForkCreated" || event.name === "forkCreated")) {
+        return event.data;
+      }
+    }
+  }
+  throw new Error(`ForkCreated CPI event not found in ${tx}`);
+}
+
 describe("unit09_program – forks", () => {
   const initOptions: BuildInitializeArgsOptions = {
     feeBps: 250,
@@ -110,7 +226,9 @@ describe("unit09_program – forks", () => {
       .accounts({
         config: pdas.config,
         lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
         fork: pdas.fork,
+        feeDestination: pdas.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -127,10 +245,11 @@ describe("unit09_program – forks", () => {
       { pubkey: pdas.fork, data: forkAcc },
       {
         label: args.label,
-        isActive: true,
+        isActive: false, // new forks start as drafts
         depth: args.depth ?? undefined,
       }
     );
+    expect(forkAcc.status).toEqual(FORK_STATUS.draft);
 
     expect(forkAcc.isRoot).toBe(true);
     expect(forkAcc.parent).toBeNull();
@@ -151,12 +270,12 @@ describe("unit09_program – forks", () => {
   it("creates a child fork that references a parent and increments depth", async () => {
     const program = ctx.program;
 
-    const parentForkKey = canonicalRootForkKey;
+    const parentFork = getForkPda(program.programId, canonicalRootForkKey);
     const childForkKey = Keypair.generate().publicKey;
 
     const args = buildCreateForkArgs({
       forkKey: childForkKey,
-      parent: parentForkKey,
+      parent: parentFork,
       label: "unit09-child-fork",
       metadataUri: "https://unit09.org/meta/fork/child.json",
       tags: "unit09,fork,child",
@@ -171,7 +290,10 @@ describe("unit09_program – forks", () => {
       .accounts({
         config: pdas.config,
         lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
         fork: pdas.fork,
+        parentFork,
+        feeDestination: pdas.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -187,12 +309,12 @@ describe("unit09_program – forks", () => {
       {
         label: args.label,
         depth: args.depth ?? undefined,
-        isActive: true,
+        isActive: false,
       }
     );
 
     expect(forkAcc.isRoot).toBe(false);
-    expect(forkAcc.parent).toEqual(parentForkKey.toBase58());
+    expect(forkAcc.parent).toEqual(parentFork.toBase58());
   });
 
   it("stores and retrieves the canonical root fork created in setup", async () => {
@@ -206,7 +328,7 @@ describe("unit09_program – forks", () => {
       { pubkey: forkPda, data: forkAcc },
       {
         label: "unit09-root-fork",
-        isActive: true,
+        isActive: false,
         depth: 0,
       }
     );
@@ -235,7 +357,9 @@ describe("unit09_program – forks", () => {
       .accounts({
         config: pdas.config,
         lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
         fork: pdas.fork,
+        feeDestination: pdas.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -259,6 +383,7 @@ describe("unit09_program – forks", () => {
           config: pdas.config,
           lifecycle: pdas.lifecycle,
           fork: pdas.fork,
+          feeDestination: pdas.feeVault,
           owner: ctx.wallet.publicKey,
           payer: ctx.wallet.publicKey,
           systemProgram: SystemProgram.programId,
@@ -287,7 +412,9 @@ describe("unit09_program – forks", () => {
       .accounts({
         config: pdas.config,
         lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
         fork: pdas.fork,
+        feeDestination: pdas.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -298,7 +425,7 @@ describe("unit09_program – forks", () => {
       label: "unit09-fork-updated",
       metadataUri: "https://unit09.org/meta/fork/updated.json",
       tags: "unit09,fork,updated",
-      isActive: false,
+      status: FORK_STATUS.testing,
     });
 
     const tx = await program.methods
@@ -318,14 +445,72 @@ describe("unit09_program – forks", () => {
       { pubkey: pdas.fork, data: forkAcc },
       {
         label: updateArgs.label ?? undefined,
-        isActive: updateArgs.isActive ?? undefined,
+        isActive: false,
       }
     );
 
+    expect(forkAcc.status).toEqual(FORK_STATUS.testing);
     expect(forkAcc.metadataUri).toEqual(updateArgs.metadataUri);
     expect(forkAcc.tags).toEqual(updateArgs.tags);
   });
 
+  it("walks a fork through its readiness lifecycle and rejects reopening", async () => {
+    const program = ctx.program;
+
+    const forkKey = Keypair.generate().publicKey;
+    const createArgs = buildCreateForkArgs({
+      forkKey,
+      label: "unit09-fork-status",
+      isRoot: true,
+      depth: 0,
+    });
+
+    const pdas = deriveAllCorePdasFromProgram(program, { forkKey });
+
+    await program.methods
+      .createFork(createArgs)
+      .accounts({
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        fork: pdas.fork,
+        feeDestination: pdas.feeVault,
+        owner: ctx.wallet.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const setStatus = (status: number) =>
+      program.methods
+        .updateForkState(buildUpdateForkStateArgs({ status }))
+        .accounts({
+          config: pdas.config,
+          fork: pdas.fork,
+          owner: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    // Draft cannot jump straight to released
+    await expect(setStatus(FORK_STATUS.released)).rejects.toThrow();
+
+    await setStatus(FORK_STATUS.testing);
+    await setStatus(FORK_STATUS.released);
+
+    let forkAcc = await program.account.fork.fetch(pdas.fork);
+    expect(forkAcc.status).toEqual(FORK_STATUS.released);
+    expect(forkAcc.isActive).toBe(true);
+
+    await setStatus(FORK_STATUS.retired);
+
+    forkAcc = await program.account.fork.fetch(pdas.fork);
+    expect(forkAcc.status).toEqual(FORK_STATUS.retired);
+    expect(forkAcc.isActive).toBe(false);
+
+    // Retired forks cannot be reopened
+    await expect(setStatus(FORK_STATUS.draft)).rejects.toThrow();
+  });
+
   it("supports partial fork updates where null fields are ignored", async () => {
     const program = ctx.program;
 
@@ -350,7 +535,9 @@ describe("unit09_program – forks", () => {
       .accounts({
         config: pdas.config,
         lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
         fork: pdas.fork,
+        feeDestination: pdas.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -360,9 +547,9 @@ describe("unit09_program – forks", () => {
     const updateArgs = buildUpdateForkStateArgs({
       label: null,
       metadataUri: null,
-      // Only change tags and active flag
+      // Only change tags and status
       tags: "unit09,fork,partial-updated",
-      isActive: true,
+      status: FORK_STATUS.testing,
     });
 
     await program.methods
@@ -381,7 +568,7 @@ describe("unit09_program – forks", () => {
       { pubkey: pdas.fork, data: forkAcc },
       {
         label: initialLabel,
-        isActive: true,
+        isActive: false,
       }
     );
 
@@ -412,6 +599,7 @@ describe("unit09_program – forks", () => {
         config: pdasAfter.config,
         lifecycle: pdasAfter.lifecycle,
         fork: pdasAfter.fork,
+        feeDestination: pdasAfter.feeVault,
         owner: ctx.wallet.publicKey,
         payer: ctx.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -460,6 +648,7 @@ describe("unit09_program – forks", () => {
           config: pdas.config,
           lifecycle: pdas.lifecycle,
           fork: pdas.fork,
+          feeDestination: pdas.feeVault,
           owner: ctx.wallet.publicKey,
           payer: ctx.wallet.publicKey,
           systemProgram: SystemProgram.programId,
@@ -480,6 +669,86 @@ describe("unit09_program – forks", () => {
     );
   });
 
+  it("charges the configured fork fee and reports it in ForkCreated", async () => {
+    const program = ctx.program;
+    const { feeVault } = deriveAllCorePdasFromProgram(program);
+
+    await setForkFee(FORK_FEE_LAMPORTS);
+    try {
+      const config = await program.account.config.fetch(
+        deriveAllCorePdasFromProgram(program).config
+      );
+      expect(config.forkFeeLamports.toNumber()).toBe(FORK_FEE_LAMPORTS);
+
+      const vaultBefore = await ctx.connection.getBalance(feeVault, "confirmed");
+
+      const { tx } = await createForkOnChain(ctx, {
+        label: "unit09-fork-paid",
+        isRoot: true,
+        depth: 0,
+        tags: "unit09,fork,fee",
+      });
+
+      const vaultAfter = await ctx.connection.getBalance(feeVault, "confirmed");
+      expect(vaultAfter - vaultBefore).toBe(FORK_FEE_LAMPORTS);
+
+      const event = await fetchForkCreatedEvent(tx);
+      expect(new BN(event.feeLamports).toNumber()).toBe(FORK_FEE_LAMPORTS);
+    } finally {
+      await setForkFee(0);
+    }
+  });
+
+  it("moves no lamports when the fork fee is zero", async () => {
+    const program = ctx.program;
+    const { feeVault } = deriveAllCorePdasFromProgram(program);
+
+    await setForkFee(0);
+
+    const vaultBefore = await ctx.connection.getBalance(feeVault, "confirmed");
+
+    const { tx } = await createForkOnChain(ctx, {
+      label: "unit09-fork-free",
+      isRoot: true,
+      depth: 0,
+      tags: "unit09,fork,free",
+    });
+
+    const vaultAfter = await ctx.connection.getBalance(feeVault, "confirmed");
+    expect(vaultAfter).toBe(vaultBefore);
+
+    const event = await fetchForkCreatedEvent(tx);
+    expect(new BN(event.feeLamports).toNumber()).toBe(0);
+  });
+
+  it("delivers ForkCreated through a self-CPI when CPI events are enabled", async () => {
+    const program = ctx.program;
+
+    await setCpiEvents(true);
+    try {
+      const config = await program.account.config.fetch(
+        deriveAllCorePdasFromProgram(program).config
+      );
+      expect(config.useCpiEvents).toBe(true);
+
+      const { tx } = await createForkOnChain(ctx, {
+        label: "unit09-fork-cpi-event",
+        isRoot: true,
+        depth: 0,
+        tags: "unit09,fork,cpi",
+      });
+
+      const event = await fetchForkCreatedCpiEvent(tx);
+      expect(event.label).toBe("unit09-fork-cpi-event");
+      expect(new BN(event.feeLamports).toNumber()).toBe(0);
+
+      // The event is no longer written to the program logs.
+      await expect(fetchForkCreatedEvent(tx)).rejects.toThrow();
+    } finally {
+      await setCpiEvents(false);
+    }
+  });
+
   it("exposes the creation transaction for the canonical root fork", () => {
     expect(canonicalRootForkTx).toBeTruthy();
     if (canonicalRootForkTx) {
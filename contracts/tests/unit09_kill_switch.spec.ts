@@ -0,0 +1,224 @@
+/**
+ * ============================================================================
+ * Unit09 – Kill Switch Integration Tests
+ * Path: contracts/unit09-program/tests/unit09_kill_switch.spec.ts
+ *
+ * This file focuses on the global kill switch:
+ *   - Non-admin instructions fail with `KillSwitchEngaged` while it is on
+ *   - Admin instructions, such as `rotateAdmin`, keep working
+ *   - Disengaging restores normal operation
+ *
+ * The kill switch is global `Config` state, so every test disengages it
+ * again before finishing.
+ *
+ * It relies on helpers from:
+ *   - tests/helpers/provider.ts
+ *   - tests/helpers/accounts.ts
+ *   - tests/helpers/builders.ts
+ *
+ * All content is written in English only.
+ * ============================================================================
+ */
+
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+
+import { createUnit09TestContext } from "./helpers/provider";
+import {
+  deriveAllCorePdasFromProgram,
+  getAuthorityPda,
+  getObserverStatsPda,
+} from "./helpers/accounts";
+import {
+  buildRecordObservationArgs,
+  buildUpdateRepoArgs,
+  createForkOnChain,
+  createModuleOnChain,
+  createRepoOnChain,
+  initializeUnit09OnChain,
+} from "./helpers/builders";
+
+// Increase timeout for CI or slow RPCs
+jest.setTimeout(120_000);
+
+// Shared test context
+const ctx = createUnit09TestContext();
+
+/**
+ * Engage the kill switch, signed by the test wallet as admin.
+ */
+async function engageKillSwitch(): Promise<string> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+
+  return ctx.program.methods
+    .engageKillSwitch()
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+    })
+    .rpc();
+}
+
+/**
+ * Disengage the kill switch, signed by the test wallet as admin.
+ */
+async function disengageKillSwitch(): Promise<string> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+
+  return ctx.program.methods
+    .disengageKillSwitch()
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+    })
+    .rpc();
+}
+
+/**
+ * Whether `Config::global_kill` is currently set.
+ */
+async function isKilled(): Promise<boolean> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+  const config = await ctx.program.account.config.fetch(pdas.config);
+  return config.globalKill as boolean;
+}
+
+describe("unit09_program – kill switch", () => {
+  // Repo registered before the kill switch is engaged.
+  let repoKey: PublicKey;
+
+  beforeAll(async () => {
+    // Ensure payer is funded
+    await ctx.ensurePayerHasFunds(2 * 1_000_000_000); // 2 SOL
+
+    const program = ctx.program;
+    const { config } = deriveAllCorePdasFromProgram(program);
+
+    // Initialize program if needed
+    let needsInit = false;
+    try {
+      await program.account.config.fetch(config);
+    } catch {
+      needsInit = true;
+    }
+
+    if (needsInit) {
+      await initializeUnit09OnChain(ctx);
+    }
+
+    ({ repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-kill-switch",
+      allowObservation: true,
+    }));
+  });
+
+  afterAll(async () => {
+    // Never leave the deployment killed for the other suites.
+    if (await isKilled()) {
+      await disengageKillSwitch();
+    }
+  });
+
+  it("rejects non-admin instructions while engaged", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await engageKillSwitch();
+    expect(await isKilled()).toBe(true);
+
+    try {
+      await expect(
+        createRepoOnChain(ctx, { name: "unit09-repo-while-killed" })
+      ).rejects.toThrow(/KillSwitchEngaged/);
+
+      await expect(
+        program.methods
+          .updateRepo(buildUpdateRepoArgs({ tags: "unit09,killed" }))
+          .accounts({
+            config: pdas.config,
+            repo: pdas.repo,
+            authority: ctx.wallet.publicKey,
+          })
+          .rpc()
+      ).rejects.toThrow(/KillSwitchEngaged/);
+
+      await expect(
+        program.methods
+          .recordObservation(buildRecordObservationArgs({ linesOfCode: BigInt(100) }))
+          .accounts({
+            config: pdas.config,
+            metrics: pdas.metrics,
+            repo: pdas.repo,
+            lifecycle: pdas.lifecycle,
+            observer: ctx.wallet.publicKey,
+            observerStats: getObserverStatsPda(
+              program.programId,
+              pdas.repo!,
+              ctx.wallet.publicKey
+            ),
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc()
+      ).rejects.toThrow(/KillSwitchEngaged/);
+
+      await expect(
+        createModuleOnChain(ctx, { repoKey, name: "unit09-module-while-killed" })
+      ).rejects.toThrow(/KillSwitchEngaged/);
+
+      await expect(
+        createForkOnChain(ctx, { label: "unit09-fork-while-killed", isRoot: true })
+      ).rejects.toThrow(/KillSwitchEngaged/);
+
+      // Nothing was written to the repo.
+      const repo = await program.account.repo.fetch(pdas.repo!);
+      expect(repo.observationCount.toNumber()).toBe(0);
+      expect(repo.moduleCount).toBe(0);
+    } finally {
+      await disengageKillSwitch();
+    }
+  });
+
+  it("still lets the admin rotate while engaged", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    await engageKillSwitch();
+
+    try {
+      const tx = await program.methods
+        .rotateAdmin({ newAdmin: ctx.wallet.publicKey, adminThreshold: null })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          adminAuthority: getAuthorityPda(program.programId, ctx.wallet.publicKey),
+        })
+        .rpc();
+      expect(tx).toBeTruthy();
+
+      const config = await program.account.config.fetch(pdas.config);
+      expect(config.admin.toBase58()).toBe(ctx.wallet.publicKey.toBase58());
+      expect(config.globalKill).toBe(true);
+    } finally {
+      await disengageKillSwitch();
+    }
+  });
+
+  it("accepts non-admin instructions again once disengaged", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    expect(await isKilled()).toBe(false);
+    await expect(disengageKillSwitch()).rejects.toThrow(/KillSwitchNotEngaged/);
+
+    await program.methods
+      .updateRepo(buildUpdateRepoArgs({ tags: "unit09,revived" }))
+      .accounts({
+        config: pdas.config,
+        repo: pdas.repo,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    const repo = await program.account.repo.fetch(pdas.repo!);
+    expect(repo.tags).toBe("unit09,revived");
+  });
+});
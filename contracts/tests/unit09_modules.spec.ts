@@ -7,8 +7,10 @@
  *   - Registering a new module attached to a repo
  *   - Ensuring initial ModuleVersion snapshot is created
  *   - Updating module metadata and version (with optional snapshot)
+ *   - Storing update snapshots at the expected ModuleVersion PDA
  *   - Preventing duplicate registration for the same module key
  *   - Verifying metrics counters react to module-level activity
+ *   - Linking modules in bulk even when a link PDA was pre-funded
  *
  * It relies on helpers from:
  *   - tests/helpers/provider.ts
@@ -20,7 +22,13 @@
  * ============================================================================
  */
 
-import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
+import {
+  SystemProgram,
+  PublicKey,
+  Keypair,
+  SYSVAR_CLOCK_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
 import { createUnit09TestContext } from "./helpers/provider";
@@ -29,6 +37,7 @@ import {
   getRepoPda,
   getModulePda,
   getModuleVersionPda,
+  getModuleRepoLinkPda,
 } from "./helpers/accounts";
 import {
   BuildInitializeArgsOptions,
@@ -428,6 +437,72 @@ describe("unit09_program – modules", () => {
     );
   });
 
+  it("stores an update snapshot at the module_version PDA of the new version", async () => {
+    const program = ctx.program;
+
+    const repoKey = canonicalRepoKey;
+    const moduleKey = Keypair.generate().publicKey;
+
+    const initialVersion = buildSemanticVersion({ major: 1, minor: 0, patch: 0 });
+    const registerArgs = buildRegisterModuleArgs({
+      moduleKey,
+      name: "unit09-module-snapshot-pda",
+      version: initialVersion,
+    });
+
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+
+    await program.methods
+      .registerModule(registerArgs)
+      .accounts({
+        config: pdas.config,
+        repo: pdas.repo,
+        module: pdas.module,
+        authority: ctx.wallet.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const nextVersion = bumpSemanticVersion(initialVersion, "patch");
+    const updateArgs = buildUpdateModuleArgs({
+      newVersion: nextVersion,
+      createVersionSnapshot: true,
+      isStable: true,
+    });
+
+    // Seeds: [b"module_version", module PDA, major, minor, patch (u16 LE)],
+    // the same layout as `module_version_pda` in `utils/seeds.rs`.
+    const versionPda = getModuleVersionPda(
+      program.programId,
+      pdas.module,
+      nextVersion[0],
+      nextVersion[1],
+      nextVersion[2]
+    );
+
+    await program.methods
+      .updateModule(updateArgs)
+      .accounts({
+        config: pdas.config,
+        module: pdas.module,
+        moduleVersion: versionPda,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    const versionAcc = await program.account.moduleVersion.fetch(versionPda);
+
+    expect(versionAcc.module.toBase58()).toBe(pdas.module.toBase58());
+    assertModuleVersion(
+      { pubkey: versionPda, data: versionAcc },
+      {
+        version: nextVersion,
+        isStable: true,
+      }
+    );
+  });
+
   it("supports partial updates (null fields mean no change)", async () => {
     const program = ctx.program;
 
@@ -499,7 +574,7 @@ describe("unit09_program – modules", () => {
         isActive: true,
       }
     );
-    expect(moduleAcc.tags).toContain("partial-updated");
+    expect(moduleAcc.tagsVec).toContain("partial-updated");
   });
 
   it("keeps metrics consistent with module registrations", async () => {
@@ -556,6 +631,63 @@ describe("unit09_program – modules", () => {
     );
   });
 
+  it("links a module in bulk when its link PDA was pre-funded", async () => {
+    const program = ctx.program;
+    const programId = program.programId;
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey: canonicalRepoKey });
+
+    const { moduleKey } = await createModuleOnChain(ctx, {
+      repoKey: canonicalRepoKey,
+      name: "unit09-prefunded-link",
+      version: buildSemanticVersion({ major: 0, minor: 1, patch: 0 }),
+      category: "unit09-core",
+      tags: "unit09,module",
+      isStable: false,
+    });
+    const modulePda = getModulePda(programId, moduleKey);
+    const linkPda = getModuleRepoLinkPda(programId, modulePda, pdas.repo!);
+
+    // Anyone can fund the address first; that must not block the link.
+    const prefund = 1_000_000;
+    await ctx.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: ctx.wallet.publicKey,
+          toPubkey: linkPda,
+          lamports: prefund,
+        })
+      )
+    );
+
+    await program.methods
+      .linkModulesToRepo({
+        links: [{ isPrimary: false, notes: "pre-funded", expiresAt: null }],
+      })
+      .accounts({
+        payer: ctx.wallet.publicKey,
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        repo: pdas.repo,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .remainingAccounts([
+        { pubkey: modulePda, isWritable: false, isSigner: false },
+        { pubkey: linkPda, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    const link = await program.account.moduleRepoLink.fetch(linkPda);
+    expect((link.module as PublicKey).toBase58()).toBe(modulePda.toBase58());
+    expect((link.repo as PublicKey).toBase58()).toBe(pdas.repo!.toBase58());
+
+    const info = await ctx.connection.getAccountInfo(linkPda);
+    expect(info!.owner.toBase58()).toBe(programId.toBase58());
+    const rentExempt = await ctx.connection.getMinimumBalanceForRentExemption(info!.data.length);
+    expect(info!.lamports).toBeGreaterThanOrEqual(rentExempt);
+  });
+
   it("can still fetch repo state for modules repo without corruption", async () => {
     const program = ctx.program;
     const programId = program.programId;
@@ -0,0 +1,154 @@
+/**
+ * ============================================================================
+ * Unit09 – Pause Integration Tests
+ * Path: contracts/unit09-program/tests/unit09_pause.spec.ts
+ *
+ * This file focuses on pausing the deployment:
+ *   - Write instructions fail with `DeploymentPaused` while it is paused,
+ *     including those outside the register/update/observe paths
+ *   - Unpausing restores normal operation
+ *
+ * The pause is global `Config` state, so every test unpauses again before
+ * finishing.
+ *
+ * It relies on helpers from:
+ *   - tests/helpers/provider.ts
+ *   - tests/helpers/accounts.ts
+ *   - tests/helpers/builders.ts
+ *
+ * All content is written in English only.
+ * ============================================================================
+ */
+
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import { createUnit09TestContext } from "./helpers/provider";
+import { deriveAllCorePdasFromProgram } from "./helpers/accounts";
+import { createRepoOnChain, initializeUnit09OnChain } from "./helpers/builders";
+
+// Increase timeout for CI or slow RPCs
+jest.setTimeout(120_000);
+
+// Shared test context
+const ctx = createUnit09TestContext();
+
+/**
+ * Pause the deployment until `unpause`, signed by the test wallet as admin.
+ */
+async function pause(): Promise<string> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+
+  return ctx.program.methods
+    .pause({ reason: "unit09 pause test", autoUnpauseAt: 0 } as any)
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+    })
+    .rpc();
+}
+
+/**
+ * Unpause the deployment, signed by the test wallet as admin.
+ */
+async function unpause(): Promise<string> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+
+  return ctx.program.methods
+    .unpause()
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+    })
+    .rpc();
+}
+
+/**
+ * Whether `Config::paused` is currently set.
+ */
+async function isPaused(): Promise<boolean> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program);
+  const config = await ctx.program.account.config.fetch(pdas.config);
+  return config.paused as boolean;
+}
+
+/**
+ * Propose `newAuthority` as the next authority of `repoKey`.
+ */
+async function initiateRepoTransfer(
+  repoKey: PublicKey,
+  newAuthority: PublicKey
+): Promise<string> {
+  const pdas = deriveAllCorePdasFromProgram(ctx.program, { repoKey });
+
+  return ctx.program.methods
+    .initiateRepoTransfer({ newAuthority })
+    .accounts({
+      authority: ctx.wallet.publicKey,
+      config: pdas.config,
+      lifecycle: pdas.lifecycle,
+      repo: pdas.repo,
+    })
+    .rpc();
+}
+
+describe("unit09_program – pause", () => {
+  // Repo registered before the deployment is paused.
+  let repoKey: PublicKey;
+
+  beforeAll(async () => {
+    // Ensure payer is funded
+    await ctx.ensurePayerHasFunds(2 * 1_000_000_000); // 2 SOL
+
+    const program = ctx.program;
+    const { config } = deriveAllCorePdasFromProgram(program);
+
+    // Initialize program if needed
+    let needsInit = false;
+    try {
+      await program.account.config.fetch(config);
+    } catch {
+      needsInit = true;
+    }
+
+    if (needsInit) {
+      await initializeUnit09OnChain(ctx);
+    }
+
+    ({ repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-pause",
+    }));
+  });
+
+  afterAll(async () => {
+    // Never leave the deployment paused for the other suites.
+    if (await isPaused()) {
+      await unpause();
+    }
+  });
+
+  it("rejects a repo transfer while paused", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const newAuthority = Keypair.generate().publicKey;
+
+    await pause();
+    expect(await isPaused()).toBe(true);
+
+    try {
+      await expect(initiateRepoTransfer(repoKey, newAuthority)).rejects.toThrow(
+        /DeploymentPaused/
+      );
+
+      // Nothing was written to the repo.
+      const repo = await program.account.repo.fetch(pdas.repo!);
+      expect((repo.pendingAuthority as PublicKey).equals(PublicKey.default)).toBe(true);
+    } finally {
+      await unpause();
+    }
+
+    await initiateRepoTransfer(repoKey, newAuthority);
+
+    const repo = await program.account.repo.fetch(pdas.repo!);
+    expect((repo.pendingAuthority as PublicKey).toBase58()).toBe(newAuthority.toBase58());
+  });
+});
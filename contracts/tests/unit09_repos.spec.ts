@@ -9,6 +9,9 @@
  *   - Updating repo metadata and observation flags
  *   - Recording observations against a repo
  *   - Verifying metrics counters react to repo-level activity
+ *   - Closing a repo and refunding its rent to an explicit treasury
+ *   - Closing a repo together with its observer stats
+ *   - Rejecting more remaining accounts than `MAX_REMAINING_ACCOUNTS`
  *
  * It relies on helpers from:
  *   - tests/helpers/provider.ts
@@ -26,7 +29,9 @@ import { BN } from "@coral-xyz/anchor";
 import { createUnit09TestContext } from "./helpers/provider";
 import {
   deriveAllCorePdasFromProgram,
+  getObserverStatsPda,
   getRepoPda,
+  getRepoUrlIndexPda,
 } from "./helpers/accounts";
 import {
   buildInitializeArgs,
@@ -50,6 +55,96 @@ jest.setTimeout(120_000);
 // Shared test context
 const ctx = createUnit09TestContext();
 
+/**
+ * Set `Config::enforce_unique_url`, leaving every other field unchanged.
+ */
+async function setEnforceUniqueUrl(enabled: boolean): Promise<void> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program);
+
+  await program.methods
+    .setConfig({
+      feeBps: null,
+      maxModulesPerRepo: null,
+      isActive: null,
+      deactivationGraceSeconds: null,
+      restrictRepoCreation: null,
+      policyRef: null,
+      treasury: null,
+      maxLocPerObservation: null,
+      maxFilesPerObservation: null,
+      forkFeeLamports: null,
+      defaultCategory: null,
+      defaultVisibility: null,
+      useCpiEvents: null,
+      blockParentForkDeactivation: null,
+      enforceUniqueUrl: enabled,
+      metadataFrozen: null,
+      renameCooldownSecs: null,
+      requireVerifiedModulesForFork: null,
+      maxTotalRepos: null,
+      maxTotalModules: null,
+      maxTotalForks: null,
+      observationRewardLamports: null,
+      restrictForkCreation: null,
+      enforceContentAddressedModules: null,
+    } as any)
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pdas.config,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+/**
+ * Register a repo at `url`, claiming the URL in its `RepoUrlIndex`.
+ */
+async function registerRepoWithUrlIndex(url: string): Promise<PublicKey> {
+  const program = ctx.program;
+  const repoKey = Keypair.generate().publicKey;
+  const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+  await program.methods
+    .registerRepo(buildRegisterRepoArgs({ repoKey, url }))
+    .accounts({
+      config: pdas.config,
+      repo: pdas.repo,
+      authority: ctx.wallet.publicKey,
+      payer: ctx.wallet.publicKey,
+      urlIndex: getRepoUrlIndexPda(program.programId, url),
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return repoKey;
+}
+
+/**
+ * Change the URL of `repoKey` from `oldUrl` to `newUrl`, passing the index
+ * accounts of both URLs unless `withIndexes` is false.
+ */
+async function updateRepoUrl(
+  repoKey: PublicKey,
+  oldUrl: string,
+  newUrl: string,
+  withIndexes = true
+): Promise<string> {
+  const program = ctx.program;
+  const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+  return program.methods
+    .updateRepo(buildUpdateRepoArgs({ url: newUrl }))
+    .accounts({
+      config: pdas.config,
+      repo: pdas.repo,
+      authority: ctx.wallet.publicKey,
+      oldUrlIndex: withIndexes ? getRepoUrlIndexPda(program.programId, oldUrl) : null,
+      newUrlIndex: withIndexes ? getRepoUrlIndexPda(program.programId, newUrl) : null,
+    })
+    .rpc();
+}
+
 describe("unit09_program – repos", () => {
   const initOptions: BuildInitializeArgsOptions = {
     feeBps: 250,
@@ -324,6 +419,12 @@ describe("unit09_program – repos", () => {
     const pdasBefore = deriveAllCorePdasFromProgram(program, { repoKey });
 
     const metricsBefore = await program.account.metrics.fetch(pdasBefore.metrics);
+    const observerStats = getObserverStatsPda(
+      programId,
+      pdasBefore.repo,
+      ctx.wallet.publicKey
+    );
+    const statsBefore = await program.account.observerStats.fetchNullable(observerStats);
 
     const observationArgs = buildRecordObservationArgs({
       // Use explicit values for predictable assertions
@@ -342,12 +443,20 @@ describe("unit09_program – repos", () => {
         repo: pdasBefore.repo,
         lifecycle: pdasBefore.lifecycle,
         observer: ctx.wallet.publicKey,
+        observerStats,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
     expect(tx).toBeTruthy();
 
+    // The observer's own tallies grow by exactly this run.
+    const statsAfter = await program.account.observerStats.fetch(observerStats);
+    const countBefore = statsBefore ? statsBefore.observationCount.toNumber() : 0;
+    const locBefore = statsBefore ? statsBefore.totalLinesOfCode.toNumber() : 0;
+    expect(statsAfter.observationCount.toNumber()).toBe(countBefore + 1);
+    expect(statsAfter.totalLinesOfCode.toNumber()).toBe(locBefore + 1234);
+
     const metricsAfter = await program.account.metrics.fetch(pdasBefore.metrics);
 
     // Metrics should have increased by at least the specified values.
@@ -421,6 +530,209 @@ describe("unit09_program – repos", () => {
     expect(repoAAcc.createdAt).not.toEqual(repoBAcc.createdAt);
   });
 
+  it("moves the URL claim when a repo changes its URL under unique URLs", async () => {
+    const program = ctx.program;
+    const suffix = Keypair.generate().publicKey.toBase58().slice(0, 8);
+    const urlA = `https://github.com/unit09-labs/url-a-${suffix}`;
+    const urlB = `https://github.com/unit09-labs/url-b-${suffix}`;
+    const urlC = `https://github.com/unit09-labs/url-c-${suffix}`;
+
+    await setEnforceUniqueUrl(true);
+
+    try {
+      const repoA = await registerRepoWithUrlIndex(urlA);
+      const repoB = await registerRepoWithUrlIndex(urlB);
+
+      // Another repository's URL stays taken, and the indexes are required.
+      await expect(updateRepoUrl(repoA, urlA, urlB)).rejects.toThrow(/RepoUrlTaken/);
+      await expect(updateRepoUrl(repoA, urlA, urlC, false)).rejects.toThrow(
+        /MissingRequiredAccount/
+      );
+
+      await updateRepoUrl(repoA, urlA, urlC);
+
+      const indexC = await program.account.repoUrlIndex.fetch(
+        getRepoUrlIndexPda(program.programId, urlC)
+      );
+      expect((indexC.repo as PublicKey).toBase58()).toBe(repoA.toBase58());
+      const indexB = await program.account.repoUrlIndex.fetch(
+        getRepoUrlIndexPda(program.programId, urlB)
+      );
+      expect((indexB.repo as PublicKey).toBase58()).toBe(repoB.toBase58());
+
+      // The released URL can be registered again.
+      expect(
+        await ctx.connection.getAccountInfo(getRepoUrlIndexPda(program.programId, urlA))
+      ).toBeNull();
+      await registerRepoWithUrlIndex(urlA);
+    } finally {
+      await setEnforceUniqueUrl(false);
+    }
+  });
+
+  it("closes a repo and refunds the rent to an explicit treasury", async () => {
+    const program = ctx.program;
+    const connection = program.provider.connection;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-closable",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    // Fund the treasury so it exists as a system account before the refund.
+    const treasury = Keypair.generate();
+    const signature = await connection.requestAirdrop(treasury.publicKey, 1_000_000_000);
+    await connection.confirmTransaction(signature, "confirmed");
+
+    const repoRent = await connection.getBalance(pdas.repo!);
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+    const authorityBefore = await connection.getBalance(ctx.wallet.publicKey);
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+
+    const tx = await program.methods
+      .closeRepo()
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        urlIndex: null,
+        languageStats: null,
+        refundTo: treasury.publicKey,
+      })
+      .rpc();
+
+    expect(tx).toBeTruthy();
+
+    // The repo account is gone and its full rent landed in the treasury.
+    expect(await connection.getAccountInfo(pdas.repo!)).toBeNull();
+    const treasuryAfter = await connection.getBalance(treasury.publicKey);
+    expect(treasuryAfter - treasuryBefore).toBe(repoRent);
+
+    // The signing authority only paid the transaction fee.
+    const authorityAfter = await connection.getBalance(ctx.wallet.publicKey);
+    expect(authorityAfter).toBeLessThanOrEqual(authorityBefore);
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAfter.totalRepos.eq(metricsBefore.totalRepos.subn(1))).toBe(true);
+  });
+
+  it("closes a repo and refunds the rent to the authority by default", async () => {
+    const program = ctx.program;
+    const connection = program.provider.connection;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-closable-default",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const authorityBefore = await connection.getBalance(ctx.wallet.publicKey);
+
+    await program.methods
+      .closeRepo()
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        urlIndex: null,
+        languageStats: null,
+        refundTo: null,
+      })
+      .rpc();
+
+    expect(await connection.getAccountInfo(pdas.repo!)).toBeNull();
+
+    // Rent refund outweighs the transaction fee.
+    const authorityAfter = await connection.getBalance(ctx.wallet.publicKey);
+    expect(authorityAfter).toBeGreaterThan(authorityBefore);
+  });
+
+  it("closes an observed repo together with its observer stats", async () => {
+    const program = ctx.program;
+    const connection = program.provider.connection;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-closable-observed",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const observerStats = getObserverStatsPda(
+      program.programId,
+      pdas.repo!,
+      ctx.wallet.publicKey
+    );
+
+    await program.methods
+      .recordObservation(buildRecordObservationArgs({ linesOfCode: BigInt(500) }))
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        lifecycle: pdas.lifecycle,
+        observer: ctx.wallet.publicKey,
+        observerStats,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    expect(await connection.getAccountInfo(observerStats)).not.toBeNull();
+
+    await program.methods
+      .closeRepo()
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        urlIndex: null,
+        languageStats: null,
+        refundTo: null,
+      })
+      .remainingAccounts([
+        { pubkey: observerStats, isWritable: true, isSigner: false },
+        { pubkey: ctx.wallet.publicKey, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    // Neither the repo nor the observer's stats are left behind.
+    expect(await connection.getAccountInfo(pdas.repo!)).toBeNull();
+    expect(await connection.getAccountInfo(observerStats)).toBeNull();
+  });
+
+  it("rejects more remaining accounts than the cap before reading them", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-over-cap",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    // MAX_REMAINING_ACCOUNTS is 16; these are not even ObserverStats.
+    const tooMany = Array.from({ length: 18 }, () => ({
+      pubkey: Keypair.generate().publicKey,
+      isWritable: true,
+      isSigner: false,
+    }));
+
+    await expect(
+      program.methods
+        .closeRepo()
+        .accounts({
+          authority: ctx.wallet.publicKey,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          urlIndex: null,
+          languageStats: null,
+          refundTo: null,
+        })
+        .remainingAccounts(tooMany)
+        .rpc()
+    ).rejects.toThrow(/TooManyRemainingAccounts/);
+
+    // The repo is untouched.
+    expect(await program.account.repo.fetchNullable(pdas.repo!)).not.toBeNull();
+  });
+
   it("exposes the registration transaction for the canonical repo", () => {
     expect(primaryRepoTx).toBeTruthy();
     if (primaryRepoTx) {